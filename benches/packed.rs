@@ -0,0 +1,90 @@
+//! multiply-adds, acc = acc z + w, over 4096 complex numbers, by `Complex` one at a time
+//! and by `Complex4` and `Complex8` a pack at a time, as an fft or escape-time loop does.
+//! `cargo bench --bench packed`, with `RUSTFLAGS="-C target-cpu=native"` for avx
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use vmath::numbers::{Complex, Complex4, Complex8};
+
+const COUNT: usize = 4096;
+const ROUNDS: usize = 2000;
+
+/// the fastest of several runs of f, which does `ROUNDS` passes
+fn time(mut f: impl FnMut()) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .expect("runs")
+}
+
+fn report(name: &str, elapsed: Duration, baseline: Duration) {
+    let each = elapsed.as_secs_f64() * 1e9 / (COUNT * ROUNDS) as f64;
+    let speedup = baseline.as_secs_f64() / elapsed.as_secs_f64();
+    println!(
+        "{name:>8}: {:>7.2} ms, {each:.3} ns a multiply-add, {speedup:.2}x",
+        elapsed.as_secs_f64() * 1e3
+    );
+}
+
+fn main() {
+    // on the unit circle, so the accumulators grow only linearly
+    let z: Vec<Complex> = (0..COUNT)
+        .map(|i| Complex::from_polar(1.0, i as f64 * 1e-3))
+        .collect();
+    let w: Vec<Complex> = (0..COUNT)
+        .map(|i| Complex::new(1e-3, i as f64 * 1e-6))
+        .collect();
+
+    let mut scalar = vec![Complex::ZERO; COUNT];
+    let scalar_time = time(|| {
+        scalar.fill(Complex::ZERO);
+        for _ in 0..ROUNDS {
+            for ((acc, &z), &w) in scalar.iter_mut().zip(&z).zip(&w) {
+                *acc = *acc * z + w;
+            }
+            black_box(&mut scalar);
+        }
+    });
+
+    let z4: Vec<Complex4> = z.chunks(4).map(Complex4::gather).collect();
+    let w4: Vec<Complex4> = w.chunks(4).map(Complex4::gather).collect();
+    let mut packed4 = vec![Complex4::ZERO; COUNT / 4];
+    let packed4_time = time(|| {
+        packed4.fill(Complex4::ZERO);
+        for _ in 0..ROUNDS {
+            for ((acc, &z), &w) in packed4.iter_mut().zip(&z4).zip(&w4) {
+                *acc = *acc * z + w;
+            }
+            black_box(&mut packed4);
+        }
+    });
+
+    let z8: Vec<Complex8> = z.chunks(8).map(Complex8::gather).collect();
+    let w8: Vec<Complex8> = w.chunks(8).map(Complex8::gather).collect();
+    let mut packed8 = vec![Complex8::ZERO; COUNT / 8];
+    let packed8_time = time(|| {
+        packed8.fill(Complex8::ZERO);
+        for _ in 0..ROUNDS {
+            for ((acc, &z), &w) in packed8.iter_mut().zip(&z8).zip(&w8) {
+                *acc = *acc * z + w;
+            }
+            black_box(&mut packed8);
+        }
+    });
+
+    // the same lanes either way
+    let mut lanes = vec![Complex::ZERO; COUNT];
+    for (pack, out) in packed4.iter().zip(lanes.chunks_mut(4)) {
+        pack.scatter(out);
+    }
+    assert_eq!(lanes, scalar);
+
+    report("Complex", scalar_time, scalar_time);
+    report("Complex4", packed4_time, scalar_time);
+    report("Complex8", packed8_time, scalar_time);
+}
@@ -0,0 +1,99 @@
+//! mathematical constants beyond those in `std::f64::consts`, each the `Real`
+//! nearest its true value, and with the `physics` feature the physical constants
+//! in [`physics`]
+//!
+//! ```
+//! use vmath::consts::{FRAC_1_SQRT_2PI, GOLDEN_RATIO};
+//!
+//! // the standard normal density at its mean
+//! let density = |x: f64| FRAC_1_SQRT_2PI * (-x * x / 2.0).exp();
+//! assert_eq!(density(0.0), 0.3989422804014327);
+//! // the golden ratio is the positive root of x^2 = x + 1
+//! assert!((GOLDEN_RATIO * GOLDEN_RATIO - GOLDEN_RATIO - 1.0).abs() < 1e-15);
+//! ```
+
+use crate::numbers::Real;
+
+/// (1 + sqrt 5) / 2, the ratio of consecutive fibonacci numbers in the limit
+pub const GOLDEN_RATIO: Real = 1.618_033_988_749_895;
+
+/// the euler-mascheroni constant, the limit of the nth harmonic number less ln n,
+/// and minus the digamma function at 1
+pub const EULER_GAMMA: Real = 0.577_215_664_901_532_9;
+
+/// catalan's constant, 1 - 1/3^2 + 1/5^2 - 1/7^2 + ...
+pub const CATALAN: Real = 0.915_965_594_177_219;
+
+/// apéry's constant, zeta(3)
+/// ```
+/// # use vmath::consts::APERY;
+/// # use vmath::special::zeta;
+/// assert!((zeta(3.0) - APERY).abs() < 1e-15);
+/// ```
+pub const APERY: Real = 1.202_056_903_159_594_2;
+
+/// 2 pi, a full turn in radians, the same as `std::f64::consts::TAU`
+pub const TWO_PI: Real = std::f64::consts::TAU;
+
+/// sqrt(pi), the integral of e^(-x^2) over the line
+pub const SQRT_PI: Real = 1.772_453_850_905_516;
+
+/// sqrt(2 pi), the normalization of the gaussian and of stirling's formula
+pub const SQRT_2PI: Real = 2.506_628_274_631_000_7;
+
+/// 1 / sqrt(2 pi), the peak of the standard normal density
+pub const FRAC_1_SQRT_2PI: Real = 0.398_942_280_401_432_7;
+
+/// ln sqrt(2 pi), the constant term of ln gamma's stirling series
+pub const LN_SQRT_2PI: Real = 0.918_938_533_204_672_8;
+
+/// the physical constants of CODATA 2022 in si units, the seven that define the si exactly
+/// and the measured ones to their published digits
+/// ```
+/// # use vmath::consts::physics::*;
+/// // the relations between them hold to the measured digits
+/// assert!((VACUUM_PERMITTIVITY * VACUUM_PERMEABILITY * SPEED_OF_LIGHT.powi(2) - 1.0).abs() < 1e-9);
+/// let alpha = ELEMENTARY_CHARGE.powi(2)
+///     / (4.0 * std::f64::consts::PI * VACUUM_PERMITTIVITY * REDUCED_PLANCK * SPEED_OF_LIGHT);
+/// assert!((alpha / FINE_STRUCTURE - 1.0).abs() < 1e-9);
+/// assert!((GAS_CONSTANT / (AVOGADRO * BOLTZMANN) - 1.0).abs() < 1e-15);
+/// ```
+#[cfg(feature = "physics")]
+pub mod physics {
+    use crate::numbers::Real;
+
+    /// c in m / s, exact
+    pub const SPEED_OF_LIGHT: Real = 299_792_458.0;
+    /// h in J s, exact
+    pub const PLANCK: Real = 6.626_070_15e-34;
+    /// h / 2 pi in J s
+    pub const REDUCED_PLANCK: Real = 1.054_571_817_646_156_5e-34;
+    /// e in C, exact
+    pub const ELEMENTARY_CHARGE: Real = 1.602_176_634e-19;
+    /// k in J / K, exact
+    pub const BOLTZMANN: Real = 1.380_649e-23;
+    /// the avogadro constant in 1 / mol, exact
+    pub const AVOGADRO: Real = 6.022_140_76e23;
+    /// R = avogadro times boltzmann, in J / (mol K)
+    pub const GAS_CONSTANT: Real = 8.314_462_618_153_24;
+    /// sigma = 2 pi^5 k^4 / (15 h^3 c^2), in W / (m^2 K^4)
+    pub const STEFAN_BOLTZMANN: Real = 5.670_374_419_184_429_4e-8;
+    /// G in m^3 / (kg s^2)
+    pub const GRAVITATIONAL: Real = 6.674_30e-11;
+    /// standard gravity, g in m / s^2, exact by definition
+    pub const STANDARD_GRAVITY: Real = 9.806_65;
+    /// the standard atmosphere in Pa, exact by definition
+    pub const STANDARD_ATMOSPHERE: Real = 101_325.0;
+    /// epsilon 0 in F / m
+    pub const VACUUM_PERMITTIVITY: Real = 8.854_187_818_8e-12;
+    /// mu 0 in N / A^2
+    pub const VACUUM_PERMEABILITY: Real = 1.256_637_061_27e-6;
+    /// alpha, dimensionless
+    pub const FINE_STRUCTURE: Real = 7.297_352_564_3e-3;
+    /// in kg
+    pub const ELECTRON_MASS: Real = 9.109_383_713_9e-31;
+    /// in kg
+    pub const PROTON_MASS: Real = 1.672_621_925_95e-27;
+    /// the dalton, a twelfth of the mass of carbon 12, in kg
+    pub const ATOMIC_MASS_UNIT: Real = 1.660_539_068_92e-27;
+}
@@ -0,0 +1,102 @@
+//! rand distributions of complex numbers, with the feature `rand`
+//!
+//! ```
+//! use rand::distr::Distribution;
+//! use rand::rngs::SmallRng;
+//! use rand::SeedableRng;
+//! use vmath::distributions::UnitDisk;
+//! use vmath::numbers::Complex;
+//!
+//! // a quarter of the disk's area is in each quadrant
+//! let mut rng = SmallRng::seed_from_u64(1);
+//! let samples: Vec<Complex> = UnitDisk.sample_iter(&mut rng).take(10_000).collect();
+//! let first = samples.iter().filter(|z| z.real > 0.0 && z.imaginary > 0.0).count();
+//! assert!((first as f64 / 10_000.0 - 0.25).abs() < 0.02);
+//! ```
+
+use std::f64::consts::TAU;
+
+use rand::distr::{Distribution, OpenClosed01};
+use rand::{Rng, RngExt};
+
+use crate::numbers::{Complex, Real};
+
+/// uniform over the open unit disk, |z| < 1, by rejection from the square around it
+/// ```
+/// # use rand::{distr::Distribution, rngs::SmallRng, SeedableRng};
+/// # use vmath::distributions::UnitDisk;
+/// let mut rng = SmallRng::seed_from_u64(2);
+/// // uniform in area, so |z|^2 is uniform on [0, 1) with mean 1 / 2
+/// let mean: f64 = (0..10_000).map(|_| UnitDisk.sample(&mut rng).norm_squared()).sum::<f64>() / 1e4;
+/// assert!((mean - 0.5).abs() < 0.01);
+/// assert!(UnitDisk.sample_iter(&mut rng).take(1000).all(|z| z.norm() < 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitDisk;
+
+impl Distribution<Complex> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex {
+        // the disk is pi / 4 of the square, so this takes 4 / pi tries on average
+        loop {
+            let real = 2.0 * rng.random::<Real>() - 1.0;
+            let imaginary = 2.0 * rng.random::<Real>() - 1.0;
+            let z = Complex::new(real, imaginary);
+            if z.norm_squared() < 1.0 {
+                return z;
+            }
+        }
+    }
+}
+
+/// uniform over the unit circle, e^(i theta) for theta uniform on [0, 2 pi)
+/// ```
+/// # use rand::{distr::Distribution, rngs::SmallRng, SeedableRng};
+/// # use vmath::distributions::UnitCircle;
+/// # use vmath::numbers::Complex;
+/// let mut rng = SmallRng::seed_from_u64(3);
+/// let samples: Vec<Complex> = UnitCircle.sample_iter(&mut rng).take(10_000).collect();
+/// assert!(samples.iter().all(|z| (z.norm() - 1.0).abs() < 1e-15));
+/// let mean: Complex = samples.iter().sum::<Complex>() / 1e4;
+/// assert!(mean.norm() < 0.02);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitCircle;
+
+impl Distribution<Complex> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex {
+        let theta = TAU * rng.random::<Real>();
+        Complex::from_polar(1.0, theta)
+    }
+}
+
+/// the standard complex normal, circularly symmetric with E|z|^2 = 1,
+/// so the real and imaginary parts are independent normals of variance 1 / 2.
+/// drawn by box-muller, as |z|^2 is exponential with mean 1 and the angle uniform
+/// ```
+/// # use rand::{distr::Distribution, rngs::SmallRng, SeedableRng};
+/// # use vmath::distributions::StandardComplexNormal;
+/// # use vmath::numbers::Complex;
+/// let mut rng = SmallRng::seed_from_u64(4);
+/// let samples: Vec<Complex> = StandardComplexNormal.sample_iter(&mut rng).take(10_000).collect();
+/// let power = samples.iter().map(|z| z.norm_squared()).sum::<f64>() / 1e4;
+/// assert!((power - 1.0).abs() < 0.05);
+/// let variance = samples.iter().map(|z| z.real * z.real).sum::<f64>() / 1e4;
+/// assert!((variance - 0.5).abs() < 0.03);
+/// // E z^2 is zero for a circular distribution, unlike a real normal on the diagonal
+/// let pseudo: Complex = samples.iter().map(|&z| z * z).sum::<Complex>() / 1e4;
+/// assert!(pseudo.norm() < 0.05);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StandardComplexNormal;
+
+impl Distribution<Complex> for StandardComplexNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex {
+        // u in (0, 1], so the logarithm is finite
+        let u: Real = rng.sample(OpenClosed01);
+        let r = (-u.ln()).sqrt();
+        UnitCircle.sample(rng) * r
+    }
+}
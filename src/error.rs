@@ -0,0 +1,66 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// why an algorithm gave no answer, returned where the alternative would be
+/// a result poisoned with NaN or infinities
+/// ```
+/// # use vmath::linalg::Mat2;
+/// # use vmath::Error;
+/// let singular = Mat2::new([[1.0, 2.0], [2.0, 4.0]]);
+/// assert_eq!(singular.inverse(), Err(Error::Singular));
+/// assert_eq!(Error::Singular.to_string(), "the matrix is singular to working precision");
+/// ```
+pub enum Error {
+    /// a quantity that had to be divided by was zero, such as the length
+    /// of something being normalized
+    DivisionByZero,
+    /// the matrix has no inverse, to within rounding
+    Singular,
+    /// an iteration ran out of steps before reaching its tolerance
+    DidNotConverge,
+    /// the input is outside the set the result is defined on
+    DomainError,
+    /// the operands' dimensions do not fit together
+    ShapeMismatch,
+    /// quantities of different physical dimensions were added or compared,
+    /// or one was converted to a unit of another dimension
+    DimensionMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DivisionByZero => "division by zero",
+            Self::Singular => "the matrix is singular to working precision",
+            Self::DidNotConverge => "the iteration did not converge",
+            Self::DomainError => "the input is outside the domain",
+            Self::ShapeMismatch => "the shapes do not fit together",
+            Self::DimensionMismatch => "the physical dimensions differ",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// why a formula, or a number like a `Complex`, `BigInt`, `BigRational`, or
+/// `Decimal`, could not be parsed, and the byte offset where it went wrong
+/// ```
+/// # use vmath::numbers::Decimal;
+/// # use vmath::ParseError;
+/// let error: ParseError = "1.2.3".parse::<Decimal>().unwrap_err();
+/// assert_eq!(error.position, 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
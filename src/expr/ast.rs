@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::numbers::Real;
+
+/// a parsed formula, built by `Expr::parse` or by hand
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr {
+    Number(Real),
+    /// a literal times i, as in 2i
+    Imaginary(Real),
+    Variable(String),
+    Negate(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Subtract(Box<Expr>, Box<Expr>),
+    Multiply(Box<Expr>, Box<Expr>),
+    Divide(Box<Expr>, Box<Expr>),
+    Power(Box<Expr>, Box<Expr>),
+    Call(Function, Box<Expr>),
+}
+
+/// the elementary functions a formula can call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Exp,
+    /// the natural logarithm, also spelled log
+    Ln,
+    Sqrt,
+}
+
+impl Function {
+    pub const ALL: [Function; 9] = [
+        Self::Sin,
+        Self::Cos,
+        Self::Tan,
+        Self::Sinh,
+        Self::Cosh,
+        Self::Tanh,
+        Self::Exp,
+        Self::Ln,
+        Self::Sqrt,
+    ];
+
+    /// ```
+    /// # use vmath::expr::Function;
+    /// assert_eq!(Function::from_name("log"), Some(Function::Ln));
+    /// assert_eq!(Function::from_name("sec"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "log" => Some(Self::Ln),
+            _ => Self::ALL
+                .into_iter()
+                .find(|function| function.name() == name),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Sinh => "sinh",
+            Self::Cosh => "cosh",
+            Self::Tanh => "tanh",
+            Self::Exp => "exp",
+            Self::Ln => "ln",
+            Self::Sqrt => "sqrt",
+        }
+    }
+}
+
+impl Expr {
+    /// the names of the variables, sorted and without repeats
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// let e = Expr::parse("y * sin(x) + x").unwrap();
+    /// assert_eq!(e.variables(), ["x", "y"]);
+    /// ```
+    pub fn variables(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_variables(&mut names);
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// whether the variable appears anywhere in the expression
+    pub fn depends_on(&self, variable: &str) -> bool {
+        match self {
+            Self::Number(_) | Self::Imaginary(_) => false,
+            Self::Variable(name) => name == variable,
+            Self::Negate(e) | Self::Call(_, e) => e.depends_on(variable),
+            Self::Add(a, b)
+            | Self::Subtract(a, b)
+            | Self::Multiply(a, b)
+            | Self::Divide(a, b)
+            | Self::Power(a, b) => a.depends_on(variable) || b.depends_on(variable),
+        }
+    }
+
+    fn collect_variables<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Number(_) | Self::Imaginary(_) => {}
+            Self::Variable(name) => names.push(name),
+            Self::Negate(e) | Self::Call(_, e) => e.collect_variables(names),
+            Self::Add(a, b)
+            | Self::Subtract(a, b)
+            | Self::Multiply(a, b)
+            | Self::Divide(a, b)
+            | Self::Power(a, b) => {
+                a.collect_variables(names);
+                b.collect_variables(names);
+            }
+        }
+    }
+
+    /// how tightly the expression binds, for placing parentheses when printing
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Add(..) | Self::Subtract(..) => 1,
+            Self::Multiply(..) | Self::Divide(..) => 2,
+            Self::Negate(_) => 3,
+            Self::Power(..) => 4,
+            // a negative literal prints with its sign, so it groups like a negation
+            Self::Number(x) | Self::Imaginary(x) if x.is_sign_negative() => 3,
+            _ => 5,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// with only the parentheses the precedence needs, so parsing it gives it back
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// let e = Expr::parse("((x + 1)) * -(2) ^ y - (a - b) - 2.5i").unwrap();
+    /// assert_eq!(e.to_string(), "(x + 1) * -2 ^ y - (a - b) - 2.5i");
+    /// assert_eq!(Expr::parse(&e.to_string()).unwrap(), e);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // operands binding less tightly than the operator need parentheses,
+        // as do those binding as tightly on the side the operator does not group from
+        let operand = |f: &mut fmt::Formatter<'_>, e: &Expr, min: u8| {
+            if e.precedence() < min {
+                write!(f, "({e})")
+            } else {
+                write!(f, "{e}")
+            }
+        };
+        let binary = |f: &mut fmt::Formatter<'_>, a, symbol, b, left, right| {
+            operand(f, a, left)?;
+            write!(f, " {symbol} ")?;
+            operand(f, b, right)
+        };
+        let p = self.precedence();
+        match self {
+            Self::Number(x) => write!(f, "{x}"),
+            Self::Imaginary(x) => write!(f, "{x}i"),
+            Self::Variable(name) => f.write_str(name),
+            Self::Negate(e) => {
+                f.write_str("-")?;
+                operand(f, e, p)
+            }
+            Self::Add(a, b) => binary(f, a, "+", b, p, p + 1),
+            Self::Subtract(a, b) => binary(f, a, "-", b, p, p + 1),
+            Self::Multiply(a, b) => binary(f, a, "*", b, p, p + 1),
+            Self::Divide(a, b) => binary(f, a, "/", b, p, p + 1),
+            // right associative, and the exponent may carry a sign
+            Self::Power(a, b) => binary(f, a, "^", b, p + 1, p - 1),
+            Self::Call(function, e) => write!(f, "{}({e})", function.name()),
+        }
+    }
+}
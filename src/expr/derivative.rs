@@ -0,0 +1,154 @@
+use crate::expr::{Expr, Function};
+use crate::numbers::Real;
+
+impl Expr {
+    /// the derivative with respect to the variable, by the sum, product, quotient,
+    /// power, and chain rules, with constants folded and zero and one terms dropped
+    /// but no further simplification
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// let e = Expr::parse("x^3 + 2*x - 7").unwrap();
+    /// assert_eq!(e.derivative("x").to_string(), "3 * x ^ 2 + 2");
+    ///
+    /// let e = Expr::parse("sin(x^2)").unwrap();
+    /// assert_eq!(e.derivative("x").to_string(), "cos(x ^ 2) * (2 * x)");
+    ///
+    /// // the derivative agrees with a finite difference
+    /// let e = Expr::parse("exp(-t) * ln(t) / sqrt(t)").unwrap();
+    /// let d = e.derivative("t");
+    /// let (t, h) = (1.7, 1e-6);
+    /// let estimate = (e.eval(&[("t", t + h)]).unwrap() - e.eval(&[("t", t - h)]).unwrap()) / (2.0 * h);
+    /// assert!((d.eval(&[("t", t)]).unwrap() - estimate).abs() < 1e-8);
+    /// ```
+    pub fn derivative(&self, variable: &str) -> Expr {
+        if !self.depends_on(variable) {
+            return Expr::Number(0.0);
+        }
+        let d = |e: &Expr| e.derivative(variable);
+        match self {
+            Self::Number(_) | Self::Imaginary(_) => Expr::Number(0.0),
+            // depends_on has ruled out every other variable
+            Self::Variable(_) => Expr::Number(1.0),
+            Self::Negate(e) => negate(d(e)),
+            Self::Add(a, b) => add(d(a), d(b)),
+            Self::Subtract(a, b) => subtract(d(a), d(b)),
+            Self::Multiply(a, b) => {
+                add(multiply(d(a), (**b).clone()), multiply((**a).clone(), d(b)))
+            }
+            Self::Divide(a, b) => divide(
+                subtract(multiply(d(a), (**b).clone()), multiply((**a).clone(), d(b))),
+                power((**b).clone(), Expr::Number(2.0)),
+            ),
+            Self::Power(a, b) if !b.depends_on(variable) => multiply(
+                multiply(
+                    (**b).clone(),
+                    power((**a).clone(), subtract((**b).clone(), Expr::Number(1.0))),
+                ),
+                d(a),
+            ),
+            // a^b (b' ln a + b a' / a)
+            Self::Power(a, b) => multiply(
+                self.clone(),
+                add(
+                    multiply(d(b), call(Function::Ln, (**a).clone())),
+                    divide(multiply((**b).clone(), d(a)), (**a).clone()),
+                ),
+            ),
+            Self::Call(function, e) => {
+                let e = (**e).clone();
+                let outer = match function {
+                    Function::Sin => call(Function::Cos, e),
+                    Function::Cos => negate(call(Function::Sin, e)),
+                    Function::Tan => divide(
+                        Expr::Number(1.0),
+                        power(call(Function::Cos, e), Expr::Number(2.0)),
+                    ),
+                    Function::Sinh => call(Function::Cosh, e),
+                    Function::Cosh => call(Function::Sinh, e),
+                    Function::Tanh => divide(
+                        Expr::Number(1.0),
+                        power(call(Function::Cosh, e), Expr::Number(2.0)),
+                    ),
+                    Function::Exp => self.clone(),
+                    Function::Ln => divide(Expr::Number(1.0), e),
+                    Function::Sqrt => {
+                        divide(Expr::Number(1.0), multiply(Expr::Number(2.0), self.clone()))
+                    }
+                };
+                multiply(outer, d(self.argument()))
+            }
+        }
+    }
+
+    fn argument(&self) -> &Expr {
+        match self {
+            Self::Call(_, e) => e,
+            _ => unreachable!("only calls have an argument"),
+        }
+    }
+}
+
+fn number(e: &Expr) -> Option<Real> {
+    match e {
+        Expr::Number(x) => Some(*x),
+        _ => None,
+    }
+}
+
+fn negate(e: Expr) -> Expr {
+    match e {
+        Expr::Number(x) => Expr::Number(-x),
+        Expr::Negate(e) => *e,
+        e => Expr::Negate(Box::new(e)),
+    }
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    match (number(&a), number(&b)) {
+        (Some(x), Some(y)) => Expr::Number(x + y),
+        (Some(0.0), _) => b,
+        (_, Some(0.0)) => a,
+        _ => Expr::Add(Box::new(a), Box::new(b)),
+    }
+}
+
+fn subtract(a: Expr, b: Expr) -> Expr {
+    match (number(&a), number(&b)) {
+        (Some(x), Some(y)) => Expr::Number(x - y),
+        (Some(0.0), _) => negate(b),
+        (_, Some(0.0)) => a,
+        _ => Expr::Subtract(Box::new(a), Box::new(b)),
+    }
+}
+
+fn multiply(a: Expr, b: Expr) -> Expr {
+    match (number(&a), number(&b)) {
+        (Some(x), Some(y)) => Expr::Number(x * y),
+        (Some(0.0), _) | (_, Some(0.0)) => Expr::Number(0.0),
+        (Some(1.0), _) => b,
+        (_, Some(1.0)) => a,
+        (Some(-1.0), _) => negate(b),
+        (_, Some(-1.0)) => negate(a),
+        _ => Expr::Multiply(Box::new(a), Box::new(b)),
+    }
+}
+
+fn divide(a: Expr, b: Expr) -> Expr {
+    match (number(&a), number(&b)) {
+        (Some(0.0), _) => Expr::Number(0.0),
+        (_, Some(1.0)) => a,
+        _ => Expr::Divide(Box::new(a), Box::new(b)),
+    }
+}
+
+fn power(a: Expr, b: Expr) -> Expr {
+    match number(&b) {
+        Some(0.0) => Expr::Number(1.0),
+        Some(1.0) => a,
+        _ => Expr::Power(Box::new(a), Box::new(b)),
+    }
+}
+
+fn call(function: Function, e: Expr) -> Expr {
+    Expr::Call(function, Box::new(e))
+}
@@ -0,0 +1,197 @@
+use crate::error::Error;
+use crate::expr::{Expr, Function};
+use crate::numbers::{Complex, Real};
+
+/// what an expression can be evaluated over
+trait Value: Copy {
+    fn from_real(x: Real) -> Self;
+
+    /// a literal times i, which only some values can hold
+    fn imaginary(x: Real) -> Result<Self, Error>;
+
+    fn negate(self) -> Self;
+    fn add(self, rhs: Self) -> Self;
+    fn subtract(self, rhs: Self) -> Self;
+    fn multiply(self, rhs: Self) -> Self;
+    fn divide(self, rhs: Self) -> Self;
+    fn power(self, rhs: Self) -> Self;
+    fn call(self, function: Function) -> Self;
+}
+
+impl Value for Real {
+    fn from_real(x: Real) -> Self {
+        x
+    }
+
+    fn imaginary(_: Real) -> Result<Self, Error> {
+        Err(Error::DomainError)
+    }
+
+    fn negate(self) -> Self {
+        -self
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn subtract(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn multiply(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn divide(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    fn power(self, rhs: Self) -> Self {
+        self.powf(rhs)
+    }
+
+    fn call(self, function: Function) -> Self {
+        match function {
+            Function::Sin => self.sin(),
+            Function::Cos => self.cos(),
+            Function::Tan => self.tan(),
+            Function::Sinh => self.sinh(),
+            Function::Cosh => self.cosh(),
+            Function::Tanh => self.tanh(),
+            Function::Exp => self.exp(),
+            Function::Ln => self.ln(),
+            Function::Sqrt => self.sqrt(),
+        }
+    }
+}
+
+impl Value for Complex {
+    fn from_real(x: Real) -> Self {
+        Complex::from(x)
+    }
+
+    fn imaginary(x: Real) -> Result<Self, Error> {
+        Ok(Complex::new(0.0, x))
+    }
+
+    fn negate(self) -> Self {
+        -self
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn subtract(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn multiply(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn divide(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// exact by repeated squaring for whole powers
+    fn power(self, rhs: Self) -> Self {
+        if rhs.imaginary == 0.0 && rhs.real.fract() == 0.0 && rhs.real.abs() <= 64.0 {
+            self.powi(rhs.real as i32)
+        } else {
+            self.powc(rhs)
+        }
+    }
+
+    fn call(self, function: Function) -> Self {
+        match function {
+            Function::Sin => self.sin(),
+            Function::Cos => self.cos(),
+            Function::Tan => self.tan(),
+            Function::Sinh => self.sinh(),
+            Function::Cosh => self.cosh(),
+            Function::Tanh => self.tanh(),
+            Function::Exp => self.exp(),
+            Function::Ln => self.ln(),
+            Function::Sqrt => self.sqrt(),
+        }
+    }
+}
+
+impl Expr {
+    /// the value over the reals, with each variable bound by name,
+    /// a `DomainError` for an unbound variable or an imaginary literal.
+    /// outside a function's domain the result is nan, as for `f64`
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// let e = Expr::parse("3*sin(x) + 2^y").unwrap();
+    /// let value = e.eval(&[("x", 0.5), ("y", 3.0)]).unwrap();
+    /// assert_eq!(value, 3.0 * 0.5f64.sin() + 8.0);
+    /// assert!(e.eval(&[("x", 0.5)]).is_err());
+    /// ```
+    pub fn eval(&self, variables: &[(&str, Real)]) -> Result<Real, Error> {
+        self.eval_over(variables)
+    }
+
+    /// the value over the complex numbers, on the principal branches of
+    /// ln, sqrt, and powers, a `DomainError` for an unbound variable
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// # use vmath::numbers::Complex;
+    /// let e = Expr::parse("3*sin(x) + (2+1i)*exp(-t)").unwrap();
+    /// let x = Complex::from(0.5);
+    /// let t = Complex::new(0.0, std::f64::consts::FRAC_PI_2);
+    /// let value = e.eval_complex(&[("x", x), ("t", t)]).unwrap();
+    /// // exp(-i pi / 2) is -i, and (2 + i) (-i) is 1 - 2i
+    /// assert!((value.real - (3.0 * 0.5f64.sin() + 1.0)).abs() < 1e-12);
+    /// assert!((value.imaginary + 2.0).abs() < 1e-12);
+    ///
+    /// let root = Expr::parse("sqrt(z)").unwrap();
+    /// let i = root.eval_complex(&[("z", Complex::from(-1.0))]).unwrap();
+    /// assert_eq!(i, Complex::new(0.0, 1.0));
+    /// ```
+    pub fn eval_complex(&self, variables: &[(&str, Complex)]) -> Result<Complex, Error> {
+        self.eval_over(variables)
+    }
+
+    /// a function of the one variable for the crate's solvers and integrators,
+    /// a `DomainError` if anything else is unbound or there is an imaginary literal,
+    /// so evaluating it cannot fail
+    /// ```
+    /// # use vmath::expr::Expr;
+    /// let e = Expr::parse("x^2 - 2").unwrap();
+    /// let f = e.as_function("x").unwrap();
+    /// assert_eq!(f(3.0), 7.0);
+    /// assert!(Expr::parse("x + y").unwrap().as_function("x").is_err());
+    /// ```
+    pub fn as_function(&self, variable: &str) -> Result<impl Fn(Real) -> Real + '_, Error> {
+        self.eval(&[(variable, 0.0)])?;
+        let variable = variable.to_owned();
+        Ok(move |x| {
+            self.eval(&[(&variable, x)])
+                .expect("checked when the function was made")
+        })
+    }
+
+    /// recursing once a level of the tree, which `Expr::parse` keeps shallow
+    fn eval_over<T: Value>(&self, variables: &[(&str, T)]) -> Result<T, Error> {
+        let eval = |e: &Expr| e.eval_over(variables);
+        Ok(match self {
+            Self::Number(x) => T::from_real(*x),
+            Self::Imaginary(x) => T::imaginary(*x)?,
+            Self::Variable(name) => variables
+                .iter()
+                .find(|(bound, _)| bound == name)
+                .map(|&(_, value)| value)
+                .ok_or(Error::DomainError)?,
+            Self::Negate(e) => eval(e)?.negate(),
+            Self::Add(a, b) => eval(a)?.add(eval(b)?),
+            Self::Subtract(a, b) => eval(a)?.subtract(eval(b)?),
+            Self::Multiply(a, b) => eval(a)?.multiply(eval(b)?),
+            Self::Divide(a, b) => eval(a)?.divide(eval(b)?),
+            Self::Power(a, b) => eval(a)?.power(eval(b)?),
+            Self::Call(function, e) => eval(e)?.call(*function),
+        })
+    }
+}
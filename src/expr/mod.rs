@@ -0,0 +1,22 @@
+//! formulas parsed from strings, like `3*sin(x) + (2+1i)*exp(-t)`,
+//! evaluated over reals or complex numbers and differentiated symbolically
+//! ```
+//! use vmath::expr::Expr;
+//!
+//! let f = Expr::parse("x^2 - 2").unwrap();
+//! let df = f.derivative("x");
+//! // newton's method for the square root of 2
+//! let mut x = 1.0;
+//! for _ in 0..6 {
+//!     x -= f.eval(&[("x", x)]).unwrap() / df.eval(&[("x", x)]).unwrap();
+//! }
+//! assert!((x - 2f64.sqrt()).abs() < 1e-15);
+//! ```
+
+pub mod ast;
+pub mod derivative;
+pub mod eval;
+pub mod parse;
+
+pub use crate::error::ParseError;
+pub use crate::expr::ast::{Expr, Function};
@@ -0,0 +1,316 @@
+use std::str::FromStr;
+
+pub use crate::error::ParseError;
+use crate::expr::{Expr, Function};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Real),
+    Imaginary(Real),
+    Identifier(String),
+    Symbol(char),
+    End,
+}
+
+/// splits the source into tokens, each with the offset it starts at
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == b'.' {
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                i += 1;
+            }
+            // an exponent only if digits follow, so 2e is 2 times e
+            if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                let mut j = i + 1;
+                if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j].is_ascii_digit() {
+                    i = j;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let value = source[start..i].parse().map_err(|_| ParseError {
+                position: start,
+                message: "malformed number",
+            })?;
+            let identifier = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+            if i < bytes.len()
+                && bytes[i] == b'i'
+                && !bytes.get(i + 1).copied().is_some_and(identifier)
+            {
+                i += 1;
+                tokens.push((Token::Imaginary(value), start));
+            } else {
+                tokens.push((Token::Number(value), start));
+            }
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            tokens.push((Token::Identifier(source[start..i].to_owned()), start));
+        } else if b"+-*/^()".contains(&c) {
+            i += 1;
+            tokens.push((Token::Symbol(c as char), start));
+        } else {
+            return Err(ParseError {
+                position: start,
+                message: "unexpected character",
+            });
+        }
+    }
+    tokens.push((Token::End, source.len()));
+    Ok(tokens)
+}
+
+/// the deepest tree `Expr::parse` builds, each term of a sum or product a level
+/// below the next, and the deepest it nests parentheses and signs,
+/// so that evaluating, differentiating, printing, and dropping the tree,
+/// which recurse once a level, stay well within a thread's stack
+const MAX_DEPTH: usize = 256;
+
+/// a parsed expression and the depth of its tree
+type Parsed = Result<(Expr, usize), ParseError>;
+
+/// recursive descent, from the loosest binding operators to the tightest
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    next: usize,
+    /// how many parentheses, signs, and exponents the descent is inside
+    nesting: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.next].1
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError {
+            position: self.position(),
+            message,
+        }
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.next].0.clone();
+        if token != Token::End {
+            self.next += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if *self.peek() == Token::Symbol(symbol) {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char, message: &'static str) -> Result<(), ParseError> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// parse inside one more level of nesting, an error past `MAX_DEPTH`
+    /// before the descent can recurse far enough to overflow the stack
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Parsed) -> Parsed {
+        if self.nesting == MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.nesting += 1;
+        let parsed = parse(self);
+        self.nesting -= 1;
+        parsed
+    }
+
+    /// e over operands of the given depth, an error past `MAX_DEPTH`
+    fn node(&self, e: Expr, operand_depth: usize) -> Parsed {
+        if operand_depth == MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        Ok((e, operand_depth + 1))
+    }
+
+    fn binary(
+        &self,
+        make: fn(Box<Expr>, Box<Expr>) -> Expr,
+        (a, a_depth): (Expr, usize),
+        (b, b_depth): (Expr, usize),
+    ) -> Parsed {
+        self.node(make(Box::new(a), Box::new(b)), a_depth.max(b_depth))
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Parsed {
+        let mut e = self.product()?;
+        loop {
+            let make = if self.eat('+') {
+                Expr::Add
+            } else if self.eat('-') {
+                Expr::Subtract
+            } else {
+                return Ok(e);
+            };
+            let rhs = self.product()?;
+            e = self.binary(make, e, rhs)?;
+        }
+    }
+
+    /// product := unary (('*' | '/') unary)*
+    fn product(&mut self) -> Parsed {
+        let mut e = self.unary()?;
+        loop {
+            let make = if self.eat('*') {
+                Expr::Multiply
+            } else if self.eat('/') {
+                Expr::Divide
+            } else {
+                return Ok(e);
+            };
+            let rhs = self.unary()?;
+            e = self.binary(make, e, rhs)?;
+        }
+    }
+
+    /// unary := ('-' | '+') unary | power, so -x^2 is -(x^2)
+    fn unary(&mut self) -> Parsed {
+        if self.eat('-') {
+            let (e, depth) = self.nested(Self::unary)?;
+            self.node(Expr::Negate(Box::new(e)), depth)
+        } else if self.eat('+') {
+            self.nested(Self::unary)
+        } else {
+            self.power()
+        }
+    }
+
+    /// power := atom ('^' unary)?, right associative
+    fn power(&mut self) -> Parsed {
+        let base = self.atom()?;
+        if self.eat('^') {
+            let exponent = self.nested(Self::unary)?;
+            self.binary(Expr::Power, base, exponent)
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// atom := number | function '(' sum ')' | constant | variable | '(' sum ')'
+    fn atom(&mut self) -> Parsed {
+        let position = self.position();
+        match self.advance() {
+            Token::Number(x) => Ok((Expr::Number(x), 1)),
+            Token::Imaginary(x) => Ok((Expr::Imaginary(x), 1)),
+            Token::Symbol('(') => {
+                let e = self.nested(Self::sum)?;
+                self.expect(')', "expected a closing parenthesis")?;
+                Ok(e)
+            }
+            Token::Identifier(name) => {
+                if *self.peek() == Token::Symbol('(') {
+                    let function = Function::from_name(&name).ok_or(ParseError {
+                        position,
+                        message: "unknown function",
+                    })?;
+                    self.next += 1;
+                    let (argument, depth) = self.nested(Self::sum)?;
+                    self.expect(')', "expected a closing parenthesis")?;
+                    return self.node(Expr::Call(function, Box::new(argument)), depth);
+                }
+                if Function::from_name(&name).is_some() {
+                    return Err(ParseError {
+                        position,
+                        message: "expected an argument in parentheses",
+                    });
+                }
+                let e = match name.as_str() {
+                    "pi" => Expr::Number(std::f64::consts::PI),
+                    "e" => Expr::Number(std::f64::consts::E),
+                    "i" => Expr::Imaginary(1.0),
+                    _ => Expr::Variable(name),
+                };
+                Ok((e, 1))
+            }
+            Token::End => Err(ParseError {
+                position,
+                message: "unexpected end of input",
+            }),
+            Token::Symbol(_) => Err(ParseError {
+                position,
+                message: "expected a number, variable, or parenthesis",
+            }),
+        }
+    }
+}
+
+impl Expr {
+    /// parses a formula of numbers, variables, + - * / ^, parentheses, and calls of
+    /// sin, cos, tan, sinh, cosh, tanh, exp, ln or log, and sqrt.
+    /// a number followed by i is imaginary, and pi, e, and i are constants.
+    /// ^ binds tightest and groups to the right, so -x^2 is -(x^2).
+    /// the tree is at most 256 deep, each term of a sum or product one level
+    /// below the next, and nests at most 256 parentheses, signs, and exponents,
+    /// so a formula from an untrusted source cannot overflow the stack
+    /// ```
+    /// # use vmath::expr::{Expr, Function};
+    /// let e = Expr::parse("3*sin(x) + (2+1i)*exp(-t)").unwrap();
+    /// assert_eq!(e.variables(), ["t", "x"]);
+    /// assert_eq!(e.to_string(), "3 * sin(x) + (2 + 1i) * exp(-t)");
+    ///
+    /// let e: Expr = "2^3^2".parse().unwrap();
+    /// assert_eq!(e.eval(&[]).unwrap(), 512.0);
+    ///
+    /// let error = Expr::parse("1 + cot(x)").unwrap_err();
+    /// assert_eq!(error.position, 4);
+    /// assert!(Expr::parse("(x + 1").is_err());
+    ///
+    /// let deep = ["(".repeat(20000) + "1" + &")".repeat(20000), "-".repeat(20000) + "1",
+    ///     "2^".repeat(20000) + "1", "x+".repeat(100000) + "x"];
+    /// for source in deep {
+    ///     assert_eq!(Expr::parse(&source).unwrap_err().message, "nested too deeply");
+    /// }
+    /// let long = "x+".repeat(255) + "x";
+    /// assert_eq!(Expr::parse(&long).unwrap().eval(&[("x", 1.0)]).unwrap(), 256.0);
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            next: 0,
+            nesting: 0,
+        };
+        let (e, _) = parser.sum()?;
+        match parser.peek() {
+            Token::End => Ok(e),
+            Token::Symbol(')') => Err(parser.error("unmatched closing parenthesis")),
+            _ => Err(parser.error("expected an operator")),
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, ParseError> {
+        Self::parse(source)
+    }
+}
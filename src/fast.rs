@@ -0,0 +1,268 @@
+//! approximations of the elementary functions that give up some digits for speed,
+//! for graphics, audio, and simulation code where an error near 1e-9 is invisible.
+//! each has its worst error documented, measured against the standard library
+//! over its range, and handles the special values as it does.
+//! they have no calls or table lookups, so loops over slices of them vectorize;
+//! built for x86_64 with avx2 they run two to three times as fast as the standard
+//! functions, less without vector instructions, and `fast_rsqrt` only gains
+//! where the hardware square root and division are slow.
+//! [`Accuracy`] picks between them and the standard functions at run time
+//!
+//! ```
+//! use vmath::fast::{fast_exp, fast_sin, Accuracy};
+//!
+//! // a decaying oscillator, sampled cheaply
+//! let sample = |t: f64| fast_exp(-0.5 * t) * fast_sin(40.0 * t);
+//! let exact = |t: f64| (-0.5 * t).exp() * (40.0 * t).sin();
+//! assert!((0..1000).all(|i| (sample(i as f64 * 1e-3) - exact(i as f64 * 1e-3)).abs() < 1e-8));
+//! // or chosen by a setting
+//! let accuracy = Accuracy::Fast;
+//! assert!((accuracy.exp(1.0) - std::f64::consts::E).abs() < 1e-8);
+//! ```
+
+use std::f64::consts::{FRAC_2_PI, LOG2_E};
+
+use crate::numbers::Real;
+
+/// pi / 2 split in two, the first with its low bits zero so k times it is exact
+/// for the k that come up, the cody-waite reduction of fdlibm
+const FRAC_PI_2_HI: Real = 1.570_796_326_734_125_6;
+const FRAC_PI_2_LO: Real = 6.077_100_506_506_192e-11;
+
+/// ln 2 split the same way
+const LN_2_HI: Real = 6.931_471_803_691_238e-1;
+const LN_2_LO: Real = 1.908_214_929_270_587_7e-10;
+
+/// past this the two part reduction loses digits, and sin and cos are left to std
+const REDUCTION_LIMIT: Real = 1e6;
+
+/// ln of the largest real and of the smallest normal one, where exp overflows and flushes
+const EXP_OVERFLOW: Real = 709.782_712_893_384;
+const EXP_UNDERFLOW: Real = -708.396_418_532_264_1;
+
+/// 1.5 2^52, which added to a real below 2^51 in magnitude leaves no bits below the point,
+/// so adding and subtracting it rounds to the nearest whole number without a call
+const SHIFTER: Real = 6_755_399_441_055_744.0;
+
+/// the newton steps after rsqrt's estimate, each squaring its relative error,
+/// from 3e-2 to 2e-3, 5e-6, and 3e-11
+const NEWTON_STEPS: usize = 3;
+
+/// the standard library's functions or these approximations, chosen at run time,
+/// by a setting say, rather than at each call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Accuracy {
+    /// the standard library, correct to an ulp or so
+    #[default]
+    Full,
+    /// `fast_sin` and the rest, to their documented errors
+    Fast,
+}
+
+impl Accuracy {
+    #[inline]
+    pub fn sin(self, x: Real) -> Real {
+        match self {
+            Self::Full => x.sin(),
+            Self::Fast => fast_sin(x),
+        }
+    }
+
+    #[inline]
+    pub fn cos(self, x: Real) -> Real {
+        match self {
+            Self::Full => x.cos(),
+            Self::Fast => fast_cos(x),
+        }
+    }
+
+    #[inline]
+    pub fn exp(self, x: Real) -> Real {
+        match self {
+            Self::Full => x.exp(),
+            Self::Fast => fast_exp(x),
+        }
+    }
+
+    /// 1 / sqrt(x)
+    #[inline]
+    pub fn rsqrt(self, x: Real) -> Real {
+        match self {
+            Self::Full => 1.0 / x.sqrt(),
+            Self::Fast => fast_rsqrt(x),
+        }
+    }
+}
+
+/// sin x by a reduction to [-pi/4, pi/4] and a taylor polynomial there, with
+/// absolute error below 2e-10 everywhere and relative error below 2e-10 wherever
+/// |sin x| >= 1e-10. nearer the zeros at k pi, other than 0, the rounding of the
+/// reduction x - k pi / 2 outweighs the result and only the absolute bound holds.
+/// beyond |x| = 1e6 it is std's sin
+/// ```
+/// # use vmath::fast::fast_sin;
+/// let relative = |x: f64| (fast_sin(x) / x.sin() - 1.0).abs();
+/// assert_eq!(fast_sin(0.0), 0.0);
+/// assert!(relative(1.0) < 2e-10);
+/// assert!(relative(-1000.0) < 2e-10);
+/// assert!(relative(1e-300) < 2e-10);
+/// // sin of the real nearest pi is about 1.2e-16, where only the absolute bound holds
+/// assert!((fast_sin(std::f64::consts::PI) - std::f64::consts::PI.sin()).abs() < 2e-10);
+/// assert!(relative(std::f64::consts::PI + 1e-9) < 2e-10);
+/// assert!(fast_sin(f64::INFINITY).is_nan());
+/// ```
+#[inline]
+pub fn fast_sin(x: Real) -> Real {
+    if x.abs() >= REDUCTION_LIMIT || x.is_nan() {
+        return x.sin();
+    }
+    let (quadrant, r) = reduce(x);
+    match quadrant & 3 {
+        0 => sin_polynomial(r),
+        1 => cos_polynomial(r),
+        2 => -sin_polynomial(r),
+        _ => -cos_polynomial(r),
+    }
+}
+
+/// cos x with absolute error below 2e-10 everywhere and relative error below
+/// 2e-10 wherever |cos x| >= 1e-10, so away from the zeros at pi / 2 + k pi, as `fast_sin`
+/// ```
+/// # use vmath::fast::fast_cos;
+/// let relative = |x: f64| (fast_cos(x) / x.cos() - 1.0).abs();
+/// assert_eq!(fast_cos(0.0), 1.0);
+/// assert!(relative(2.5) < 2e-10);
+/// assert!(relative(12345.678) < 2e-10);
+/// let near_zero = std::f64::consts::FRAC_PI_2 - 1e-8;
+/// assert!(relative(near_zero) < 2e-10);
+/// assert!((fast_cos(std::f64::consts::FRAC_PI_2) - std::f64::consts::FRAC_PI_2.cos()).abs() < 2e-10);
+/// ```
+#[inline]
+pub fn fast_cos(x: Real) -> Real {
+    if x.abs() >= REDUCTION_LIMIT || x.is_nan() {
+        return x.cos();
+    }
+    let (quadrant, r) = reduce(x);
+    match quadrant & 3 {
+        0 => cos_polynomial(r),
+        1 => -sin_polynomial(r),
+        2 => -cos_polynomial(r),
+        _ => sin_polynomial(r),
+    }
+}
+
+/// e^x with relative error below 1e-8, by x = k ln 2 + r with |r| <= ln 2 / 2,
+/// a taylor polynomial in r, and k put straight into the exponent bits.
+/// results below `f64::MIN_POSITIVE` are flushed to 0
+/// ```
+/// # use vmath::fast::fast_exp;
+/// assert_eq!(fast_exp(0.0), 1.0);
+/// assert!((fast_exp(1.0) / std::f64::consts::E - 1.0).abs() < 1e-8);
+/// assert!((fast_exp(-700.0) / (-700.0_f64).exp() - 1.0).abs() < 1e-8);
+/// assert!((fast_exp(709.7) / 709.7_f64.exp() - 1.0).abs() < 1e-8);
+/// assert_eq!(fast_exp(710.0), f64::INFINITY);
+/// assert_eq!(fast_exp(-710.0), 0.0);
+/// assert!(fast_exp(f64::NAN).is_nan());
+/// ```
+#[inline]
+pub fn fast_exp(x: Real) -> Real {
+    // without branches or calls until the special values are picked out at the end,
+    // so loops over slices of it can vectorize
+    let clamped = x.clamp(EXP_UNDERFLOW, EXP_OVERFLOW);
+    let shifted = clamped * LOG2_E + SHIFTER;
+    let k = shifted - SHIFTER;
+    let r = (clamped - k * LN_2_HI) - k * LN_2_LO;
+    let p = 1.0
+        + r * (1.0
+            + r * (1.0 / 2.0
+                + r * (1.0 / 6.0
+                    + r * (1.0 / 24.0
+                        + r * (1.0 / 120.0 + r * (1.0 / 720.0 + r * (1.0 / 5040.0)))))));
+    // the shifter's bits less its own are k, which near the overflow is 1024,
+    // past the largest exponent, so 2^k goes in as two halves
+    let k = (shifted.to_bits() as i64).wrapping_sub(SHIFTER.to_bits() as i64);
+    let y = p * power_of_two(k >> 1) * power_of_two(k - (k >> 1));
+    if x > EXP_OVERFLOW {
+        Real::INFINITY
+    } else if x < EXP_UNDERFLOW {
+        0.0
+    } else {
+        y
+    }
+}
+
+/// 1 / sqrt(x) with relative error below 4e-11, from the bit pattern estimate of
+/// quake's inverse square root, with a better constant for doubles, and three newton steps
+/// ```
+/// # use vmath::fast::fast_rsqrt;
+/// assert!((fast_rsqrt(4.0) / 0.5 - 1.0).abs() < 4e-11);
+/// assert!((fast_rsqrt(2.0) * 2.0_f64.sqrt() - 1.0).abs() < 4e-11);
+/// assert!((fast_rsqrt(1e-310) * 1e-155 - 1.0).abs() < 4e-11);
+/// assert_eq!(fast_rsqrt(0.0), f64::INFINITY);
+/// assert_eq!(fast_rsqrt(f64::INFINITY), 0.0);
+/// assert!(fast_rsqrt(-1.0).is_nan());
+/// ```
+#[inline]
+pub fn fast_rsqrt(x: Real) -> Real {
+    // subnormals have no exponent for the estimate to halve, so they are scaled up first
+    let subnormal = x < Real::MIN_POSITIVE;
+    let scaled = if subnormal { x * power_of_two(54) } else { x };
+    // negative x wraps, to a value the special cases below replace
+    let mut y = Real::from_bits(0x5fe6_eb50_c7b5_37a9_u64.wrapping_sub(scaled.to_bits() >> 1));
+    let half = scaled / 2.0;
+    for _ in 0..NEWTON_STEPS {
+        y *= 1.5 - half * y * y;
+    }
+    if subnormal {
+        y *= power_of_two(27);
+    }
+    if x == 0.0 {
+        Real::INFINITY
+    } else if x == Real::INFINITY {
+        0.0
+    } else if x > 0.0 {
+        y
+    } else {
+        Real::NAN
+    }
+}
+
+/// (k, x - k pi / 2) for the nearest whole k, so the remainder is within pi / 4
+#[inline]
+fn reduce(x: Real) -> (i64, Real) {
+    let k = round(x * FRAC_2_PI);
+    (k as i64, (x - k * FRAC_PI_2_HI) - k * FRAC_PI_2_LO)
+}
+
+/// sin r to r^11 for |r| <= pi / 4
+#[inline]
+fn sin_polynomial(r: Real) -> Real {
+    let s = r * r;
+    r * (1.0
+        + s * (-1.0 / 6.0
+            + s * (1.0 / 120.0
+                + s * (-1.0 / 5040.0 + s * (1.0 / 362_880.0 + s * (-1.0 / 39_916_800.0))))))
+}
+
+/// cos r to r^10 for |r| <= pi / 4
+#[inline]
+fn cos_polynomial(r: Real) -> Real {
+    let s = r * r;
+    1.0 + s
+        * (-1.0 / 2.0
+            + s * (1.0 / 24.0
+                + s * (-1.0 / 720.0 + s * (1.0 / 40_320.0 + s * (-1.0 / 3_628_800.0)))))
+}
+
+/// x to the nearest whole number, ties to even, for |x| < 2^51
+#[inline]
+fn round(x: Real) -> Real {
+    (x + SHIFTER) - SHIFTER
+}
+
+/// 2^k for k in the normal exponents, from the bits
+#[inline]
+fn power_of_two(k: i64) -> Real {
+    Real::from_bits(((k + 1023) as u64) << 52)
+}
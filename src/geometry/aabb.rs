@@ -0,0 +1,265 @@
+use super::Linear;
+use crate::linalg::{Vec2, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the points p where min <= p <= max in every component
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+pub type Aabb2 = Aabb<Vec2>;
+pub type Aabb3 = Aabb<Vec3>;
+
+impl<V: Vector> Aabb<V> {
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0));
+    /// assert_eq!(aabb.min, Vec2::new(0.0, 0.0));
+    /// assert_eq!(aabb.max, Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+
+    /// the smallest box around the points,
+    /// None when there are none
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [Vec2::new(1.0, 5.0), Vec2::new(-2.0, 3.0), Vec2::new(0.0, 7.0)];
+    /// let aabb = Aabb2::from_points(points);
+    /// assert_eq!(aabb, Some(Aabb2::new(Vec2::new(-2.0, 3.0), Vec2::new(1.0, 7.0))));
+    /// assert_eq!(Aabb2::from_points([]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = V>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), Self::include))
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.center(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn center(self) -> V {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.size(), Vec3::new(1.0, 3.0, 5.0));
+    /// ```
+    pub fn size(self) -> V {
+        self.max - self.min
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let a = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    /// let b = Aabb2::new(Vec2::new(2.0, -1.0), Vec2::new(3.0, 0.5));
+    /// assert_eq!(a.union(b), Aabb2::new(Vec2::new(0.0, -1.0), Vec2::new(3.0, 1.0)));
+    /// ```
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// None when the boxes are disjoint
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let a = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    /// let b = Aabb2::new(Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0));
+    /// assert_eq!(
+    ///     a.intersection(b),
+    ///     Some(Aabb2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0))),
+    /// );
+    /// let c = Aabb2::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0));
+    /// assert_eq!(a.intersection(c), None);
+    /// ```
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let intersection = Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+        (0..V::DIMENSION)
+            .all(|i| intersection.min[i] <= intersection.max[i])
+            .then_some(intersection)
+    }
+
+    /// touching boxes intersect
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let a = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb3::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 1.0));
+    /// let c = Aabb3::new(Vec3::new(0.0, 0.0, 2.0), Vec3::new(1.0, 1.0, 3.0));
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(self, other: Self) -> bool {
+        (0..V::DIMENSION).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    /// assert!(aabb.contains_point(Vec2::new(0.5, 1.0)));
+    /// assert!(!aabb.contains_point(Vec2::new(0.5, 1.5)));
+    /// ```
+    pub fn contains_point(self, point: V) -> bool {
+        (0..V::DIMENSION).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let outer = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 4.0));
+    /// let inner = Aabb2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0));
+    /// assert!(outer.contains(inner));
+    /// assert!(!inner.contains(outer));
+    /// ```
+    pub fn contains(self, other: Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// grown by margin on every side
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    /// assert_eq!(aabb.expand(0.5), Aabb2::new(Vec2::new(-0.5, -0.5), Vec2::new(1.5, 1.5)));
+    /// ```
+    pub fn expand(self, margin: Real) -> Self {
+        let margin = V::splat(margin);
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// the smallest box containing both self and point
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(
+    ///     aabb.include(Vec3::new(2.0, 0.5, -1.0)),
+    ///     Aabb3::new(Vec3::new(0.0, 0.0, -1.0), Vec3::new(2.0, 1.0, 1.0)),
+    /// );
+    /// ```
+    pub fn include(self, point: V) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    /// assert_eq!(aabb.closest_point(Vec2::new(3.0, 1.0)), Vec2::new(2.0, 1.0));
+    /// assert_eq!(aabb.closest_point(Vec2::new(1.0, 1.0)), Vec2::new(1.0, 1.0));
+    /// ```
+    pub fn closest_point(self, point: V) -> V {
+        point.max(self.min).min(self.max)
+    }
+
+    /// the range of parameters for which the linear component lies inside the box,
+    /// found by clipping against one pair of slabs per axis
+    /// ```
+    /// # use vmath::geometry::{Aabb2, Ray2, Segment2};
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0));
+    /// let ray = Ray2::new(Vec2::new(0.0, 2.0), Vec2::new(1.0, 0.0));
+    /// assert_eq!(aabb.clip(ray), Some((1.0, 3.0)));
+    ///
+    /// let miss = Ray2::new(Vec2::new(0.0, 2.0), Vec2::new(-1.0, 0.0));
+    /// assert_eq!(aabb.clip(miss), None);
+    ///
+    /// let inside = Segment2::new(Vec2::new(2.0, 2.0), Vec2::new(6.0, 2.0));
+    /// assert_eq!(aabb.clip(inside), Some((0.0, 0.25)));
+    /// ```
+    pub fn clip<L: Linear<V>>(self, linear: L) -> Option<(Real, Real)> {
+        let origin = linear.origin();
+        let direction = linear.direction();
+        let (mut low, mut high) = linear.bounds();
+        for i in 0..V::DIMENSION {
+            if direction[i] == 0.0 {
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let t_0 = (self.min[i] - origin[i]) / direction[i];
+            let t_1 = (self.max[i] - origin[i]) / direction[i];
+            low = low.max(t_0.min(t_1));
+            high = high.min(t_0.max(t_1));
+            if low > high {
+                return None;
+            }
+        }
+        Some((low, high))
+    }
+}
+
+impl Aabb2 {
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 3.0));
+    /// assert_eq!(aabb.area(), 6.0);
+    /// ```
+    pub fn area(self) -> Real {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb2;
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 3.0));
+    /// assert_eq!(aabb.perimeter(), 10.0);
+    /// ```
+    pub fn perimeter(self) -> Real {
+        let size = self.size();
+        2.0 * (size.x + size.y)
+    }
+}
+
+impl Aabb3 {
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(aabb.surface_area(), 22.0);
+    /// ```
+    pub fn surface_area(self) -> Real {
+        let size = self.size();
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Aabb3;
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(aabb.volume(), 6.0);
+    /// ```
+    pub fn volume(self) -> Real {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+}
@@ -0,0 +1,303 @@
+use std::f64::consts::PI;
+
+use super::{Aabb, Linear, EPSILON};
+use crate::linalg::{Vec2, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the points within radius of center
+pub struct Ball<V> {
+    pub center: V,
+    pub radius: Real,
+}
+
+pub type Circle = Ball<Vec2>;
+pub type Sphere = Ball<Vec3>;
+
+impl<V: Vector> Ball<V> {
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// let circle = Circle::new(Vec2::new(1.0, 2.0), 3.0);
+    /// assert_eq!(circle.center, Vec2::new(1.0, 2.0));
+    /// assert_eq!(circle.radius, 3.0);
+    /// ```
+    pub fn new(center: V, radius: Real) -> Self {
+        Self { center, radius }
+    }
+
+    /// the smallest ball containing every point, found with Welzl's algorithm
+    /// in its move-to-front form, None when there are no points
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(4.0, 0.0),
+    ///     Vec2::new(2.0, 1.0),
+    ///     Vec2::new(1.0, -1.0),
+    /// ];
+    /// let circle = Circle::from_points(&points).unwrap();
+    /// assert!((circle.center - Vec2::new(2.0, 0.0)).x.abs() < 1e-12);
+    /// assert!((circle.radius - 2.0).abs() < 1e-12);
+    /// assert!(points.iter().all(|&p| circle.contains_point(p)));
+    /// ```
+    /// ```
+    /// # use vmath::geometry::Sphere;
+    /// # use vmath::linalg::Vec3;
+    /// let points = [
+    ///     Vec3::new(1.0, 0.0, 0.0),
+    ///     Vec3::new(-1.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 1.0, 0.0),
+    ///     Vec3::new(0.0, -1.0, 0.0),
+    ///     Vec3::new(0.0, 0.0, 1.0),
+    ///     Vec3::new(0.0, 0.0, -1.0),
+    ///     Vec3::new(0.1, 0.2, 0.3),
+    /// ];
+    /// let sphere = Sphere::from_points(&points).unwrap();
+    /// assert!(sphere.center.x.abs() < 1e-12);
+    /// assert!((sphere.radius - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_points(points: &[V]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let mut points = points.to_vec();
+        let mut boundary = Vec::with_capacity(V::DIMENSION + 1);
+        let length = points.len();
+        Some(move_to_front(&mut points, length, &mut boundary))
+    }
+
+    /// ```
+    /// # use vmath::geometry::Sphere;
+    /// # use vmath::linalg::Vec3;
+    /// let sphere = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert!(sphere.contains_point(Vec3::new(0.0, 1.0, 0.0)));
+    /// assert!(!sphere.contains_point(Vec3::new(0.0, 1.0, 1.0)));
+    /// ```
+    pub fn contains_point(self, point: V) -> bool {
+        self.center.distance(point) <= self.radius + EPSILON
+    }
+
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// let outer = Circle::new(Vec2::new(0.0, 0.0), 3.0);
+    /// let inner = Circle::new(Vec2::new(1.0, 0.0), 2.0);
+    /// assert!(outer.contains(inner));
+    /// assert!(!inner.contains(outer));
+    /// ```
+    pub fn contains(self, other: Self) -> bool {
+        self.center.distance(other.center) + other.radius <= self.radius + EPSILON
+    }
+
+    /// points inside the ball are their own closest point
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// let circle = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+    /// assert_eq!(circle.closest_point(Vec2::new(1.0, 5.0)), Vec2::new(1.0, 3.0));
+    /// assert_eq!(circle.closest_point(Vec2::new(2.0, 1.0)), Vec2::new(2.0, 1.0));
+    /// ```
+    pub fn closest_point(self, point: V) -> V {
+        let offset = point - self.center;
+        let length = offset.length();
+        if length <= self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / length)
+    }
+
+    /// touching balls intersect
+    /// ```
+    /// # use vmath::geometry::Sphere;
+    /// # use vmath::linalg::Vec3;
+    /// let a = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+    /// let b = Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0);
+    /// let c = Sphere::new(Vec3::new(3.0, 0.0, 0.0), 1.0);
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(self, other: Self) -> bool {
+        self.center.distance(other.center) <= self.radius + other.radius + EPSILON
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Aabb3, Sphere};
+    /// # use vmath::linalg::Vec3;
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    /// let near = Sphere::new(Vec3::new(2.0, 0.5, 0.5), 1.0);
+    /// let corner = Sphere::new(Vec3::new(2.0, 2.0, 2.0), 1.5);
+    /// assert!(near.intersects_aabb(aabb));
+    /// assert!(!corner.intersects_aabb(aabb));
+    /// ```
+    pub fn intersects_aabb(self, aabb: Aabb<V>) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// the range of parameters for which the linear component lies inside the ball
+    /// ```
+    /// # use vmath::geometry::{Circle, Ray2};
+    /// # use vmath::linalg::Vec2;
+    /// let circle = Circle::new(Vec2::new(5.0, 0.0), 2.0);
+    /// let ray = Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0));
+    /// assert_eq!(circle.clip(ray), Some((3.0, 7.0)));
+    ///
+    /// let miss = Ray2::new(Vec2::new(0.0, 3.0), Vec2::new(1.0, 0.0));
+    /// assert_eq!(circle.clip(miss), None);
+    ///
+    /// let inside = Ray2::new(Vec2::new(5.0, 0.0), Vec2::new(0.0, 1.0));
+    /// assert_eq!(circle.clip(inside), Some((0.0, 2.0)));
+    /// ```
+    pub fn clip<L: Linear<V>>(self, linear: L) -> Option<(Real, Real)> {
+        let (min, max) = linear.bounds();
+        let direction = linear.direction();
+        let offset = linear.origin() - self.center;
+        let a = direction.length_squared();
+        let half_b = direction.dot(offset);
+        let c = offset.length_squared() - self.radius * self.radius;
+        if a == 0.0 {
+            return (c <= 0.0).then_some((min.max(0.0), max.min(0.0)));
+        }
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let low = ((-half_b - root) / a).max(min);
+        let high = ((-half_b + root) / a).min(max);
+        (low <= high).then_some((low, high))
+    }
+}
+
+impl Circle {
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// # use std::f64::consts::PI;
+    /// let circle = Circle::new(Vec2::new(0.0, 0.0), 2.0);
+    /// assert_eq!(circle.area(), 4.0 * PI);
+    /// ```
+    pub fn area(self) -> Real {
+        PI * self.radius * self.radius
+    }
+
+    /// ```
+    /// # use vmath::geometry::Circle;
+    /// # use vmath::linalg::Vec2;
+    /// # use std::f64::consts::PI;
+    /// let circle = Circle::new(Vec2::new(0.0, 0.0), 2.0);
+    /// assert_eq!(circle.circumference(), 4.0 * PI);
+    /// ```
+    pub fn circumference(self) -> Real {
+        2.0 * PI * self.radius
+    }
+}
+
+impl Sphere {
+    /// ```
+    /// # use vmath::geometry::Sphere;
+    /// # use vmath::linalg::Vec3;
+    /// # use std::f64::consts::PI;
+    /// let sphere = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(sphere.surface_area(), 16.0 * PI);
+    /// ```
+    pub fn surface_area(self) -> Real {
+        4.0 * PI * self.radius * self.radius
+    }
+
+    /// ```
+    /// # use vmath::geometry::Sphere;
+    /// # use vmath::linalg::Vec3;
+    /// # use std::f64::consts::PI;
+    /// let sphere = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 3.0);
+    /// assert!((sphere.volume() - 36.0 * PI).abs() < 1e-12);
+    /// ```
+    pub fn volume(self) -> Real {
+        4.0 / 3.0 * PI * self.radius * self.radius * self.radius
+    }
+}
+
+/// smallest ball around points[..length] with every boundary point on its surface,
+/// points that force the ball to grow are moved to the front so later passes see them early
+fn move_to_front<V: Vector>(points: &mut [V], length: usize, boundary: &mut Vec<V>) -> Ball<V> {
+    let mut ball = circumscribe(boundary);
+    if boundary.len() == V::DIMENSION + 1 {
+        return ball;
+    }
+    for i in 0..length {
+        let point = points[i];
+        if !ball.contains_point(point) {
+            boundary.push(point);
+            ball = move_to_front(points, i, boundary);
+            boundary.pop();
+            points[..=i].rotate_right(1);
+        }
+    }
+    ball
+}
+
+/// the smallest ball with every point on its surface
+fn circumscribe<V: Vector>(points: &[V]) -> Ball<V> {
+    let Some((&origin, rest)) = points.split_first() else {
+        return Ball::new(V::ZERO, Real::NEG_INFINITY);
+    };
+    // the center is origin + sum(lambda_j * v_j) where each v_i = p_i - origin,
+    // equidistance from origin and p_i gives 2 * (center - origin) . v_i = v_i . v_i
+    let edges: Vec<V> = rest.iter().map(|&p| p - origin).collect();
+    let size = edges.len();
+    let mut system = [[0.0; 4]; 3];
+    for i in 0..size {
+        for j in 0..size {
+            system[i][j] = 2.0 * edges[i].dot(edges[j]);
+        }
+        system[i][size] = edges[i].length_squared();
+    }
+    let center = match solve(&mut system, size) {
+        Some(lambda) => (0..size).fold(origin, |center, i| center + edges[i] * lambda[i]),
+        // degenerate boundaries, such as collinear points, span their farthest pair
+        None => {
+            let mut farthest = (origin, origin);
+            for &a in points {
+                for &b in points {
+                    if a.distance(b) > farthest.0.distance(farthest.1) {
+                        farthest = (a, b);
+                    }
+                }
+            }
+            farthest.0.lerp(farthest.1, 0.5)
+        }
+    };
+    let radius = points
+        .iter()
+        .map(|&p| center.distance(p))
+        .fold(0.0, Real::max);
+    Ball::new(center, radius)
+}
+
+/// gaussian elimination with partial pivoting on an augmented system of up to 3 unknowns
+fn solve(system: &mut [[Real; 4]; 3], size: usize) -> Option<[Real; 3]> {
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| system[a][column].abs().total_cmp(&system[b][column].abs()))?;
+        if system[pivot][column].abs() <= EPSILON * EPSILON {
+            return None;
+        }
+        system.swap(column, pivot);
+        let pivot_row = system[column];
+        for row in system.iter_mut().take(size).skip(column + 1) {
+            let factor = row[column] / pivot_row[column];
+            for (entry, pivot_entry) in row[column..=size].iter_mut().zip(&pivot_row[column..=size])
+            {
+                *entry -= factor * pivot_entry;
+            }
+        }
+    }
+    let mut solution = [0.0; 3];
+    for row in (0..size).rev() {
+        let sum: Real = (row + 1..size).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][size] - sum) / system[row][row];
+    }
+    Some(solution)
+}
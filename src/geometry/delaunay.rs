@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use super::{Polygon2, Triangle2};
+use crate::linalg::{Point2, Vec2, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// a triangulation of a point set where no point lies inside
+/// the circumcircle of any triangle
+///
+/// triangles index into points and run counter-clockwise,
+/// duplicate points are left out of every triangle
+pub struct Delaunay {
+    pub points: Vec<Point2>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the regions of the plane closer to one point than to any other,
+/// the dual of a delaunay triangulation
+///
+/// vertices are the circumcenters of the delaunay triangles,
+/// each cell lists the vertices around its point counter-clockwise,
+/// cells of points on the convex hull are unbounded and list an open chain
+pub struct Voronoi {
+    pub vertices: Vec<Point2>,
+    pub cells: Vec<Vec<usize>>,
+    pub bounded: Vec<bool>,
+}
+
+impl Delaunay {
+    /// bowyer-watson, inserting one point at a time into a triangle that encloses them all,
+    /// whose corners are treated as infinitely far away so no hull triangle is lost
+    /// ```
+    /// # use vmath::geometry::Delaunay;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    ///     Vec2::new(1.0, 0.5),
+    /// ];
+    /// let delaunay = Delaunay::new(&points);
+    /// assert_eq!(delaunay.triangles.len(), 4);
+    /// let area: f64 = delaunay.triangles().map(|triangle| triangle.signed_area()).sum();
+    /// assert!((area - 4.0).abs() < 1e-12);
+    /// ```
+    /// points on a common circle, where every circle test is a near tie,
+    /// still give n - 2 counter-clockwise triangles covering the hull
+    /// ```
+    /// # use vmath::geometry::{Delaunay, Polygon2};
+    /// # use vmath::linalg::Vec2;
+    /// # use std::f64::consts::TAU;
+    /// let on_circle = |angle: f64| Vec2::new(angle.cos(), angle.sin());
+    /// for n in [8, 12, 64] {
+    ///     let polygon: Vec<_> = (0..n).map(|i| on_circle(TAU * i as f64 / n as f64)).collect();
+    ///     let delaunay = Delaunay::new(&polygon);
+    ///     assert_eq!(delaunay.triangles.len(), n - 2);
+    ///     assert!(delaunay.triangles().all(|triangle| triangle.signed_area() > 0.0));
+    ///     let area: f64 = delaunay.triangles().map(|triangle| triangle.signed_area()).sum();
+    ///     assert!((area - Polygon2::new(polygon).area()).abs() < 1e-12);
+    /// }
+    ///
+    /// // scattered around the unit circle in no order, by the golden angle
+    /// let mut angles: Vec<f64> = (0..38).map(|i| (i as f64 * 2.399963229728653) % TAU).collect();
+    /// let points: Vec<_> = angles.iter().map(|&angle| on_circle(angle)).collect();
+    /// let delaunay = Delaunay::new(&points);
+    /// assert_eq!(delaunay.triangles.len(), 36);
+    /// assert!(delaunay.triangles().all(|triangle| triangle.signed_area() > 0.0));
+    /// angles.sort_by(f64::total_cmp);
+    /// let hull = Polygon2::new(angles.iter().map(|&angle| on_circle(angle)).collect());
+    /// let area: f64 = delaunay.triangles().map(|triangle| triangle.signed_area()).sum();
+    /// assert!((area - hull.area()).abs() < 1e-12);
+    /// ```
+    pub fn new(points: &[Point2]) -> Self {
+        let mut delaunay = Self {
+            points: points.to_vec(),
+            triangles: Vec::new(),
+        };
+        // the enclosing triangle's corners are indices past the real points
+        let first = points.len();
+        let mut triangles = vec![[first, first + 1, first + 2]];
+
+        for (index, &point) in points.iter().enumerate() {
+            if points[..index].contains(&point) {
+                continue;
+            }
+            let cavity = delaunay.cavity(&triangles, point);
+            let mut bad = Vec::new();
+            let mut good = Vec::with_capacity(triangles.len());
+            for (triangle, remove) in triangles.into_iter().zip(cavity) {
+                if remove {
+                    bad.push(triangle);
+                } else {
+                    good.push(triangle);
+                }
+            }
+            triangles = good;
+
+            // edges of the cavity are the edges of exactly one bad triangle
+            let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+            for [a, b, c] in &bad {
+                for (u, v) in [(*a, *b), (*b, *c), (*c, *a)] {
+                    *edges.entry((u.min(v), u.max(v))).or_default() += 1;
+                }
+            }
+            for [a, b, c] in bad {
+                for (u, v) in [(a, b), (b, c), (c, a)] {
+                    if edges[&(u.min(v), u.max(v))] == 1 {
+                        triangles.push([u, v, index]);
+                    }
+                }
+            }
+        }
+
+        triangles.retain(|triangle| triangle.iter().all(|&corner| corner < first));
+        delaunay.triangles = triangles;
+        delaunay
+    }
+
+    /// ```
+    /// # use vmath::geometry::Delaunay;
+    /// # use vmath::linalg::Vec2;
+    /// let delaunay = Delaunay::new(&[Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)]);
+    /// assert_eq!(delaunay.triangle(0).area(), 0.5);
+    /// ```
+    pub fn triangle(&self, index: usize) -> Triangle2 {
+        let [a, b, c] = self.triangles[index];
+        Triangle2::new(self.points[a], self.points[b], self.points[c])
+    }
+
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle2> + '_ {
+        (0..self.triangles.len()).map(|index| self.triangle(index))
+    }
+
+    /// each edge once as a pair of point indices, smaller index first,
+    /// so every point is joined to its natural neighbors
+    /// ```
+    /// # use vmath::geometry::Delaunay;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(3.0, 0.0),
+    ///     Vec2::new(3.0, 1.0),
+    ///     Vec2::new(0.0, 1.0),
+    /// ];
+    /// let edges = Delaunay::new(&points).edges();
+    /// assert_eq!(edges.len(), 5);
+    /// assert!(edges.contains(&[0, 2]) || edges.contains(&[1, 3]));
+    /// ```
+    pub fn edges(&self) -> Vec<[usize; 2]> {
+        let mut edges: Vec<[usize; 2]> = self
+            .triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [[a, b], [b, c], [c, a]])
+            .map(|[u, v]| [u.min(v), u.max(v)])
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    /// ```
+    /// # use vmath::geometry::Delaunay;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    ///     Vec2::new(1.0, 1.0),
+    /// ];
+    /// let voronoi = Delaunay::new(&points).voronoi();
+    /// assert_eq!(voronoi.bounded, vec![false, false, false, false, true]);
+    /// let cell = voronoi.cell(4).unwrap();
+    /// assert!((cell.area() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn voronoi(&self) -> Voronoi {
+        let vertices = self
+            .triangles()
+            .map(|triangle| {
+                triangle
+                    .circumcenter()
+                    .unwrap_or_else(|| triangle.centroid())
+            })
+            .collect();
+
+        // around each point, the triangles keyed by the corner following it,
+        // with the corner preceding it, so each triangle leads to the next counter-clockwise
+        let mut around: Vec<HashMap<usize, (usize, usize)>> =
+            vec![HashMap::new(); self.points.len()];
+        for (index, &[a, b, c]) in self.triangles.iter().enumerate() {
+            around[a].insert(b, (index, c));
+            around[b].insert(c, (index, a));
+            around[c].insert(a, (index, b));
+        }
+
+        let mut cells = Vec::with_capacity(self.points.len());
+        let mut bounded = Vec::with_capacity(self.points.len());
+        for fan in around {
+            // a point on the hull has exactly one triangle no other triangle leads into
+            let preceding: Vec<usize> = fan.values().map(|&(_, previous)| previous).collect();
+            let open = fan.keys().copied().find(|next| !preceding.contains(next));
+            let Some(mut next) = open.or_else(|| fan.keys().copied().next()) else {
+                cells.push(Vec::new());
+                bounded.push(false);
+                continue;
+            };
+            let mut cell = Vec::with_capacity(fan.len());
+            while let Some(&(triangle, previous)) = fan.get(&next) {
+                cell.push(triangle);
+                if cell.len() == fan.len() {
+                    break;
+                }
+                next = previous;
+            }
+            bounded.push(open.is_none());
+            cells.push(cell);
+        }
+
+        Voronoi {
+            vertices,
+            cells,
+            bounded,
+        }
+    }
+
+    /// which triangles to remove for point, grown from the triangle holding it across
+    /// shared edges, so the cavity is connected and every edge of its boundary has
+    /// point strictly on its inner side, which keeps the new fan counter-clockwise
+    /// and free of overlaps where rounding blurs the circle tests of cocircular points.
+    /// a neighbor whose circle holds point joins only if its far edges stay visible
+    /// from point, and one across an edge point is not strictly inside of always joins
+    fn cavity(&self, triangles: &[[usize; 3]], point: Point2) -> Vec<bool> {
+        let mut cavity = vec![false; triangles.len()];
+        let inside = |[a, b, c]: [usize; 3]| {
+            [(a, b), (b, c), (c, a)]
+                .iter()
+                .all(|&(u, v)| self.orientation(u, v, point) >= 0.0)
+        };
+        let Some(seed) = (0..triangles.len())
+            .find(|&i| inside(triangles[i]))
+            .or_else(|| (0..triangles.len()).find(|&i| self.in_circumcircle(triangles[i], point)))
+        else {
+            return cavity;
+        };
+        let across: HashMap<(usize, usize), usize> = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &[a, b, c])| [((a, b), i), ((b, c), i), ((c, a), i)])
+            .collect();
+
+        cavity[seed] = true;
+        let mut stack = vec![seed];
+        while let Some(current) = stack.pop() {
+            let [a, b, c] = triangles[current];
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                let Some(&neighbor) = across.get(&(v, u)) else {
+                    continue;
+                };
+                if cavity[neighbor] {
+                    continue;
+                }
+                // the neighbor runs v, u, far counter-clockwise
+                let far = triangles[neighbor]
+                    .into_iter()
+                    .find(|&corner| corner != u && corner != v)
+                    .unwrap_or(u);
+                let visible =
+                    self.orientation(u, far, point) > 0.0 && self.orientation(far, v, point) > 0.0;
+                if self.orientation(u, v, point) <= 0.0
+                    || (visible && self.in_circumcircle(triangles[neighbor], point))
+                {
+                    cavity[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        cavity
+    }
+
+    /// the sign of (v - u) x (point - u), positive when point is left of u to v,
+    /// taking the leading term as the enclosing corners move out to infinity
+    fn orientation(&self, u: usize, v: usize, point: Point2) -> Real {
+        let first = self.points.len();
+        match (u < first, v < first) {
+            (true, true) => {
+                let (u, v) = (self.points[u], self.points[v]);
+                (v - u).perp_dot(point - u)
+            }
+            (true, false) => ENCLOSING[v - first].perp_dot(point - self.points[u]),
+            (false, true) => ENCLOSING[u - first].perp_dot(self.points[v] - point),
+            (false, false) => ENCLOSING[u - first].perp_dot(ENCLOSING[v - first]),
+        }
+    }
+
+    /// whether point lies strictly inside the circumcircle of the counter-clockwise triangle,
+    /// taking the limit as the enclosing corners move out to infinity
+    fn in_circumcircle(&self, triangle: [usize; 3], point: Point2) -> bool {
+        let first = self.points.len();
+        let finite = triangle.map(|corner| corner < first);
+        match finite {
+            [true, true, true] => {
+                let [a, b, c] = triangle.map(|corner| self.points[corner] - point);
+                let determinant = a.length_squared() * b.perp_dot(c)
+                    + b.length_squared() * c.perp_dot(a)
+                    + c.length_squared() * a.perp_dot(b);
+                determinant > 0.0
+            }
+            [false, false, false] => true,
+            _ => {
+                let count = finite.iter().filter(|&&finite| finite).count();
+                // rotate so the finite corners come first, keeping the winding
+                let start = (0..3)
+                    .find(|&i| finite[i] && !finite[(i + 2) % 3])
+                    .unwrap_or(0);
+                let [a, b, c] = [0, 1, 2].map(|i| triangle[(start + i) % 3]);
+                let a = self.points[a];
+                if count == 2 {
+                    // the circle opens into the half-plane left of a to b
+                    let b = self.points[b];
+                    let side = (b - a).perp_dot(point - a);
+                    side > 0.0 || (side == 0.0 && (point - a).dot(point - b) < 0.0)
+                } else {
+                    // the circle opens into the half-plane facing the center
+                    // of the circle through the two directions and the origin
+                    let towards =
+                        Triangle2::new(Vec2::ZERO, ENCLOSING[b - first], ENCLOSING[c - first]);
+                    let center = towards.circumcenter().unwrap_or(Vec2::ZERO);
+                    (point - a).dot(center) > 0.0
+                }
+            }
+        }
+    }
+}
+
+/// directions of the enclosing triangle's corners
+const ENCLOSING: [Vec2; 3] = [
+    Vec2 { x: -1.0, y: -1.0 },
+    Vec2 { x: 1.0, y: -1.0 },
+    Vec2 { x: 0.0, y: 1.0 },
+];
+
+impl Voronoi {
+    /// None for the unbounded cells of points on the convex hull
+    /// ```
+    /// # use vmath::geometry::Delaunay;
+    /// # use vmath::linalg::Vec2;
+    /// let points = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(4.0, 0.0),
+    ///     Vec2::new(2.0, 4.0),
+    ///     Vec2::new(2.0, 1.5),
+    /// ];
+    /// let voronoi = Delaunay::new(&points).voronoi();
+    /// assert_eq!(voronoi.cell(0), None);
+    /// let cell = voronoi.cell(3).unwrap();
+    /// assert_eq!(cell.vertices.len(), 3);
+    /// assert!(cell.contains_point(Vec2::new(2.0, 1.5)));
+    /// ```
+    pub fn cell(&self, site: usize) -> Option<Polygon2> {
+        if !self.bounded[site] {
+            return None;
+        }
+        let vertices = self.cells[site]
+            .iter()
+            .map(|&vertex| self.vertices[vertex])
+            .collect();
+        Some(Polygon2::new(vertices))
+    }
+}
@@ -0,0 +1,161 @@
+use super::{Aabb3, Plane, Projective3, Sphere};
+use crate::linalg::{Mat4, Point3, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// how a volume sits relative to a frustum
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the visible volume of a camera,
+/// bounded by six planes whose normals face inward
+/// in the order left, right, bottom, top, near, far
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// extracts the planes from a view-projection matrix by gribb-hartmann,
+    /// each is a sum or difference of the w row and one of the x, y, z rows
+    /// ```
+    /// # use vmath::geometry::{Frustum, Projective3};
+    /// # use vmath::linalg::Vec3;
+    /// let projection = Projective3::orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    /// let frustum = Frustum::from_matrix(projection.matrix);
+    /// assert_eq!(frustum.left().normal, Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(frustum.near().normal, Vec3::new(0.0, 0.0, -1.0));
+    /// assert!((frustum.far().signed_distance(Vec3::new(0.0, 0.0, -4.0)) - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn from_matrix(matrix: Mat4) -> Self {
+        let [x, y, z, w] = matrix.rows;
+        let plane = |sign: Real, row: [Real; 4]| {
+            let [a, b, c, d] = [0, 1, 2, 3].map(|i| w[i] + sign * row[i]);
+            let normal = Vec3::new(a, b, c);
+            let length = normal.length();
+            Plane::new(normal / length, -d / length)
+        };
+        Self {
+            planes: [
+                plane(1.0, x),
+                plane(-1.0, x),
+                plane(1.0, y),
+                plane(-1.0, y),
+                plane(1.0, z),
+                plane(-1.0, z),
+            ],
+        }
+    }
+
+    pub fn left(self) -> Plane {
+        self.planes[0]
+    }
+
+    pub fn right(self) -> Plane {
+        self.planes[1]
+    }
+
+    pub fn bottom(self) -> Plane {
+        self.planes[2]
+    }
+
+    pub fn top(self) -> Plane {
+        self.planes[3]
+    }
+
+    pub fn near(self) -> Plane {
+        self.planes[4]
+    }
+
+    pub fn far(self) -> Plane {
+        self.planes[5]
+    }
+
+    /// points on the boundary are contained
+    /// ```
+    /// # use vmath::geometry::{Frustum, Projective3};
+    /// # use vmath::linalg::Vec3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let frustum = Frustum::from(Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0));
+    /// assert!(frustum.contains_point(Vec3::new(0.0, 0.0, -5.0)));
+    /// assert!(frustum.contains_point(Vec3::new(4.0, 4.0, -5.0)));
+    /// assert!(!frustum.contains_point(Vec3::new(6.0, 0.0, -5.0)));
+    /// assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -11.0)));
+    /// ```
+    pub fn contains_point(self, point: Point3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Containment, Frustum, Projective3, Sphere};
+    /// # use vmath::linalg::Vec3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let frustum = Frustum::from(Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0));
+    /// let inside = Sphere::new(Vec3::new(0.0, 0.0, -5.0), 1.0);
+    /// let straddling = Sphere::new(Vec3::new(0.0, 0.0, -10.0), 1.0);
+    /// let behind = Sphere::new(Vec3::new(0.0, 0.0, 5.0), 1.0);
+    /// assert_eq!(frustum.classify_sphere(inside), Containment::Inside);
+    /// assert_eq!(frustum.classify_sphere(straddling), Containment::Intersecting);
+    /// assert_eq!(frustum.classify_sphere(behind), Containment::Outside);
+    /// ```
+    pub fn classify_sphere(self, sphere: Sphere) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in self.planes {
+            let distance = plane.signed_distance(sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance < sphere.radius {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// tests the corner furthest along and furthest against each plane normal,
+    /// so boxes just outside a frustum edge may be reported as intersecting
+    /// ```
+    /// # use vmath::geometry::{Aabb3, Containment, Frustum, Projective3};
+    /// # use vmath::linalg::Vec3;
+    /// let frustum = Frustum::from(Projective3::orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0));
+    /// let inside = Aabb3::new(Vec3::new(-0.5, -0.5, -3.0), Vec3::new(0.5, 0.5, -2.0));
+    /// let straddling = Aabb3::new(Vec3::new(0.5, -0.5, -3.0), Vec3::new(1.5, 0.5, -2.0));
+    /// let beside = Aabb3::new(Vec3::new(2.0, -0.5, -3.0), Vec3::new(3.0, 0.5, -2.0));
+    /// assert_eq!(frustum.classify_aabb(inside), Containment::Inside);
+    /// assert_eq!(frustum.classify_aabb(straddling), Containment::Intersecting);
+    /// assert_eq!(frustum.classify_aabb(beside), Containment::Outside);
+    /// ```
+    pub fn classify_aabb(self, aabb: Aabb3) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in self.planes {
+            let mut positive = aabb.min;
+            let mut negative = aabb.max;
+            for i in 0..3 {
+                if plane.normal[i] >= 0.0 {
+                    positive[i] = aabb.max[i];
+                    negative[i] = aabb.min[i];
+                }
+            }
+            if plane.signed_distance(positive) < 0.0 {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(negative) < 0.0 {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+}
+
+impl From<Projective3> for Frustum {
+    fn from(projective: Projective3) -> Self {
+        Self::from_matrix(projective.matrix)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::linalg::Point2;
+
+/// the corners of the smallest convex polygon around the points,
+/// counter-clockwise from the lowest-leftmost point,
+/// by andrew's monotone chain
+///
+/// duplicate points and points lying along a hull edge are left out,
+/// so collinear input reduces to its two extremes
+/// ```
+/// # use vmath::geometry::convex_hull;
+/// # use vmath::linalg::Vec2;
+/// let points = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+///     // on an edge
+///     Vec2::new(1.0, 0.0),
+///     // duplicate
+///     Vec2::new(2.0, 2.0),
+/// ];
+/// assert_eq!(
+///     convex_hull(&points),
+///     vec![
+///         Vec2::new(0.0, 0.0),
+///         Vec2::new(2.0, 0.0),
+///         Vec2::new(2.0, 2.0),
+///         Vec2::new(0.0, 2.0),
+///     ],
+/// );
+///
+/// let collinear = [Vec2::new(1.0, 1.0), Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0)];
+/// assert_eq!(convex_hull(&collinear), vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0)]);
+/// ```
+pub fn convex_hull(points: &[Point2]) -> Vec<Point2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // lower chain left to right, then upper chain right to left,
+    // dropping every corner that fails to turn strictly counter-clockwise
+    let mut hull: Vec<Point2> = Vec::with_capacity(sorted.len() + 1);
+    for &point in &sorted {
+        while hull.len() >= 2 && !turns_left(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    let lower_length = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_length && !turns_left(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    // the upper chain ends back at the first point
+    hull.pop();
+    hull
+}
+
+/// whether the last two points of the chain followed by point make a counter-clockwise turn
+fn turns_left(chain: &[Point2], point: Point2) -> bool {
+    let a = chain[chain.len() - 2];
+    let b = chain[chain.len() - 1];
+    (b - a).perp_dot(point - b) > 0.0
+}
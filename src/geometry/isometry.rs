@@ -0,0 +1,271 @@
+use std::ops::Mul;
+
+use super::{Rotation2, Rotation3, Transform2, Transform3};
+use crate::linalg::{Mat3, Mat4, Point2, Point3, Vec2, Vec3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// p -> rotation * p + translation
+/// in the plane, the pose of a rigid body
+pub struct Isometry2 {
+    pub rotation: Rotation2,
+    pub translation: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// p -> rotation * p + translation
+/// in space, the pose of a rigid body
+pub struct Isometry3 {
+    pub rotation: Rotation3,
+    pub translation: Vec3,
+}
+
+impl Isometry2 {
+    pub const IDENTITY: Self = Self {
+        rotation: Rotation2::IDENTITY,
+        translation: Vec2 { x: 0.0, y: 0.0 },
+    };
+
+    /// ```
+    /// # use vmath::geometry::{Isometry2, Rotation2};
+    /// # use vmath::linalg::Vec2;
+    /// let isometry = Isometry2::new(Rotation2::IDENTITY, Vec2::new(1.0, 2.0));
+    /// assert_eq!(isometry.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(2.0, 3.0));
+    /// ```
+    pub const fn new(rotation: Rotation2, translation: Vec2) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    pub const fn from_translation(translation: Vec2) -> Self {
+        Self::new(Rotation2::IDENTITY, translation)
+    }
+
+    pub const fn from_rotation(rotation: Rotation2) -> Self {
+        Self::new(rotation, Vec2::new(0.0, 0.0))
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Isometry2, Rotation2};
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let isometry = Isometry2::new(Rotation2::from_angle(FRAC_PI_2), Vec2::new(1.0, 0.0));
+    /// let moved = isometry.transform_point(Vec2::new(1.0, 0.0));
+    /// assert!(moved.distance(Vec2::new(1.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn transform_point(self, point: Point2) -> Point2 {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    /// directions ignore the translation
+    /// ```
+    /// # use vmath::geometry::{Isometry2, Rotation2};
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let isometry = Isometry2::new(Rotation2::from_angle(FRAC_PI_2), Vec2::new(1.0, 0.0));
+    /// let turned = isometry.transform_vector(Vec2::new(1.0, 0.0));
+    /// assert!(turned.distance(Vec2::new(0.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn transform_vector(self, vector: Vec2) -> Vec2 {
+        self.rotation.rotate(vector)
+    }
+
+    /// the rotation is undone by its conjugate, so no matrix is inverted
+    /// ```
+    /// # use vmath::geometry::{Isometry2, Rotation2};
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let isometry = Isometry2::new(Rotation2::from_angle(1.2), Vec2::new(3.0, -1.0));
+    /// let point = Vec2::new(0.5, 4.0);
+    /// let back = isometry.inverse().transform_point(isometry.transform_point(point));
+    /// assert!(back.distance(point) < 1e-14);
+    /// ```
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    /// the equivalent matrix acting on homogeneous coordinates (x, y, 1)
+    /// ```
+    /// # use vmath::geometry::Isometry2;
+    /// # use vmath::linalg::{Mat3, Vec2};
+    /// let isometry = Isometry2::from_translation(Vec2::new(1.0, 2.0));
+    /// assert_eq!(
+    ///     isometry.to_matrix(),
+    ///     Mat3::new([[1.0, 0.0, 1.0], [0.0, 1.0, 2.0], [0.0, 0.0, 1.0]]),
+    /// );
+    /// ```
+    pub fn to_matrix(self) -> Mat3 {
+        Transform2::from(self).to_matrix()
+    }
+}
+
+impl Isometry3 {
+    pub const IDENTITY: Self = Self {
+        rotation: Rotation3::IDENTITY,
+        translation: Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::Vec3;
+    /// let isometry = Isometry3::new(Rotation3::IDENTITY, Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     isometry.transform_point(Vec3::new(1.0, 1.0, 1.0)),
+    ///     Vec3::new(2.0, 3.0, 4.0),
+    /// );
+    /// ```
+    pub const fn new(rotation: Rotation3, translation: Vec3) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self::new(Rotation3::IDENTITY, translation)
+    }
+
+    pub const fn from_rotation(rotation: Rotation3) -> Self {
+        Self::new(rotation, Vec3::new(0.0, 0.0, 0.0))
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    /// let isometry = Isometry3::new(rotation, Vec3::new(0.0, 0.0, 1.0));
+    /// let moved = isometry.transform_point(Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(moved.distance(Vec3::new(0.0, 1.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn transform_point(self, point: Point3) -> Point3 {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    /// directions ignore the translation
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    /// let isometry = Isometry3::new(rotation, Vec3::new(0.0, 0.0, 1.0));
+    /// let turned = isometry.transform_vector(Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(turned.distance(Vec3::new(0.0, 1.0, 0.0)) < 1e-15);
+    /// ```
+    pub fn transform_vector(self, vector: Vec3) -> Vec3 {
+        self.rotation.rotate(vector)
+    }
+
+    /// the rotation is undone by its conjugate, so no matrix is inverted
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 0.7);
+    /// let isometry = Isometry3::new(rotation, Vec3::new(3.0, -1.0, 2.0));
+    /// let point = Vec3::new(0.5, 4.0, -2.0);
+    /// let back = isometry.inverse().transform_point(isometry.transform_point(point));
+    /// assert!(back.distance(point) < 1e-14);
+    /// ```
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    /// the equivalent matrix acting on homogeneous coordinates (x, y, z, 1)
+    /// ```
+    /// # use vmath::geometry::Isometry3;
+    /// # use vmath::linalg::{Mat4, Vec3};
+    /// let isometry = Isometry3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     isometry.to_matrix(),
+    ///     Mat4::new([
+    ///         [1.0, 0.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 0.0, 2.0],
+    ///         [0.0, 0.0, 1.0, 3.0],
+    ///         [0.0, 0.0, 0.0, 1.0],
+    ///     ]),
+    /// );
+    /// ```
+    pub fn to_matrix(self) -> Mat4 {
+        Transform3::from(self).to_matrix()
+    }
+}
+
+impl Mul for Isometry2 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::{Isometry2, Rotation2};
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let turn = Isometry2::from_rotation(Rotation2::from_angle(FRAC_PI_2));
+    /// let shift = Isometry2::from_translation(Vec2::new(1.0, 0.0));
+    /// let point = Vec2::new(1.0, 0.0);
+    /// assert!((shift * turn).transform_point(point).distance(Vec2::new(1.0, 1.0)) < 1e-15);
+    /// assert!((turn * shift).transform_point(point).distance(Vec2::new(0.0, 2.0)) < 1e-15);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.rotation.rotate(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl Mul for Isometry3 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let turn = Isometry3::from_rotation(Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2));
+    /// let shift = Isometry3::from_translation(Vec3::new(1.0, 0.0, 0.0));
+    /// let point = Vec3::new(1.0, 0.0, 0.0);
+    /// assert!((shift * turn).transform_point(point).distance(Vec3::new(1.0, 1.0, 0.0)) < 1e-15);
+    /// assert!((turn * shift).transform_point(point).distance(Vec3::new(0.0, 2.0, 0.0)) < 1e-15);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.rotation.rotate(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl From<Isometry2> for Transform2 {
+    fn from(isometry: Isometry2) -> Transform2 {
+        Transform2::new(isometry.rotation.to_matrix(), isometry.translation)
+    }
+}
+
+impl From<Isometry3> for Transform3 {
+    fn from(isometry: Isometry3) -> Transform3 {
+        Transform3::new(isometry.rotation.to_matrix(), isometry.translation)
+    }
+}
+
+impl From<Isometry2> for Mat3 {
+    fn from(isometry: Isometry2) -> Mat3 {
+        isometry.to_matrix()
+    }
+}
+
+impl From<Isometry3> for Mat4 {
+    fn from(isometry: Isometry3) -> Mat4 {
+        isometry.to_matrix()
+    }
+}
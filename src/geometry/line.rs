@@ -0,0 +1,366 @@
+use super::EPSILON;
+use crate::linalg::{Vec2, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// origin + t * direction
+/// for every real t
+pub struct Line<V> {
+    pub origin: V,
+    pub direction: V,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// origin + t * direction
+/// for t >= 0
+pub struct Ray<V> {
+    pub origin: V,
+    pub direction: V,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// start + t * (end - start)
+/// for 0 <= t <= 1
+pub struct Segment<V> {
+    pub start: V,
+    pub end: V,
+}
+
+pub type Line2 = Line<Vec2>;
+pub type Line3 = Line<Vec3>;
+pub type Ray2 = Ray<Vec2>;
+pub type Ray3 = Ray<Vec3>;
+pub type Segment2 = Segment<Vec2>;
+pub type Segment3 = Segment<Vec3>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the set shared by two linear components,
+/// collinear components overlap in whichever shape their parameter ranges leave
+pub enum Intersection<V> {
+    None,
+    Point(V),
+    Segment(Segment<V>),
+    Ray(Ray<V>),
+    Line(Line<V>),
+}
+
+/// a line, ray, or segment seen as origin + t * direction
+/// with t restricted to a parameter range
+pub trait Linear<V: Vector>: Copy {
+    /// the point at t = 0
+    fn origin(self) -> V;
+
+    /// the step taken per unit of t, not necessarily normalized
+    fn direction(self) -> V;
+
+    /// the range of t covered, possibly infinite at either end
+    fn bounds(self) -> (Real, Real);
+
+    /// ```
+    /// # use vmath::geometry::{Linear, Ray2};
+    /// # use vmath::linalg::Vec2;
+    /// let ray = Ray2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0));
+    /// assert_eq!(ray.point_at(1.5), Vec2::new(4.0, 1.0));
+    /// ```
+    fn point_at(self, t: Real) -> V {
+        self.origin() + self.direction() * t
+    }
+
+    /// parameter of the closest point, clamped to the bounds
+    /// ```
+    /// # use vmath::geometry::{Linear, Segment2};
+    /// # use vmath::linalg::Vec2;
+    /// let segment = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+    /// assert_eq!(segment.closest_parameter(Vec2::new(1.0, 3.0)), 0.25);
+    /// assert_eq!(segment.closest_parameter(Vec2::new(9.0, 3.0)), 1.0);
+    /// ```
+    fn closest_parameter(self, point: V) -> Real {
+        let direction = self.direction();
+        let length_squared = direction.length_squared();
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        let (min, max) = self.bounds();
+        ((point - self.origin()).dot(direction) / length_squared).clamp(min, max)
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Linear, Ray3};
+    /// # use vmath::linalg::Vec3;
+    /// let ray = Ray3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(ray.closest_point(Vec3::new(1.0, 2.0, 5.0)), Vec3::new(0.0, 0.0, 5.0));
+    /// assert_eq!(ray.closest_point(Vec3::new(1.0, 2.0, -5.0)), Vec3::new(0.0, 0.0, 0.0));
+    /// ```
+    fn closest_point(self, point: V) -> V {
+        self.point_at(self.closest_parameter(point))
+    }
+
+    /// parameters of the closest points on self and other, clamped to their bounds,
+    /// parallel components pick one of the equally close pairs
+    /// ```
+    /// # use vmath::geometry::{Linear, Ray2, Segment2};
+    /// # use vmath::linalg::Vec2;
+    /// let segment = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+    /// let ray = Ray2::new(Vec2::new(2.0, 1.0), Vec2::new(0.0, 1.0));
+    /// assert_eq!(segment.closest_parameters(ray), (0.5, 0.0));
+    /// ```
+    fn closest_parameters<L: Linear<V>>(self, other: L) -> (Real, Real) {
+        let d = self.direction();
+        let e = other.direction();
+        let a = d.length_squared();
+        let c = e.length_squared();
+        let b = d.dot(e);
+        let denominator = a * c - b * b;
+
+        // start from the closest points of the infinite lines where they exist,
+        // then clamp each in turn, as in ericson's segment-segment test
+        let t = if a == 0.0 || c == 0.0 || denominator <= Real::EPSILON * a * c {
+            0.0
+        } else {
+            let r = self.origin() - other.origin();
+            let (min, max) = self.bounds();
+            ((b * e.dot(r) - c * d.dot(r)) / denominator).clamp(min, max)
+        };
+        let s = other.closest_parameter(self.point_at(t));
+        let t = self.closest_parameter(other.point_at(s));
+        (t, s)
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Intersection, Line2, Linear, Ray2, Segment2};
+    /// # use vmath::linalg::Vec2;
+    /// let a = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    /// let b = Segment2::new(Vec2::new(0.0, 2.0), Vec2::new(2.0, 0.0));
+    /// assert_eq!(a.intersect(b), Intersection::Point(Vec2::new(1.0, 1.0)));
+    ///
+    /// // parallel
+    /// let c = Segment2::new(Vec2::new(0.0, 1.0), Vec2::new(2.0, 3.0));
+    /// assert_eq!(a.intersect(c), Intersection::None);
+    ///
+    /// // collinear
+    /// let ray = Ray2::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0));
+    /// assert_eq!(
+    ///     a.intersect(ray),
+    ///     Intersection::Segment(Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0))),
+    /// );
+    /// let line = Line2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    /// assert_eq!(ray.intersect(line), Intersection::Ray(ray));
+    /// ```
+    /// ```
+    /// # use vmath::geometry::{Intersection, Line3, Linear};
+    /// # use vmath::linalg::Vec3;
+    /// // skew lines never meet
+    /// let a = Line3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// let b = Line3::new(Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(a.intersect(b), Intersection::None);
+    /// ```
+    fn intersect<L: Linear<V>>(self, other: L) -> Intersection<V> {
+        let p = self.origin();
+        let d = self.direction();
+        let q = other.origin();
+        let e = other.direction();
+        let a = d.length_squared();
+        let c = e.length_squared();
+
+        // degenerate segments are points
+        if a == 0.0 {
+            return match other.closest_point(p).distance(p) <= EPSILON {
+                true => Intersection::Point(p),
+                false => Intersection::None,
+            };
+        }
+        if c == 0.0 {
+            return match self.closest_point(q).distance(q) <= EPSILON {
+                true => Intersection::Point(q),
+                false => Intersection::None,
+            };
+        }
+
+        let (a_min, a_max) = self.bounds();
+        let (b_min, b_max) = other.bounds();
+        let tolerance = EPSILON / a.sqrt();
+        let b = d.dot(e);
+        let denominator = a * c - b * b;
+
+        if denominator <= Real::EPSILON * a * c {
+            // parallel, only collinear components meet
+            let r = q - p;
+            let r_dot_d = r.dot(d);
+            if (r - d * (r_dot_d / a)).length_squared() > EPSILON * EPSILON {
+                return Intersection::None;
+            }
+            // express the other's bounds as parameters along self
+            let t_0 = (r_dot_d + b_min * b) / a;
+            let t_1 = (r_dot_d + b_max * b) / a;
+            let low = t_0.min(t_1).max(a_min);
+            let high = t_0.max(t_1).min(a_max);
+            if low > high + tolerance {
+                return Intersection::None;
+            }
+            if high - low <= tolerance {
+                return Intersection::Point(self.point_at((low + high) / 2.0));
+            }
+            return span(self, low, high);
+        }
+
+        // closest points of the two infinite lines
+        let r = p - q;
+        let d_r = d.dot(r);
+        let e_r = e.dot(r);
+        let t = (b * e_r - c * d_r) / denominator;
+        let s = (a * e_r - b * d_r) / denominator;
+        let other_tolerance = EPSILON / c.sqrt();
+        if t < a_min - tolerance
+            || t > a_max + tolerance
+            || s < b_min - other_tolerance
+            || s > b_max + other_tolerance
+        {
+            return Intersection::None;
+        }
+        let point = self.point_at(t.clamp(a_min, a_max));
+        match point.distance(other.point_at(s.clamp(b_min, b_max))) <= EPSILON {
+            true => Intersection::Point(point),
+            false => Intersection::None,
+        }
+    }
+}
+
+/// the part of a linear component between two parameters,
+/// either of which may be infinite
+pub(crate) fn span<V: Vector, L: Linear<V>>(linear: L, low: Real, high: Real) -> Intersection<V> {
+    let direction = linear.direction();
+    match (low.is_finite(), high.is_finite()) {
+        (true, true) => Intersection::Segment(Segment {
+            start: linear.point_at(low),
+            end: linear.point_at(high),
+        }),
+        (true, false) => Intersection::Ray(Ray {
+            origin: linear.point_at(low),
+            direction,
+        }),
+        (false, true) => Intersection::Ray(Ray {
+            origin: linear.point_at(high),
+            direction: -direction,
+        }),
+        (false, false) => Intersection::Line(Line {
+            origin: linear.origin(),
+            direction,
+        }),
+    }
+}
+
+impl<V: Vector> Line<V> {
+    /// ```
+    /// # use vmath::geometry::Line2;
+    /// # use vmath::linalg::Vec2;
+    /// let line = Line2::new(Vec2::new(1.0, 2.0), Vec2::new(0.0, 1.0));
+    /// assert_eq!(line.origin, Vec2::new(1.0, 2.0));
+    /// assert_eq!(line.direction, Vec2::new(0.0, 1.0));
+    /// ```
+    pub fn new(origin: V, direction: V) -> Self {
+        Self { origin, direction }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Line3;
+    /// # use vmath::linalg::Vec3;
+    /// let line = Line3::through(Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(line.direction, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn through(a: V, b: V) -> Self {
+        Self {
+            origin: a,
+            direction: b - a,
+        }
+    }
+}
+
+impl<V: Vector> Linear<V> for Line<V> {
+    fn origin(self) -> V {
+        self.origin
+    }
+
+    fn direction(self) -> V {
+        self.direction
+    }
+
+    fn bounds(self) -> (Real, Real) {
+        (Real::NEG_INFINITY, Real::INFINITY)
+    }
+}
+
+impl<V: Vector> Ray<V> {
+    /// ```
+    /// # use vmath::geometry::Ray3;
+    /// # use vmath::linalg::Vec3;
+    /// let ray = Ray3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(ray.direction, Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn new(origin: V, direction: V) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<V: Vector> Linear<V> for Ray<V> {
+    fn origin(self) -> V {
+        self.origin
+    }
+
+    fn direction(self) -> V {
+        self.direction
+    }
+
+    fn bounds(self) -> (Real, Real) {
+        (0.0, Real::INFINITY)
+    }
+}
+
+impl<V: Vector> Segment<V> {
+    /// ```
+    /// # use vmath::geometry::Segment2;
+    /// # use vmath::linalg::Vec2;
+    /// let segment = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    /// assert_eq!(segment.end, Vec2::new(1.0, 1.0));
+    /// ```
+    pub fn new(start: V, end: V) -> Self {
+        Self { start, end }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Segment3;
+    /// # use vmath::linalg::Vec3;
+    /// let segment = Segment3::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(3.0, 4.0, 7.0));
+    /// assert_eq!(segment.length(), 7.0);
+    /// ```
+    pub fn length(self) -> Real {
+        self.start.distance(self.end)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Segment2;
+    /// # use vmath::linalg::Vec2;
+    /// let segment = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 4.0));
+    /// assert_eq!(segment.midpoint(), Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn midpoint(self) -> V {
+        self.start.lerp(self.end, 0.5)
+    }
+}
+
+impl<V: Vector> Linear<V> for Segment<V> {
+    fn origin(self) -> V {
+        self.start
+    }
+
+    fn direction(self) -> V {
+        self.end - self.start
+    }
+
+    fn bounds(self) -> (Real, Real) {
+        (0.0, 1.0)
+    }
+}
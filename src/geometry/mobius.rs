@@ -0,0 +1,171 @@
+use std::ops::Mul;
+
+use crate::numbers::{Complex, ExtendedComplex};
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// z -> (a z + b) / (c z + d)
+/// on the complex plane, a map sending circles and lines to circles and lines.
+/// it is the 2 x 2 complex matrix [[a, b], [c, d]] acting projectively,
+/// so scaling all four gives the same map
+/// ```
+/// # use vmath::geometry::Mobius;
+/// # use vmath::numbers::Complex;
+/// // the cayley transform takes the upper half plane onto the unit disk
+/// let cayley = Mobius::new(Complex::ONE, -Complex::I, Complex::ONE, Complex::I);
+/// assert_eq!(cayley.apply(Complex::I), Complex::ZERO);
+/// assert!(cayley.apply(Complex::new(3.0, 2.0)).norm() < 1.0);
+/// ```
+pub struct Mobius {
+    pub a: Complex,
+    pub b: Complex,
+    pub c: Complex,
+    pub d: Complex,
+}
+
+impl Mobius {
+    pub const IDENTITY: Self = Self::new(Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ONE);
+
+    pub const fn new(a: Complex, b: Complex, c: Complex, d: Complex) -> Self {
+        Self { a, b, c, d }
+    }
+
+    /// ad - bc, zero when the map is degenerate, sending every point to one
+    pub fn determinant(self) -> Complex {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// (a z + b) / (c z + d). the pole -d / c goes to infinity,
+    /// which a `Complex` cannot hold, so it comes out infinite or NaN,
+    /// `apply_extended` takes it there
+    /// ```
+    /// # use vmath::geometry::Mobius;
+    /// # use vmath::numbers::Complex;
+    /// // 1 / z
+    /// let inversion = Mobius::new(Complex::ZERO, Complex::ONE, Complex::ONE, Complex::ZERO);
+    /// assert_eq!(inversion.apply(Complex::new(0.0, 2.0)), Complex::new(0.0, -0.5));
+    /// ```
+    pub fn apply(self, z: Complex) -> Complex {
+        (self.a * z + self.b) / (self.c * z + self.d)
+    }
+
+    /// the map on the riemann sphere, sending the pole -d / c to infinity
+    /// and infinity to a / c
+    /// ```
+    /// # use vmath::geometry::Mobius;
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// let map = Mobius::new(Complex::from(2.0), Complex::ONE, Complex::ONE, -Complex::ONE);
+    /// let pole = ExtendedComplex::from(Complex::ONE);
+    /// assert_eq!(map.apply_extended(pole), ExtendedComplex::Infinity);
+    /// assert_eq!(
+    ///     map.apply_extended(ExtendedComplex::Infinity),
+    ///     ExtendedComplex::from(Complex::from(2.0)),
+    /// );
+    /// ```
+    pub fn apply_extended(self, z: ExtendedComplex) -> ExtendedComplex {
+        match z {
+            ExtendedComplex::Finite(z) => {
+                ExtendedComplex::from(self.a * z + self.b)
+                    / ExtendedComplex::from(self.c * z + self.d)
+            }
+            ExtendedComplex::Infinity => {
+                ExtendedComplex::from(self.a) / ExtendedComplex::from(self.c)
+            }
+        }
+    }
+
+    /// the map undoing this one, the adjugate [[d, -b], [-c, a]],
+    /// an error when the map is degenerate
+    /// ```
+    /// # use vmath::geometry::Mobius;
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// let map = Mobius::new(
+    ///     Complex::new(2.0, 1.0),
+    ///     Complex::new(0.0, -1.0),
+    ///     Complex::new(1.0, 0.0),
+    ///     Complex::new(3.0, 0.0),
+    /// );
+    /// let z = Complex::new(0.5, -1.5);
+    /// let back = map.inverse().unwrap().apply(map.apply(z));
+    /// assert!((back - z).norm() < 1e-15);
+    ///
+    /// let constant = Mobius::new(Complex::ONE, Complex::ONE, Complex::ONE, Complex::ONE);
+    /// assert_eq!(constant.inverse(), Err(Error::Singular));
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        if self.determinant() == Complex::ZERO {
+            return Err(Error::Singular);
+        }
+        Ok(Self::new(self.d, -self.b, -self.c, self.a))
+    }
+
+    /// the two points the map leaves where they are, counted with multiplicity,
+    /// among them infinity whenever c is zero.
+    /// None for the identity, which fixes every point
+    /// ```
+    /// # use vmath::geometry::Mobius;
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// // z -> 2z fixes 0 and infinity
+    /// let scale = Mobius::new(Complex::from(2.0), Complex::ZERO, Complex::ZERO, Complex::ONE);
+    /// assert_eq!(scale.fixed_points(), Some([ExtendedComplex::ZERO, ExtendedComplex::Infinity]));
+    ///
+    /// // z -> 1 / z fixes 1 and -1
+    /// let inversion = Mobius::new(Complex::ZERO, Complex::ONE, Complex::ONE, Complex::ZERO);
+    /// let [p, q] = inversion.fixed_points().unwrap().map(|p| p.finite().unwrap());
+    /// assert_eq!((p.real * q.real, p.imaginary, q.imaginary), (-1.0, 0.0, 0.0));
+    ///
+    /// // a translation fixes only infinity, twice
+    /// let shift = Mobius::new(Complex::ONE, Complex::I, Complex::ZERO, Complex::ONE);
+    /// assert_eq!(shift.fixed_points(), Some([ExtendedComplex::Infinity; 2]));
+    /// assert_eq!(Mobius::IDENTITY.fixed_points(), None);
+    /// ```
+    pub fn fixed_points(self) -> Option<[ExtendedComplex; 2]> {
+        let Self { a, b, c, d } = self;
+        if c == Complex::ZERO {
+            return match (a == d, b == Complex::ZERO) {
+                (true, true) => None,
+                (true, false) => Some([ExtendedComplex::Infinity; 2]),
+                (false, _) => Some([
+                    ExtendedComplex::from(b / (d - a)),
+                    ExtendedComplex::Infinity,
+                ]),
+            };
+        }
+        // c z^2 + (d - a) z - b = 0, taking the larger of (a - d) +- sqrt to avoid
+        // cancellation and the other root from the product, -b / c
+        let root = ((d - a) * (d - a) + b * c * 4.0).sqrt();
+        let q = if ((a - d).conjugate() * root).real >= 0.0 {
+            (a - d) + root
+        } else {
+            (a - d) - root
+        };
+        if q == Complex::ZERO {
+            return Some([ExtendedComplex::ZERO; 2]);
+        }
+        Some([q / (c * 2.0), b * -2.0 / q].map(ExtendedComplex::from))
+    }
+}
+
+impl Mul for Mobius {
+    type Output = Self;
+    /// applies rhs first, then self, as the product of their matrices
+    /// ```
+    /// # use vmath::geometry::Mobius;
+    /// # use vmath::numbers::Complex;
+    /// let shift = Mobius::new(Complex::ONE, Complex::ONE, Complex::ZERO, Complex::ONE);
+    /// let inversion = Mobius::new(Complex::ZERO, Complex::ONE, Complex::ONE, Complex::ZERO);
+    /// let z = Complex::new(1.0, 1.0);
+    /// assert_eq!((shift * inversion).apply(z), shift.apply(inversion.apply(z)));
+    /// assert_eq!((inversion * shift).apply(z), inversion.apply(shift.apply(z)));
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.a * rhs.a + self.b * rhs.c,
+            self.a * rhs.b + self.b * rhs.d,
+            self.c * rhs.a + self.d * rhs.c,
+            self.c * rhs.b + self.d * rhs.d,
+        )
+    }
+}
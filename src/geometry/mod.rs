@@ -0,0 +1,38 @@
+pub mod aabb;
+pub mod ball;
+pub mod delaunay;
+pub mod frustum;
+pub mod hull;
+pub mod isometry;
+pub mod line;
+pub mod mobius;
+pub mod plane;
+pub mod polygon;
+pub mod projective;
+pub mod proximity;
+pub mod rotation;
+pub mod transform;
+pub mod triangle;
+
+pub use crate::geometry::aabb::{Aabb, Aabb2, Aabb3};
+pub use crate::geometry::ball::{Ball, Circle, Sphere};
+pub use crate::geometry::delaunay::{Delaunay, Voronoi};
+pub use crate::geometry::frustum::{Containment, Frustum};
+pub use crate::geometry::hull::convex_hull;
+pub use crate::geometry::isometry::{Isometry2, Isometry3};
+pub use crate::geometry::line::{
+    Intersection, Line, Line2, Line3, Linear, Ray, Ray2, Ray3, Segment, Segment2, Segment3,
+};
+pub use crate::geometry::mobius::Mobius;
+pub use crate::geometry::plane::Plane;
+pub use crate::geometry::polygon::Polygon2;
+pub use crate::geometry::projective::{Projective3, Viewport};
+pub use crate::geometry::proximity::Proximity;
+pub use crate::geometry::rotation::{Rotation2, Rotation3};
+pub use crate::geometry::transform::{Transform2, Transform3};
+pub use crate::geometry::triangle::{Triangle, Triangle2, Triangle3};
+
+use crate::numbers::Real;
+
+/// distance below which two points are considered coincident
+pub const EPSILON: Real = 1e-9;
@@ -0,0 +1,149 @@
+use super::line::span;
+use super::{Intersection, Linear, EPSILON};
+use crate::linalg::{Point3, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the points p where normal . p = distance
+/// with normal of unit length
+pub struct Plane {
+    pub normal: Vec3,
+    pub distance: Real,
+}
+
+impl Plane {
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 0.0, 1.0), 2.0);
+    /// assert_eq!(plane.normal, Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(plane.distance, 2.0);
+    /// ```
+    pub fn new(normal: Vec3, distance: Real) -> Self {
+        Self { normal, distance }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::from_point_normal(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 2.0, 0.0));
+    /// assert_eq!(plane, Plane::new(Vec3::new(0.0, 1.0, 0.0), 2.0));
+    /// ```
+    pub fn from_point_normal(point: Point3, normal: Vec3) -> Self {
+        let normal = normal.normalize();
+        Self {
+            normal,
+            distance: normal.dot(point),
+        }
+    }
+
+    /// counter-clockwise points give a normal facing the viewer,
+    /// None when the points are collinear
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::from_points(
+    ///     Vec3::new(1.0, 0.0, 5.0),
+    ///     Vec3::new(0.0, 1.0, 5.0),
+    ///     Vec3::new(0.0, 0.0, 5.0),
+    /// );
+    /// assert_eq!(plane, Some(Plane::new(Vec3::new(0.0, 0.0, 1.0), 5.0)));
+    ///
+    /// let collinear = Plane::from_points(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(1.0, 1.0, 1.0),
+    ///     Vec3::new(2.0, 2.0, 2.0),
+    /// );
+    /// assert_eq!(collinear, None);
+    /// ```
+    pub fn from_points(a: Point3, b: Point3, c: Point3) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        if normal.length_squared() <= EPSILON * EPSILON {
+            return None;
+        }
+        Some(Self::from_point_normal(a, normal))
+    }
+
+    /// positive on the side the normal faces
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 1.0, 0.0), 1.0);
+    /// assert_eq!(plane.signed_distance(Vec3::new(5.0, 3.0, 5.0)), 2.0);
+    /// assert_eq!(plane.signed_distance(Vec3::new(5.0, -3.0, 5.0)), -4.0);
+    /// ```
+    pub fn signed_distance(self, point: Point3) -> Real {
+        self.normal.dot(point) - self.distance
+    }
+
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 1.0, 0.0), 1.0);
+    /// assert_eq!(plane.project(Vec3::new(5.0, 3.0, 5.0)), Vec3::new(5.0, 1.0, 5.0));
+    /// ```
+    pub fn project(self, point: Point3) -> Point3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// the same plane with its normal facing the other way
+    /// ```
+    /// # use vmath::geometry::Plane;
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 1.0, 0.0), 1.0);
+    /// assert_eq!(plane.flip(), Plane::new(Vec3::new(0.0, -1.0, 0.0), -1.0));
+    /// ```
+    pub fn flip(self) -> Self {
+        Self {
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+
+    /// the parameter where the line through a linear component crosses the plane,
+    /// None when it runs parallel
+    /// ```
+    /// # use vmath::geometry::{Plane, Ray3};
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 0.0, 1.0), 4.0);
+    /// let ray = Ray3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 2.0));
+    /// assert_eq!(plane.crossing_parameter(ray), Some(2.0));
+    /// ```
+    pub fn crossing_parameter<L: Linear<Vec3>>(self, linear: L) -> Option<Real> {
+        let rate = self.normal.dot(linear.direction());
+        if rate == 0.0 {
+            return None;
+        }
+        Some(-self.signed_distance(linear.origin()) / rate)
+    }
+
+    /// components lying in the plane intersect it entirely
+    /// ```
+    /// # use vmath::geometry::{Intersection, Plane, Ray3, Segment3};
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 0.0, 1.0), 4.0);
+    /// let ray = Ray3::new(Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(plane.intersect(ray), Intersection::Point(Vec3::new(1.0, 1.0, 4.0)));
+    /// assert_eq!(plane.intersect(Ray3::new(ray.origin, -ray.direction)), Intersection::None);
+    ///
+    /// let segment = Segment3::new(Vec3::new(0.0, 0.0, 4.0), Vec3::new(1.0, 0.0, 4.0));
+    /// assert_eq!(plane.intersect(segment), Intersection::Segment(segment));
+    /// ```
+    pub fn intersect<L: Linear<Vec3>>(self, linear: L) -> Intersection<Vec3> {
+        let (min, max) = linear.bounds();
+        match self.crossing_parameter(linear) {
+            Some(t) => {
+                let tolerance = EPSILON / linear.direction().length();
+                match t >= min - tolerance && t <= max + tolerance {
+                    true => Intersection::Point(linear.point_at(t.clamp(min, max))),
+                    false => Intersection::None,
+                }
+            }
+            None => match self.signed_distance(linear.origin()).abs() <= EPSILON {
+                true => span(linear, min, max),
+                false => Intersection::None,
+            },
+        }
+    }
+}
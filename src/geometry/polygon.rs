@@ -0,0 +1,225 @@
+use super::{Proximity, Segment2, EPSILON};
+use crate::linalg::{Point2, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// a closed chain of vertices,
+/// the last vertex joins back to the first
+pub struct Polygon2 {
+    pub vertices: Vec<Point2>,
+}
+
+impl Polygon2 {
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let polygon = Polygon2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)]);
+    /// assert_eq!(polygon.vertices.len(), 3);
+    /// ```
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Self { vertices }
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Polygon2, Segment2};
+    /// # use vmath::linalg::Vec2;
+    /// let polygon = Polygon2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)]);
+    /// let edges: Vec<Segment2> = polygon.edges().collect();
+    /// assert_eq!(edges[2], Segment2::new(Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0)));
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&start, &end)| Segment2::new(start, end))
+    }
+
+    /// shoelace formula, positive when the vertices run counter-clockwise
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let square = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ]);
+    /// assert_eq!(square.signed_area(), 4.0);
+    /// let mut reversed = square.clone();
+    /// reversed.vertices.reverse();
+    /// assert_eq!(reversed.signed_area(), -4.0);
+    /// ```
+    pub fn signed_area(&self) -> Real {
+        self.edges()
+            .map(|edge| edge.start.perp_dot(edge.end))
+            .sum::<Real>()
+            / 2.0
+    }
+
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Polygon2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(0.0, 3.0), Vec2::new(4.0, 0.0)]);
+    /// assert_eq!(triangle.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> Real {
+        self.signed_area().abs()
+    }
+
+    /// the center of mass of the enclosed region,
+    /// or the mean vertex when the polygon encloses no area
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// // an L made of two unit squares and one more above the first
+    /// let l = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 1.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(1.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ]);
+    /// let centroid = l.centroid();
+    /// assert!((centroid.x - 5.0 / 6.0).abs() < 1e-12);
+    /// assert!((centroid.y - 5.0 / 6.0).abs() < 1e-12);
+    /// ```
+    pub fn centroid(&self) -> Point2 {
+        let signed_area = self.signed_area();
+        if signed_area == 0.0 {
+            let sum = self.vertices.iter().fold(Point2::ZERO, |sum, &v| sum + v);
+            return sum / self.vertices.len() as Real;
+        }
+        let weighted = self.edges().fold(Point2::ZERO, |sum, edge| {
+            sum + (edge.start + edge.end) * edge.start.perp_dot(edge.end)
+        });
+        weighted / (6.0 * signed_area)
+    }
+
+    /// true when every turn goes the same way,
+    /// straight runs of collinear vertices are allowed
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let square = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ]);
+    /// assert!(square.is_convex());
+    ///
+    /// let dart = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 1.0),
+    ///     Vec2::new(4.0, 0.0),
+    ///     Vec2::new(2.0, 4.0),
+    /// ]);
+    /// assert!(!dart.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let count = self.vertices.len();
+        let mut sign = 0.0;
+        for i in 0..count {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % count];
+            let c = self.vertices[(i + 2) % count];
+            let turn = (b - a).perp_dot(c - b);
+            if turn.abs() <= EPSILON {
+                continue;
+            }
+            if sign * turn < 0.0 {
+                return false;
+            }
+            sign = turn.signum();
+        }
+        true
+    }
+
+    /// points on the boundary are contained,
+    /// self-intersecting polygons use the non-zero winding rule
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let dart = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 1.0),
+    ///     Vec2::new(4.0, 0.0),
+    ///     Vec2::new(2.0, 4.0),
+    /// ]);
+    /// assert!(dart.contains_point(Vec2::new(2.0, 2.0)));
+    /// assert!(dart.contains_point(Vec2::new(1.0, 0.5)));
+    /// assert!(!dart.contains_point(Vec2::new(2.0, 0.5)));
+    /// ```
+    pub fn contains_point(&self, point: Point2) -> bool {
+        let mut winding = 0;
+        for edge in self.edges() {
+            if edge.distance(point) <= EPSILON {
+                return true;
+            }
+            let side = (edge.end - edge.start).perp_dot(point - edge.start);
+            if edge.start.y <= point.y {
+                if edge.end.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if edge.end.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+
+    /// the part of self inside clipper, by sutherland-hodgman,
+    /// clipper must be convex but may run in either direction
+    /// ```
+    /// # use vmath::geometry::Polygon2;
+    /// # use vmath::linalg::Vec2;
+    /// let square = Polygon2::new(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ]);
+    /// let shifted = Polygon2::new(square.vertices.iter().map(|&v| v + Vec2::new(1.0, 1.0)).collect());
+    /// let clipped = square.clip(&shifted);
+    /// assert_eq!(clipped.area(), 1.0);
+    /// assert!(clipped.vertices.contains(&Vec2::new(1.0, 1.0)));
+    /// assert!(clipped.vertices.contains(&Vec2::new(2.0, 2.0)));
+    /// ```
+    pub fn clip(&self, clipper: &Self) -> Self {
+        let orientation = clipper.signed_area().signum();
+        let mut output = self.vertices.clone();
+        for edge in clipper.edges() {
+            if output.is_empty() {
+                break;
+            }
+            let direction = edge.end - edge.start;
+            let inside = |p: Point2| orientation * direction.perp_dot(p - edge.start) >= 0.0;
+            let input = std::mem::take(&mut output);
+            let mut previous = input[input.len() - 1];
+            for &current in &input {
+                match (inside(previous), inside(current)) {
+                    (true, true) => output.push(current),
+                    (true, false) => output.push(crossing(previous, current, edge)),
+                    (false, true) => {
+                        output.push(crossing(previous, current, edge));
+                        output.push(current);
+                    }
+                    (false, false) => {}
+                }
+                previous = current;
+            }
+        }
+        Self { vertices: output }
+    }
+}
+
+/// where the segment from a to b crosses the line through edge
+fn crossing(a: Point2, b: Point2, edge: Segment2) -> Point2 {
+    let direction = edge.end - edge.start;
+    let side_a = direction.perp_dot(a - edge.start);
+    let side_b = direction.perp_dot(b - edge.start);
+    a.lerp(b, side_a / (side_a - side_b))
+}
@@ -0,0 +1,259 @@
+use std::ops::Mul;
+
+use super::{Ray3, Transform3};
+use crate::linalg::{Mat4, Point2, Point3, Vec3};
+use crate::numbers::Real;
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// p -> matrix * (p, 1) followed by the divide by w,
+/// mapping camera or world space into normalized device coordinates
+/// where the visible volume is -1 <= x, y, z <= 1
+pub struct Projective3 {
+    pub matrix: Mat4,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the pixel rectangle normalized device coordinates are drawn into,
+/// with y growing downward from the top-left corner and depth in [0, 1]
+pub struct Viewport {
+    pub x: Real,
+    pub y: Real,
+    pub width: Real,
+    pub height: Real,
+}
+
+impl Projective3 {
+    pub const IDENTITY: Self = Self {
+        matrix: Mat4::IDENTITY,
+    };
+
+    /// ```
+    /// # use vmath::geometry::Projective3;
+    /// # use vmath::linalg::Mat4;
+    /// let projective = Projective3::new(Mat4::IDENTITY);
+    /// assert_eq!(projective, Projective3::IDENTITY);
+    /// ```
+    pub const fn new(matrix: Mat4) -> Self {
+        Self { matrix }
+    }
+
+    /// right-handed perspective looking down -z,
+    /// with vertical field of view fov_y radians and aspect = width / height
+    /// ```
+    /// # use vmath::geometry::Projective3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let projective = Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let near = projective.project_point(Vec3::new(1.0, 0.0, -1.0)).unwrap();
+    /// assert!(near.distance(Vec3::new(1.0, 0.0, -1.0)) < 1e-12);
+    /// let far = projective.project_point(Vec3::new(0.0, 10.0, -10.0)).unwrap();
+    /// assert!(far.distance(Vec3::new(0.0, 1.0, 1.0)) < 1e-12);
+    /// ```
+    pub fn perspective(fov_y: Real, aspect: Real, near: Real, far: Real) -> Self {
+        let f = 1.0 / (fov_y / 2.0).tan();
+        Self::new(Mat4::new([
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ]))
+    }
+
+    /// right-handed orthographic projection looking down -z
+    /// ```
+    /// # use vmath::geometry::Projective3;
+    /// # use vmath::linalg::Vec3;
+    /// let projective = Projective3::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 4.0);
+    /// assert_eq!(
+    ///     projective.project_point(Vec3::new(2.0, -1.0, -2.0)),
+    ///     Some(Vec3::new(1.0, -1.0, 0.0)),
+    /// );
+    /// ```
+    pub fn orthographic(
+        left: Real,
+        right: Real,
+        bottom: Real,
+        top: Real,
+        near: Real,
+        far: Real,
+    ) -> Self {
+        Self::new(Mat4::new([
+            [
+                2.0 / (right - left),
+                0.0,
+                0.0,
+                -(right + left) / (right - left),
+            ],
+            [
+                0.0,
+                2.0 / (top - bottom),
+                0.0,
+                -(top + bottom) / (top - bottom),
+            ],
+            [0.0, 0.0, -2.0 / (far - near), -(far + near) / (far - near)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
+    /// None when the point maps to infinity, such as the eye of a perspective
+    /// ```
+    /// # use vmath::geometry::Projective3;
+    /// # use vmath::linalg::Vec3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let projective = Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// assert_eq!(projective.project_point(Vec3::new(0.0, 0.0, 0.0)), None);
+    /// ```
+    pub fn project_point(self, point: Point3) -> Option<Point3> {
+        let projected = self.matrix * point.extend(1.0);
+        (projected.w != 0.0).then(|| projected.truncate() / projected.w)
+    }
+
+    /// an error when the matrix is singular
+    /// ```
+    /// # use vmath::geometry::Projective3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let projective = Projective3::perspective(FRAC_PI_2, 1.5, 0.1, 100.0);
+    /// let point = Vec3::new(1.0, 2.0, -5.0);
+    /// let ndc = projective.project_point(point).unwrap();
+    /// let back = projective.inverse().unwrap().project_point(ndc).unwrap();
+    /// assert!(back.distance(point) < 1e-12);
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        Ok(Self::new(self.matrix.inverse()?))
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Projective3, Viewport};
+    /// # use vmath::linalg::Vec3;
+    /// let projective = Projective3::orthographic(-1.0, 1.0, -1.0, 1.0, 0.0, 2.0);
+    /// let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    /// assert_eq!(
+    ///     projective.world_to_screen(Vec3::new(1.0, 1.0, 0.0), viewport),
+    ///     Some(Vec3::new(800.0, 0.0, 0.0)),
+    /// );
+    /// ```
+    pub fn world_to_screen(self, point: Point3, viewport: Viewport) -> Option<Point3> {
+        Some(viewport.ndc_to_screen(self.project_point(point)?))
+    }
+
+    /// the point with the given pixel position and depth, None when the matrix is singular
+    /// ```
+    /// # use vmath::geometry::{Projective3, Viewport};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_3;
+    /// let projective = Projective3::perspective(FRAC_PI_3, 4.0 / 3.0, 0.5, 50.0);
+    /// let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    /// let point = Vec3::new(-1.0, 0.5, -7.0);
+    /// let screen = projective.world_to_screen(point, viewport).unwrap();
+    /// let back = projective.screen_to_world(screen, viewport).unwrap();
+    /// assert!(back.distance(point) < 1e-9);
+    /// ```
+    pub fn screen_to_world(self, screen: Point3, viewport: Viewport) -> Option<Point3> {
+        self.inverse()
+            .ok()?
+            .project_point(viewport.screen_to_ndc(screen))
+    }
+
+    /// the ray from the near plane through the pixel to the far plane,
+    /// None when the matrix is singular
+    /// ```
+    /// # use vmath::geometry::{Linear, Projective3, Viewport};
+    /// # use vmath::linalg::{Vec2, Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let projective = Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let viewport = Viewport::new(0.0, 0.0, 100.0, 100.0);
+    /// // the center pixel looks straight down -z
+    /// let ray = projective.picking_ray(Vec2::new(50.0, 50.0), viewport).unwrap();
+    /// assert!(ray.origin.distance(Vec3::new(0.0, 0.0, -1.0)) < 1e-12);
+    /// assert!(ray.point_at(1.0).distance(Vec3::new(0.0, 0.0, -10.0)) < 1e-12);
+    /// ```
+    pub fn picking_ray(self, pixel: Point2, viewport: Viewport) -> Option<Ray3> {
+        let inverse = self.inverse().ok()?;
+        let near = inverse.project_point(viewport.screen_to_ndc(pixel.extend(0.0)))?;
+        let far = inverse.project_point(viewport.screen_to_ndc(pixel.extend(1.0)))?;
+        Some(Ray3::new(near, far - near))
+    }
+}
+
+impl Viewport {
+    /// ```
+    /// # use vmath::geometry::Viewport;
+    /// let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    /// assert_eq!(viewport.width, 800.0);
+    /// ```
+    pub const fn new(x: Real, y: Real, width: Real, height: Real) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Viewport;
+    /// # use vmath::linalg::Vec3;
+    /// let viewport = Viewport::new(10.0, 20.0, 200.0, 100.0);
+    /// assert_eq!(
+    ///     viewport.ndc_to_screen(Vec3::new(0.0, 1.0, -1.0)),
+    ///     Vec3::new(110.0, 20.0, 0.0),
+    /// );
+    /// ```
+    pub fn ndc_to_screen(self, ndc: Point3) -> Point3 {
+        Vec3::new(
+            self.x + (ndc.x + 1.0) / 2.0 * self.width,
+            self.y + (1.0 - ndc.y) / 2.0 * self.height,
+            (ndc.z + 1.0) / 2.0,
+        )
+    }
+
+    /// ```
+    /// # use vmath::geometry::Viewport;
+    /// # use vmath::linalg::Vec3;
+    /// let viewport = Viewport::new(10.0, 20.0, 200.0, 100.0);
+    /// assert_eq!(
+    ///     viewport.screen_to_ndc(Vec3::new(110.0, 20.0, 0.0)),
+    ///     Vec3::new(0.0, 1.0, -1.0),
+    /// );
+    /// ```
+    pub fn screen_to_ndc(self, screen: Point3) -> Point3 {
+        Vec3::new(
+            (screen.x - self.x) / self.width * 2.0 - 1.0,
+            1.0 - (screen.y - self.y) / self.height * 2.0,
+            screen.z * 2.0 - 1.0,
+        )
+    }
+}
+
+impl Mul for Projective3 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::{Projective3, Transform3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let projection = Projective3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let view = Transform3::from_translation(Vec3::new(0.0, 0.0, -2.0));
+    /// let view_projection = projection * Projective3::from(view);
+    /// let ndc = view_projection.project_point(Vec3::new(1.0, 0.0, 1.0)).unwrap();
+    /// assert!(ndc.distance(Vec3::new(1.0, 0.0, -1.0)) < 1e-12);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.matrix * rhs.matrix)
+    }
+}
+
+impl From<Transform3> for Projective3 {
+    fn from(transform: Transform3) -> Self {
+        Self::new(transform.to_matrix())
+    }
+}
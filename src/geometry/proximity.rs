@@ -0,0 +1,227 @@
+use super::{Aabb, Ball, Line, Linear, Plane, Ray, Segment, Triangle};
+use crate::linalg::{Point3, Vector};
+use crate::numbers::Real;
+
+/// closest points between two shapes, where every shape is solid,
+/// so overlapping shapes share a closest point and are at distance 0
+pub trait Proximity<Rhs>: Sized {
+    type Point: Vector;
+
+    /// the closest point on self and the closest point on rhs, in that order
+    /// ```
+    /// # use vmath::geometry::{Proximity, Segment3};
+    /// # use vmath::linalg::Vec3;
+    /// let a = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+    /// let b = Segment3::new(Vec3::new(1.0, -1.0, 3.0), Vec3::new(1.0, 1.0, 3.0));
+    /// assert_eq!(
+    ///     a.closest_points(b),
+    ///     (Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 3.0)),
+    /// );
+    /// ```
+    fn closest_points(self, rhs: Rhs) -> (Self::Point, Self::Point);
+
+    /// ```
+    /// # use vmath::geometry::{Line2, Proximity};
+    /// # use vmath::linalg::Vec2;
+    /// let line = Line2::new(Vec2::new(0.0, 1.0), Vec2::new(1.0, 0.0));
+    /// assert_eq!(line.distance_squared(Vec2::new(-7.0, 4.0)), 9.0);
+    /// ```
+    fn distance_squared(self, rhs: Rhs) -> Real {
+        let (a, b) = self.closest_points(rhs);
+        (b - a).length_squared()
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Proximity, Segment3};
+    /// # use vmath::linalg::Vec3;
+    /// let segment = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(segment.distance(Vec3::new(4.0, 4.0, 0.0)), 5.0);
+    /// ```
+    fn distance(self, rhs: Rhs) -> Real {
+        self.distance_squared(rhs).sqrt()
+    }
+}
+
+macro_rules! impl_linear_proximity {
+    ($($linear:ident),*) => {
+        $(
+            impl<V: Vector> Proximity<V> for $linear<V> {
+                type Point = V;
+                fn closest_points(self, point: V) -> (V, V) {
+                    (self.closest_point(point), point)
+                }
+            }
+
+            impl<V: Vector> Proximity<Line<V>> for $linear<V> {
+                type Point = V;
+                fn closest_points(self, other: Line<V>) -> (V, V) {
+                    let (t, s) = self.closest_parameters(other);
+                    (self.point_at(t), other.point_at(s))
+                }
+            }
+
+            impl<V: Vector> Proximity<Ray<V>> for $linear<V> {
+                type Point = V;
+                fn closest_points(self, other: Ray<V>) -> (V, V) {
+                    let (t, s) = self.closest_parameters(other);
+                    (self.point_at(t), other.point_at(s))
+                }
+            }
+
+            impl<V: Vector> Proximity<Segment<V>> for $linear<V> {
+                type Point = V;
+                fn closest_points(self, other: Segment<V>) -> (V, V) {
+                    let (t, s) = self.closest_parameters(other);
+                    (self.point_at(t), other.point_at(s))
+                }
+            }
+
+            impl<V: Vector> Proximity<Ball<V>> for $linear<V> {
+                type Point = V;
+                fn closest_points(self, ball: Ball<V>) -> (V, V) {
+                    let point = self.closest_point(ball.center);
+                    (point, ball.closest_point(point))
+                }
+            }
+
+            impl<V: Vector> Proximity<$linear<V>> for Ball<V> {
+                type Point = V;
+                fn closest_points(self, linear: $linear<V>) -> (V, V) {
+                    let (a, b) = linear.closest_points(self);
+                    (b, a)
+                }
+            }
+        )*
+    };
+}
+
+impl_linear_proximity!(Line, Ray, Segment);
+
+impl<V: Vector> Proximity<V> for Triangle<V> {
+    type Point = V;
+    /// ```
+    /// # use vmath::geometry::{Proximity, Triangle3};
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(2.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.distance(Vec3::new(0.5, 0.5, -2.0)), 2.0);
+    /// ```
+    fn closest_points(self, point: V) -> (V, V) {
+        (self.closest_point(point), point)
+    }
+}
+
+impl<V: Vector> Proximity<V> for Aabb<V> {
+    type Point = V;
+    /// ```
+    /// # use vmath::geometry::{Aabb2, Proximity};
+    /// # use vmath::linalg::Vec2;
+    /// let aabb = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    /// assert_eq!(aabb.distance(Vec2::new(5.0, 6.0)), 5.0);
+    /// assert_eq!(aabb.distance(Vec2::new(1.0, 1.0)), 0.0);
+    /// ```
+    fn closest_points(self, point: V) -> (V, V) {
+        (self.closest_point(point), point)
+    }
+}
+
+impl<V: Vector> Proximity<Aabb<V>> for Aabb<V> {
+    type Point = V;
+    /// overlapping boxes meet at the center of their overlap
+    /// ```
+    /// # use vmath::geometry::{Aabb2, Proximity};
+    /// # use vmath::linalg::Vec2;
+    /// let a = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+    /// let b = Aabb2::new(Vec2::new(3.0, 1.0), Vec2::new(4.0, 5.0));
+    /// assert_eq!(
+    ///     a.closest_points(b),
+    ///     (Vec2::new(2.0, 1.5), Vec2::new(3.0, 1.5)),
+    /// );
+    /// ```
+    fn closest_points(self, other: Self) -> (V, V) {
+        let mut a = V::ZERO;
+        let mut b = V::ZERO;
+        for i in 0..V::DIMENSION {
+            if self.max[i] < other.min[i] {
+                a[i] = self.max[i];
+                b[i] = other.min[i];
+            } else if other.max[i] < self.min[i] {
+                a[i] = self.min[i];
+                b[i] = other.max[i];
+            } else {
+                let middle = (self.min[i].max(other.min[i]) + self.max[i].min(other.max[i])) / 2.0;
+                a[i] = middle;
+                b[i] = middle;
+            }
+        }
+        (a, b)
+    }
+}
+
+impl<V: Vector> Proximity<V> for Ball<V> {
+    type Point = V;
+    /// ```
+    /// # use vmath::geometry::{Proximity, Sphere};
+    /// # use vmath::linalg::Vec3;
+    /// let sphere = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+    /// assert_eq!(sphere.distance(Vec3::new(0.0, 3.0, 0.0)), 2.0);
+    /// ```
+    fn closest_points(self, point: V) -> (V, V) {
+        (self.closest_point(point), point)
+    }
+}
+
+impl<V: Vector> Proximity<Ball<V>> for Ball<V> {
+    type Point = V;
+    /// ```
+    /// # use vmath::geometry::{Circle, Proximity};
+    /// # use vmath::linalg::Vec2;
+    /// let a = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+    /// let b = Circle::new(Vec2::new(5.0, 0.0), 2.0);
+    /// assert_eq!(a.closest_points(b), (Vec2::new(1.0, 0.0), Vec2::new(3.0, 0.0)));
+    /// assert_eq!(a.distance(Circle::new(Vec2::new(2.0, 0.0), 2.0)), 0.0);
+    /// ```
+    fn closest_points(self, other: Self) -> (V, V) {
+        let a = self.closest_point(other.center);
+        (a, other.closest_point(a))
+    }
+}
+
+impl<V: Vector> Proximity<Aabb<V>> for Ball<V> {
+    type Point = V;
+    /// ```
+    /// # use vmath::geometry::{Aabb3, Proximity, Sphere};
+    /// # use vmath::linalg::Vec3;
+    /// let sphere = Sphere::new(Vec3::new(4.0, 0.5, 0.5), 1.0);
+    /// let aabb = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(sphere.distance(aabb), 2.0);
+    /// ```
+    fn closest_points(self, aabb: Aabb<V>) -> (V, V) {
+        let b = aabb.closest_point(self.center);
+        (self.closest_point(b), b)
+    }
+}
+
+impl<V: Vector> Proximity<Ball<V>> for Aabb<V> {
+    type Point = V;
+    fn closest_points(self, ball: Ball<V>) -> (V, V) {
+        let (a, b) = ball.closest_points(self);
+        (b, a)
+    }
+}
+
+impl Proximity<Point3> for Plane {
+    type Point = Point3;
+    /// ```
+    /// # use vmath::geometry::{Plane, Proximity};
+    /// # use vmath::linalg::Vec3;
+    /// let plane = Plane::new(Vec3::new(0.0, 0.0, 1.0), 1.0);
+    /// assert_eq!(plane.distance(Vec3::new(3.0, 4.0, -2.0)), 3.0);
+    /// ```
+    fn closest_points(self, point: Point3) -> (Point3, Point3) {
+        (self.project(point), point)
+    }
+}
@@ -0,0 +1,516 @@
+use std::f64::consts::PI;
+use std::ops::Mul;
+
+use super::EPSILON;
+use crate::linalg::{Mat2, Mat3, Vec2, Vec3, Vector};
+use crate::numbers::{Angle, Complex, Real};
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// a rotation of the plane about the origin,
+/// stored as a complex number of magnitude 1
+pub struct Rotation2 {
+    unit: Complex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// a rotation of space about the origin,
+/// stored as a quaternion scalar + vector of magnitude 1
+pub struct Rotation3 {
+    scalar: Real,
+    vector: Vec3,
+}
+
+impl Rotation2 {
+    pub const IDENTITY: Self = Self {
+        unit: Complex {
+            real: 1.0,
+            imaginary: 0.0,
+        },
+    };
+
+    /// counter-clockwise rotation by angle, in radians when it is a bare `Real`
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Rotation2::from_angle(FRAC_PI_2);
+    /// assert!(rotation.rotate(Vec2::new(1.0, 0.0)).distance(Vec2::new(0.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn from_angle(angle: impl Into<Angle>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self {
+            unit: Complex::new(cos, sin),
+        }
+    }
+
+    /// the rotation by the complex number's argument, an error when it is zero
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// let rotation = Rotation2::from_complex(Complex::new(0.0, 3.0)).unwrap();
+    /// assert_eq!(rotation.complex(), Complex::new(0.0, 1.0));
+    /// assert_eq!(Rotation2::from_complex(Complex::new(0.0, 0.0)), Err(Error::DivisionByZero));
+    /// ```
+    pub fn from_complex(z: Complex) -> Result<Self, Error> {
+        let norm = z.norm();
+        if norm == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self { unit: z / norm })
+    }
+
+    /// the rotation taking the direction of from to the direction of to,
+    /// an error when either is zero
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let rotation = Rotation2::between(Vec2::new(2.0, 0.0), Vec2::new(-1.0, 1.0)).unwrap();
+    /// let rotated = rotation.rotate(Vec2::new(1.0, 0.0));
+    /// assert!(rotated.distance(Vec2::new(-1.0, 1.0).normalize()) < 1e-15);
+    /// ```
+    pub fn between(from: Vec2, to: Vec2) -> Result<Self, Error> {
+        Self::from_complex(Complex::new(from.dot(to), from.perp_dot(to)))
+    }
+
+    pub fn complex(self) -> Complex {
+        self.unit
+    }
+
+    /// the same rotation scaled back to magnitude 1,
+    /// for after long chains of products have let rounding creep in
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// let step = Rotation2::from_angle(0.1);
+    /// let drifted = (0..100_000).fold(Rotation2::IDENTITY, |rotation, _| rotation * step);
+    /// let error = |rotation: Rotation2| (rotation.complex().norm() - 1.0).abs();
+    /// assert!(error(drifted) > 1e-14);
+    /// assert!(error(drifted.renormalize()) <= f64::EPSILON);
+    /// ```
+    pub fn renormalize(self) -> Self {
+        Self {
+            unit: self.unit / self.unit.norm(),
+        }
+    }
+
+    /// in (-pi, pi]
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// let rotation = Rotation2::from_angle(-2.5);
+    /// assert!((rotation.angle() + 2.5).abs() < 1e-15);
+    /// ```
+    pub fn angle(self) -> Real {
+        self.unit.imaginary.atan2(self.unit.real)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// let rotation = Rotation2::from_angle(0.75);
+    /// assert!((rotation * rotation.inverse()).angle().abs() < 1e-15);
+    /// ```
+    pub fn inverse(self) -> Self {
+        Self {
+            unit: self.unit.conjugate(),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use vmath::linalg::Vec2;
+    /// # use std::f64::consts::PI;
+    /// let rotation = Rotation2::from_angle(PI);
+    /// let rotated = rotation.rotate(Vec2::new(1.0, 2.0));
+    /// assert!((rotated.x + 1.0).abs() < 1e-15 && (rotated.y + 2.0).abs() < 1e-15);
+    /// ```
+    pub fn rotate(self, vector: Vec2) -> Vec2 {
+        let Complex {
+            real: cos,
+            imaginary: sin,
+        } = self.unit;
+        Vec2::new(
+            cos * vector.x - sin * vector.y,
+            sin * vector.x + cos * vector.y,
+        )
+    }
+
+    /// turns from self toward other the short way round,
+    /// by t of the angle between them
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use std::f64::consts::PI;
+    /// // the short way from 170 to -170 degrees passes through 180
+    /// let a = Rotation2::from_angle(PI * 17.0 / 18.0);
+    /// let b = Rotation2::from_angle(-PI * 17.0 / 18.0);
+    /// assert!((a.slerp(b, 0.5).angle().abs() - PI).abs() < 1e-12);
+    /// ```
+    pub fn slerp(self, other: Self, t: Real) -> Self {
+        let angle = (self.inverse() * other).angle();
+        self * Self::from_angle(angle * t)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// # use vmath::linalg::Mat2;
+    /// assert_eq!(Rotation2::IDENTITY.to_matrix(), Mat2::IDENTITY);
+    /// ```
+    pub fn to_matrix(self) -> Mat2 {
+        let Complex {
+            real: cos,
+            imaginary: sin,
+        } = self.unit;
+        Mat2::new([[cos, -sin], [sin, cos]])
+    }
+}
+
+impl Rotation3 {
+    pub const IDENTITY: Self = Self {
+        scalar: 1.0,
+        vector: Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+
+    /// right-handed rotation by angle about axis,
+    /// which need not be unit length, a zero axis gives the identity
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+    /// let rotated = rotation.rotate(Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(0.0, 1.0, 0.0)) < 1e-15);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Angle>) -> Self {
+        let length = axis.length();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle.into() / 2.0).sin_cos();
+        Self {
+            scalar: cos,
+            vector: axis * (sin / length),
+        }
+    }
+
+    /// the rotation of the quaternion w + xi + yj + zk once scaled to magnitude 1,
+    /// an error when it is zero
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::Error;
+    /// let rotation = Rotation3::from_quaternion(2.0, 0.0, 0.0, 0.0).unwrap();
+    /// assert_eq!(rotation, Rotation3::IDENTITY);
+    /// assert_eq!(Rotation3::from_quaternion(0.0, 0.0, 0.0, 0.0), Err(Error::DivisionByZero));
+    /// ```
+    pub fn from_quaternion(w: Real, x: Real, y: Real, z: Real) -> Result<Self, Error> {
+        let norm = (w * w + x * x + y * y + z * z).sqrt();
+        if norm == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self {
+            scalar: w / norm,
+            vector: Vec3::new(x, y, z) / norm,
+        })
+    }
+
+    /// a domain error unless the matrix is orthonormal with determinant 1, to within EPSILON
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Mat3, Vec3, Vector};
+    /// # use vmath::Error;
+    /// let matrix = Mat3::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), 2.0);
+    /// let rotation = Rotation3::from_matrix(matrix).unwrap();
+    /// let point = Vec3::new(1.0, 2.0, 3.0);
+    /// assert!(rotation.rotate(point).distance(matrix * point) < 1e-12);
+    ///
+    /// assert_eq!(
+    ///     Rotation3::from_matrix(Mat3::from_diagonal([1.0, 1.0, -1.0])),
+    ///     Err(Error::DomainError),
+    /// );
+    /// ```
+    pub fn from_matrix(matrix: Mat3) -> Result<Self, Error> {
+        let product = matrix * matrix.transpose();
+        let orthonormal = (0..3).all(|i| {
+            (0..3).all(|j| {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (product[i][j] - expected).abs() <= EPSILON
+            })
+        });
+        if !orthonormal || matrix.determinant() <= 0.0 {
+            return Err(Error::DomainError);
+        }
+
+        // shepperd's method, dividing by the largest of the four components
+        let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = matrix.rows;
+        let trace = m00 + m11 + m22;
+        let (w, x, y, z) = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            (s / 4.0, (m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            ((m21 - m12) / s, s / 4.0, (m01 + m10) / s, (m02 + m20) / s)
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            ((m02 - m20) / s, (m01 + m10) / s, s / 4.0, (m12 + m21) / s)
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            ((m10 - m01) / s, (m02 + m20) / s, (m12 + m21) / s, s / 4.0)
+        };
+        Self::from_quaternion(w, x, y, z)
+    }
+
+    /// the shortest rotation taking the direction of from to the direction of to,
+    /// an error when either is zero
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let from = Vec3::new(1.0, 0.0, 0.0);
+    /// let to = Vec3::new(0.0, 3.0, 4.0);
+    /// let rotation = Rotation3::between(from, to).unwrap();
+    /// assert!(rotation.rotate(from).distance(to.normalize()) < 1e-15);
+    ///
+    /// let opposite = Rotation3::between(from, -from).unwrap();
+    /// assert!(opposite.rotate(from).distance(-from) < 1e-15);
+    /// ```
+    pub fn between(from: Vec3, to: Vec3) -> Result<Self, Error> {
+        if from.length_squared() == 0.0 || to.length_squared() == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        let from = from.normalize();
+        let to = to.normalize();
+        let dot = from.dot(to);
+        if dot < EPSILON - 1.0 {
+            // half a turn about any axis perpendicular to from
+            let mut axis = from.cross(Vec3::new(1.0, 0.0, 0.0));
+            if axis.length_squared() < EPSILON {
+                axis = from.cross(Vec3::new(0.0, 1.0, 0.0));
+            }
+            return Ok(Self::from_axis_angle(axis, PI));
+        }
+        let vector = from.cross(to);
+        Self::from_quaternion(1.0 + dot, vector.x, vector.y, vector.z)
+    }
+
+    /// the w of the quaternion w + xi + yj + zk
+    pub fn scalar(self) -> Real {
+        self.scalar
+    }
+
+    /// the (x, y, z) of the quaternion w + xi + yj + zk
+    pub fn vector(self) -> Vec3 {
+        self.vector
+    }
+
+    /// in [0, pi]
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::Vec3;
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), -1.0);
+    /// assert!((rotation.angle() - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn angle(self) -> Real {
+        2.0 * self.vector.length().atan2(self.scalar.abs())
+    }
+
+    /// the unit axis the rotation turns right-handedly about by angle,
+    /// None for the identity
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), -1.0);
+    /// assert!(rotation.axis().unwrap().distance(Vec3::new(-1.0, 0.0, 0.0)) < 1e-15);
+    /// assert_eq!(Rotation3::IDENTITY.axis(), None);
+    /// ```
+    pub fn axis(self) -> Option<Vec3> {
+        let length = self.vector.length();
+        if length == 0.0 {
+            return None;
+        }
+        Some(self.vector * (self.scalar.signum() / length))
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.5);
+    /// let point = Vec3::new(-1.0, 0.5, 2.0);
+    /// assert!(rotation.inverse().rotate(rotation.rotate(point)).distance(point) < 1e-15);
+    /// ```
+    pub fn inverse(self) -> Self {
+        Self {
+            scalar: self.scalar,
+            vector: -self.vector,
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+    /// let rotated = rotation.rotate(Vec3::new(0.0, 1.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(0.0, 0.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn rotate(self, vector: Vec3) -> Vec3 {
+        let t = self.vector.cross(vector) * 2.0;
+        vector + t * self.scalar + self.vector.cross(t)
+    }
+
+    /// turns from self toward other the short way round,
+    /// by t of the angle between them, at a constant rate
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let axis = Vec3::new(0.0, 1.0, 0.0);
+    /// let a = Rotation3::from_axis_angle(axis, 0.2);
+    /// let b = Rotation3::from_axis_angle(axis, 1.0);
+    /// let halfway = a.slerp(b, 0.5);
+    /// assert!((halfway.angle() - 0.6).abs() < 1e-15);
+    /// ```
+    pub fn slerp(self, other: Self, t: Real) -> Self {
+        let mut dot = self.scalar * other.scalar + self.vector.dot(other.vector);
+        // q and -q are the same rotation, take whichever is nearer
+        let other = match dot < 0.0 {
+            true => {
+                dot = -dot;
+                Self {
+                    scalar: -other.scalar,
+                    vector: -other.vector,
+                }
+            }
+            false => other,
+        };
+        let (a, b) = if dot > 1.0 - EPSILON {
+            (1.0 - t, t)
+        } else {
+            let angle = dot.acos();
+            let sin = angle.sin();
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+        let vector = self.vector * a + other.vector * b;
+        Self::from_quaternion(
+            self.scalar * a + other.scalar * b,
+            vector.x,
+            vector.y,
+            vector.z,
+        )
+        .unwrap_or(self)
+    }
+
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let axis = Vec3::new(0.0, 0.6, 0.8);
+    /// let matrix = Rotation3::from_axis_angle(axis, 1.0).to_matrix();
+    /// let expected = Mat3::from_axis_angle(axis, 1.0);
+    /// assert!((0..3).all(|i| (0..3).all(|j| (matrix[i][j] - expected[i][j]).abs() < 1e-15)));
+    /// ```
+    pub fn to_matrix(self) -> Mat3 {
+        let w = self.scalar;
+        let Vec3 { x, y, z } = self.vector;
+        Mat3::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+}
+
+impl Mul for Rotation2 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// let rotation = Rotation2::from_angle(0.5) * Rotation2::from_angle(0.25);
+    /// assert!((rotation.angle() - 0.75).abs() < 1e-15);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            unit: self.unit * rhs.unit,
+        }
+    }
+}
+
+impl Mul for Rotation3 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let about_x = Rotation3::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+    /// let about_z = Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    /// let rotated = (about_z * about_x).rotate(Vec3::new(0.0, 1.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(0.0, 0.0, 1.0)) < 1e-15);
+    /// let rotated = (about_x * about_z).rotate(Vec3::new(0.0, 1.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(-1.0, 0.0, 0.0)) < 1e-15);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            scalar: self.scalar * rhs.scalar - self.vector.dot(rhs.vector),
+            vector: rhs.vector * self.scalar
+                + self.vector * rhs.scalar
+                + self.vector.cross(rhs.vector),
+        }
+    }
+}
+
+impl From<Rotation2> for Mat2 {
+    fn from(rotation: Rotation2) -> Mat2 {
+        rotation.to_matrix()
+    }
+}
+
+impl From<Rotation3> for Mat3 {
+    fn from(rotation: Rotation3) -> Mat3 {
+        rotation.to_matrix()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rotation2 {
+    /// the unit complex number
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.unit.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation2 {
+    /// renormalized on the way in, so the magnitude 1 invariant survives edited data
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let z = Complex::deserialize(deserializer)?;
+        Self::from_complex(z).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rotation3 {
+    /// the unit quaternion as (scalar, vector)
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.scalar, self.vector).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation3 {
+    /// renormalized on the way in, so the magnitude 1 invariant survives edited data
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (w, Vec3 { x, y, z }) = <(Real, Vec3)>::deserialize(deserializer)?;
+        Self::from_quaternion(w, x, y, z).map_err(serde::de::Error::custom)
+    }
+}
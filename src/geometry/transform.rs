@@ -0,0 +1,358 @@
+use std::ops::Mul;
+
+use crate::linalg::{Mat2, Mat3, Mat4, Point2, Point3, Vec2, Vec3, Vector};
+use crate::numbers::Angle;
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// p -> linear * p + translation
+/// in the plane
+pub struct Transform2 {
+    pub linear: Mat2,
+    pub translation: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// p -> linear * p + translation
+/// in space
+pub struct Transform3 {
+    pub linear: Mat3,
+    pub translation: Vec3,
+}
+
+impl Transform2 {
+    pub const IDENTITY: Self = Self {
+        linear: Mat2::IDENTITY,
+        translation: Vec2 { x: 0.0, y: 0.0 },
+    };
+
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::{Mat2, Vec2};
+    /// let transform = Transform2::new(Mat2::IDENTITY, Vec2::new(1.0, 2.0));
+    /// assert_eq!(transform.translation, Vec2::new(1.0, 2.0));
+    /// ```
+    pub const fn new(linear: Mat2, translation: Vec2) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::Vec2;
+    /// let transform = Transform2::from_translation(Vec2::new(1.0, 2.0));
+    /// assert_eq!(transform.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(2.0, 3.0));
+    /// ```
+    pub const fn from_translation(translation: Vec2) -> Self {
+        Self {
+            linear: Mat2::IDENTITY,
+            translation,
+        }
+    }
+
+    /// counter-clockwise rotation by angle about the origin
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use std::f64::consts::PI;
+    /// let transform = Transform2::from_angle(PI);
+    /// let rotated = transform.transform_point(Vec2::new(1.0, 2.0));
+    /// assert!(rotated.distance(Vec2::new(-1.0, -2.0)) < 1e-15);
+    /// ```
+    pub fn from_angle(angle: impl Into<Angle>) -> Self {
+        Self {
+            linear: Mat2::from_angle(angle),
+            translation: Vec2::new(0.0, 0.0),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::Vec2;
+    /// let transform = Transform2::from_scale(Vec2::new(2.0, 3.0));
+    /// assert_eq!(transform.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(2.0, 3.0));
+    /// ```
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self {
+            linear: Mat2::from_diagonal([scale.x, scale.y]),
+            translation: Vec2::new(0.0, 0.0),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::{Mat2, Vec2};
+    /// let transform = Transform2::new(Mat2::from_diagonal([2.0, 2.0]), Vec2::new(1.0, 0.0));
+    /// assert_eq!(transform.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(3.0, 2.0));
+    /// ```
+    pub fn transform_point(self, point: Point2) -> Point2 {
+        self.linear * point + self.translation
+    }
+
+    /// directions ignore the translation
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::{Mat2, Vec2};
+    /// let transform = Transform2::new(Mat2::from_diagonal([2.0, 2.0]), Vec2::new(1.0, 0.0));
+    /// assert_eq!(transform.transform_vector(Vec2::new(1.0, 1.0)), Vec2::new(2.0, 2.0));
+    /// ```
+    pub fn transform_vector(self, vector: Vec2) -> Vec2 {
+        self.linear * vector
+    }
+
+    /// only the 2 x 2 linear part is inverted,
+    /// an error when it is singular
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::Vec2;
+    /// # use vmath::Error;
+    /// let transform = Transform2::from_translation(Vec2::new(1.0, 2.0))
+    ///     * Transform2::from_scale(Vec2::new(2.0, 4.0));
+    /// let inverse = transform.inverse().unwrap();
+    /// let point = Vec2::new(3.0, 5.0);
+    /// assert_eq!(inverse.transform_point(transform.transform_point(point)), point);
+    ///
+    /// assert_eq!(Transform2::from_scale(Vec2::new(0.0, 1.0)).inverse(), Err(Error::Singular));
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        let linear = self.linear.inverse()?;
+        Ok(Self {
+            linear,
+            translation: -(linear * self.translation),
+        })
+    }
+
+    /// the equivalent matrix acting on homogeneous coordinates (x, y, 1)
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::{Mat3, Vec2};
+    /// let transform = Transform2::from_translation(Vec2::new(1.0, 2.0));
+    /// assert_eq!(
+    ///     transform.to_matrix(),
+    ///     Mat3::new([[1.0, 0.0, 1.0], [0.0, 1.0, 2.0], [0.0, 0.0, 1.0]]),
+    /// );
+    /// ```
+    pub fn to_matrix(self) -> Mat3 {
+        let [[a, b], [c, d]] = self.linear.rows;
+        let Vec2 { x, y } = self.translation;
+        Mat3::new([[a, b, x], [c, d, y], [0.0, 0.0, 1.0]])
+    }
+}
+
+impl Transform3 {
+    pub const IDENTITY: Self = Self {
+        linear: Mat3::IDENTITY,
+        translation: Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let transform = Transform3::new(Mat3::IDENTITY, Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub const fn new(linear: Mat3, translation: Vec3) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::Vec3;
+    /// let transform = Transform3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     transform.transform_point(Vec3::new(1.0, 1.0, 1.0)),
+    ///     Vec3::new(2.0, 3.0, 4.0),
+    /// );
+    /// ```
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self {
+            linear: Mat3::IDENTITY,
+            translation,
+        }
+    }
+
+    /// right-handed rotation by angle about a unit axis through the origin
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let transform = Transform3::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+    /// let rotated = transform.transform_point(Vec3::new(0.0, 1.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(0.0, 0.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Angle>) -> Self {
+        Self {
+            linear: Mat3::from_axis_angle(axis, angle),
+            translation: Vec3::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::Vec3;
+    /// let transform = Transform3::from_scale(Vec3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(
+    ///     transform.transform_point(Vec3::new(1.0, 1.0, 1.0)),
+    ///     Vec3::new(2.0, 3.0, 4.0),
+    /// );
+    /// ```
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self {
+            linear: Mat3::from_diagonal([scale.x, scale.y, scale.z]),
+            translation: Vec3::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let transform = Transform3::new(Mat3::from_diagonal([2.0; 3]), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     transform.transform_point(Vec3::new(1.0, 1.0, 1.0)),
+    ///     Vec3::new(3.0, 2.0, 2.0),
+    /// );
+    /// ```
+    pub fn transform_point(self, point: Point3) -> Point3 {
+        self.linear * point + self.translation
+    }
+
+    /// directions ignore the translation
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let transform = Transform3::new(Mat3::from_diagonal([2.0; 3]), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     transform.transform_vector(Vec3::new(1.0, 1.0, 1.0)),
+    ///     Vec3::new(2.0, 2.0, 2.0),
+    /// );
+    /// ```
+    pub fn transform_vector(self, vector: Vec3) -> Vec3 {
+        self.linear * vector
+    }
+
+    /// the view transform of a camera at eye looking toward target,
+    /// mapping eye to the origin and the view direction to -z
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let eye = Vec3::new(0.0, 0.0, 5.0);
+    /// let view = Transform3::look_at(eye, Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(view.transform_point(eye), Vec3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(view.transform_point(Vec3::new(0.0, 0.0, 0.0)), Vec3::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at(eye: Point3, target: Point3, up: Vec3) -> Self {
+        let forward = (target - eye).normalize();
+        let right = forward.cross(up).normalize();
+        let up = right.cross(forward);
+        let linear = Mat3::new([right.into(), up.into(), (-forward).into()]);
+        Self::new(linear, -(linear * eye))
+    }
+
+    /// only the 3 x 3 linear part is inverted,
+    /// an error when it is singular
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::Vec3;
+    /// let transform = Transform3::from_translation(Vec3::new(1.0, 2.0, 3.0))
+    ///     * Transform3::from_scale(Vec3::new(2.0, 4.0, 8.0));
+    /// let inverse = transform.inverse().unwrap();
+    /// let point = Vec3::new(3.0, 5.0, 7.0);
+    /// assert_eq!(inverse.transform_point(transform.transform_point(point)), point);
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        let linear = self.linear.inverse()?;
+        Ok(Self {
+            linear,
+            translation: -(linear * self.translation),
+        })
+    }
+
+    /// the equivalent matrix acting on homogeneous coordinates (x, y, z, 1)
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::{Mat4, Vec3};
+    /// let transform = Transform3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(
+    ///     transform.to_matrix(),
+    ///     Mat4::new([
+    ///         [1.0, 0.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 0.0, 2.0],
+    ///         [0.0, 0.0, 1.0, 3.0],
+    ///         [0.0, 0.0, 0.0, 1.0],
+    ///     ]),
+    /// );
+    /// ```
+    pub fn to_matrix(self) -> Mat4 {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.linear.rows;
+        let Vec3 { x, y, z } = self.translation;
+        Mat4::new([
+            [a, b, c, x],
+            [d, e, f, y],
+            [g, h, i, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+impl Mul for Transform2 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::Transform2;
+    /// # use vmath::linalg::Vec2;
+    /// let scale = Transform2::from_scale(Vec2::new(2.0, 2.0));
+    /// let shift = Transform2::from_translation(Vec2::new(1.0, 0.0));
+    /// let point = Vec2::new(1.0, 1.0);
+    /// assert_eq!((shift * scale).transform_point(point), Vec2::new(3.0, 2.0));
+    /// assert_eq!((scale * shift).transform_point(point), Vec2::new(4.0, 2.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            linear: self.linear * rhs.linear,
+            translation: self.linear * rhs.translation + self.translation,
+        }
+    }
+}
+
+impl Mul for Transform3 {
+    type Output = Self;
+    /// applies rhs first, then self
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::Vec3;
+    /// let scale = Transform3::from_scale(Vec3::new(2.0, 2.0, 2.0));
+    /// let shift = Transform3::from_translation(Vec3::new(1.0, 0.0, 0.0));
+    /// let point = Vec3::new(1.0, 1.0, 1.0);
+    /// assert_eq!((shift * scale).transform_point(point), Vec3::new(3.0, 2.0, 2.0));
+    /// assert_eq!((scale * shift).transform_point(point), Vec3::new(4.0, 2.0, 2.0));
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            linear: self.linear * rhs.linear,
+            translation: self.linear * rhs.translation + self.translation,
+        }
+    }
+}
+
+impl From<Transform2> for Mat3 {
+    fn from(transform: Transform2) -> Mat3 {
+        transform.to_matrix()
+    }
+}
+
+impl From<Transform3> for Mat4 {
+    fn from(transform: Transform3) -> Mat4 {
+        transform.to_matrix()
+    }
+}
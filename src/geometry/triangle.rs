@@ -0,0 +1,283 @@
+use super::{Linear, Plane, EPSILON};
+use crate::linalg::{Vec2, Vec3, Vector};
+use crate::numbers::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the points between corners a, b, and c
+pub struct Triangle<V> {
+    pub a: V,
+    pub b: V,
+    pub c: V,
+}
+
+pub type Triangle2 = Triangle<Vec2>;
+pub type Triangle3 = Triangle<Vec3>;
+
+impl<V: Vector> Triangle<V> {
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+    /// assert_eq!(triangle.b, Vec2::new(1.0, 0.0));
+    /// ```
+    pub fn new(a: V, b: V, c: V) -> Self {
+        Self { a, b, c }
+    }
+
+    /// ```
+    /// # use vmath::geometry::Triangle3;
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(4.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 3.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.area(), 6.0);
+    /// ```
+    pub fn area(self) -> Real {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        // |ab x ac|^2 by lagrange's identity, which holds in any dimension
+        let dot = ab.dot(ac);
+        (ab.length_squared() * ac.length_squared() - dot * dot)
+            .max(0.0)
+            .sqrt()
+            / 2.0
+    }
+
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(3.0, 0.0), Vec2::new(0.0, 6.0));
+    /// assert_eq!(triangle.centroid(), Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn centroid(self) -> V {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// the point equally far from all three corners, None when they are collinear
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 2.0));
+    /// assert_eq!(triangle.circumcenter(), Some(Vec2::new(2.0, 1.0)));
+    /// let flat = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0));
+    /// assert_eq!(flat.circumcenter(), None);
+    /// ```
+    pub fn circumcenter(self) -> Option<V> {
+        // barycentric weights from the squared lengths of the opposite sides
+        let a = (self.b - self.c).length_squared();
+        let b = (self.c - self.a).length_squared();
+        let c = (self.a - self.b).length_squared();
+        let weights = [a * (b + c - a), b * (c + a - b), c * (a + b - c)];
+        let sum: Real = weights.iter().sum();
+        let scale = a + b + c;
+        if sum <= Real::EPSILON * scale * scale {
+            return None;
+        }
+        Some(self.point_at(weights.map(|weight| weight / sum)))
+    }
+
+    /// weights of a, b, and c that reproduce the point,
+    /// projected onto the triangle's plane first when the point lies off it
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0));
+    /// assert_eq!(triangle.barycentric(Vec2::new(1.0, 2.0)), [0.25, 0.25, 0.5]);
+    /// assert_eq!(triangle.barycentric(Vec2::new(4.0, 0.0)), [0.0, 1.0, 0.0]);
+    /// ```
+    pub fn barycentric(self, point: V) -> [Real; 3] {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = point - self.a;
+        let d_00 = ab.dot(ab);
+        let d_01 = ab.dot(ac);
+        let d_11 = ac.dot(ac);
+        let d_20 = ap.dot(ab);
+        let d_21 = ap.dot(ac);
+        let denominator = d_00 * d_11 - d_01 * d_01;
+        let v = (d_11 * d_20 - d_01 * d_21) / denominator;
+        let w = (d_00 * d_21 - d_01 * d_20) / denominator;
+        [1.0 - v - w, v, w]
+    }
+
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0));
+    /// assert_eq!(triangle.point_at([0.25, 0.25, 0.5]), Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn point_at(self, [u, v, w]: [Real; 3]) -> V {
+        self.a * u + self.b * v + self.c * w
+    }
+
+    /// ```
+    /// # use vmath::geometry::Triangle3;
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(1.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(triangle.contains_point(Vec3::new(0.25, 0.25, 0.0)));
+    /// assert!(!triangle.contains_point(Vec3::new(0.25, 0.25, 1.0)));
+    /// assert!(!triangle.contains_point(Vec3::new(1.0, 1.0, 0.0)));
+    /// ```
+    pub fn contains_point(self, point: V) -> bool {
+        self.closest_point(point).distance(point) <= EPSILON
+    }
+
+    /// found by classifying the point against the corner and edge regions
+    /// ```
+    /// # use vmath::geometry::Triangle3;
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(2.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 2.0, 0.0),
+    /// );
+    /// // above the face
+    /// assert_eq!(triangle.closest_point(Vec3::new(0.5, 0.5, 3.0)), Vec3::new(0.5, 0.5, 0.0));
+    /// // beyond an edge
+    /// assert_eq!(triangle.closest_point(Vec3::new(1.0, -1.0, 0.0)), Vec3::new(1.0, 0.0, 0.0));
+    /// // beyond a corner
+    /// assert_eq!(triangle.closest_point(Vec3::new(-1.0, -1.0, 0.0)), Vec3::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point(self, point: V) -> V {
+        let Self { a, b, c } = self;
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d_1 = ab.dot(ap);
+        let d_2 = ac.dot(ap);
+        if d_1 <= 0.0 && d_2 <= 0.0 {
+            return a;
+        }
+
+        let bp = point - b;
+        let d_3 = ab.dot(bp);
+        let d_4 = ac.dot(bp);
+        if d_3 >= 0.0 && d_4 <= d_3 {
+            return b;
+        }
+
+        let v_c = d_1 * d_4 - d_3 * d_2;
+        if v_c <= 0.0 && d_1 >= 0.0 && d_3 <= 0.0 {
+            return a + ab * (d_1 / (d_1 - d_3));
+        }
+
+        let cp = point - c;
+        let d_5 = ab.dot(cp);
+        let d_6 = ac.dot(cp);
+        if d_6 >= 0.0 && d_5 <= d_6 {
+            return c;
+        }
+
+        let v_b = d_5 * d_2 - d_1 * d_6;
+        if v_b <= 0.0 && d_2 >= 0.0 && d_6 <= 0.0 {
+            return a + ac * (d_2 / (d_2 - d_6));
+        }
+
+        let v_a = d_3 * d_6 - d_5 * d_4;
+        if v_a <= 0.0 && d_4 - d_3 >= 0.0 && d_5 - d_6 >= 0.0 {
+            return b + (c - b) * ((d_4 - d_3) / ((d_4 - d_3) + (d_5 - d_6)));
+        }
+
+        let denominator = v_a + v_b + v_c;
+        a + ab * (v_b / denominator) + ac * (v_c / denominator)
+    }
+}
+
+impl Triangle2 {
+    /// positive when the corners run counter-clockwise
+    /// ```
+    /// # use vmath::geometry::Triangle2;
+    /// # use vmath::linalg::Vec2;
+    /// let triangle = Triangle2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+    /// assert_eq!(triangle.signed_area(), 2.0);
+    /// let reversed = Triangle2::new(triangle.a, triangle.c, triangle.b);
+    /// assert_eq!(reversed.signed_area(), -2.0);
+    /// ```
+    pub fn signed_area(self) -> Real {
+        (self.b - self.a).perp_dot(self.c - self.a) / 2.0
+    }
+}
+
+impl Triangle3 {
+    /// unit normal facing the side from which the corners run counter-clockwise
+    /// ```
+    /// # use vmath::geometry::Triangle3;
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(3.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 3.0, 0.0),
+    /// );
+    /// assert_eq!(triangle.normal(), Vec3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn normal(self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// ```
+    /// # use vmath::geometry::{Plane, Triangle3};
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 2.0),
+    ///     Vec3::new(3.0, 0.0, 2.0),
+    ///     Vec3::new(0.0, 3.0, 2.0),
+    /// );
+    /// assert_eq!(triangle.plane(), Plane::new(Vec3::new(0.0, 0.0, 1.0), 2.0));
+    /// ```
+    pub fn plane(self) -> Plane {
+        Plane::from_point_normal(self.a, self.normal())
+    }
+
+    /// the parameter where the linear component crosses the triangle,
+    /// found with the möller-trumbore algorithm,
+    /// None when it misses or runs parallel to the triangle
+    /// ```
+    /// # use vmath::geometry::{Ray3, Segment3, Triangle3};
+    /// # use vmath::linalg::Vec3;
+    /// let triangle = Triangle3::new(
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(2.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 2.0, 0.0),
+    /// );
+    /// let ray = Ray3::new(Vec3::new(0.5, 0.5, 4.0), Vec3::new(0.0, 0.0, -2.0));
+    /// assert_eq!(triangle.crossing_parameter(ray), Some(2.0));
+    ///
+    /// let short = Segment3::new(Vec3::new(0.5, 0.5, 4.0), Vec3::new(0.5, 0.5, 1.0));
+    /// assert_eq!(triangle.crossing_parameter(short), None);
+    ///
+    /// let beside = Ray3::new(Vec3::new(2.0, 2.0, 4.0), Vec3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(triangle.crossing_parameter(beside), None);
+    /// ```
+    pub fn crossing_parameter<L: Linear<Vec3>>(self, linear: L) -> Option<Real> {
+        let direction = linear.direction();
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let p = direction.cross(ac);
+        let determinant = ab.dot(p);
+        if determinant.abs() <= Real::EPSILON * ab.length() * p.length() {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        let offset = linear.origin() - self.a;
+        let u = offset.dot(p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = offset.cross(ab);
+        let v = direction.dot(q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = ac.dot(q) * inverse;
+        let (min, max) = linear.bounds();
+        (min <= t && t <= max).then_some(t)
+    }
+}
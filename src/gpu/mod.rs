@@ -0,0 +1,532 @@
+//! matrix products, elementwise arithmetic, and fourier transforms on the gpu
+//! through wgpu, with the feature `wgpu`
+//!
+//! the caller owns the device and queue, requested with [`Gpu::FEATURES`] for double
+//! precision in shaders, which vulkan and dx12 offer on most desktop gpus but metal and
+//! the browser do not. every call uploads its operands, runs, and waits to read back the
+//! result, so it pays off for large problems; a buffer bigger than the device's limits
+//! is a wgpu validation error, so for 8k x 8k matrices, 512 MiB each, request the
+//! adapter's own limits rather than the defaults
+//! ```
+//! use vmath::gpu::Gpu;
+//! use vmath::linalg::DMatrix;
+//! # use std::future::Future;
+//! # fn ready<T>(future: impl Future<Output = T>) -> Option<T> {
+//! #     let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+//! #     match std::pin::pin!(future).poll(&mut context) {
+//! #         std::task::Poll::Ready(value) => Some(value),
+//! #         std::task::Poll::Pending => None,
+//! #     }
+//! # }
+//!
+//! let instance = wgpu::Instance::default();
+//! # let Some(Ok(adapter)) = ready(instance.request_adapter(&Default::default())) else { return };
+//! # if !adapter.features().contains(Gpu::FEATURES) { return }
+//! // let adapter = pollster::block_on(instance.request_adapter(&Default::default())).unwrap();
+//! let descriptor = wgpu::DeviceDescriptor {
+//!     required_features: Gpu::FEATURES,
+//!     required_limits: adapter.limits(),
+//!     ..Default::default()
+//! };
+//! # let Some(Ok((device, queue))) = ready(adapter.request_device(&descriptor)) else { return };
+//! // let (device, queue) = pollster::block_on(adapter.request_device(&descriptor)).unwrap();
+//! let gpu = Gpu::new(device, queue).unwrap();
+//!
+//! let a = DMatrix::from_fn(300, 200, |i, j| (i as f64 - j as f64) / 100.0);
+//! let b = DMatrix::from_fn(200, 100, |i, j| ((i * j) % 7) as f64);
+//! let error = gpu.matmul(&a, &b) - &a * &b;
+//! assert!(error.data.iter().all(|e| e.abs() < 1e-10));
+//! ```
+
+use std::f64::consts::PI;
+use std::sync::mpsc;
+
+use wgpu::util::DeviceExt;
+
+use crate::linalg::DMatrix;
+use crate::numbers::{Complex, Real};
+use crate::signal;
+
+/// the caller's device and queue with the crate's compute pipelines built on them
+pub struct Gpu {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    matmul: wgpu::ComputePipeline,
+    add: wgpu::ComputePipeline,
+    subtract: wgpu::ComputePipeline,
+    multiply: wgpu::ComputePipeline,
+    scale: wgpu::ComputePipeline,
+    fft_pass: wgpu::ComputePipeline,
+    multiply_each: wgpu::ComputePipeline,
+}
+
+/// the shaders' workgroup sizes
+const TILE: usize = 16;
+const GROUP: usize = 256;
+
+/// the most workgroups wgpu allows along one dimension of a dispatch
+const MAX_GROUPS: usize = 65535;
+
+impl Gpu {
+    /// the features the device has to be requested with
+    pub const FEATURES: wgpu::Features = wgpu::Features::SHADER_F64;
+
+    /// builds the pipelines, None when the device was not requested with `FEATURES`
+    pub fn new(device: wgpu::Device, queue: wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(Self::FEATURES) {
+            return None;
+        }
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("vmath"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders.wgsl").into()),
+        });
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("vmath"),
+            entries: &[
+                storage(0, true),
+                storage(1, true),
+                storage(2, false),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("vmath"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+        Some(Self {
+            matmul: pipeline("matmul"),
+            add: pipeline("add"),
+            subtract: pipeline("subtract"),
+            multiply: pipeline("multiply"),
+            scale: pipeline("scale"),
+            fft_pass: pipeline("fft_pass"),
+            multiply_each: pipeline("multiply_each"),
+            device,
+            queue,
+            layout,
+        })
+    }
+
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// a b, panics unless a has as many columns as b has rows
+    pub fn matmul(&self, a: &DMatrix, b: &DMatrix) -> DMatrix {
+        let mut products = self.matmul_batch(std::slice::from_ref(a), std::slice::from_ref(b));
+        products.remove(0)
+    }
+
+    /// the products of the matching matrices of a and b in one dispatch,
+    /// panics unless there are as many of each, every a has one shape and every b another,
+    /// and an a has as many columns as a b has rows
+    pub fn matmul_batch(&self, a: &[DMatrix], b: &[DMatrix]) -> Vec<DMatrix> {
+        assert_eq!(a.len(), b.len(), "batch sizes differ");
+        let Some((first_a, first_b)) = a.first().zip(b.first()) else {
+            return Vec::new();
+        };
+        let (rows, inner, columns) = (first_a.rows, first_a.columns, first_b.columns);
+        assert_eq!(inner, first_b.rows, "matrix shapes do not fit");
+        assert!(
+            a.iter().all(|m| (m.rows, m.columns) == (rows, inner))
+                && b.iter().all(|m| (m.rows, m.columns) == (inner, columns)),
+            "matrix shapes differ"
+        );
+        let batch = a.len();
+        if rows * columns == 0 || inner == 0 {
+            return vec![DMatrix::zeros(rows, columns); batch];
+        }
+        let a_buffer = self.storage(&concatenate(a));
+        let b_buffer = self.storage(&concatenate(b));
+        let out = self.output(batch * rows * columns);
+        let params = Params {
+            rows: count(rows),
+            inner: count(inner),
+            columns: count(columns),
+            ..Params::default()
+        };
+        let bind_group = self.bind_group(&a_buffer, &b_buffer, &out, &params);
+        let groups = (
+            count(columns.div_ceil(TILE)),
+            count(rows.div_ceil(TILE)),
+            count(batch),
+        );
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        dispatch(&mut encoder, &self.matmul, &bind_group, groups);
+        self.queue.submit([encoder.finish()]);
+        let mut data = vec![0.0; batch * rows * columns];
+        self.read(&out, bytemuck::cast_slice_mut(&mut data));
+        data.chunks(rows * columns)
+            .map(|product| DMatrix::new(rows, columns, product.to_vec()))
+            .collect()
+    }
+
+    /// a + b, panics when the shapes differ
+    pub fn add(&self, a: &DMatrix, b: &DMatrix) -> DMatrix {
+        self.elementwise(&self.add, a, Some(b), 0.0)
+    }
+
+    /// a - b, panics when the shapes differ
+    pub fn subtract(&self, a: &DMatrix, b: &DMatrix) -> DMatrix {
+        self.elementwise(&self.subtract, a, Some(b), 0.0)
+    }
+
+    /// the entrywise product of a and b, panics when the shapes differ
+    pub fn multiply(&self, a: &DMatrix, b: &DMatrix) -> DMatrix {
+        self.elementwise(&self.multiply, a, Some(b), 0.0)
+    }
+
+    /// a times factor
+    pub fn scale(&self, a: &DMatrix, factor: Real) -> DMatrix {
+        self.elementwise(&self.scale, a, None, factor)
+    }
+
+    /// as `signal::fft`, radix-2 on the gpu for a power of two length and bluestein's
+    /// transform through a power of two otherwise
+    pub fn fft(&self, data: &mut [Complex]) {
+        if !data.is_empty() {
+            self.fft_batch(data, data.len());
+        }
+    }
+
+    /// as `signal::inverse_fft`
+    pub fn inverse_fft(&self, data: &mut [Complex]) {
+        if !data.is_empty() {
+            self.inverse_fft_batch(data, data.len());
+        }
+    }
+
+    /// as `signal::fft_batch`, every signal in one dispatch per pass,
+    /// panics unless length divides the number of entries
+    pub fn fft_batch(&self, data: &mut [Complex], length: usize) {
+        self.transform(data, length, -1.0);
+    }
+
+    /// as `signal::inverse_fft_batch`
+    pub fn inverse_fft_batch(&self, data: &mut [Complex], length: usize) {
+        self.transform(data, length, 1.0);
+        let scale = 1.0 / length as Real;
+        for value in data {
+            *value = *value * scale;
+        }
+    }
+
+    fn elementwise(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        a: &DMatrix,
+        b: Option<&DMatrix>,
+        scalar: Real,
+    ) -> DMatrix {
+        if let Some(b) = b {
+            assert_eq!(
+                (a.rows, a.columns),
+                (b.rows, b.columns),
+                "matrix shapes differ"
+            );
+        }
+        let entries = a.data.len();
+        if entries == 0 {
+            return a.clone();
+        }
+        let a_buffer = self.storage(&a.data);
+        let b_buffer = b.map_or_else(|| self.output(1), |b| self.storage(&b.data));
+        let out = self.output(entries);
+        let params = Params {
+            count: count(entries),
+            scalar,
+            ..Params::default()
+        };
+        let bind_group = self.bind_group(&a_buffer, &b_buffer, &out, &params);
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        dispatch(&mut encoder, pipeline, &bind_group, flat_groups(entries));
+        self.queue.submit([encoder.finish()]);
+        let mut result = DMatrix::zeros(a.rows, a.columns);
+        self.read(&out, bytemuck::cast_slice_mut(&mut result.data));
+        result
+    }
+
+    /// the unscaled transform with e^(sign 2 pi i k n / len) of each signal,
+    /// the same steps as `signal::fft` with the power of two transforms on the gpu
+    fn transform(&self, data: &mut [Complex], length: usize, sign: Real) {
+        assert!(
+            length > 0 && data.len().is_multiple_of(length),
+            "batch length does not divide the data"
+        );
+        if length <= 1 {
+            return;
+        }
+        let signals = data.len() / length;
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        if length.is_power_of_two() {
+            let input = self.storage(bytemuck::cast_slice(data));
+            let out = self.encode_fft(&mut encoder, input, length, signals, sign);
+            self.queue.submit([encoder.finish()]);
+            self.read(&out, bytemuck::cast_slice_mut(data));
+            return;
+        }
+        // bluestein, kn = (k^2 + n^2 - (k - n)^2) / 2 makes the transform a convolution
+        // with the chirp e^(-sign pi i m^2 / len), done by power of two transforms of length m
+        let m = (2 * length - 1).next_power_of_two();
+        let chirp: Vec<Complex> = (0..length)
+            .map(|k| unit(sign * PI * ((k * k) % (2 * length)) as Real / length as Real))
+            .collect();
+        let zero = Complex::new(0.0, 0.0);
+        let mut padded = vec![zero; signals * m];
+        for (signal, padded) in data.chunks(length).zip(padded.chunks_mut(m)) {
+            for ((padded, &x), &w) in padded.iter_mut().zip(signal).zip(&chirp) {
+                *padded = x * w;
+            }
+        }
+        let mut filter = vec![zero; m];
+        filter[0] = chirp[0].conjugate();
+        for k in 1..length {
+            filter[k] = chirp[k].conjugate();
+            filter[m - k] = filter[k];
+        }
+        signal::fft(&mut filter);
+        let input = self.storage(bytemuck::cast_slice(&padded));
+        let spectrum = self.encode_fft(&mut encoder, input, m, signals, -1.0);
+        let filter = self.storage(bytemuck::cast_slice(&filter));
+        let product = self.output(2 * signals * m);
+        let params = Params {
+            count: count(signals * m),
+            length: count(m),
+            ..Params::default()
+        };
+        let bind_group = self.bind_group(&spectrum, &filter, &product, &params);
+        dispatch(
+            &mut encoder,
+            &self.multiply_each,
+            &bind_group,
+            flat_groups(signals * m),
+        );
+        let convolved = self.encode_fft(&mut encoder, product, m, signals, 1.0);
+        self.queue.submit([encoder.finish()]);
+        self.read(&convolved, bytemuck::cast_slice_mut(&mut padded));
+        let scale = 1.0 / m as Real;
+        for (signal, convolved) in data.chunks_mut(length).zip(padded.chunks(m)) {
+            for ((x, &a), &w) in signal.iter_mut().zip(convolved).zip(&chirp) {
+                *x = a * w * scale;
+            }
+        }
+    }
+
+    /// the passes of a power of two transform of every signal in input,
+    /// returning the buffer the result ends up in
+    fn encode_fft(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        input: wgpu::Buffer,
+        length: usize,
+        signals: usize,
+        sign: Real,
+    ) -> wgpu::Buffer {
+        let twiddles: Vec<Complex> = (0..length / 2)
+            .map(|t| unit(-2.0 * PI * t as Real / length as Real))
+            .collect();
+        let twiddles = self.storage(bytemuck::cast_slice(&twiddles));
+        let butterflies = signals * length / 2;
+        let (mut source, mut destination) = (input, self.output(2 * signals * length));
+        let mut half = 1;
+        while half < length {
+            let params = Params {
+                count: count(butterflies),
+                length: count(length),
+                half: count(half),
+                scalar: sign,
+                ..Params::default()
+            };
+            let bind_group = self.bind_group(&source, &twiddles, &destination, &params);
+            dispatch(
+                encoder,
+                &self.fft_pass,
+                &bind_group,
+                flat_groups(butterflies),
+            );
+            std::mem::swap(&mut source, &mut destination);
+            half *= 2;
+        }
+        source
+    }
+
+    /// a buffer holding the reals, which the shaders can read and write
+    fn storage(&self, data: &[Real]) -> wgpu::Buffer {
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: bytemuck::cast_slice(data),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            })
+    }
+
+    /// an uninitialized buffer of len reals
+    fn output(&self, len: usize) -> wgpu::Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (len * size_of::<Real>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn bind_group(
+        &self,
+        a: &wgpu::Buffer,
+        b: &wgpu::Buffer,
+        out: &wgpu::Buffer,
+        params: &Params,
+    ) -> wgpu::BindGroup {
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: &params.bytes(),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let entries: Vec<_> = [a, b, out, &params]
+            .into_iter()
+            .zip(0..)
+            .map(|(buffer, binding)| wgpu::BindGroupEntry {
+                binding,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.layout,
+            entries: &entries,
+        })
+    }
+
+    /// copies the buffer back into out, waiting for the work before it to finish
+    fn read(&self, buffer: &wgpu::Buffer, out: &mut [u8]) {
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: out.len() as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, out.len() as u64);
+        self.queue.submit([encoder.finish()]);
+        let (sender, receiver) = mpsc::channel();
+        staging.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("waiting for the gpu failed");
+        receiver
+            .recv()
+            .expect("the buffer was never mapped")
+            .expect("mapping the buffer failed");
+        let view = staging
+            .get_mapped_range(..)
+            .expect("reading the mapped buffer failed");
+        out.copy_from_slice(&view);
+    }
+}
+
+/// the shaders' uniform parameters, see Params in shaders.wgsl
+#[derive(Debug, Clone, Copy, Default)]
+struct Params {
+    rows: u32,
+    inner: u32,
+    columns: u32,
+    count: u32,
+    length: u32,
+    half: u32,
+    scalar: Real,
+}
+
+impl Params {
+    /// laid out as wgsl lays out the struct, six u32s and then the f64 at offset 24
+    fn bytes(self) -> Vec<u8> {
+        [
+            self.rows,
+            self.inner,
+            self.columns,
+            self.count,
+            self.length,
+            self.half,
+        ]
+        .iter()
+        .flat_map(|field| field.to_le_bytes())
+        .chain(self.scalar.to_le_bytes())
+        .collect()
+    }
+}
+
+fn dispatch(
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::ComputePipeline,
+    bind_group: &wgpu::BindGroup,
+    (x, y, z): (u32, u32, u32),
+) {
+    let mut pass = encoder.begin_compute_pass(&Default::default());
+    pass.set_pipeline(pipeline);
+    pass.set_bind_group(0, bind_group, &[]);
+    pass.dispatch_workgroups(x, y, z);
+}
+
+/// enough workgroups of GROUP for n invocations,
+/// spread over x and then y since one dimension holds at most MAX_GROUPS
+fn flat_groups(n: usize) -> (u32, u32, u32) {
+    let groups = n.div_ceil(GROUP);
+    let x = groups.min(MAX_GROUPS);
+    (count(x), count(groups.div_ceil(x)), 1)
+}
+
+/// the shaders index with u32, panics for sizes beyond it
+fn count(n: usize) -> u32 {
+    u32::try_from(n).expect("too large for the gpu to index")
+}
+
+fn concatenate(matrices: &[DMatrix]) -> Vec<Real> {
+    matrices
+        .iter()
+        .flat_map(|m| m.data.iter().copied())
+        .collect()
+}
+
+/// e^(i angle)
+fn unit(angle: Real) -> Complex {
+    let (sin, cos) = angle.sin_cos();
+    Complex::new(cos, sin)
+}
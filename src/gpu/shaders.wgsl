@@ -0,0 +1,151 @@
+// every entry point shares one bind group layout,
+// two inputs, an output, and the parameters, each using what it needs
+
+struct Params {
+    // matmul: a is rows x inner, b is inner x columns, batch matrices of each
+    rows: u32,
+    inner: u32,
+    columns: u32,
+    // elementwise: the entry count, fft: the number of butterflies in a pass
+    count: u32,
+    // fft: the signal length and the length of the subtransforms being merged
+    length: u32,
+    half: u32,
+    // scale: the factor, fft: the sign of the exponent
+    scalar: f64,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f64>;
+@group(0) @binding(1) var<storage, read> b: array<f64>;
+@group(0) @binding(2) var<storage, read_write> out: array<f64>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+const TILE: u32 = 16u;
+const GROUP: u32 = 256u;
+
+var<workgroup> a_tile: array<array<f64, TILE>, TILE>;
+var<workgroup> b_tile: array<array<f64, TILE>, TILE>;
+
+// one matrix of the batch per z, a TILE x TILE block of the product per workgroup,
+// staging TILE x TILE blocks of a and b through workgroup memory
+@compute @workgroup_size(TILE, TILE)
+fn matmul(
+    @builtin(workgroup_id) group: vec3<u32>,
+    @builtin(local_invocation_id) local: vec3<u32>,
+) {
+    let row = group.y * TILE + local.y;
+    let column = group.x * TILE + local.x;
+    let a_base = group.z * params.rows * params.inner;
+    let b_base = group.z * params.inner * params.columns;
+    var sum = 0.0lf;
+    for (var start = 0u; start < params.inner; start += TILE) {
+        let a_column = start + local.x;
+        let b_row = start + local.y;
+        if row < params.rows && a_column < params.inner {
+            a_tile[local.y][local.x] = a[a_base + row * params.inner + a_column];
+        } else {
+            a_tile[local.y][local.x] = 0.0lf;
+        }
+        if b_row < params.inner && column < params.columns {
+            b_tile[local.y][local.x] = b[b_base + b_row * params.columns + column];
+        } else {
+            b_tile[local.y][local.x] = 0.0lf;
+        }
+        workgroupBarrier();
+        for (var i = 0u; i < TILE; i++) {
+            sum += a_tile[local.y][i] * b_tile[i][local.x];
+        }
+        workgroupBarrier();
+    }
+    if row < params.rows && column < params.columns {
+        out[group.z * params.rows * params.columns + row * params.columns + column] = sum;
+    }
+}
+
+// the flat index of an invocation, with the groups laid out over x and then y
+// so there can be more of them than one dimension allows
+fn flat_index(id: vec3<u32>, groups: vec3<u32>) -> u32 {
+    return id.y * groups.x * GROUP + id.x;
+}
+
+@compute @workgroup_size(GROUP)
+fn add(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = flat_index(id, groups);
+    if i < params.count {
+        out[i] = a[i] + b[i];
+    }
+}
+
+@compute @workgroup_size(GROUP)
+fn subtract(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = flat_index(id, groups);
+    if i < params.count {
+        out[i] = a[i] - b[i];
+    }
+}
+
+@compute @workgroup_size(GROUP)
+fn multiply(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = flat_index(id, groups);
+    if i < params.count {
+        out[i] = a[i] * b[i];
+    }
+}
+
+@compute @workgroup_size(GROUP)
+fn scale(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = flat_index(id, groups);
+    if i < params.count {
+        out[i] = a[i] * params.scalar;
+    }
+}
+
+// complex numbers are (real, imaginary) pairs of consecutive reals
+fn load(buffer_index: u32, i: u32) -> vec2<f64> {
+    if buffer_index == 0u {
+        return vec2<f64>(a[2u * i], a[2u * i + 1u]);
+    }
+    return vec2<f64>(b[2u * i], b[2u * i + 1u]);
+}
+
+fn complex_multiply(z: vec2<f64>, w: vec2<f64>) -> vec2<f64> {
+    return vec2<f64>(z.x * w.x - z.y * w.y, z.x * w.y + z.y * w.x);
+}
+
+fn store(i: u32, z: vec2<f64>) {
+    out[2u * i] = z.x;
+    out[2u * i + 1u] = z.y;
+}
+
+// one radix-2 pass of stockham's autosorting fft over a batch of signals in a,
+// merging pairs of transforms of length half into transforms of length 2 half,
+// with b holding e^(-2 pi i t / length) for t < length / 2 and the sign choosing
+// the forward or inverse transform; after the passes for half = 1, 2, ... length / 2
+// the transforms are in natural order
+@compute @workgroup_size(GROUP)
+fn fft_pass(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let g = flat_index(id, groups);
+    if g >= params.count {
+        return;
+    }
+    let pairs = params.length / 2u;
+    let base = (g / pairs) * params.length;
+    let i = g % pairs;
+    let k = i % params.half;
+    var twiddle = load(1u, k * (pairs / params.half));
+    twiddle.y *= -params.scalar;
+    let u = load(0u, base + i);
+    let v = complex_multiply(load(0u, base + i + pairs), twiddle);
+    let j = base + 2u * i - k;
+    store(j, u + v);
+    store(j + params.half, u - v);
+}
+
+// each of the signals in a times the one signal in b, params.length long
+@compute @workgroup_size(GROUP)
+fn multiply_each(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = flat_index(id, groups);
+    if i < params.count {
+        store(i, complex_multiply(load(0u, i), load(1u, i % params.length)));
+    }
+}
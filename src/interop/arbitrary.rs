@@ -0,0 +1,89 @@
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::linalg::{DMatrix, DVector, Mat, Vec2, Vec3, Vec4};
+use crate::numbers::{Complex, Real};
+
+impl<'a> Arbitrary<'a> for Complex {
+    /// any pair of reals, NaN and the infinities included, as a fuzzer wants
+    /// ```
+    /// # use arbitrary::{Arbitrary, Unstructured};
+    /// # use vmath::numbers::Complex;
+    /// let bytes = [0_u8, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64];
+    /// let z = Complex::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    /// assert_eq!(z, Complex::new(1.0, 2.0));
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Real; 2]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vec2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[Real; 2]>::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Real; 2]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vec3 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[Real; 3]>::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Real; 3]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Vec4 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(<[Real; 4]>::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Real; 4]>::size_hint(depth)
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for Mat<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[[Real; N]; N]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for DVector {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for DMatrix {
+    /// a shape and then enough entries to fill it,
+    /// so what is left of the input bounds the size
+    /// ```
+    /// # use arbitrary::{Arbitrary, Unstructured};
+    /// # use vmath::linalg::DMatrix;
+    /// let bytes: Vec<u8> = (0..=255).collect();
+    /// let m = DMatrix::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    /// assert_eq!(m.data.len(), m.rows * m.columns);
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let entries = u.arbitrary_len::<Real>()?;
+        let rows = u.int_in_range(0..=entries)?;
+        let columns = entries.checked_div(rows).unwrap_or(0);
+        let data = (0..rows * columns)
+            .map(|_| u.arbitrary())
+            .collect::<Result<_>>()?;
+        Ok(Self::new(rows, columns, data))
+    }
+}
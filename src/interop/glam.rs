@@ -0,0 +1,122 @@
+use ::glam::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+use crate::geometry::Rotation3;
+use crate::linalg::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+impl From<DVec2> for Vec2 {
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// assert_eq!(Vec2::from(glam::DVec2::new(1.0, 2.0)), Vec2::new(1.0, 2.0));
+    /// ```
+    fn from(v: DVec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<Vec2> for DVec2 {
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<DVec3> for Vec3 {
+    fn from(v: DVec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for DVec3 {
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v: glam::DVec3 = Vec3::new(1.0, 2.0, 3.0).into();
+    /// assert_eq!(v, glam::DVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<DVec4> for Vec4 {
+    fn from(v: DVec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vec4> for DVec4 {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<DMat2> for Mat2 {
+    /// glam stores columns and vmath stores rows, the matrix itself is unchanged
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = glam::DMat2::from_cols_array_2d(&[[1.0, 3.0], [2.0, 4.0]]);
+    /// assert_eq!(Mat2::from(m), Mat2::new([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    fn from(m: DMat2) -> Self {
+        Self::new(m.transpose().to_cols_array_2d())
+    }
+}
+
+impl From<Mat2> for DMat2 {
+    fn from(m: Mat2) -> Self {
+        Self::from_cols_array_2d(&m.rows).transpose()
+    }
+}
+
+impl From<DMat3> for Mat3 {
+    fn from(m: DMat3) -> Self {
+        Self::new(m.transpose().to_cols_array_2d())
+    }
+}
+
+impl From<Mat3> for DMat3 {
+    fn from(m: Mat3) -> Self {
+        Self::from_cols_array_2d(&m.rows).transpose()
+    }
+}
+
+impl From<DMat4> for Mat4 {
+    fn from(m: DMat4) -> Self {
+        Self::new(m.transpose().to_cols_array_2d())
+    }
+}
+
+impl From<Mat4> for DMat4 {
+    /// ```
+    /// # use vmath::geometry::Transform3;
+    /// # use vmath::linalg::Vec3;
+    /// let m = Transform3::from_translation(Vec3::new(1.0, 2.0, 3.0)).to_matrix();
+    /// let point = glam::DMat4::from(m).transform_point3(glam::DVec3::ZERO);
+    /// assert_eq!(point, glam::DVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn from(m: Mat4) -> Self {
+        Self::from_cols_array_2d(&m.rows).transpose()
+    }
+}
+
+impl From<DQuat> for Rotation3 {
+    /// glam only treats unit quaternions as rotations, so this renormalizes,
+    /// and the zero quaternion, which is no rotation at all, becomes the identity
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let q = glam::DQuat::from_rotation_z(0.5);
+    /// let rotation = Rotation3::from(q);
+    /// let expected = Rotation3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.5);
+    /// assert!((rotation.scalar() - expected.scalar()).abs() < 1e-15);
+    /// assert!(rotation.vector().distance(expected.vector()) < 1e-15);
+    /// ```
+    fn from(q: DQuat) -> Self {
+        Self::from_quaternion(q.w, q.x, q.y, q.z).unwrap_or(Self::IDENTITY)
+    }
+}
+
+impl From<Rotation3> for DQuat {
+    fn from(rotation: Rotation3) -> Self {
+        let v = rotation.vector();
+        Self::from_xyzw(v.x, v.y, v.z, rotation.scalar())
+    }
+}
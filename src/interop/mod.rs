@@ -0,0 +1,6 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
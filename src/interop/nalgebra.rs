@@ -0,0 +1,180 @@
+use ::nalgebra::{
+    Point2 as NPoint2, Point3 as NPoint3, Quaternion, SMatrix, Translation2, Translation3,
+    UnitComplex, UnitQuaternion, Vector2, Vector3, Vector4,
+};
+
+use crate::geometry::{Isometry2, Isometry3, Rotation2, Rotation3};
+use crate::linalg::{Mat, Vec2, Vec3, Vec4};
+use crate::numbers::{Complex, Real};
+
+impl From<Vector2<Real>> for Vec2 {
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// assert_eq!(Vec2::from(nalgebra::Vector2::new(1.0, 2.0)), Vec2::new(1.0, 2.0));
+    /// ```
+    fn from(v: Vector2<Real>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<Vec2> for Vector2<Real> {
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<Vector3<Real>> for Vec3 {
+    fn from(v: Vector3<Real>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for Vector3<Real> {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector4<Real>> for Vec4 {
+    fn from(v: Vector4<Real>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vec4> for Vector4<Real> {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<NPoint2<Real>> for Vec2 {
+    fn from(p: NPoint2<Real>) -> Self {
+        Self::new(p.x, p.y)
+    }
+}
+
+impl From<Vec2> for NPoint2<Real> {
+    fn from(p: Vec2) -> Self {
+        Self::new(p.x, p.y)
+    }
+}
+
+impl From<NPoint3<Real>> for Vec3 {
+    fn from(p: NPoint3<Real>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vec3> for NPoint3<Real> {
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let p: nalgebra::Point3<f64> = Vec3::new(1.0, 2.0, 3.0).into();
+    /// assert_eq!(p, nalgebra::Point3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn from(p: Vec3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl<const N: usize> From<SMatrix<Real, N, N>> for Mat<N> {
+    /// nalgebra stores columns and vmath stores rows, the matrix itself is unchanged
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = nalgebra::Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(Mat2::from(m), Mat2::new([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    fn from(m: SMatrix<Real, N, N>) -> Self {
+        Self::new(std::array::from_fn(|i| std::array::from_fn(|j| m[(i, j)])))
+    }
+}
+
+impl<const N: usize> From<Mat<N>> for SMatrix<Real, N, N> {
+    /// ```
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let m = Mat3::new([[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+    /// let product = nalgebra::Matrix3::from(m) * nalgebra::Vector3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(Vec3::from(product), m * Vec3::new(1.0, 1.0, 1.0));
+    /// ```
+    fn from(m: Mat<N>) -> Self {
+        Self::from_fn(|i, j| m.rows[i][j])
+    }
+}
+
+impl From<UnitComplex<Real>> for Rotation2 {
+    fn from(rotation: UnitComplex<Real>) -> Self {
+        let z = rotation.complex();
+        Self::from_complex(Complex::new(z.re, z.im)).unwrap_or(Self::IDENTITY)
+    }
+}
+
+impl From<Rotation2> for UnitComplex<Real> {
+    /// ```
+    /// # use vmath::geometry::Rotation2;
+    /// let rotation = nalgebra::UnitComplex::from(Rotation2::from_angle(0.3));
+    /// assert!((rotation.angle() - 0.3).abs() < 1e-15);
+    /// ```
+    fn from(rotation: Rotation2) -> Self {
+        let z = rotation.complex();
+        Self::new_unchecked(::nalgebra::Complex::new(z.real, z.imaginary))
+    }
+}
+
+impl From<UnitQuaternion<Real>> for Rotation3 {
+    /// ```
+    /// # use vmath::geometry::Rotation3;
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let q = nalgebra::UnitQuaternion::from_euler_angles(0.0, 0.0, 0.5);
+    /// let rotated = Rotation3::from(q).rotate(Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(rotated.distance(Vec3::new(0.5_f64.cos(), 0.5_f64.sin(), 0.0)) < 1e-15);
+    /// ```
+    fn from(rotation: UnitQuaternion<Real>) -> Self {
+        Self::from_quaternion(rotation.w, rotation.i, rotation.j, rotation.k)
+            .unwrap_or(Self::IDENTITY)
+    }
+}
+
+impl From<Rotation3> for UnitQuaternion<Real> {
+    fn from(rotation: Rotation3) -> Self {
+        let v = rotation.vector();
+        Self::new_unchecked(Quaternion::new(rotation.scalar(), v.x, v.y, v.z))
+    }
+}
+
+impl From<::nalgebra::Isometry2<Real>> for Isometry2 {
+    fn from(isometry: ::nalgebra::Isometry2<Real>) -> Self {
+        Self::new(isometry.rotation.into(), isometry.translation.vector.into())
+    }
+}
+
+impl From<Isometry2> for ::nalgebra::Isometry2<Real> {
+    fn from(isometry: Isometry2) -> Self {
+        Self::from_parts(
+            Translation2::from(Vector2::from(isometry.translation)),
+            isometry.rotation.into(),
+        )
+    }
+}
+
+impl From<::nalgebra::Isometry3<Real>> for Isometry3 {
+    fn from(isometry: ::nalgebra::Isometry3<Real>) -> Self {
+        Self::new(isometry.rotation.into(), isometry.translation.vector.into())
+    }
+}
+
+impl From<Isometry3> for ::nalgebra::Isometry3<Real> {
+    /// ```
+    /// # use vmath::geometry::{Isometry3, Rotation3};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let rotation = Rotation3::from_axis_angle(Vec3::new(1.0, 2.0, 2.0), 0.8);
+    /// let isometry = Isometry3::new(rotation, Vec3::new(1.0, -1.0, 0.5));
+    /// let point = Vec3::new(0.3, 0.2, 0.1);
+    /// let theirs = nalgebra::Isometry3::from(isometry) * nalgebra::Point3::from(point);
+    /// assert!(Vec3::from(theirs).distance(isometry.transform_point(point)) < 1e-15);
+    /// ```
+    fn from(isometry: Isometry3) -> Self {
+        Self::from_parts(
+            Translation3::from(Vector3::from(isometry.translation)),
+            isometry.rotation.into(),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+//! escape-time iteration of z -> z^2 + c, the kernel behind
+//! pictures of the mandelbrot and julia sets.
+//! each function counts the steps until |z| leaves a disk
+//! outside which the orbit must run off to infinity,
+//! None when it is still inside after max_iter steps
+//!
+//! ```
+//! use vmath::iterate::escape_time;
+//! use vmath::numbers::Complex;
+//!
+//! // a coarse mandelbrot set, # for the points that stay bounded
+//! let picture: Vec<String> = (0..5)
+//!     .map(|row| {
+//!         (0..11)
+//!             .map(|column| {
+//!                 let c = Complex::new(-2.0 + 0.25 * column as f64, 1.0 - 0.5 * row as f64);
+//!                 match escape_time(c, 100) {
+//!                     None => '#',
+//!                     Some(_) => '.',
+//!                 }
+//!             })
+//!             .collect()
+//!     })
+//!     .collect();
+//! // the real axis is in it from -2 to 1 / 4
+//! assert_eq!(picture[2], "##########.");
+//! ```
+
+use crate::numbers::{Complex, Real};
+
+/// the radius the smooth counts escape, large so that
+/// the orbit is near enough to its asymptotic growth
+const SMOOTH_RADIUS: Real = 256.0;
+
+/// the steps of z -> z^2 + c from z = 0 until |z| > 2,
+/// None when c is taken to be in the mandelbrot set
+/// ```
+/// # use vmath::iterate::escape_time;
+/// # use vmath::numbers::Complex;
+/// // 0, 1, 2, 5
+/// assert_eq!(escape_time(Complex::ONE, 100), Some(3));
+/// assert_eq!(escape_time(Complex::new(-1.0, 0.0), 100), None);
+/// assert_eq!(escape_time(Complex::new(0.0, 3.0), 100), Some(1));
+/// ```
+pub fn escape_time(c: Complex, max_iter: u32) -> Option<u32> {
+    // an orbit from 0 that leaves |z| <= 2 diverges, whatever c is
+    escape(Complex::ZERO, c, max_iter, 2.0).map(|(steps, _)| steps)
+}
+
+/// the steps of z -> z^2 + c from z0 until |z| > max(2, |c|),
+/// None when z0 is taken to be in the filled julia set of c
+/// ```
+/// # use vmath::iterate::julia;
+/// # use vmath::numbers::Complex;
+/// // for c = 0 the filled julia set is the unit disk
+/// assert_eq!(julia(Complex::new(0.5, 0.5), Complex::ZERO, 100), None);
+/// // 1.5, 2.25
+/// assert_eq!(julia(Complex::new(1.5, 0.0), Complex::ZERO, 100), Some(1));
+/// ```
+pub fn julia(z0: Complex, c: Complex, max_iter: u32) -> Option<u32> {
+    escape(z0, c, max_iter, c.norm().max(2.0)).map(|(steps, _)| steps)
+}
+
+/// a continuous count for c, which colors without bands,
+/// n + 1 - log2(ln |z_n|) for the first z_n outside a disk of radius 256,
+/// so it runs a few steps past `escape_time`
+/// ```
+/// # use vmath::iterate::{escape_time, smooth_escape_time};
+/// # use vmath::numbers::Complex;
+/// let c = Complex::new(0.4, 0.5);
+/// let smooth = smooth_escape_time(c, 1000).unwrap();
+/// let steps = escape_time(c, 1000).unwrap() as f64;
+/// assert!(smooth > 0.0);
+/// assert!((smooth - steps).abs() < 4.0);
+///
+/// // and it changes little between nearby points
+/// let nearby = smooth_escape_time(c + Complex::new(1e-6, 0.0), 1000).unwrap();
+/// assert!((smooth - nearby).abs() < 0.1);
+/// assert_eq!(smooth_escape_time(Complex::ZERO, 1000), None);
+/// ```
+pub fn smooth_escape_time(c: Complex, max_iter: u32) -> Option<Real> {
+    smooth_count(escape(Complex::ZERO, c, max_iter, SMOOTH_RADIUS))
+}
+
+/// the continuous count of `smooth_escape_time` for the julia set of c
+/// ```
+/// # use vmath::iterate::smooth_julia;
+/// # use vmath::numbers::Complex;
+/// let c = Complex::new(-0.8, 0.156);
+/// let smooth = smooth_julia(Complex::new(0.0, 0.8), c, 1000).unwrap();
+/// assert!(smooth.is_finite() && smooth > 0.0);
+/// assert_eq!(smooth_julia(Complex::ZERO, Complex::ZERO, 1000), None);
+/// ```
+pub fn smooth_julia(z0: Complex, c: Complex, max_iter: u32) -> Option<Real> {
+    smooth_count(escape(z0, c, max_iter, c.norm().max(SMOOTH_RADIUS)))
+}
+
+fn smooth_count(escaped: Option<(u32, Complex)>) -> Option<Real> {
+    let (steps, z) = escaped?;
+    Some(Real::from(steps) + 1.0 - z.norm().ln().log2())
+}
+
+/// the steps until |z| > radius and the z that got there
+fn escape(z0: Complex, c: Complex, max_iter: u32, radius: Real) -> Option<(u32, Complex)> {
+    let bound = radius * radius;
+    let mut z = z0;
+    for steps in 0..=max_iter {
+        if z.norm_squared() > bound {
+            return Some((steps, z));
+        }
+        z = z * z + c;
+    }
+    None
+}
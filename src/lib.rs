@@ -1 +1,136 @@
+//! # features
+//!
+//! - `arbitrary`: `Arbitrary` for `Complex`, `Vec2`, `Vec3`, `Vec4`, `Mat<N>`, `DVector`,
+//!   and `DMatrix`, for fuzzing, with every bit pattern of a real possible.
+//! - `bytemuck`: `Pod` and `Zeroable` for `Complex`, `Complex32`, `Complex4`, `Complex8`,
+//!   `Vec2`, `Vec3`, `Vec4`, and `Mat<N>`,
+//!   which are `#[repr(C)]` with their fields in declaration order and no padding,
+//!   so slices of them cast to and from bytes or reals for gpu buffers.
+//!   a matrix keeps its row-major order, so shaders expecting columns want it transposed.
+//! - `glam`: `From` conversions both ways between the vectors, matrices, and `Rotation3`
+//!   and glam's double precision `DVec2`, `DVec3`, `DVec4`, `DMat2`, `DMat3`, `DMat4`, and `DQuat`.
+//! - `nalgebra`: `From` conversions both ways between the vectors, matrices, rotations,
+//!   and isometries and nalgebra's `Vector2`, `Vector3`, `Vector4`, `Point2`, `Point3`,
+//!   `SMatrix`, `UnitComplex`, `UnitQuaternion`, `Isometry2`, and `Isometry3` over `f64`.
+//! - `num-complex`: `From` conversions both ways between `Complex` and `num_complex::Complex64`
+//!   and between `Complex32` and `num_complex::Complex32`, and `as_num` views of a `Complex`
+//!   or a slice of them as `Complex64`s in place, the two having the same layout.
+//! - `physics`: the CODATA physical constants in [`consts::physics`], in si units.
+//! - `proptest`: strategies in [`strategies`] for the same types, over finite ranges
+//!   that shrink toward zero, along with well-conditioned and orthogonal matrices.
+//! - `python`: the `vmath` python extension module in [`python`], through pyo3,
+//!   with numpy conversions for the dense `Vector` and `Matrix`.
+//!   build it with maturin, `maturin develop`, which takes the feature and the cdylib
+//!   crate type from `pyproject.toml`, leaving plain rust builds an rlib alone.
+//! - `rand`: the distributions in [`distributions`] for sampling complex numbers
+//!   uniformly in the unit disk, uniformly on the unit circle, and from the standard
+//!   complex normal, with any `rand::Rng`.
+//! - `rayon`: parallel `par_map`, `par_zip_map`, `par_sum`, and `par_dot` on `DVector`
+//!   and `DMatrix`, and `par_fft_batch` and `par_inverse_fft_batch` in [`signal`],
+//!   all on rayon's global thread pool.
+//! - `rkyv`: `Archive`, `Serialize`, and `Deserialize` for `Complex`, `Vec2`, `Vec3`, `Vec4`,
+//!   `Mat<N>`, `DVector`, and `DMatrix`, so a checkpoint written with `rkyv::to_bytes`
+//!   can be read in place, from a memory map say, through `rkyv::access` and the
+//!   `Archived` types without deserializing it first.
+//! - `serde`: `Serialize` and `Deserialize` for every public type.
+//!   structs are maps of their field names and enums are externally tagged,
+//!   as serde derives them, with three exceptions:
+//!   a `Mat<N>` is a sequence of N rows of N reals,
+//!   a `Rotation2` is its unit `Complex`,
+//!   and a `Rotation3` is its unit quaternion as a (scalar, vector) pair.
+//!   rotations are renormalized as they are read, and a zero one is an error.
+//! - `wasm-bindgen`: javascript classes in [`wasm`] for `Complex`, the vectors,
+//!   and a dense `Matrix`, for builds targeting `wasm32-unknown-unknown`.
+//!   the crate needs no threads, clocks, or randomness from the platform,
+//!   so it builds for that target with any of its features but `python` and `rayon`.
+//! - `wgpu`: matrix products, batched or not, elementwise arithmetic, and fourier
+//!   transforms in [`gpu`], run by compute shaders on a device and queue the caller
+//!   requests with `SHADER_F64`, for problems too large for the cpu. enables `bytemuck`.
+//!
+//! ```
+//! # #[cfg(feature = "bytemuck")]
+//! # {
+//! use vmath::linalg::Vec3;
+//!
+//! let vertices = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+//! let reals: &[f64] = bytemuck::cast_slice(&vertices);
+//! assert_eq!(reals, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+//! assert_eq!(bytes.len(), 48);
+//!
+//! use vmath::numbers::{Complex, Complex32, Complex4};
+//! let samples = [Complex32::new(1.0, -1.0), Complex32::new(0.5, 2.0)];
+//! assert_eq!(bytemuck::cast_slice::<_, f32>(&samples), &[1.0, -1.0, 0.5, 2.0]);
+//! // the packed lanes are all the real parts and then all the imaginary ones
+//! let packed = Complex4::splat(Complex::new(1.0, 2.0));
+//! assert_eq!(bytemuck::cast::<_, [f64; 8]>(packed), [1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0]);
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "rkyv")]
+//! # {
+//! use vmath::linalg::dmatrix::ArchivedDMatrix;
+//! use vmath::linalg::DMatrix;
+//!
+//! let m = DMatrix::from_fn(100, 100, |i, j| (i * j) as f64);
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&m).unwrap();
+//! let archived = rkyv::access::<ArchivedDMatrix, rkyv::rancor::Error>(&bytes).unwrap();
+//! assert_eq!(archived.columns, 100);
+//! assert_eq!(archived.data[3 * 100 + 7], 21.0);
+//! let restored = rkyv::deserialize::<DMatrix, rkyv::rancor::Error>(archived).unwrap();
+//! assert_eq!(restored, m);
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use vmath::linalg::{Mat2, Vec2};
+//! use vmath::numbers::Complex;
+//!
+//! let z = Complex::new(1.0, -2.0);
+//! let json = serde_json::to_string(&z).unwrap();
+//! assert_eq!(json, r#"{"real":1.0,"imaginary":-2.0}"#);
+//! assert_eq!(serde_json::from_str::<Complex>(&json).unwrap(), z);
+//!
+//! let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+//! let json = serde_json::to_string(&m).unwrap();
+//! assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+//! assert_eq!(serde_json::from_str::<Mat2>(&json).unwrap(), m);
+//! assert!(serde_json::from_str::<Mat2>("[[1.0,2.0]]").is_err());
+//!
+//! let v = Vec2::new(0.5, 1.5);
+//! assert_eq!(serde_json::from_str::<Vec2>(&serde_json::to_string(&v).unwrap()).unwrap(), v);
+//!
+//! use vmath::geometry::Rotation2;
+//! let rotation: Rotation2 = serde_json::from_str(r#"{"real":0.0,"imaginary":2.0}"#).unwrap();
+//! assert_eq!(rotation.complex(), Complex::new(0.0, 1.0));
+//! # }
+//! ```
+
+pub mod consts;
+#[cfg(feature = "rand")]
+pub mod distributions;
+mod error;
+pub mod expr;
+pub mod fast;
+pub mod geometry;
+#[cfg(feature = "wgpu")]
+pub mod gpu;
+mod interop;
+pub mod iterate;
+pub mod linalg;
 pub mod numbers;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod signal;
+pub mod special;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod units;
+pub mod util;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+pub use crate::error::{Error, ParseError};
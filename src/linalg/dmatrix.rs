@@ -0,0 +1,487 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::linalg::{DVector, Mat};
+use crate::numbers::{Field, Real};
+use crate::Error;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// a matrix of any shape on the heap, for when the size is only known at run time,
+/// with its entries in row-major order like `Mat<N>`
+///
+/// the entries are `Real` unless given over another `Field`, which the
+/// eliminations need nothing beyond, and the arithmetic panics when the shapes do not fit
+pub struct DMatrix<T = Real> {
+    pub rows: usize,
+    pub columns: usize,
+    pub data: Vec<T>,
+}
+
+impl<T: Field> DMatrix<T> {
+    /// panics unless there are rows * columns entries
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(m[(1, 0)], 4.0);
+    /// assert_eq!(m.row(0), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn new(rows: usize, columns: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * columns,
+            "entry count does not match the shape"
+        );
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    /// the matrix whose (i, j) entry is f(i, j)
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::from_fn(2, 2, |i, j| (10 * i + j) as f64);
+    /// assert_eq!(m, DMatrix::new(2, 2, vec![0.0, 1.0, 10.0, 11.0]));
+    /// ```
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows)
+            .flat_map(|i| (0..columns).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self::new(rows, columns, data)
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.columns..(i + 1) * self.columns]
+    }
+
+    /// ```
+    /// # use vmath::linalg::{DMatrix, DVector};
+    /// let m = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.column(1), DVector::new(vec![2.0, 4.0]));
+    /// ```
+    pub fn column(&self, j: usize) -> DVector<T> {
+        DVector::from_fn(self.rows, |i| self[(i, j)])
+    }
+
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(m.transpose(), DMatrix::new(3, 2, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.columns, self.rows, |i, j| self[(j, i)])
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// by elimination with partial pivoting, panics unless square
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m: DMatrix = DMatrix::new(3, 3, vec![2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+    /// assert!((m.determinant() - 6.0).abs() < 1e-12);
+    /// assert_eq!(DMatrix::zeros(0, 0).determinant(), 1.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        assert!(self.is_square(), "determinant of a non-square matrix");
+        let n = self.rows;
+        let mut entries = self.clone();
+        let mut determinant = T::ONE;
+        for column in 0..n {
+            let pivot = entries.pivot(column);
+            if entries[(pivot, column)] == T::ZERO {
+                return T::ZERO;
+            }
+            if pivot != column {
+                entries.swap_rows(pivot, column);
+                determinant = -determinant;
+            }
+            let divisor = entries[(column, column)];
+            determinant = determinant * divisor;
+            for i in column + 1..n {
+                let factor = entries[(i, column)] / divisor;
+                for j in column..n {
+                    entries[(i, j)] = entries[(i, j)] - factor * entries[(column, j)];
+                }
+            }
+        }
+        determinant
+    }
+
+    /// the x with self x = b, by gauss-jordan elimination with partial pivoting,
+    /// an error when the matrix is singular to working precision,
+    /// or is not square, or b has other than as many entries as it has rows
+    /// ```
+    /// # use vmath::linalg::{DMatrix, DVector};
+    /// # use vmath::Error;
+    /// let m = DMatrix::new(2, 2, vec![3.0, 2.0, 1.0, 2.0]);
+    /// let x = m.solve(&DVector::new(vec![5.0, 5.0])).unwrap();
+    /// assert!((x - DVector::new(vec![0.0, 2.5])).length() < 1e-15);
+    ///
+    /// let singular = DMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert_eq!(singular.solve(&DVector::new(vec![1.0, 1.0])), Err(Error::Singular));
+    /// assert_eq!(m.solve(&DVector::zeros(3)), Err(Error::ShapeMismatch));
+    /// ```
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, Error> {
+        if self.rows != b.len() {
+            return Err(Error::ShapeMismatch);
+        }
+        let solution = self.solve_columns(Self::new(b.len(), 1, b.data.clone()))?;
+        Ok(DVector::new(solution.data))
+    }
+
+    /// by gauss-jordan elimination,
+    /// an error when the matrix is singular to working precision or not square
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// # use vmath::Error;
+    /// let m: DMatrix = DMatrix::new(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    /// let inverse = m.inverse().unwrap();
+    /// let expected = DMatrix::new(2, 2, vec![0.6, -0.7, -0.2, 0.4]);
+    /// assert!((inverse - expected).data.iter().all(|e| e.abs() < 1e-15));
+    ///
+    /// assert_eq!(DMatrix::zeros(2, 3).inverse(), Err(Error::ShapeMismatch));
+    /// ```
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let n = self.rows;
+        self.solve_columns(Self::from_fn(
+            n,
+            n,
+            |i, j| if i == j { T::ONE } else { T::ZERO },
+        ))
+    }
+
+    /// the solution of self x = b, None when there is no unique one
+    /// or the shapes do not fit
+    /// ```
+    /// # use vmath::linalg::{DMatrix, DVector};
+    /// let m = DMatrix::identity(2) * 2.0;
+    /// let x = m.checked_solve(&DVector::new(vec![2.0, 4.0]));
+    /// assert_eq!(x, Some(DVector::new(vec![1.0, 2.0])));
+    /// assert_eq!(DMatrix::zeros(2, 2).checked_solve(&DVector::zeros(2)), None);
+    /// ```
+    pub fn checked_solve(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        self.solve(b).ok()
+    }
+
+    /// the inverse, None when the matrix is singular or not square
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// assert_eq!(DMatrix::identity(3).checked_inverse(), Some(DMatrix::identity(3)));
+    /// assert_eq!(DMatrix::zeros(3, 3).checked_inverse(), None);
+    /// ```
+    pub fn checked_inverse(&self) -> Option<Self> {
+        self.inverse().ok()
+    }
+
+    /// reduces self to the identity while applying the same row operations to rhs,
+    /// leaving the solution of self x = rhs for every column of rhs
+    fn solve_columns(&self, mut rhs: Self) -> Result<Self, Error> {
+        if !self.is_square() {
+            return Err(Error::ShapeMismatch);
+        }
+        let n = self.rows;
+        let scale = self
+            .data
+            .iter()
+            .fold(0.0, |max: Real, entry| max.max(entry.magnitude()));
+        let tolerance = scale * n as Real * T::ROUNDING;
+        let mut entries = self.clone();
+        for column in 0..n {
+            let pivot = entries.pivot(column);
+            if entries[(pivot, column)].magnitude() <= tolerance {
+                return Err(Error::Singular);
+            }
+            entries.swap_rows(pivot, column);
+            rhs.swap_rows(pivot, column);
+            let divisor = entries[(column, column)];
+            entries.scale_row(column, T::ONE / divisor);
+            rhs.scale_row(column, T::ONE / divisor);
+            for i in (0..n).filter(|&i| i != column) {
+                let factor = entries[(i, column)];
+                entries.subtract_row(i, column, factor);
+                rhs.subtract_row(i, column, factor);
+            }
+        }
+        Ok(rhs)
+    }
+
+    /// the row from column down whose entry there has the largest magnitude
+    fn pivot(&self, column: usize) -> usize {
+        (column..self.rows)
+            .max_by(|&a, &b| {
+                self[(a, column)]
+                    .magnitude()
+                    .total_cmp(&self[(b, column)].magnitude())
+            })
+            .unwrap_or(column)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.columns {
+            self.data.swap(a * self.columns + j, b * self.columns + j);
+        }
+    }
+
+    fn scale_row(&mut self, i: usize, factor: T) {
+        for j in 0..self.columns {
+            self[(i, j)] = self[(i, j)] * factor;
+        }
+    }
+
+    /// row i -= factor * row k
+    fn subtract_row(&mut self, i: usize, k: usize, factor: T) {
+        for j in 0..self.columns {
+            let entry = self[(k, j)];
+            self[(i, j)] = self[(i, j)] - factor * entry;
+        }
+    }
+}
+
+impl DMatrix {
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self::new(rows, columns, vec![0.0; rows * columns])
+    }
+
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::identity(2);
+    /// assert_eq!(m, DMatrix::new(2, 2, vec![1.0, 0.0, 0.0, 1.0]));
+    /// ```
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { 1.0 } else { 0.0 })
+    }
+}
+
+/// the entries shared out across the rayon thread pool, as for `DVector`
+#[cfg(feature = "rayon")]
+impl DMatrix {
+    /// the matrix of f applied to every entry
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::from_fn(30, 40, |i, j| (i * j) as f64);
+    /// assert_eq!(m.par_map(f64::sqrt), DMatrix::from_fn(30, 40, |i, j| ((i * j) as f64).sqrt()));
+    /// ```
+    pub fn par_map(&self, f: impl Fn(Real) -> Real + Sync + Send) -> Self {
+        Self::new(
+            self.rows,
+            self.columns,
+            self.data.par_iter().map(|&x| f(x)).collect(),
+        )
+    }
+
+    /// the matrix of f applied to the matching entries of self and rhs,
+    /// panics when the shapes differ
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::identity(3);
+    /// let b = DMatrix::from_fn(3, 3, |_, _| 2.0);
+    /// assert_eq!(a.par_zip_map(&b, |a, b| a * b), DMatrix::identity(3) * 2.0);
+    /// ```
+    pub fn par_zip_map(&self, rhs: &Self, f: impl Fn(Real, Real) -> Real + Sync + Send) -> Self {
+        assert_eq!(
+            (self.rows, self.columns),
+            (rhs.rows, rhs.columns),
+            "matrix shapes differ"
+        );
+        let data = self
+            .data
+            .par_iter()
+            .zip(&rhs.data)
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        Self::new(self.rows, self.columns, data)
+    }
+
+    /// the sum of the entries
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// assert_eq!(DMatrix::identity(50).par_sum(), 50.0);
+    /// ```
+    pub fn par_sum(&self) -> Real {
+        self.data.par_iter().sum()
+    }
+
+    /// the frobenius inner product, the sum of the products of matching entries,
+    /// panics when the shapes differ
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(m.par_dot(&m), 30.0);
+    /// ```
+    pub fn par_dot(&self, rhs: &Self) -> Real {
+        assert_eq!(
+            (self.rows, self.columns),
+            (rhs.rows, rhs.columns),
+            "matrix shapes differ"
+        );
+        self.data
+            .par_iter()
+            .zip(&rhs.data)
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+}
+
+impl<const N: usize> From<Mat<N>> for DMatrix {
+    /// ```
+    /// # use vmath::linalg::{DMatrix, Mat2};
+    /// let m = DMatrix::from(Mat2::new([[1.0, 2.0], [3.0, 4.0]]));
+    /// assert_eq!(m, DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+    /// ```
+    fn from(m: Mat<N>) -> Self {
+        Self::new(N, N, m.rows.iter().flatten().copied().collect())
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(j < self.columns, "column index out of bounds");
+        &self.data[i * self.columns + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let mut m = DMatrix::zeros(2, 2);
+    /// m[(0, 1)] = 5.0;
+    /// assert_eq!(m, DMatrix::new(2, 2, vec![0.0, 5.0, 0.0, 0.0]));
+    /// ```
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(j < self.columns, "column index out of bounds");
+        &mut self.data[i * self.columns + j]
+    }
+}
+
+impl<T: Field> Neg for DMatrix<T> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for entry in &mut self.data {
+            *entry = -*entry;
+        }
+        self
+    }
+}
+
+impl<T: Field> Add for DMatrix<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::new(1, 2, vec![1.0, 2.0]);
+    /// let b = DMatrix::new(1, 2, vec![3.0, 5.0]);
+    /// assert_eq!(a + b, DMatrix::new(1, 2, vec![4.0, 7.0]));
+    /// ```
+    fn add(mut self, rhs: Self) -> Self {
+        assert_eq!(
+            (self.rows, self.columns),
+            (rhs.rows, rhs.columns),
+            "matrix shapes differ"
+        );
+        for (entry, rhs_entry) in self.data.iter_mut().zip(rhs.data) {
+            *entry = *entry + rhs_entry;
+        }
+        self
+    }
+}
+
+impl<T: Field> Sub for DMatrix<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::new(1, 2, vec![4.0, 7.0]);
+    /// let b = DMatrix::new(1, 2, vec![3.0, 5.0]);
+    /// assert_eq!(a - b, DMatrix::new(1, 2, vec![1.0, 2.0]));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Field> Mul<T> for DMatrix<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::new(1, 2, vec![1.0, 2.0]);
+    /// assert_eq!(m * 2.0, DMatrix::new(1, 2, vec![2.0, 4.0]));
+    /// ```
+    fn mul(mut self, rhs: T) -> Self {
+        for entry in &mut self.data {
+            *entry = *entry * rhs;
+        }
+        self
+    }
+}
+
+impl Mul<DMatrix> for Real {
+    type Output = DMatrix;
+    fn mul(self, rhs: DMatrix) -> DMatrix {
+        rhs * self
+    }
+}
+
+impl<T: Field> Mul for &DMatrix<T> {
+    type Output = DMatrix<T>;
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let b = DMatrix::new(3, 1, vec![1.0, 0.0, -1.0]);
+    /// assert_eq!(&a * &b, DMatrix::new(2, 1, vec![-2.0, -2.0]));
+    /// ```
+    fn mul(self, rhs: &DMatrix<T>) -> DMatrix<T> {
+        assert_eq!(self.columns, rhs.rows, "matrix shapes do not fit");
+        let mut product = DMatrix::from_fn(self.rows, rhs.columns, |_, _| T::ZERO);
+        T::matmul(
+            &self.data,
+            &rhs.data,
+            &mut product.data,
+            self.rows,
+            self.columns,
+            rhs.columns,
+        );
+        product
+    }
+}
+
+impl<T: Field> Mul for DMatrix<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl<T: Field> Mul<&DVector<T>> for &DMatrix<T> {
+    type Output = DVector<T>;
+    /// ```
+    /// # use vmath::linalg::{DMatrix, DVector};
+    /// let m = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let v = DVector::new(vec![1.0, 1.0]);
+    /// assert_eq!(&m * &v, DVector::new(vec![3.0, 7.0]));
+    /// ```
+    fn mul(self, rhs: &DVector<T>) -> DVector<T> {
+        assert_eq!(
+            self.columns,
+            rhs.len(),
+            "matrix and vector shapes do not fit"
+        );
+        DVector::from_fn(self.rows, |i| T::dot(self.row(i), &rhs.data))
+    }
+}
+
+impl<T: Field> Mul<DVector<T>> for DMatrix<T> {
+    type Output = DVector<T>;
+    fn mul(self, rhs: DVector<T>) -> DVector<T> {
+        &self * &rhs
+    }
+}
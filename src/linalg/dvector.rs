@@ -0,0 +1,297 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::numbers::{Field, Real};
+use crate::Error;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// a vector of any length on the heap, for when the size is only known at run time
+///
+/// the entries are `Real` unless given over another `Field`,
+/// and the arithmetic panics when the lengths differ
+pub struct DVector<T = Real> {
+    pub data: Vec<T>,
+}
+
+impl<T: Field> DVector<T> {
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(v.len(), 3);
+    /// assert_eq!(v[2], 3.0);
+    /// ```
+    pub fn new(data: Vec<T>) -> Self {
+        Self { data }
+    }
+
+    /// the vector whose i-th entry is f(i)
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::from_fn(4, |i| (i * i) as f64);
+    /// assert_eq!(v, DVector::new(vec![0.0, 1.0, 4.0, 9.0]));
+    /// ```
+    pub fn from_fn(len: usize, f: impl FnMut(usize) -> T) -> Self {
+        Self::new((0..len).map(f).collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// assert!(DVector::zeros(0).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let a = DVector::new(vec![1.0, 2.0, 3.0]);
+    /// let b = DVector::new(vec![4.0, 5.0, 6.0]);
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
+    pub fn dot(&self, rhs: &Self) -> T {
+        assert_eq!(self.len(), rhs.len(), "vector lengths differ");
+        T::dot(&self.data, &rhs.data)
+    }
+
+    /// self / rhs, an error rather than infinities and NaN when rhs is zero
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// # use vmath::Error;
+    /// let v = DVector::new(vec![3.0, 6.0]);
+    /// assert_eq!(v.try_div(3.0), Ok(DVector::new(vec![1.0, 2.0])));
+    /// assert_eq!(v.try_div(0.0), Err(Error::DivisionByZero));
+    /// ```
+    pub fn try_div(&self, rhs: T) -> Result<Self, Error> {
+        if rhs == T::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.clone() / rhs)
+    }
+
+    /// self / rhs, None when rhs is zero
+    pub fn checked_div(&self, rhs: T) -> Option<Self> {
+        self.try_div(rhs).ok()
+    }
+}
+
+impl DVector {
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// assert_eq!(DVector::zeros(2), DVector::new(vec![0.0, 0.0]));
+    /// ```
+    pub fn zeros(len: usize) -> Self {
+        Self::new(vec![0.0; len])
+    }
+
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![2.0, 3.0, 6.0]);
+    /// assert_eq!(v.length(), 7.0);
+    /// ```
+    pub fn length(&self) -> Real {
+        self.dot(self).sqrt()
+    }
+
+    /// self scaled to length one, an error rather than NaN for the zero vector
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// # use vmath::Error;
+    /// let v = DVector::new(vec![2.0, 3.0, 6.0]);
+    /// assert!((v.try_normalize().unwrap().length() - 1.0).abs() < 1e-15);
+    /// assert_eq!(DVector::zeros(3).try_normalize(), Err(Error::DivisionByZero));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, Error> {
+        self.try_div(self.length())
+    }
+
+    /// self scaled to length one, None for the zero vector
+    pub fn checked_normalize(&self) -> Option<Self> {
+        self.try_normalize().ok()
+    }
+}
+
+/// the entries shared out across the rayon thread pool, for vectors long enough to pay
+/// for it; the reductions add in a different order than a serial loop,
+/// so they can differ from it in the last bits
+#[cfg(feature = "rayon")]
+impl DVector {
+    /// the vector of f applied to every entry
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::from_fn(1000, |i| i as f64);
+    /// assert_eq!(v.par_map(|x| 2.0 * x), v.clone() * 2.0);
+    /// ```
+    pub fn par_map(&self, f: impl Fn(Real) -> Real + Sync + Send) -> Self {
+        Self::new(self.data.par_iter().map(|&x| f(x)).collect())
+    }
+
+    /// the vector of f applied to the matching entries of self and rhs,
+    /// panics when the lengths differ
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let a = DVector::new(vec![1.0, 2.0, 3.0]);
+    /// let b = DVector::new(vec![4.0, 5.0, 6.0]);
+    /// assert_eq!(a.par_zip_map(&b, f64::max), b);
+    /// ```
+    pub fn par_zip_map(&self, rhs: &Self, f: impl Fn(Real, Real) -> Real + Sync + Send) -> Self {
+        assert_eq!(self.len(), rhs.len(), "vector lengths differ");
+        Self::new(
+            self.data
+                .par_iter()
+                .zip(&rhs.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        )
+    }
+
+    /// the sum of the entries
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::from_fn(1001, |i| i as f64);
+    /// assert_eq!(v.par_sum(), 500500.0);
+    /// ```
+    pub fn par_sum(&self) -> Real {
+        self.data.par_iter().sum()
+    }
+
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::from_fn(100, |i| i as f64);
+    /// assert_eq!(v.par_dot(&v), v.dot(&v));
+    /// ```
+    pub fn par_dot(&self, rhs: &Self) -> Real {
+        assert_eq!(self.len(), rhs.len(), "vector lengths differ");
+        self.data
+            .par_iter()
+            .zip(&rhs.data)
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+}
+
+impl<T: Field> From<Vec<T>> for DVector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.data[i]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let mut v = DVector::zeros(2);
+    /// v[1] = 5.0;
+    /// assert_eq!(v, DVector::new(vec![0.0, 5.0]));
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.data[i]
+    }
+}
+
+impl<T: Field> Neg for DVector<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![1.0, -2.0]);
+    /// assert_eq!(-v, DVector::new(vec![-1.0, 2.0]));
+    /// ```
+    fn neg(mut self) -> Self {
+        for entry in &mut self.data {
+            *entry = -*entry;
+        }
+        self
+    }
+}
+
+impl<T: Field> Add for DVector<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let a = DVector::new(vec![1.0, 2.0]);
+    /// let b = DVector::new(vec![3.0, 5.0]);
+    /// assert_eq!(a + b, DVector::new(vec![4.0, 7.0]));
+    /// ```
+    fn add(mut self, rhs: Self) -> Self {
+        assert_eq!(self.len(), rhs.len(), "vector lengths differ");
+        for (entry, rhs_entry) in self.data.iter_mut().zip(rhs.data) {
+            *entry = *entry + rhs_entry;
+        }
+        self
+    }
+}
+
+impl<T: Field> Sub for DVector<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let a = DVector::new(vec![4.0, 7.0]);
+    /// let b = DVector::new(vec![3.0, 5.0]);
+    /// assert_eq!(a - b, DVector::new(vec![1.0, 2.0]));
+    /// ```
+    fn sub(mut self, rhs: Self) -> Self {
+        assert_eq!(self.len(), rhs.len(), "vector lengths differ");
+        for (entry, rhs_entry) in self.data.iter_mut().zip(rhs.data) {
+            *entry = *entry - rhs_entry;
+        }
+        self
+    }
+}
+
+impl<T: Field> Mul<T> for DVector<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![1.0, 2.0]);
+    /// assert_eq!(v * 3.0, DVector::new(vec![3.0, 6.0]));
+    /// ```
+    fn mul(mut self, rhs: T) -> Self {
+        for entry in &mut self.data {
+            *entry = *entry * rhs;
+        }
+        self
+    }
+}
+
+impl Mul<DVector> for Real {
+    type Output = DVector;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![1.0, 2.0]);
+    /// assert_eq!(3.0 * v, DVector::new(vec![3.0, 6.0]));
+    /// ```
+    fn mul(self, rhs: DVector) -> DVector {
+        rhs * self
+    }
+}
+
+impl<T: Field> Div<T> for DVector<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::DVector;
+    /// let v = DVector::new(vec![3.0, 6.0]);
+    /// assert_eq!(v / 3.0, DVector::new(vec![1.0, 2.0]));
+    /// ```
+    fn div(mut self, rhs: T) -> Self {
+        for entry in &mut self.data {
+            *entry = *entry / rhs;
+        }
+        self
+    }
+}
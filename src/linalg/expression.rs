@@ -0,0 +1,313 @@
+//! lazy elementwise arithmetic on borrowed `DVector`s and `DMatrix`s
+//!
+//! adding, subtracting, negating, or scaling references builds an `Expression`
+//! rather than a new vector, and nothing is computed until it is evaluated,
+//! when every entry of the result is worked out in one pass with no temporaries.
+//! the owned operators are unchanged, and still allocate at every step
+//! ```
+//! use vmath::linalg::DVector;
+//!
+//! let a = DVector::new(vec![1.0, 2.0, 3.0]);
+//! let b = DVector::new(vec![4.0, 5.0, 6.0]);
+//! let c = DVector::new(vec![7.0, 8.0, 9.0]);
+//! let lazy = &a + &b * 3.0 - &c;
+//! assert_eq!(lazy.eval(), a.clone() + b.clone() * 3.0 - c.clone());
+//!
+//! // or into a buffer kept from frame to frame, without allocating
+//! let mut out = DVector::zeros(3);
+//! (-&a + 2.0 * &c).eval_into(&mut out);
+//! assert_eq!(out, DVector::new(vec![13.0, 14.0, 15.0]));
+//! ```
+
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::linalg::{DMatrix, DVector};
+use crate::numbers::Real;
+
+/// the dense types an expression can be built from and evaluated to,
+/// both storing their entries in one contiguous run
+pub trait Dense {
+    /// the length of a vector, or the rows and columns of a matrix
+    type Shape: Copy + PartialEq + Debug;
+
+    fn shape(&self) -> Self::Shape;
+
+    fn entries(&self) -> &[Real];
+
+    fn entries_mut(&mut self) -> &mut [Real];
+
+    /// the number of entries a value of the shape has
+    fn entry_count(shape: Self::Shape) -> usize;
+
+    /// panics unless the shape holds as many entries as there are
+    fn from_entries(shape: Self::Shape, entries: Vec<Real>) -> Self;
+}
+
+impl Dense for DVector {
+    type Shape = usize;
+
+    fn shape(&self) -> usize {
+        self.len()
+    }
+
+    fn entries(&self) -> &[Real] {
+        &self.data
+    }
+
+    fn entries_mut(&mut self) -> &mut [Real] {
+        &mut self.data
+    }
+
+    fn entry_count(len: usize) -> usize {
+        len
+    }
+
+    fn from_entries(len: usize, entries: Vec<Real>) -> Self {
+        assert_eq!(len, entries.len(), "entry count does not match the shape");
+        Self::new(entries)
+    }
+}
+
+impl Dense for DMatrix {
+    type Shape = (usize, usize);
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    fn entries(&self) -> &[Real] {
+        &self.data
+    }
+
+    fn entries_mut(&mut self) -> &mut [Real] {
+        &mut self.data
+    }
+
+    fn entry_count((rows, columns): (usize, usize)) -> usize {
+        rows * columns
+    }
+
+    fn from_entries((rows, columns): (usize, usize), entries: Vec<Real>) -> Self {
+        Self::new(rows, columns, entries)
+    }
+}
+
+/// an expression tree, the i-th entry of its value worked out on demand
+pub trait Entries {
+    fn entry(&self, i: usize) -> Real;
+}
+
+impl Entries for &[Real] {
+    #[inline]
+    fn entry(&self, i: usize) -> Real {
+        self[i]
+    }
+}
+
+/// a + b
+#[derive(Debug, Clone, Copy)]
+pub struct Sum<A, B>(pub A, pub B);
+
+impl<A: Entries, B: Entries> Entries for Sum<A, B> {
+    #[inline]
+    fn entry(&self, i: usize) -> Real {
+        self.0.entry(i) + self.1.entry(i)
+    }
+}
+
+/// a - b
+#[derive(Debug, Clone, Copy)]
+pub struct Difference<A, B>(pub A, pub B);
+
+impl<A: Entries, B: Entries> Entries for Difference<A, B> {
+    #[inline]
+    fn entry(&self, i: usize) -> Real {
+        self.0.entry(i) - self.1.entry(i)
+    }
+}
+
+/// a times a real, negation being a scale by -1
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<A>(pub A, pub Real);
+
+impl<A: Entries> Entries for Scaled<A> {
+    #[inline]
+    fn entry(&self, i: usize) -> Real {
+        self.0.entry(i) * self.1
+    }
+}
+
+/// an unevaluated elementwise combination of values of the dense type T,
+/// whose shapes were checked as it was built, panicking when they differ
+#[derive(Debug, Clone, Copy)]
+pub struct Expression<E, T: Dense> {
+    pub entries: E,
+    pub shape: T::Shape,
+}
+
+impl<'a, T: Dense> Expression<&'a [Real], T> {
+    /// the leaf for an existing value, which the operators on references start from
+    /// ```
+    /// # use vmath::linalg::expression::Expression;
+    /// # use vmath::linalg::DMatrix;
+    /// let m = DMatrix::identity(2);
+    /// assert_eq!(Expression::from_dense(&m).eval(), m);
+    /// ```
+    pub fn from_dense(value: &'a T) -> Self {
+        Self {
+            entries: value.entries(),
+            shape: value.shape(),
+        }
+    }
+}
+
+impl<E: Entries, T: Dense> Expression<E, T> {
+    /// a new value holding the result
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::new(1, 2, vec![1.0, 2.0]);
+    /// let b = DMatrix::new(1, 2, vec![3.0, 5.0]);
+    /// assert_eq!((&a - &b * 2.0).eval(), DMatrix::new(1, 2, vec![-5.0, -8.0]));
+    /// ```
+    pub fn eval(self) -> T {
+        let entries = (0..T::entry_count(self.shape))
+            .map(|i| self.entries.entry(i))
+            .collect();
+        T::from_entries(self.shape, entries)
+    }
+
+    /// writes the result over out, reusing its storage,
+    /// panics unless out already has the expression's shape
+    /// ```
+    /// # use vmath::linalg::DMatrix;
+    /// let a = DMatrix::identity(2);
+    /// let mut out = DMatrix::zeros(2, 2);
+    /// (&a * 3.0 + &a).eval_into(&mut out);
+    /// assert_eq!(out, DMatrix::identity(2) * 4.0);
+    /// ```
+    pub fn eval_into(self, out: &mut T) {
+        assert_eq!(self.shape, out.shape(), "output shape differs");
+        for (i, entry) in out.entries_mut().iter_mut().enumerate() {
+            *entry = self.entries.entry(i);
+        }
+    }
+
+    /// combines two expressions entry by entry, panicking when their shapes differ
+    fn zip<B, N>(self, rhs: Expression<B, T>, node: impl FnOnce(E, B) -> N) -> Expression<N, T> {
+        assert_eq!(self.shape, rhs.shape, "shapes differ");
+        Expression {
+            entries: node(self.entries, rhs.entries),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<E: Entries, B: Entries, T: Dense> Add<Expression<B, T>> for Expression<E, T> {
+    type Output = Expression<Sum<E, B>, T>;
+    fn add(self, rhs: Expression<B, T>) -> Self::Output {
+        self.zip(rhs, Sum)
+    }
+}
+
+impl<'a, E: Entries, T: Dense> Add<&'a T> for Expression<E, T> {
+    type Output = Expression<Sum<E, &'a [Real]>, T>;
+    fn add(self, rhs: &'a T) -> Self::Output {
+        self + Expression::from_dense(rhs)
+    }
+}
+
+impl<E: Entries, B: Entries, T: Dense> Sub<Expression<B, T>> for Expression<E, T> {
+    type Output = Expression<Difference<E, B>, T>;
+    fn sub(self, rhs: Expression<B, T>) -> Self::Output {
+        self.zip(rhs, Difference)
+    }
+}
+
+impl<'a, E: Entries, T: Dense> Sub<&'a T> for Expression<E, T> {
+    type Output = Expression<Difference<E, &'a [Real]>, T>;
+    fn sub(self, rhs: &'a T) -> Self::Output {
+        self - Expression::from_dense(rhs)
+    }
+}
+
+impl<E: Entries, T: Dense> Mul<Real> for Expression<E, T> {
+    type Output = Expression<Scaled<E>, T>;
+    fn mul(self, rhs: Real) -> Self::Output {
+        Expression {
+            entries: Scaled(self.entries, rhs),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<E: Entries, T: Dense> Mul<Expression<E, T>> for Real {
+    type Output = Expression<Scaled<E>, T>;
+    fn mul(self, rhs: Expression<E, T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<E: Entries, T: Dense> Neg for Expression<E, T> {
+    type Output = Expression<Scaled<E>, T>;
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+/// the operators on references to a dense type, which start an expression
+macro_rules! lazy_operators {
+    ($dense:ty) => {
+        impl<'a, 'b> Add<&'b $dense> for &'a $dense {
+            type Output = Expression<Sum<&'a [Real], &'b [Real]>, $dense>;
+            fn add(self, rhs: &'b $dense) -> Self::Output {
+                Expression::from_dense(self) + rhs
+            }
+        }
+
+        impl<'a, E: Entries> Add<Expression<E, $dense>> for &'a $dense {
+            type Output = Expression<Sum<&'a [Real], E>, $dense>;
+            fn add(self, rhs: Expression<E, $dense>) -> Self::Output {
+                Expression::from_dense(self) + rhs
+            }
+        }
+
+        impl<'a, 'b> Sub<&'b $dense> for &'a $dense {
+            type Output = Expression<Difference<&'a [Real], &'b [Real]>, $dense>;
+            fn sub(self, rhs: &'b $dense) -> Self::Output {
+                Expression::from_dense(self) - rhs
+            }
+        }
+
+        impl<'a, E: Entries> Sub<Expression<E, $dense>> for &'a $dense {
+            type Output = Expression<Difference<&'a [Real], E>, $dense>;
+            fn sub(self, rhs: Expression<E, $dense>) -> Self::Output {
+                Expression::from_dense(self) - rhs
+            }
+        }
+
+        impl<'a> Mul<Real> for &'a $dense {
+            type Output = Expression<Scaled<&'a [Real]>, $dense>;
+            fn mul(self, rhs: Real) -> Self::Output {
+                Expression::from_dense(self) * rhs
+            }
+        }
+
+        impl<'a> Mul<&'a $dense> for Real {
+            type Output = Expression<Scaled<&'a [Real]>, $dense>;
+            fn mul(self, rhs: &'a $dense) -> Self::Output {
+                Expression::from_dense(rhs) * self
+            }
+        }
+
+        impl<'a> Neg for &'a $dense {
+            type Output = Expression<Scaled<&'a [Real]>, $dense>;
+            fn neg(self) -> Self::Output {
+                -Expression::from_dense(self)
+            }
+        }
+    };
+}
+
+lazy_operators!(DVector);
+lazy_operators!(DMatrix);
@@ -0,0 +1,477 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use super::{Vec2, Vec3, Vec4};
+use crate::numbers::{Angle, Real};
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// N x N matrix
+/// stored row by row, so rows[i][j] is row i, column j
+#[repr(C)]
+pub struct Mat<const N: usize> {
+    pub rows: [[Real; N]; N],
+}
+
+pub type Mat2 = Mat<2>;
+pub type Mat3 = Mat<3>;
+pub type Mat4 = Mat<4>;
+
+impl<const N: usize> Mat<N> {
+    pub const ZERO: Self = Self {
+        rows: [[0.0; N]; N],
+    };
+    pub const IDENTITY: Self = Self::from_diagonal([1.0; N]);
+
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m[0][1], 2.0);
+    /// assert_eq!(m[1][0], 3.0);
+    /// ```
+    pub const fn new(rows: [[Real; N]; N]) -> Self {
+        Self { rows }
+    }
+
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::from_columns([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m, Mat2::new([[1.0, 3.0], [2.0, 4.0]]));
+    /// ```
+    pub const fn from_columns(columns: [[Real; N]; N]) -> Self {
+        Self { rows: columns }.transpose()
+    }
+
+    /// ```
+    /// # use vmath::linalg::Mat3;
+    /// let m = Mat3::from_diagonal([1.0, 2.0, 3.0]);
+    /// assert_eq!(m, Mat3::new([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]));
+    /// assert_eq!(Mat3::IDENTITY, Mat3::from_diagonal([1.0; 3]));
+    /// ```
+    pub const fn from_diagonal(diagonal: [Real; N]) -> Self {
+        let mut rows = [[0.0; N]; N];
+        let mut i = 0;
+        while i < N {
+            rows[i][i] = diagonal[i];
+            i += 1;
+        }
+        Self { rows }
+    }
+
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.column(1), [2.0, 4.0]);
+    /// ```
+    pub fn column(self, j: usize) -> [Real; N] {
+        self.rows.map(|row| row[j])
+    }
+
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.transpose(), Mat2::new([[1.0, 3.0], [2.0, 4.0]]));
+    /// ```
+    pub const fn transpose(self) -> Self {
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < N {
+                rows[i][j] = self.rows[j][i];
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { rows }
+    }
+
+    /// self + rhs, which unlike the operator works in constants
+    pub const fn plus(self, rhs: Self) -> Self {
+        self.combine(rhs, 1.0)
+    }
+
+    /// self - rhs, in constants
+    pub const fn minus(self, rhs: Self) -> Self {
+        self.combine(rhs, -1.0)
+    }
+
+    /// -self, in constants
+    pub const fn negated(self) -> Self {
+        self.scaled(-1.0)
+    }
+
+    /// self times a real, in constants
+    pub const fn scaled(self, factor: Real) -> Self {
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < N {
+                rows[i][j] *= factor;
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { rows }
+    }
+
+    /// the matrix product self rhs, in constants
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// const SHEAR: Mat2 = Mat2::new([[1.0, 1.0], [0.0, 1.0]]);
+    /// const SCALE: Mat2 = Mat2::from_diagonal([2.0, 3.0]);
+    /// const BOTH: Mat2 = SCALE.times(SHEAR).plus(Mat2::IDENTITY);
+    /// assert_eq!(BOTH, SCALE * SHEAR + Mat2::IDENTITY);
+    /// ```
+    pub const fn times(self, rhs: Self) -> Self {
+        let mut rows = [[0.0; N]; N];
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < N {
+                let mut k = 0;
+                while k < N {
+                    rows[i][j] += self.rows[i][k] * rhs.rows[k][j];
+                    k += 1;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { rows }
+    }
+
+    /// self + factor rhs, entry by entry
+    const fn combine(self, rhs: Self, factor: Real) -> Self {
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < N {
+                rows[i][j] += factor * rhs.rows[i][j];
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { rows }
+    }
+
+    /// ```
+    /// # use vmath::linalg::Mat3;
+    /// let m = Mat3::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert_eq!(m.trace(), 15.0);
+    /// ```
+    pub fn trace(self) -> Real {
+        (0..N).map(|i| self.rows[i][i]).sum()
+    }
+
+    /// by elimination with partial pivoting
+    /// ```
+    /// # use vmath::linalg::{Mat2, Mat3};
+    /// let m = Mat2::new([[3.0, 8.0], [4.0, 6.0]]);
+    /// assert_eq!(m.determinant(), -14.0);
+    ///
+    /// let m = Mat3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+    /// assert!((m.determinant() - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn determinant(self) -> Real {
+        let mut rows = self.rows;
+        let mut determinant = 1.0;
+        for column in 0..N {
+            let pivot = (column..N)
+                .max_by(|&a, &b| rows[a][column].abs().total_cmp(&rows[b][column].abs()))
+                .unwrap_or(column);
+            if rows[pivot][column] == 0.0 {
+                return 0.0;
+            }
+            if pivot != column {
+                rows.swap(pivot, column);
+                determinant = -determinant;
+            }
+            let pivot_row = rows[column];
+            determinant *= pivot_row[column];
+            for row in rows.iter_mut().skip(column + 1) {
+                let factor = row[column] / pivot_row[column];
+                for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(column) {
+                    *entry -= factor * pivot_entry;
+                }
+            }
+        }
+        determinant
+    }
+
+    /// by gauss-jordan elimination,
+    /// an error when the matrix is singular to working precision
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// # use vmath::Error;
+    /// let m = Mat2::new([[4.0, 7.0], [2.0, 6.0]]);
+    /// let inverse = m.inverse().unwrap();
+    /// let expected = Mat2::new([[0.6, -0.7], [-0.2, 0.4]]);
+    /// let error = inverse - expected;
+    /// assert!(error.rows.iter().flatten().all(|e| e.abs() < 1e-15));
+    ///
+    /// let singular = Mat2::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.inverse(), Err(Error::Singular));
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        let scale = self
+            .rows
+            .iter()
+            .flatten()
+            .fold(0.0, |max: Real, entry| max.max(entry.abs()));
+        let tolerance = scale * N as Real * Real::EPSILON;
+        let mut rows = self.rows;
+        let mut inverse = Self::IDENTITY.rows;
+        for column in 0..N {
+            let pivot = (column..N)
+                .max_by(|&a, &b| rows[a][column].abs().total_cmp(&rows[b][column].abs()))
+                .unwrap_or(column);
+            if rows[pivot][column].abs() <= tolerance {
+                return Err(Error::Singular);
+            }
+            rows.swap(pivot, column);
+            inverse.swap(pivot, column);
+            let divisor = rows[column][column];
+            for j in 0..N {
+                rows[column][j] /= divisor;
+                inverse[column][j] /= divisor;
+            }
+            let (pivot_row, pivot_inverse) = (rows[column], inverse[column]);
+            for i in (0..N).filter(|&i| i != column) {
+                let factor = rows[i][column];
+                for j in 0..N {
+                    rows[i][j] -= factor * pivot_row[j];
+                    inverse[i][j] -= factor * pivot_inverse[j];
+                }
+            }
+        }
+        Ok(Self { rows: inverse })
+    }
+
+    /// the inverse, None when the matrix is singular to working precision
+    /// ```
+    /// # use vmath::linalg::Mat3;
+    /// let m = Mat3::from_diagonal([2.0, 4.0, 8.0]);
+    /// assert_eq!(m.checked_inverse(), Some(Mat3::from_diagonal([0.5, 0.25, 0.125])));
+    /// assert_eq!(Mat3::from_diagonal([2.0, 0.0, 8.0]).checked_inverse(), None);
+    /// ```
+    pub fn checked_inverse(self) -> Option<Self> {
+        self.inverse().ok()
+    }
+
+    fn apply(self, v: [Real; N]) -> [Real; N] {
+        self.rows
+            .map(|row| row.iter().zip(v).map(|(entry, v)| entry * v).sum())
+    }
+}
+
+impl Mat2 {
+    /// counter-clockwise rotation by angle, in radians when it is a bare `Real`
+    /// ```
+    /// # use vmath::linalg::{Mat2, Vec2, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Mat2::from_angle(FRAC_PI_2);
+    /// let rotated = rotation * Vec2::new(1.0, 0.0);
+    /// assert!(rotated.distance(Vec2::new(0.0, 1.0)) < 1e-15);
+    /// ```
+    pub fn from_angle(angle: impl Into<Angle>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new([[cos, -sin], [sin, cos]])
+    }
+}
+
+impl Mat3 {
+    /// right-handed rotation by angle about a unit axis, by rodrigues' formula
+    /// ```
+    /// # use vmath::linalg::{Mat3, Vec3, Vector};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let rotation = Mat3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    /// let rotated = rotation * Vec3::new(1.0, 0.0, 0.0);
+    /// assert!(rotated.distance(Vec3::new(0.0, 1.0, 0.0)) < 1e-15);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Angle>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let Vec3 { x, y, z } = axis;
+        let k = 1.0 - cos;
+        Self::new([
+            [cos + x * x * k, x * y * k - z * sin, x * z * k + y * sin],
+            [y * x * k + z * sin, cos + y * y * k, y * z * k - x * sin],
+            [z * x * k - y * sin, z * y * k + x * sin, cos + z * z * k],
+        ])
+    }
+}
+
+impl<const N: usize> Index<usize> for Mat<N> {
+    type Output = [Real; N];
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m[1], [3.0, 4.0]);
+    /// ```
+    fn index(&self, i: usize) -> &[Real; N] {
+        &self.rows[i]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Mat<N> {
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let mut m = Mat2::IDENTITY;
+    /// m[0][1] = 5.0;
+    /// assert_eq!(m, Mat2::new([[1.0, 5.0], [0.0, 1.0]]));
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut [Real; N] {
+        &mut self.rows[i]
+    }
+}
+
+impl<const N: usize> Neg for Mat<N> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, -2.0], [3.0, -4.0]]);
+    /// assert_eq!(-m, Mat2::new([[-1.0, 2.0], [-3.0, 4.0]]));
+    /// ```
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl<const N: usize> Add for Mat<N> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let a = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Mat2::new([[5.0, 6.0], [7.0, 8.0]]);
+    /// assert_eq!(a + b, Mat2::new([[6.0, 8.0], [10.0, 12.0]]));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        self.plus(rhs)
+    }
+}
+
+impl<const N: usize> Sub for Mat<N> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let a = Mat2::new([[6.0, 8.0], [10.0, 12.0]]);
+    /// let b = Mat2::new([[5.0, 6.0], [7.0, 8.0]]);
+    /// assert_eq!(a - b, Mat2::new([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        self.minus(rhs)
+    }
+}
+
+impl<const N: usize> Mul<Real> for Mat<N> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m * 2.0, Mat2::new([[2.0, 4.0], [6.0, 8.0]]));
+    /// ```
+    fn mul(self, rhs: Real) -> Self {
+        self.scaled(rhs)
+    }
+}
+
+impl<const N: usize> Mul<Mat<N>> for Real {
+    type Output = Mat<N>;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(2.0 * m, Mat2::new([[2.0, 4.0], [6.0, 8.0]]));
+    /// ```
+    fn mul(self, rhs: Mat<N>) -> Mat<N> {
+        rhs * self
+    }
+}
+
+impl<const N: usize> Mul for Mat<N> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Mat2;
+    /// let a = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Mat2::new([[5.0, 6.0], [7.0, 8.0]]);
+    /// assert_eq!(a * b, Mat2::new([[19.0, 22.0], [43.0, 50.0]]));
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        let columns = rhs.transpose();
+        Self {
+            rows: self.rows.map(|row| columns.apply(row)),
+        }
+    }
+}
+
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+    /// ```
+    /// # use vmath::linalg::{Mat2, Vec2};
+    /// let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m * Vec2::new(1.0, 1.0), Vec2::new(3.0, 7.0));
+    /// ```
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        Vec2::from(self.apply(rhs.into()))
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    /// ```
+    /// # use vmath::linalg::{Mat3, Vec3};
+    /// let m = Mat3::from_diagonal([1.0, 2.0, 3.0]);
+    /// assert_eq!(m * Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::from(self.apply(rhs.into()))
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+    /// ```
+    /// # use vmath::linalg::{Mat4, Vec4};
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(Mat4::IDENTITY * v, v);
+    /// ```
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        Vec4::from(self.apply(rhs.into()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Mat<N> {
+    /// a sequence of rows, each a sequence of N reals
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows.iter().map(|row| row.as_slice()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Mat<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<Real>>::deserialize(deserializer)?;
+        if rows.len() != N || rows.iter().any(|row| row.len() != N) {
+            return Err(serde::de::Error::custom(format!(
+                "expected {N} rows of {N} reals"
+            )));
+        }
+        let mut matrix = Self::ZERO;
+        for (target, row) in matrix.rows.iter_mut().zip(rows) {
+            target.copy_from_slice(&row);
+        }
+        Ok(matrix)
+    }
+}
+
+// SAFETY: repr(C) with only Real fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for Mat<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for Mat<N> {}
@@ -0,0 +1,11 @@
+pub mod dmatrix;
+pub mod dvector;
+pub mod expression;
+pub mod matrix;
+pub mod simd;
+pub mod vector;
+
+pub use crate::linalg::dmatrix::DMatrix;
+pub use crate::linalg::dvector::DVector;
+pub use crate::linalg::matrix::{Mat, Mat2, Mat3, Mat4};
+pub use crate::linalg::vector::{Point2, Point3, Vec2, Vec3, Vec4, Vector};
@@ -0,0 +1,377 @@
+//! explicitly vectorized kernels for the hot loops, avx2 with fma on x86_64 and neon on
+//! aarch64, chosen once per call by runtime feature detection, with a portable scalar
+//! fallback everywhere else
+//!
+//! the vector paths sum in a different order and fuse multiplies into adds,
+//! so their results can differ from a plain loop in the last bits
+
+use crate::numbers::{Complex, Real};
+
+/// the sum of a_i b_i, panics when the lengths differ
+/// ```
+/// # use vmath::linalg::simd::dot;
+/// let a: Vec<f64> = (0..37).map(|i| i as f64).collect();
+/// assert_eq!(dot(&a, &a), (0..37).map(|i| (i * i) as f64).sum::<f64>());
+/// ```
+pub fn dot(a: &[Real], b: &[Real]) -> Real {
+    assert_eq!(a.len(), b.len(), "slice lengths differ");
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: avx2 and fma were just detected
+        return unsafe { avx2::dot(a, b) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::available() {
+        // SAFETY: neon was just detected
+        return unsafe { neon::dot(a, b) };
+    }
+    scalar::dot(a, b)
+}
+
+/// y += alpha x, panics when the lengths differ
+/// ```
+/// # use vmath::linalg::simd::axpy;
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let mut y = [1.0; 5];
+/// axpy(2.0, &x, &mut y);
+/// assert_eq!(y, [3.0, 5.0, 7.0, 9.0, 11.0]);
+/// ```
+pub fn axpy(alpha: Real, x: &[Real], y: &mut [Real]) {
+    assert_eq!(x.len(), y.len(), "slice lengths differ");
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: avx2 and fma were just detected
+        return unsafe { avx2::axpy(alpha, x, y) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::available() {
+        // SAFETY: neon was just detected
+        return unsafe { neon::axpy(alpha, x, y) };
+    }
+    scalar::axpy(alpha, x, y)
+}
+
+/// out_i = a_i b_i for arrays of complex numbers, panics when the lengths differ
+/// ```
+/// # use vmath::linalg::simd::complex_multiply;
+/// # use vmath::numbers::Complex;
+/// let a: Vec<Complex> = (0..5).map(|i| Complex::new(i as f64, 1.0)).collect();
+/// let b: Vec<Complex> = (0..5).map(|i| Complex::new(2.0, -(i as f64))).collect();
+/// let mut out = vec![Complex::new(0.0, 0.0); 5];
+/// complex_multiply(&a, &b, &mut out);
+/// for i in 0..5 {
+///     assert_eq!(out[i], a[i] * b[i]);
+/// }
+/// ```
+pub fn complex_multiply(a: &[Complex], b: &[Complex], out: &mut [Complex]) {
+    assert_eq!(a.len(), b.len(), "slice lengths differ");
+    assert_eq!(a.len(), out.len(), "slice lengths differ");
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: avx2 and fma were just detected
+        return unsafe { avx2::complex_multiply(a, b, out) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::available() {
+        // SAFETY: neon was just detected
+        return unsafe { neon::complex_multiply(a, b, out) };
+    }
+    scalar::complex_multiply(a, b, out)
+}
+
+/// c = a b for row-major a with m rows and k columns and b with k rows and n columns,
+/// overwriting c, which has m rows and n columns; panics when the lengths do not fit
+/// ```
+/// # use vmath::linalg::simd::matmul;
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let b = [1.0, 0.0, -1.0];
+/// let mut c = [0.0; 2];
+/// matmul(&a, &b, &mut c, 2, 3, 1);
+/// assert_eq!(c, [-2.0, -2.0]);
+/// ```
+pub fn matmul(a: &[Real], b: &[Real], c: &mut [Real], m: usize, k: usize, n: usize) {
+    assert_eq!(a.len(), m * k, "left matrix does not have m k entries");
+    assert_eq!(b.len(), k * n, "right matrix does not have k n entries");
+    assert_eq!(c.len(), m * n, "product does not have m n entries");
+    #[cfg(target_arch = "x86_64")]
+    if avx2::available() {
+        // SAFETY: avx2 and fma were just detected
+        return unsafe { avx2::matmul(a, b, c, m, k, n) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::available() {
+        // SAFETY: neon was just detected
+        return unsafe { neon::matmul(a, b, c, m, k, n) };
+    }
+    scalar::matmul(a, b, c, m, k, n)
+}
+
+/// reals as pairs of (real, imaginary), which Complex is by repr(C)
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn interleaved(z: &[Complex]) -> *const Real {
+    z.as_ptr().cast()
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn interleaved_mut(z: &mut [Complex]) -> *mut Real {
+    z.as_mut_ptr().cast()
+}
+
+mod scalar {
+    use crate::numbers::{Complex, Real};
+
+    pub fn dot(a: &[Real], b: &[Real]) -> Real {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    pub fn axpy(alpha: Real, x: &[Real], y: &mut [Real]) {
+        for (y, x) in y.iter_mut().zip(x) {
+            *y += alpha * x;
+        }
+    }
+
+    pub fn complex_multiply(a: &[Complex], b: &[Complex], out: &mut [Complex]) {
+        for ((out, &a), &b) in out.iter_mut().zip(a).zip(b) {
+            *out = a * b;
+        }
+    }
+
+    pub fn matmul(a: &[Real], b: &[Real], c: &mut [Real], m: usize, k: usize, n: usize) {
+        c.fill(0.0);
+        for i in 0..m {
+            let row = &mut c[i * n..(i + 1) * n];
+            for (p, &entry) in a[i * k..(i + 1) * k].iter().enumerate() {
+                axpy(entry, &b[p * n..(p + 1) * n], row);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    use super::{interleaved, interleaved_mut};
+    use crate::numbers::{Complex, Real};
+
+    pub fn available() -> bool {
+        is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+    }
+
+    /// four accumulators of four lanes, to hide the latency of the fused adds
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn dot(a: &[Real], b: &[Real]) -> Real {
+        let n = a.len();
+        let (a, b) = (a.as_ptr(), b.as_ptr());
+        let mut sums = [_mm256_setzero_pd(); 4];
+        let mut i = 0;
+        while i + 16 <= n {
+            for (lane, sum) in sums.iter_mut().enumerate() {
+                let offset = i + 4 * lane;
+                *sum = _mm256_fmadd_pd(
+                    _mm256_loadu_pd(a.add(offset)),
+                    _mm256_loadu_pd(b.add(offset)),
+                    *sum,
+                );
+            }
+            i += 16;
+        }
+        while i + 4 <= n {
+            sums[0] = _mm256_fmadd_pd(
+                _mm256_loadu_pd(a.add(i)),
+                _mm256_loadu_pd(b.add(i)),
+                sums[0],
+            );
+            i += 4;
+        }
+        let sum = _mm256_add_pd(
+            _mm256_add_pd(sums[0], sums[1]),
+            _mm256_add_pd(sums[2], sums[3]),
+        );
+        let mut lanes = [0.0; 4];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), sum);
+        let mut total = (lanes[0] + lanes[1]) + (lanes[2] + lanes[3]);
+        while i < n {
+            total += *a.add(i) * *b.add(i);
+            i += 1;
+        }
+        total
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn axpy(alpha: Real, x: &[Real], y: &mut [Real]) {
+        let n = x.len();
+        let (x, y) = (x.as_ptr(), y.as_mut_ptr());
+        let alpha4 = _mm256_set1_pd(alpha);
+        let mut i = 0;
+        while i + 4 <= n {
+            let sum = _mm256_fmadd_pd(alpha4, _mm256_loadu_pd(x.add(i)), _mm256_loadu_pd(y.add(i)));
+            _mm256_storeu_pd(y.add(i), sum);
+            i += 4;
+        }
+        while i < n {
+            *y.add(i) += alpha * *x.add(i);
+            i += 1;
+        }
+    }
+
+    /// two complex numbers a lane pair each, (ar br - ai bi, ai br + ar bi)
+    /// from one multiply and one alternating fused multiply-subtract-add
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn complex_multiply(a: &[Complex], b: &[Complex], out: &mut [Complex]) {
+        let n = a.len();
+        let (pa, pb, po) = (interleaved(a), interleaved(b), interleaved_mut(out));
+        let mut i = 0;
+        while i + 2 <= n {
+            let x = _mm256_loadu_pd(pa.add(2 * i));
+            let y = _mm256_loadu_pd(pb.add(2 * i));
+            let y_real = _mm256_movedup_pd(y);
+            let y_imaginary = _mm256_permute_pd(y, 0b1111);
+            let x_swapped = _mm256_permute_pd(x, 0b0101);
+            let product = _mm256_fmaddsub_pd(x, y_real, _mm256_mul_pd(x_swapped, y_imaginary));
+            _mm256_storeu_pd(po.add(2 * i), product);
+            i += 2;
+        }
+        if i < n {
+            out[i] = a[i] * b[i];
+        }
+    }
+
+    /// four rows by eight columns of c held in registers across the whole of k,
+    /// with the ragged edges done a row at a time by axpy
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn matmul(a: &[Real], b: &[Real], c: &mut [Real], m: usize, k: usize, n: usize) {
+        c.fill(0.0);
+        let (pa, pb) = (a.as_ptr(), b.as_ptr());
+        let mut i = 0;
+        while i + 4 <= m {
+            let mut j = 0;
+            while j + 8 <= n {
+                let mut sums = [[_mm256_setzero_pd(); 2]; 4];
+                for p in 0..k {
+                    let low = _mm256_loadu_pd(pb.add(p * n + j));
+                    let high = _mm256_loadu_pd(pb.add(p * n + j + 4));
+                    for (r, sum) in sums.iter_mut().enumerate() {
+                        let entry = _mm256_broadcast_sd(&*pa.add((i + r) * k + p));
+                        sum[0] = _mm256_fmadd_pd(entry, low, sum[0]);
+                        sum[1] = _mm256_fmadd_pd(entry, high, sum[1]);
+                    }
+                }
+                let pc = c.as_mut_ptr();
+                for (r, sum) in sums.iter().enumerate() {
+                    _mm256_storeu_pd(pc.add((i + r) * n + j), sum[0]);
+                    _mm256_storeu_pd(pc.add((i + r) * n + j + 4), sum[1]);
+                }
+                j += 8;
+            }
+            if j < n {
+                for r in i..i + 4 {
+                    for p in 0..k {
+                        let entry = a[r * k + p];
+                        axpy(
+                            entry,
+                            &b[p * n + j..(p + 1) * n],
+                            &mut c[r * n + j..(r + 1) * n],
+                        );
+                    }
+                }
+            }
+            i += 4;
+        }
+        for r in i..m {
+            for p in 0..k {
+                axpy(
+                    a[r * k + p],
+                    &b[p * n..(p + 1) * n],
+                    &mut c[r * n..(r + 1) * n],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use std::arch::aarch64::*;
+
+    use super::{interleaved, interleaved_mut};
+    use crate::numbers::{Complex, Real};
+
+    pub fn available() -> bool {
+        std::arch::is_aarch64_feature_detected!("neon")
+    }
+
+    /// four accumulators of two lanes, to hide the latency of the fused adds
+    #[target_feature(enable = "neon")]
+    pub unsafe fn dot(a: &[Real], b: &[Real]) -> Real {
+        let n = a.len();
+        let (a, b) = (a.as_ptr(), b.as_ptr());
+        let mut sums = [vdupq_n_f64(0.0); 4];
+        let mut i = 0;
+        while i + 8 <= n {
+            for (lane, sum) in sums.iter_mut().enumerate() {
+                let offset = i + 2 * lane;
+                *sum = vfmaq_f64(*sum, vld1q_f64(a.add(offset)), vld1q_f64(b.add(offset)));
+            }
+            i += 8;
+        }
+        while i + 2 <= n {
+            sums[0] = vfmaq_f64(sums[0], vld1q_f64(a.add(i)), vld1q_f64(b.add(i)));
+            i += 2;
+        }
+        let sum = vaddq_f64(vaddq_f64(sums[0], sums[1]), vaddq_f64(sums[2], sums[3]));
+        let mut total = vaddvq_f64(sum);
+        if i < n {
+            total += *a.add(i) * *b.add(i);
+        }
+        total
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn axpy(alpha: Real, x: &[Real], y: &mut [Real]) {
+        let n = x.len();
+        let (x, y) = (x.as_ptr(), y.as_mut_ptr());
+        let alpha2 = vdupq_n_f64(alpha);
+        let mut i = 0;
+        while i + 2 <= n {
+            vst1q_f64(
+                y.add(i),
+                vfmaq_f64(vld1q_f64(y.add(i)), alpha2, vld1q_f64(x.add(i))),
+            );
+            i += 2;
+        }
+        if i < n {
+            *y.add(i) += alpha * *x.add(i);
+        }
+    }
+
+    /// one complex number a register, (ar br - ai bi, ai br + ar bi)
+    /// as a br plus the swapped a times (-bi, bi)
+    #[target_feature(enable = "neon")]
+    pub unsafe fn complex_multiply(a: &[Complex], b: &[Complex], out: &mut [Complex]) {
+        let (pa, pb, po) = (interleaved(a), interleaved(b), interleaved_mut(out));
+        let signs = vld1q_f64([-1.0, 1.0].as_ptr());
+        for i in 0..a.len() {
+            let x = vld1q_f64(pa.add(2 * i));
+            let y = vld1q_f64(pb.add(2 * i));
+            let x_swapped = vextq_f64(x, x, 1);
+            let y_imaginary = vmulq_f64(vdupq_laneq_f64(y, 1), signs);
+            let product = vfmaq_f64(vmulq_f64(x, vdupq_laneq_f64(y, 0)), x_swapped, y_imaginary);
+            vst1q_f64(po.add(2 * i), product);
+        }
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn matmul(a: &[Real], b: &[Real], c: &mut [Real], m: usize, k: usize, n: usize) {
+        c.fill(0.0);
+        for i in 0..m {
+            for p in 0..k {
+                axpy(
+                    a[i * k + p],
+                    &b[p * n..(p + 1) * n],
+                    &mut c[i * n..(i + 1) * n],
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,969 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::numbers::Real;
+use crate::Error;
+
+/// operations shared by the fixed-size vectors,
+/// so geometry can be written once for both 2D and 3D
+pub trait Vector:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<Real, Output = Self>
+    + Div<Real, Output = Self>
+    + Index<usize, Output = Real>
+    + IndexMut<usize>
+{
+    const ZERO: Self;
+    const DIMENSION: usize;
+
+    /// every component set to value
+    fn splat(value: Real) -> Self;
+
+    fn dot(self, rhs: Self) -> Real;
+
+    /// component-wise minimum
+    fn min(self, rhs: Self) -> Self;
+
+    /// component-wise maximum
+    fn max(self, rhs: Self) -> Self;
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let v = Vec2::new(3.0, 4.0);
+    /// assert_eq!(v.length_squared(), 25.0);
+    /// ```
+    fn length_squared(self) -> Real {
+        self.dot(self)
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let v = Vec3::new(2.0, 3.0, 6.0);
+    /// assert_eq!(v.length(), 7.0);
+    /// ```
+    fn length(self) -> Real {
+        self.length_squared().sqrt()
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let v = Vec2::new(0.0, 5.0);
+    /// assert_eq!(v.normalize(), Vec2::new(0.0, 1.0));
+    /// ```
+    fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// an error rather than NaN for the zero vector
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// # use vmath::Error;
+    /// let v = Vec3::new(0.0, 3.0, 4.0);
+    /// assert_eq!(v.try_normalize(), Ok(Vec3::new(0.0, 0.6, 0.8)));
+    /// assert_eq!(Vec3::ZERO.try_normalize(), Err(Error::DivisionByZero));
+    /// ```
+    fn try_normalize(self) -> Result<Self, Error> {
+        self.try_div(self.length())
+    }
+
+    /// None for the zero vector
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// assert_eq!(Vec2::new(0.0, -2.0).checked_normalize(), Some(Vec2::new(0.0, -1.0)));
+    /// assert_eq!(Vec2::ZERO.checked_normalize(), None);
+    /// ```
+    fn checked_normalize(self) -> Option<Self> {
+        self.try_normalize().ok()
+    }
+
+    /// self / rhs, an error rather than infinities and NaN when rhs is zero
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// # use vmath::Error;
+    /// let v = Vec2::new(3.0, 6.0);
+    /// assert_eq!(v.try_div(3.0), Ok(Vec2::new(1.0, 2.0)));
+    /// assert_eq!(v.try_div(0.0), Err(Error::DivisionByZero));
+    /// ```
+    fn try_div(self, rhs: Real) -> Result<Self, Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self / rhs)
+    }
+
+    /// self / rhs, None when rhs is zero
+    /// ```
+    /// # use vmath::linalg::{Vec4, Vector};
+    /// let v = Vec4::new(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(v.checked_div(2.0), Some(Vec4::new(1.0, 2.0, 3.0, 4.0)));
+    /// assert_eq!(v.checked_div(0.0), None);
+    /// ```
+    fn checked_div(self, rhs: Real) -> Option<Self> {
+        self.try_div(rhs).ok()
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let a = Vec2::new(1.0, 1.0);
+    /// let b = Vec2::new(4.0, 5.0);
+    /// assert_eq!(a.distance(b), 5.0);
+    /// ```
+    fn distance(self, other: Self) -> Real {
+        (other - self).length()
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let a = Vec3::new(0.0, 0.0, 0.0);
+    /// let b = Vec3::new(2.0, 4.0, 8.0);
+    /// assert_eq!(a.lerp(b, 0.5), Vec3::new(1.0, 2.0, 4.0));
+    /// ```
+    fn lerp(self, other: Self, t: Real) -> Self {
+        self + (other - self) * t
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// (x, y)
+#[repr(C)]
+pub struct Vec2 {
+    pub x: Real,
+    pub y: Real,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// (x, y, z)
+#[repr(C)]
+pub struct Vec3 {
+    pub x: Real,
+    pub y: Real,
+    pub z: Real,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// (x, y, z, w)
+#[repr(C)]
+pub struct Vec4 {
+    pub x: Real,
+    pub y: Real,
+    pub z: Real,
+    pub w: Real,
+}
+
+/// a position in the plane, as opposed to a direction
+pub type Point2 = Vec2;
+
+/// a position in space, as opposed to a direction
+pub type Point3 = Vec3;
+
+impl Vec2 {
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, 2.0);
+    /// ```
+    pub const fn new(x: Real, y: Real) -> Self {
+        Self { x, y }
+    }
+
+    /// self + rhs, which unlike the operator works in constants
+    pub const fn plus(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+
+    /// self - rhs, in constants
+    pub const fn minus(self, rhs: Self) -> Self {
+        self.plus(rhs.negated())
+    }
+
+    /// -self, in constants
+    pub const fn negated(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+
+    /// self times a real, in constants
+    pub const fn scaled(self, factor: Real) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+
+    /// counter-clockwise quarter turn
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.perp(), Vec2::new(-2.0, 1.0));
+    /// ```
+    pub const fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// z component of the 3D cross product,
+    /// positive when rhs is counter-clockwise of self
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let a = Vec2::new(1.0, 0.0);
+    /// let b = Vec2::new(0.0, 1.0);
+    /// assert_eq!(a.perp_dot(b), 1.0);
+    /// assert_eq!(b.perp_dot(a), -1.0);
+    /// ```
+    pub const fn perp_dot(self, rhs: Self) -> Real {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.extend(3.0), vmath::linalg::Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub const fn extend(self, z: Real) -> Vec3 {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z,
+        }
+    }
+}
+
+impl Vector for Vec2 {
+    const ZERO: Self = Self { x: 0.0, y: 0.0 };
+    const DIMENSION: usize = 2;
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// assert_eq!(Vec2::splat(3.0), Vec2::new(3.0, 3.0));
+    /// ```
+    fn splat(value: Real) -> Self {
+        Self { x: value, y: value }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let a = Vec2::new(1.0, 2.0);
+    /// let b = Vec2::new(3.0, 4.0);
+    /// assert_eq!(a.dot(b), 11.0);
+    /// ```
+    fn dot(self, rhs: Self) -> Real {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let a = Vec2::new(1.0, 4.0);
+    /// let b = Vec2::new(3.0, 2.0);
+    /// assert_eq!(a.min(b), Vec2::new(1.0, 2.0));
+    /// ```
+    fn min(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.min(rhs.x),
+            y: self.y.min(rhs.y),
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vector};
+    /// let a = Vec2::new(1.0, 4.0);
+    /// let b = Vec2::new(3.0, 2.0);
+    /// assert_eq!(a.max(b), Vec2::new(3.0, 4.0));
+    /// ```
+    fn max(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.max(rhs.x),
+            y: self.y.max(rhs.y),
+        }
+    }
+}
+
+impl Vec3 {
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, 2.0);
+    /// assert_eq!(v.z, 3.0);
+    /// ```
+    pub const fn new(x: Real, y: Real, z: Real) -> Self {
+        Self { x, y, z }
+    }
+
+    /// self + rhs, which unlike the operator works in constants
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// const UP: Vec3 = Vec3::new(0.0, 0.0, 1.0);
+    /// const EYE: Vec3 = Vec3::new(1.0, 2.0, 3.0).plus(UP.scaled(2.0));
+    /// assert_eq!(EYE, Vec3::new(1.0, 2.0, 5.0));
+    /// ```
+    pub const fn plus(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+
+    /// self - rhs, in constants
+    pub const fn minus(self, rhs: Self) -> Self {
+        self.plus(rhs.negated())
+    }
+
+    /// -self, in constants
+    pub const fn negated(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    /// self times a real, in constants
+    pub const fn scaled(self, factor: Real) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let x = Vec3::new(1.0, 0.0, 0.0);
+    /// let y = Vec3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(x.cross(y), Vec3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub const fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec2, Vec3};
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.truncate(), Vec2::new(1.0, 2.0));
+    /// ```
+    pub const fn truncate(self) -> Vec2 {
+        Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vec4};
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.extend(1.0), Vec4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub const fn extend(self, w: Real) -> Vec4 {
+        Vec4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w,
+        }
+    }
+}
+
+impl Vector for Vec3 {
+    const ZERO: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    const DIMENSION: usize = 3;
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// assert_eq!(Vec3::splat(3.0), Vec3::new(3.0, 3.0, 3.0));
+    /// ```
+    fn splat(value: Real) -> Self {
+        Self {
+            x: value,
+            y: value,
+            z: value,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(4.0, 5.0, 6.0);
+    /// assert_eq!(a.dot(b), 32.0);
+    /// ```
+    fn dot(self, rhs: Self) -> Real {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let a = Vec3::new(1.0, 4.0, 5.0);
+    /// let b = Vec3::new(3.0, 2.0, 6.0);
+    /// assert_eq!(a.min(b), Vec3::new(1.0, 2.0, 5.0));
+    /// ```
+    fn min(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.min(rhs.x),
+            y: self.y.min(rhs.y),
+            z: self.z.min(rhs.z),
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// let a = Vec3::new(1.0, 4.0, 5.0);
+    /// let b = Vec3::new(3.0, 2.0, 6.0);
+    /// assert_eq!(a.max(b), Vec3::new(3.0, 4.0, 6.0));
+    /// ```
+    fn max(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.max(rhs.x),
+            y: self.y.max(rhs.y),
+            z: self.z.max(rhs.z),
+        }
+    }
+}
+
+impl Vec4 {
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.w, 4.0);
+    /// ```
+    pub const fn new(x: Real, y: Real, z: Real, w: Real) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// self + rhs, which unlike the operator works in constants
+    pub const fn plus(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+
+    /// self - rhs, in constants
+    pub const fn minus(self, rhs: Self) -> Self {
+        self.plus(rhs.negated())
+    }
+
+    /// -self, in constants
+    pub const fn negated(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+
+    /// self times a real, in constants
+    pub const fn scaled(self, factor: Real) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+            w: self.w * factor,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec3, Vec4};
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.truncate(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub const fn truncate(self) -> Vec3 {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Vector for Vec4 {
+    const ZERO: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 0.0,
+    };
+    const DIMENSION: usize = 4;
+
+    /// ```
+    /// # use vmath::linalg::{Vec4, Vector};
+    /// assert_eq!(Vec4::splat(3.0), Vec4::new(3.0, 3.0, 3.0, 3.0));
+    /// ```
+    fn splat(value: Real) -> Self {
+        Self {
+            x: value,
+            y: value,
+            z: value,
+            w: value,
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec4, Vector};
+    /// let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(a.dot(b), 70.0);
+    /// ```
+    fn dot(self, rhs: Self) -> Real {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec4, Vector};
+    /// let a = Vec4::new(1.0, 4.0, 5.0, 8.0);
+    /// let b = Vec4::new(3.0, 2.0, 6.0, 7.0);
+    /// assert_eq!(a.min(b), Vec4::new(1.0, 2.0, 5.0, 7.0));
+    /// ```
+    fn min(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.min(rhs.x),
+            y: self.y.min(rhs.y),
+            z: self.z.min(rhs.z),
+            w: self.w.min(rhs.w),
+        }
+    }
+
+    /// ```
+    /// # use vmath::linalg::{Vec4, Vector};
+    /// let a = Vec4::new(1.0, 4.0, 5.0, 8.0);
+    /// let b = Vec4::new(3.0, 2.0, 6.0, 7.0);
+    /// assert_eq!(a.max(b), Vec4::new(3.0, 4.0, 6.0, 8.0));
+    /// ```
+    fn max(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.max(rhs.x),
+            y: self.y.max(rhs.y),
+            z: self.z.max(rhs.z),
+            w: self.w.max(rhs.w),
+        }
+    }
+}
+
+impl From<[Real; 2]> for Vec2 {
+    fn from([x, y]: [Real; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[Real; 3]> for Vec3 {
+    fn from([x, y, z]: [Real; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<[Real; 4]> for Vec4 {
+    fn from([x, y, z, w]: [Real; 4]) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl From<Vec2> for [Real; 2] {
+    fn from(v: Vec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl From<Vec3> for [Real; 3] {
+    fn from(v: Vec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<Vec4> for [Real; 4] {
+    fn from(v: Vec4) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl Index<usize> for Vec2 {
+    type Output = Real;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v[1], 2.0);
+    /// ```
+    fn index(&self, index: usize) -> &Real {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index {index} out of range for Vec2"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec2 {
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let mut v = Vec2::new(1.0, 2.0);
+    /// v[0] = 5.0;
+    /// assert_eq!(v, Vec2::new(5.0, 2.0));
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Real {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index {index} out of range for Vec2"),
+        }
+    }
+}
+
+impl Index<usize> for Vec3 {
+    type Output = Real;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v[2], 3.0);
+    /// ```
+    fn index(&self, index: usize) -> &Real {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index {index} out of range for Vec3"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec3 {
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let mut v = Vec3::new(1.0, 2.0, 3.0);
+    /// v[2] = 5.0;
+    /// assert_eq!(v, Vec3::new(1.0, 2.0, 5.0));
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Real {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index {index} out of range for Vec3"),
+        }
+    }
+}
+
+impl Index<usize> for Vec4 {
+    type Output = Real;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v[3], 4.0);
+    /// ```
+    fn index(&self, index: usize) -> &Real {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index {index} out of range for Vec4"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec4 {
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// v[3] = 5.0;
+    /// assert_eq!(v, Vec4::new(1.0, 2.0, 3.0, 5.0));
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Real {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index {index} out of range for Vec4"),
+        }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, -2.0);
+    /// assert_eq!(-v, Vec2::new(-1.0, 2.0));
+    /// ```
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let a = Vec2::new(1.0, 2.0);
+    /// let b = Vec2::new(3.0, 4.0);
+    /// assert_eq!(a + b, Vec2::new(4.0, 6.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        self.plus(rhs)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let a = Vec2::new(4.0, 6.0);
+    /// let b = Vec2::new(3.0, 4.0);
+    /// assert_eq!(a - b, Vec2::new(1.0, 2.0));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        self.minus(rhs)
+    }
+}
+
+impl Mul<Real> for Vec2 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v * 2.0, Vec2::new(2.0, 4.0));
+    /// ```
+    fn mul(self, rhs: Real) -> Self {
+        self.scaled(rhs)
+    }
+}
+
+impl Mul<Vec2> for Real {
+    type Output = Vec2;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(2.0 * v, Vec2::new(2.0, 4.0));
+    /// ```
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        rhs * self
+    }
+}
+
+impl Div<Real> for Vec2 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// let v = Vec2::new(2.0, 4.0);
+    /// assert_eq!(v / 2.0, Vec2::new(1.0, 2.0));
+    /// ```
+    fn div(self, rhs: Real) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(1.0, -2.0, 3.0);
+    /// assert_eq!(-v, Vec3::new(-1.0, 2.0, -3.0));
+    /// ```
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(4.0, 5.0, 6.0);
+    /// assert_eq!(a + b, Vec3::new(5.0, 7.0, 9.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        self.plus(rhs)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let a = Vec3::new(5.0, 7.0, 9.0);
+    /// let b = Vec3::new(4.0, 5.0, 6.0);
+    /// assert_eq!(a - b, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        self.minus(rhs)
+    }
+}
+
+impl Mul<Real> for Vec3 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v * 2.0, Vec3::new(2.0, 4.0, 6.0));
+    /// ```
+    fn mul(self, rhs: Real) -> Self {
+        self.scaled(rhs)
+    }
+}
+
+impl Mul<Vec3> for Real {
+    type Output = Vec3;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(2.0 * v, Vec3::new(2.0, 4.0, 6.0));
+    /// ```
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        rhs * self
+    }
+}
+
+impl Div<Real> for Vec3 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// let v = Vec3::new(2.0, 4.0, 6.0);
+    /// assert_eq!(v / 2.0, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn div(self, rhs: Real) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(1.0, -2.0, 3.0, -4.0);
+    /// assert_eq!(-v, Vec4::new(-1.0, 2.0, -3.0, 4.0));
+    /// ```
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(a + b, Vec4::new(6.0, 8.0, 10.0, 12.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        self.plus(rhs)
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let a = Vec4::new(6.0, 8.0, 10.0, 12.0);
+    /// let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    /// assert_eq!(a - b, Vec4::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        self.minus(rhs)
+    }
+}
+
+impl Mul<Real> for Vec4 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v * 2.0, Vec4::new(2.0, 4.0, 6.0, 8.0));
+    /// ```
+    fn mul(self, rhs: Real) -> Self {
+        self.scaled(rhs)
+    }
+}
+
+impl Mul<Vec4> for Real {
+    type Output = Vec4;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(2.0 * v, Vec4::new(2.0, 4.0, 6.0, 8.0));
+    /// ```
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        rhs * self
+    }
+}
+
+impl Div<Real> for Vec4 {
+    type Output = Self;
+    /// ```
+    /// # use vmath::linalg::Vec4;
+    /// let v = Vec4::new(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(v / 2.0, Vec4::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    fn div(self, rhs: Real) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+            w: self.w / rhs,
+        }
+    }
+}
+
+// SAFETY: repr(C) with only Real fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec2 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec2 {}
+
+// SAFETY: repr(C) with only Real fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec3 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec3 {}
+
+// SAFETY: repr(C) with only Real fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec4 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec4 {}
@@ -0,0 +1,245 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt::{self, Write};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::numbers::Real;
+use crate::util::wrap;
+
+/// an angle, kept in radians but built and read in either unit by name,
+/// so a value in degrees cannot be passed where radians are meant.
+/// the rotations and rotation matrices take anything `Into<Angle>`,
+/// which a bare `Real` is, as radians
+/// ```
+/// # use vmath::numbers::Angle;
+/// # use vmath::geometry::Rotation2;
+/// let heading = Angle::from_degrees(350.0);
+/// let turn = Angle::from_degrees(20.0);
+/// assert!(((heading + turn).wrap_positive().to_degrees() - 10.0).abs() < 1e-12);
+/// assert!((heading.shortest_to(turn).to_degrees() - 30.0).abs() < 1e-12);
+/// let rotation = Rotation2::from_angle(Angle::from_degrees(90.0));
+/// assert!((rotation.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Angle {
+    radians: Real,
+}
+
+impl Angle {
+    pub const ZERO: Self = Self { radians: 0.0 };
+    pub const QUARTER_TURN: Self = Self { radians: FRAC_PI_2 };
+    pub const HALF_TURN: Self = Self { radians: PI };
+    pub const FULL_TURN: Self = Self { radians: TAU };
+
+    pub const fn from_radians(radians: Real) -> Self {
+        Self { radians }
+    }
+
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::from_degrees(180.0), Angle::HALF_TURN);
+    /// ```
+    pub fn from_degrees(degrees: Real) -> Self {
+        Self {
+            radians: degrees.to_radians(),
+        }
+    }
+
+    pub const fn to_radians(self) -> Real {
+        self.radians
+    }
+
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::QUARTER_TURN.to_degrees(), 90.0);
+    /// ```
+    pub fn to_degrees(self) -> Real {
+        self.radians.to_degrees()
+    }
+
+    pub fn sin(self) -> Real {
+        self.radians.sin()
+    }
+
+    pub fn cos(self) -> Real {
+        self.radians.cos()
+    }
+
+    pub fn tan(self) -> Real {
+        self.radians.tan()
+    }
+
+    pub fn sin_cos(self) -> (Real, Real) {
+        self.radians.sin_cos()
+    }
+
+    /// the same direction by whole turns into (-pi, pi]
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::from_degrees(270.0).wrap_signed(), Angle::from_degrees(-90.0));
+    /// assert_eq!(Angle::HALF_TURN.wrap_signed(), Angle::HALF_TURN);
+    /// assert_eq!((-Angle::HALF_TURN).wrap_signed(), Angle::HALF_TURN);
+    /// ```
+    pub fn wrap_signed(self) -> Self {
+        // wrapping the negation into [-pi, pi) and negating back keeps pi and not -pi
+        Self::from_radians(-wrap(-self.radians, -PI, PI))
+    }
+
+    /// the same direction by whole turns into [0, 2 pi)
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::from_degrees(-90.0).wrap_positive(), Angle::from_degrees(270.0));
+    /// assert_eq!(Angle::FULL_TURN.wrap_positive(), Angle::ZERO);
+    /// ```
+    pub fn wrap_positive(self) -> Self {
+        Self::from_radians(wrap(self.radians, 0.0, TAU))
+    }
+
+    /// the turn in (-pi, pi] from self to other the short way round,
+    /// positive counterclockwise, so self + self.shortest_to(other) faces as other does
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// let a = Angle::from_degrees(170.0);
+    /// let b = Angle::from_degrees(-170.0);
+    /// assert!((a.shortest_to(b).to_degrees() - 20.0).abs() < 1e-12);
+    /// assert!((b.shortest_to(a).to_degrees() + 20.0).abs() < 1e-12);
+    /// ```
+    pub fn shortest_to(self, other: Self) -> Self {
+        (other - self).wrap_signed()
+    }
+}
+
+impl From<Real> for Angle {
+    /// radians, the unit the rest of the crate takes its angles in
+    fn from(radians: Real) -> Self {
+        Self::from_radians(radians)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_radians(-self.radians)
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::from_radians(self.radians + rhs.radians)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_radians(self.radians - rhs.radians)
+    }
+}
+
+impl Mul<Real> for Angle {
+    type Output = Self;
+    fn mul(self, rhs: Real) -> Self {
+        Self::from_radians(self.radians * rhs)
+    }
+}
+
+impl Mul<Angle> for Real {
+    type Output = Angle;
+    fn mul(self, rhs: Angle) -> Angle {
+        rhs * self
+    }
+}
+
+impl Div<Real> for Angle {
+    type Output = Self;
+    fn div(self, rhs: Real) -> Self {
+        Self::from_radians(self.radians / rhs)
+    }
+}
+
+impl Div for Angle {
+    type Output = Real;
+    /// the ratio of the two, whatever unit they were made in
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::from_degrees(540.0) / Angle::FULL_TURN, 1.5);
+    /// ```
+    fn div(self, rhs: Self) -> Real {
+        self.radians / rhs.radians
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<Real> for Angle {
+    fn mul_assign(&mut self, rhs: Real) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<Real> for Angle {
+    fn div_assign(&mut self, rhs: Real) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Angle {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl fmt::Display for Angle {
+    /// in degrees with a degree sign, or with the alternate flag, `{:#}`,
+    /// in radians with none, with the formatter's precision and padded as a whole
+    /// ```
+    /// # use vmath::numbers::Angle;
+    /// assert_eq!(Angle::from_degrees(-45.0).to_string(), "-45°");
+    /// assert_eq!(format!("{:.3}", Angle::from_radians(1.0)), "57.296°");
+    /// assert_eq!(format!("{:#.4}", Angle::QUARTER_TURN), "1.5708");
+    /// assert_eq!(format!("{:>6}", Angle::HALF_TURN), "  180°");
+    /// assert_eq!(format!("{:<+7.1}|", Angle::ZERO), "+0.0°  |");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, unit) = if f.alternate() {
+            (self.radians, "")
+        } else {
+            (self.to_degrees(), "°")
+        };
+        let mut text = match f.precision() {
+            Some(precision) => format!("{value:.precision$}{unit}"),
+            None => format!("{value}{unit}"),
+        };
+        if f.sign_plus() && !text.starts_with('-') {
+            text.insert(0, '+');
+        }
+        // the degree sign is two bytes, so the padding counts chars rather than leaving it to pad
+        let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&text)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
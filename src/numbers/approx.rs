@@ -0,0 +1,91 @@
+use crate::numbers::{Complex, Real};
+
+/// equality to within a tolerance, for testing computed results
+pub trait ApproxEq: Copy {
+    /// whether |self - other| <= max(abs_tol, rel_tol max(|self|, |other|)),
+    /// the absolute tolerance deciding near zero and the relative one elsewhere.
+    /// equal values, infinities included, always are, and NaN never is
+    /// ```
+    /// use vmath::numbers::ApproxEq;
+    ///
+    /// assert!((0.1 + 0.2).approx_eq(0.3, 0.0, 1e-15));
+    /// assert!(!(0.1 + 0.2).approx_eq(0.3, 0.0, 0.0));
+    /// assert!(1e-20.approx_eq(0.0, 1e-15, 1e-15));
+    /// assert!(!f64::NAN.approx_eq(f64::NAN, 1.0, 1.0));
+    /// ```
+    fn approx_eq(self, other: Self, abs_tol: Real, rel_tol: Real) -> bool;
+}
+
+/// the tolerance test on a difference and the two magnitudes
+fn within(difference: Real, a: Real, b: Real, abs_tol: Real, rel_tol: Real) -> bool {
+    difference <= abs_tol.max(rel_tol * a.max(b))
+}
+
+impl ApproxEq for Real {
+    fn approx_eq(self, other: Self, abs_tol: Real, rel_tol: Real) -> bool {
+        self == other
+            || within(
+                (self - other).abs(),
+                self.abs(),
+                other.abs(),
+                abs_tol,
+                rel_tol,
+            )
+    }
+}
+
+impl ApproxEq for Complex {
+    /// with the norm as the magnitude, so the tolerance is a disc around each value
+    /// ```
+    /// use vmath::numbers::{ApproxEq, Complex};
+    ///
+    /// let z = Complex::new(1.0, 2.0);
+    /// let w = z.ln().exp();
+    /// assert!(z.approx_eq(w, 0.0, 1e-15));
+    /// assert!(!z.approx_eq(z.conjugate(), 1e-3, 1e-3));
+    /// ```
+    fn approx_eq(self, other: Self, abs_tol: Real, rel_tol: Real) -> bool {
+        self == other
+            || within(
+                (self - other).norm(),
+                self.norm(),
+                other.norm(),
+                abs_tol,
+                rel_tol,
+            )
+    }
+}
+
+/// asserts two values are `ApproxEq` to within absolute and relative tolerances,
+/// 1e-12 of each unless given, printing both when they are not
+/// ```
+/// use vmath::assert_approx_eq;
+/// use vmath::numbers::Complex;
+///
+/// assert_approx_eq!(0.1 + 0.2, 0.3);
+/// assert_approx_eq!(Complex::I.powi(3), -Complex::I);
+/// assert_approx_eq!(1000.0, 1001.0, 0.0, 1e-2);
+/// ```
+/// ```should_panic
+/// # use vmath::assert_approx_eq;
+/// assert_approx_eq!(1.0, 1.1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, 1e-12, 1e-12)
+    };
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {
+        match (&$left, &$right, $abs_tol, $rel_tol) {
+            (left, right, abs_tol, rel_tol) => {
+                if !$crate::numbers::ApproxEq::approx_eq(*left, *right, abs_tol, rel_tol) {
+                    panic!(
+                        "assertion failed: left and right differ beyond the tolerance\n  \
+                         left: {:?}\n right: {:?}\n   abs: {:e}\n   rel: {:e}",
+                        left, right, abs_tol, rel_tol
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,508 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::numbers::{BigInt, BigRational, Real};
+use crate::Error;
+
+/// a binary floating point number with as many mantissa bits as asked for,
+/// mantissa 2^exponent, for checking real algorithms against a more precise reference.
+/// every operation is rounded once to nearest, ties to even, at the larger precision
+/// of its operands, as ieee 754 does at 53 bits, and the exponent has no bound,
+/// so nothing overflows or underflows. dividing by zero panics
+/// ```
+/// # use vmath::numbers::BigFloat;
+/// // at 53 bits it agrees with f64 wherever the result is a normal f64
+/// let (a, b) = (BigFloat::from_real(0.1, 53).unwrap(), BigFloat::from_real(0.2, 53).unwrap());
+/// assert_eq!((&a + &b).to_real(), 0.1 + 0.2);
+/// assert_eq!((&a / &b).to_real(), 0.1 / 0.2);
+///
+/// // and at 200 bits shows how far f64's sum drifts
+/// let mut reference = BigFloat::zero(200);
+/// let mut sum = 0.0;
+/// for k in 1..=1000 {
+///     let term = 1.0 / k as f64;
+///     reference += BigFloat::from_real(term, 200).unwrap();
+///     sum += term;
+/// }
+/// let error = (BigFloat::from_real(sum, 200).unwrap() - &reference).to_real();
+/// assert!(error != 0.0 && error.abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BigFloat {
+    // an odd mantissa, or zero with a zero exponent, so each value has one form
+    mantissa: BigInt,
+    exponent: i64,
+    precision: u32,
+}
+
+impl BigFloat {
+    /// 0 with room for precision bits, panics for fewer than 2
+    pub fn zero(precision: u32) -> Self {
+        assert!(precision >= 2, "precision below 2 bits");
+        Self {
+            mantissa: BigInt::ZERO,
+            exponent: 0,
+            precision,
+        }
+    }
+
+    /// x rounded to precision bits, None when it is infinite or NaN,
+    /// panics for fewer than 2 bits
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// let third = BigFloat::from_real(1.0 / 3.0, 53).unwrap();
+    /// assert_eq!(third.to_real(), 1.0 / 3.0);
+    /// assert_eq!(BigFloat::from_real(1.0 / 3.0, 4).unwrap().to_real(), 0.34375);
+    /// assert!(BigFloat::from_real(f64::INFINITY, 53).is_none());
+    /// ```
+    /// ```should_panic
+    /// # use vmath::numbers::BigFloat;
+    /// BigFloat::from_real(f64::INFINITY, 1);
+    /// ```
+    pub fn from_real(x: Real, precision: u32) -> Option<Self> {
+        assert!(precision >= 2, "precision below 2 bits");
+        let x = BigRational::from_real(x)?;
+        let exponent = -(x.denominator().bits() as i64 - 1);
+        Some(Self::rounded(
+            x.numerator().clone(),
+            exponent,
+            precision,
+            false,
+        ))
+    }
+
+    /// n rounded to precision bits, panics for fewer than 2
+    /// ```
+    /// # use vmath::numbers::{BigFloat, BigInt};
+    /// let big = BigFloat::from_int(BigInt::from(10).pow(30), 20);
+    /// assert_eq!(big.to_real(), 1000000468394648575362629369856.0);
+    /// ```
+    pub fn from_int(n: BigInt, precision: u32) -> Self {
+        Self::rounded(n, 0, precision, false)
+    }
+
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// the same value rounded to another precision, panics for fewer than 2 bits
+    pub fn with_precision(&self, precision: u32) -> Self {
+        Self::rounded(self.mantissa.clone(), self.exponent, precision, false)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            mantissa: self.mantissa.abs(),
+            ..self.clone()
+        }
+    }
+
+    /// the square root rounded to self's precision, an error when self is negative
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// # use vmath::Error;
+    /// let two = BigFloat::from_real(2.0, 200).unwrap();
+    /// let root = two.sqrt().unwrap();
+    /// assert_eq!(root.to_string(), "1.4142135623730950488016887242096980785696718753769480731766796e0");
+    /// assert_eq!(BigFloat::from_real(2.0, 53).unwrap().sqrt().unwrap().to_real(), 2f64.sqrt());
+    /// assert_eq!(BigFloat::from_real(-1.0, 53).unwrap().sqrt(), Err(Error::DomainError));
+    /// ```
+    pub fn sqrt(&self) -> Result<Self, Error> {
+        if self.is_negative() {
+            return Err(Error::DomainError);
+        }
+        if self.is_zero() {
+            return Ok(self.clone());
+        }
+        // at least 2 precision + 4 bits under the root, so it has 2 bits past the last kept,
+        // and an even exponent left over to halve
+        let bits = self.mantissa.bits() as i64;
+        let mut shift = (2 * i64::from(self.precision) + 4 - bits).max(0);
+        if (self.exponent - shift).rem_euclid(2) == 1 {
+            shift += 1;
+        }
+        let square = &self.mantissa << shift as usize;
+        let root = integer_sqrt(&square);
+        let exact = &root * &root == square;
+        Ok(Self::rounded(
+            root,
+            (self.exponent - shift) / 2,
+            self.precision,
+            !exact,
+        ))
+    }
+
+    /// the nearest real, ties to even, including among the subnormals,
+    /// and infinite past the largest finite one
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// let tiny = BigFloat::from_real(f64::MIN_POSITIVE, 60).unwrap();
+    /// let quarter = BigFloat::from_real(0.25, 60).unwrap();
+    /// assert_eq!((&tiny * &quarter).to_real(), f64::MIN_POSITIVE / 4.0);
+    /// let huge = BigFloat::from_real(f64::MAX, 60).unwrap();
+    /// assert_eq!((&huge + &huge).to_real(), f64::INFINITY);
+    /// assert_eq!((&(&huge + &huge) / &huge).to_real(), 2.0);
+    /// ```
+    pub fn to_real(&self) -> Real {
+        let top = self.exponent.saturating_add(self.mantissa.bits() as i64);
+        let sign = if self.is_negative() { -1.0 } else { 1.0 };
+        // past 2^1024 is infinite, and below half the least subnormal is zero
+        if self.is_zero() || top < -1076 {
+            return 0.0 * sign;
+        }
+        if top > 1025 {
+            return Real::INFINITY * sign;
+        }
+        let one = BigInt::from(1);
+        let exact = if self.exponent >= 0 {
+            BigRational::from(&self.mantissa << self.exponent as usize)
+        } else {
+            BigRational::new(
+                self.mantissa.clone(),
+                one << self.exponent.unsigned_abs() as usize,
+            )
+        };
+        exact.to_real()
+    }
+
+    /// mantissa 2^exponent, and a little more in magnitude when sticky, rounded to
+    /// precision bits. sticky needs the mantissa to have two bits past the precision
+    fn rounded(mantissa: BigInt, exponent: i64, precision: u32, sticky: bool) -> Self {
+        let mut result = Self::zero(precision);
+        if mantissa.is_zero() {
+            return result;
+        }
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+        let bits = mantissa.bits();
+        let precision_bits = u64::from(precision);
+        if bits > precision_bits {
+            let shift = bits - precision_bits;
+            let half = mantissa.bit(shift - 1);
+            let below = sticky || mantissa.trailing_zeros() < Some(shift - 1);
+            let mut kept = mantissa.abs() >> shift as usize;
+            if half && (below || kept.bit(0)) {
+                kept += BigInt::from(1);
+            }
+            mantissa = if mantissa.is_negative() { -kept } else { kept };
+            exponent += shift as i64;
+        } else {
+            debug_assert!(!sticky, "too few bits to round");
+        }
+        let zeros = mantissa.trailing_zeros().unwrap_or(0);
+        result.mantissa = mantissa >> zeros as usize;
+        result.exponent = exponent + zeros as i64;
+        result
+    }
+
+    /// the bit just past the most significant, so |self| < 2^top
+    fn top(&self) -> i64 {
+        self.exponent + self.mantissa.bits() as i64
+    }
+
+    /// the mantissa scaled up to a lower exponent
+    fn aligned(&self, exponent: i64) -> BigInt {
+        &self.mantissa << (self.exponent - exponent) as usize
+    }
+}
+
+/// the largest integer whose square is at most n, by newton's method from above
+fn integer_sqrt(n: &BigInt) -> BigInt {
+    let mut x = BigInt::from(1) << n.bits().div_ceil(2) as usize;
+    loop {
+        let next = (&x + n / &x) >> 1;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+impl PartialEq for BigFloat {
+    /// by value, whatever the precisions
+    fn eq(&self, other: &Self) -> bool {
+        self.mantissa == other.mantissa && self.exponent == other.exponent
+    }
+}
+
+impl Eq for BigFloat {}
+
+impl Hash for BigFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mantissa.hash(state);
+        self.exponent.hash(state);
+    }
+}
+
+impl Ord for BigFloat {
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// let a = BigFloat::from_real(-1e300, 53).unwrap();
+    /// let b = BigFloat::from_real(1e-300, 100).unwrap();
+    /// assert!(a < b && b < BigFloat::from_real(1.0, 2).unwrap());
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = |x: &Self| x.mantissa.signum();
+        match sign(self).cmp(&sign(other)) {
+            Ordering::Equal if sign(self) != 0 => {}
+            order => return order,
+        }
+        // same sign and nonzero, so the one further from zero is larger for positives
+        let magnitude = self.top().cmp(&other.top()).then_with(|| {
+            let exponent = self.exponent.min(other.exponent);
+            self.aligned(exponent)
+                .abs()
+                .cmp(&other.aligned(exponent).abs())
+        });
+        if self.is_negative() {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+    fn neg(self) -> BigFloat {
+        BigFloat {
+            mantissa: -self.mantissa,
+            ..self
+        }
+    }
+}
+
+impl Neg for &BigFloat {
+    type Output = BigFloat;
+    fn neg(self) -> BigFloat {
+        -self.clone()
+    }
+}
+
+impl Add for &BigFloat {
+    type Output = BigFloat;
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// // 1 + 2^-100 is exact at 101 bits and rounds back to 1 at 100
+    /// let one = BigFloat::from_real(1.0, 101).unwrap();
+    /// let tiny = BigFloat::from_real(2f64.powi(-100), 2).unwrap();
+    /// assert!(&one + &tiny > one);
+    /// assert_eq!(&one.with_precision(100) + &tiny.with_precision(100), one);
+    /// // a far smaller term is rounded away without aligning the two over 1000 bits
+    /// let speck = BigFloat::from_real(1e-300, 2).unwrap();
+    /// assert_eq!(&one - &speck, one);
+    /// assert_eq!(&speck - &one, -one);
+    /// ```
+    fn add(self, rhs: &BigFloat) -> BigFloat {
+        let precision = self.precision.max(rhs.precision);
+        if self.is_zero() {
+            return rhs.with_precision(precision);
+        }
+        if rhs.is_zero() {
+            return self.with_precision(precision);
+        }
+        let (large, small) = if self.top() >= rhs.top() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        // a term below both the last bit of the larger and a quarter of the last place
+        // of the result rounds just as any other number that small with its sign would,
+        // so it stands in for it rather than aligning the two over a long way
+        let threshold = large.exponent.min(large.top() - i64::from(precision) - 3) - 1;
+        let proxy;
+        let small = if small.top() <= threshold {
+            proxy = BigFloat {
+                mantissa: BigInt::from(small.mantissa.signum()),
+                exponent: threshold - 1,
+                precision,
+            };
+            &proxy
+        } else {
+            small
+        };
+        let exponent = large.exponent.min(small.exponent);
+        BigFloat::rounded(
+            large.aligned(exponent) + small.aligned(exponent),
+            exponent,
+            precision,
+            false,
+        )
+    }
+}
+
+impl Sub for &BigFloat {
+    type Output = BigFloat;
+    fn sub(self, rhs: &BigFloat) -> BigFloat {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigFloat {
+    type Output = BigFloat;
+    fn mul(self, rhs: &BigFloat) -> BigFloat {
+        BigFloat::rounded(
+            &self.mantissa * &rhs.mantissa,
+            self.exponent + rhs.exponent,
+            self.precision.max(rhs.precision),
+            false,
+        )
+    }
+}
+
+impl Div for &BigFloat {
+    type Output = BigFloat;
+    /// panics when rhs is zero
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// let one = BigFloat::from_real(1.0, 100).unwrap();
+    /// let three = BigFloat::from_real(3.0, 100).unwrap();
+    /// let third = &one / &three;
+    /// assert_eq!(third.to_string(), "3.3333333333333333333333333333346e-1");
+    /// assert_eq!(third.with_precision(53).to_real(), 1.0 / 3.0);
+    /// ```
+    fn div(self, rhs: &BigFloat) -> BigFloat {
+        if rhs.is_zero() {
+            panic!("division by zero");
+        }
+        let precision = self.precision.max(rhs.precision);
+        // enough bits in the quotient for two past the precision
+        let shift = (i64::from(precision) + 2 + rhs.mantissa.bits() as i64
+            - self.mantissa.bits() as i64)
+            .max(0);
+        let (quotient, remainder) = (&self.mantissa << shift as usize).div_rem(&rhs.mantissa);
+        BigFloat::rounded(
+            quotient,
+            self.exponent - rhs.exponent - shift,
+            precision,
+            !remainder.is_zero(),
+        )
+    }
+}
+
+/// the operators on values and on a value and a reference, through the one on references
+macro_rules! forward {
+    ($($trait:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl $trait for BigFloat {
+                type Output = BigFloat;
+                fn $method(self, rhs: BigFloat) -> BigFloat {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&BigFloat> for BigFloat {
+                type Output = BigFloat;
+                fn $method(self, rhs: &BigFloat) -> BigFloat {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<BigFloat> for &BigFloat {
+                type Output = BigFloat;
+                fn $method(self, rhs: BigFloat) -> BigFloat {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $assign<&BigFloat> for BigFloat {
+                fn $assign_method(&mut self, rhs: &BigFloat) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+
+            impl $assign for BigFloat {
+                fn $assign_method(&mut self, rhs: BigFloat) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign
+);
+
+impl fmt::Display for BigFloat {
+    /// in decimal as d.ddde±n, rounded to nearest, with the formatter's precision
+    /// as the digits after the point, or else enough that the digits pin down the value
+    /// ```
+    /// # use vmath::numbers::BigFloat;
+    /// let x = BigFloat::from_real(-1234.5, 53).unwrap();
+    /// assert_eq!(format!("{x:.3}"), "-1.235e3");
+    /// assert_eq!(format!("{:.0}", BigFloat::from_real(0.00095, 53).unwrap()), "9e-4");
+    /// assert_eq!(BigFloat::zero(10).to_string(), "0e0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0e0");
+        }
+        let digits = match f.precision() {
+            Some(after) => after as i64 + 1,
+            None => (f64::from(self.precision) * std::f64::consts::LOG10_2).ceil() as i64 + 1,
+        };
+        // |self| 10^(digits - 1 - power) rounded, for power the decimal exponent,
+        // first guessed from the binary one and then corrected by a step either way
+        let magnitude = self.mantissa.abs();
+        let scaled = |power: i64| {
+            let (mut numerator, mut denominator) = (magnitude.clone(), BigInt::from(1));
+            let ten = BigInt::from(10);
+            let shift = digits - 1 - power;
+            if shift >= 0 {
+                numerator *= ten.pow(shift as u32);
+            } else {
+                denominator *= ten.pow(shift.unsigned_abs() as u32);
+            }
+            if self.exponent >= 0 {
+                numerator = numerator << self.exponent as usize;
+            } else {
+                denominator = denominator << self.exponent.unsigned_abs() as usize;
+            }
+            let (quotient, remainder) = numerator.div_rem(&denominator);
+            if (remainder << 1) >= denominator {
+                quotient + BigInt::from(1)
+            } else {
+                quotient
+            }
+        };
+        let limit = BigInt::from(10).pow(digits as u32);
+        let mut power = ((self.top() - 1) as f64 * std::f64::consts::LOG10_2).floor() as i64;
+        let mut value = scaled(power);
+        while value >= limit {
+            power += 1;
+            value = scaled(power);
+        }
+        while &value * BigInt::from(10) < limit {
+            power -= 1;
+            value = scaled(power);
+        }
+        let text = value.to_string();
+        let (first, rest) = text.split_at(1);
+        let point = if rest.is_empty() { "" } else { "." };
+        // pad_integral, as f.pad would cut the text at the precision
+        f.pad_integral(
+            !self.is_negative(),
+            "",
+            &format!("{first}{point}{rest}e{power}"),
+        )
+    }
+}
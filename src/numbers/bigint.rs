@@ -0,0 +1,903 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+use crate::numbers::Real;
+use crate::ParseError;
+
+/// below this many limbs in the shorter operand, products are done the schoolbook way
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// an integer of any size, a sign and a magnitude in 64 bit limbs, least significant first.
+/// the arithmetic is exact, division truncates toward zero as it does for the
+/// primitive integers, and dividing by zero panics.
+/// the operators take values or references, the latter saving a clone
+/// ```
+/// # use vmath::numbers::BigInt;
+/// let factorial = (1..=30).fold(BigInt::from(1), |product, k| product * BigInt::from(k));
+/// assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+///
+/// let big: BigInt = "-123456789012345678901234567890".parse().unwrap();
+/// assert_eq!(&big * &big / &big, big);
+/// assert_eq!(format!("{:#x}", BigInt::from(u128::MAX) + BigInt::from(1)), "0x100000000000000000000000000000000");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    // zero is an empty magnitude and never negative, and the last limb is never zero
+    negative: bool,
+    magnitude: Vec<u64>,
+}
+
+impl BigInt {
+    pub const ZERO: Self = Self {
+        negative: false,
+        magnitude: Vec::new(),
+    };
+
+    /// the number with this sign and these limbs, least significant first
+    fn from_parts(negative: bool, mut magnitude: Vec<u64>) -> Self {
+        trim(&mut magnitude);
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// -1, 0, or 1 by the sign
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.is_zero()) {
+            (true, _) => -1,
+            (false, true) => 0,
+            (false, false) => 1,
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    /// the bits in the magnitude, 0 for zero
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(BigInt::from(255).bits(), 8);
+    /// assert_eq!(BigInt::from(-256).bits(), 9);
+    /// assert_eq!((BigInt::from(1) << 200).bits(), 201);
+    /// ```
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => 64 * self.magnitude.len() as u64 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// bit i of the magnitude
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let n = BigInt::from(-0b1010) << 100;
+    /// assert!(n.bit(101) && !n.bit(102) && n.bit(103));
+    /// assert!(!n.bit(100_000));
+    /// ```
+    pub fn bit(&self, i: u64) -> bool {
+        let limb = (i / 64) as usize;
+        self.magnitude
+            .get(limb)
+            .is_some_and(|limb| limb >> (i % 64) & 1 == 1)
+    }
+
+    /// the zero bits below the lowest one, None for zero which has no lowest one
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!((BigInt::from(-3) << 130).trailing_zeros(), Some(130));
+    /// assert_eq!(BigInt::ZERO.trailing_zeros(), None);
+    /// ```
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let limb = self.magnitude.iter().position(|&limb| limb != 0)?;
+        Some(64 * limb as u64 + u64::from(self.magnitude[limb].trailing_zeros()))
+    }
+
+    /// the quotient truncated toward zero and the remainder, which has self's sign,
+    /// so self = q rhs + r with |r| < |rhs|. panics when rhs is zero
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let (q, r) = BigInt::from(-7).div_rem(&BigInt::from(2));
+    /// assert_eq!((q, r), (BigInt::from(-3), BigInt::from(-1)));
+    ///
+    /// let a = BigInt::from(3).pow(200);
+    /// let b = BigInt::from(7).pow(50) + BigInt::from(1);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(&q * &b + &r, a);
+    /// assert!(r < b);
+    /// ```
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.checked_div_rem(rhs).expect("division by zero")
+    }
+
+    /// `div_rem`, None when rhs is zero
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (q, r) = div_rem_magnitudes(&self.magnitude, &rhs.magnitude);
+        Some((
+            Self::from_parts(self.negative != rhs.negative, q),
+            Self::from_parts(self.negative, r),
+        ))
+    }
+
+    /// self^exponent by repeated squaring, with 0^0 = 1
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(BigInt::from(-2).pow(3), BigInt::from(-8));
+    /// assert_eq!(BigInt::from(10).pow(30).to_string(), format!("1{}", "0".repeat(30)));
+    /// ```
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// the greatest common divisor, never negative, with gcd(0, 0) = 0
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let a = BigInt::from(2).pow(100) * BigInt::from(45);
+    /// let b = BigInt::from(-6).pow(60);
+    /// assert_eq!(a.gcd(&b), BigInt::from(2).pow(60) * BigInt::from(9));
+    /// assert_eq!(BigInt::ZERO.gcd(&BigInt::from(-4)), BigInt::from(4));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.magnitude.clone(), other.magnitude.clone());
+        while !b.is_empty() {
+            let (_, r) = div_rem_magnitudes(&a, &b);
+            a = std::mem::replace(&mut b, r);
+        }
+        Self::from_parts(false, a)
+    }
+
+    /// the nearest real, ties to even, infinite past the largest finite one
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(BigInt::from(-3).to_real(), -3.0);
+    /// assert_eq!((BigInt::from(1) << 1000).to_real(), 2f64.powi(1000));
+    /// // 2^53 + 1 is halfway and rounds to even, a bit further on rounds up
+    /// let halfway = (BigInt::from(1) << 53) + BigInt::from(1);
+    /// assert_eq!(halfway.to_real(), 2f64.powi(53));
+    /// assert_eq!(((halfway << 70) + BigInt::from(1)).to_real(), (2f64.powi(53) + 2.0) * 2f64.powi(70));
+    /// assert_eq!((BigInt::from(1) << 1024).to_real(), f64::INFINITY);
+    /// ```
+    pub fn to_real(&self) -> Real {
+        let bits = self.bits();
+        let magnitude = if bits <= 64 {
+            self.magnitude.first().copied().unwrap_or(0) as Real
+        } else {
+            // the top 64 bits, with the lowest one set if anything below was dropped,
+            // round the same way as the whole magnitude would
+            let shift = bits - 64;
+            let top = shift_right(&self.magnitude, shift as usize);
+            let dropped = any_below(&self.magnitude, shift);
+            let rounded = (top.first().copied().unwrap_or(0) | u64::from(dropped)) as Real;
+            if shift > 1023 {
+                Real::INFINITY
+            } else {
+                rounded * (2.0 as Real).powi(shift as i32)
+            }
+        };
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// the value when it fits in an i128
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+    /// assert_eq!((BigInt::from(i128::MAX) + BigInt::from(1)).to_i128(), None);
+    /// ```
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let low = self.magnitude.first().copied().unwrap_or(0);
+        let high = self.magnitude.get(1).copied().unwrap_or(0);
+        let magnitude = (u128::from(high) << 64) | u128::from(low);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// an optional sign and then digits in the radix, from 2 to 36,
+    /// with letters of either case for the digits past 9. panics for other radixes
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let n = BigInt::from_str_radix("-DeadBeef0123456789abcdef", 16).unwrap();
+    /// assert_eq!(format!("{n:x}"), "-deadbeef0123456789abcdef");
+    /// assert_eq!(BigInt::from_str_radix("101", 2), Ok(BigInt::from(5)));
+    /// let error = BigInt::from_str_radix("12z", 10).unwrap_err();
+    /// assert_eq!(error.to_string(), "invalid digit at byte 2");
+    /// ```
+    pub fn from_str_radix(source: &str, radix: u32) -> Result<Self, ParseError> {
+        assert!((2..=36).contains(&radix), "radix not in 2 to 36");
+        let (negative, start) = match source.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let digits = &source[start..];
+        if digits.is_empty() {
+            return Err(ParseError {
+                position: start,
+                message: "missing digits",
+            });
+        }
+        // as many digits at a time as fit in a limb
+        let mut chunk_length = 1;
+        let mut chunk_scale = u64::from(radix);
+        while let Some(scale) = chunk_scale.checked_mul(u64::from(radix)) {
+            chunk_scale = scale;
+            chunk_length += 1;
+        }
+        let mut magnitude = Vec::new();
+        let bytes = digits.as_bytes();
+        let mut at = 0;
+        while at < bytes.len() {
+            let end = (at + chunk_length).min(bytes.len());
+            let mut value = 0u64;
+            let mut scale = 1u64;
+            for (offset, &byte) in bytes[at..end].iter().enumerate() {
+                let digit = (byte as char).to_digit(radix).ok_or(ParseError {
+                    position: start + at + offset,
+                    message: "invalid digit",
+                })?;
+                value = value * u64::from(radix) + u64::from(digit);
+                scale *= u64::from(radix);
+            }
+            multiply_add_small(&mut magnitude, scale, value);
+            at = end;
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+
+    /// the digits of the magnitude in a radix that is a power of two, most significant first
+    fn power_of_two_digits(&self, bits_per_digit: u32, upper: bool) -> String {
+        if self.is_zero() {
+            return "0".to_owned();
+        }
+        let mut digits = Vec::new();
+        let mask = (1u64 << bits_per_digit) - 1;
+        let total = self.bits();
+        let mut bit = 0;
+        while bit < total {
+            let limb = (bit / 64) as usize;
+            let offset = bit % 64;
+            let mut value = self.magnitude[limb] >> offset;
+            if offset + u64::from(bits_per_digit) > 64 && limb + 1 < self.magnitude.len() {
+                value |= self.magnitude[limb + 1] << (64 - offset);
+            }
+            let digit = std::char::from_digit((value & mask) as u32, 1 << bits_per_digit).unwrap();
+            digits.push(if upper {
+                digit.to_ascii_uppercase()
+            } else {
+                digit
+            });
+            bit += u64::from(bits_per_digit);
+        }
+        digits.iter().rev().collect()
+    }
+}
+
+/// drops the zero limbs at the most significant end
+fn trim(magnitude: &mut Vec<u64>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+/// the slice without its most significant zero limbs
+fn trimmed(mut magnitude: &[u64]) -> &[u64] {
+    while let Some((&0, rest)) = magnitude.split_last() {
+        magnitude = rest;
+    }
+    magnitude
+}
+
+fn compare_magnitudes(a: &[u64], b: &[u64]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = long.to_vec();
+    add_into(&mut sum, short);
+    sum
+}
+
+/// total += addend, growing total when the carry runs off its end
+fn add_into(total: &mut Vec<u64>, addend: &[u64]) {
+    if total.len() < addend.len() {
+        total.resize(addend.len(), 0);
+    }
+    let mut carry = false;
+    for (i, limb) in total.iter_mut().enumerate() {
+        if i >= addend.len() && !carry {
+            return;
+        }
+        let (sum, first) = limb.overflowing_add(addend.get(i).copied().unwrap_or(0));
+        let (sum, second) = sum.overflowing_add(u64::from(carry));
+        *limb = sum;
+        carry = first || second;
+    }
+    if carry {
+        total.push(1);
+    }
+}
+
+/// total += addend where the sum is known to fit in total
+fn add_in_place(total: &mut [u64], addend: &[u64]) {
+    let mut carry = false;
+    for (i, limb) in total.iter_mut().enumerate() {
+        if i >= addend.len() && !carry {
+            return;
+        }
+        let (sum, first) = limb.overflowing_add(addend.get(i).copied().unwrap_or(0));
+        let (sum, second) = sum.overflowing_add(u64::from(carry));
+        *limb = sum;
+        carry = first || second;
+    }
+    debug_assert!(!carry, "sum does not fit");
+}
+
+/// a - b for a at least b
+fn subtract_magnitudes(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut difference = a.to_vec();
+    let mut borrow = false;
+    for (i, limb) in difference.iter_mut().enumerate() {
+        if i >= b.len() && !borrow {
+            break;
+        }
+        let (value, first) = limb.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (value, second) = value.overflowing_sub(u64::from(borrow));
+        *limb = value;
+        borrow = first || second;
+    }
+    debug_assert!(!borrow, "subtracting a larger magnitude");
+    trim(&mut difference);
+    difference
+}
+
+/// a b, by karatsuba once both are long enough
+fn multiply_magnitudes(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.is_empty() {
+        return Vec::new();
+    }
+    if short.len() < KARATSUBA_THRESHOLD {
+        return schoolbook(long, short);
+    }
+    let mut product = vec![0; long.len() + short.len()];
+    if 2 * short.len() <= long.len() {
+        // lopsided, so balanced products of short with each piece of long
+        for (k, piece) in long.chunks(short.len()).enumerate() {
+            add_in_place(
+                &mut product[k * short.len()..],
+                &multiply_magnitudes(piece, short),
+            );
+        }
+    } else {
+        // (a1 B + a0)(b1 B + b0) = z2 B^2 + z1 B + z0 with three half-size products,
+        // z1 = (a0 + a1)(b0 + b1) - z2 - z0
+        let half = long.len() / 2;
+        let (a0, a1) = long.split_at(half);
+        let (b0, b1) = short.split_at(half);
+        let z0 = multiply_magnitudes(a0, b0);
+        let z2 = multiply_magnitudes(a1, b1);
+        let z1 = multiply_magnitudes(&add_magnitudes(a0, a1), &add_magnitudes(b0, b1));
+        let z1 = subtract_magnitudes(&subtract_magnitudes(&z1, &z0), &z2);
+        add_in_place(&mut product, &z0);
+        add_in_place(&mut product[half..], &z1);
+        add_in_place(&mut product[2 * half..], &z2);
+    }
+    trim(&mut product);
+    product
+}
+
+fn schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = u128::from(x) * u128::from(y) + u128::from(product[i + j]) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    trim(&mut product);
+    product
+}
+
+/// magnitude = magnitude scale + addend
+fn multiply_add_small(magnitude: &mut Vec<u64>, scale: u64, addend: u64) {
+    let mut carry = u128::from(addend);
+    for limb in magnitude.iter_mut() {
+        let t = u128::from(*limb) * u128::from(scale) + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u64);
+    }
+}
+
+/// magnitude /= divisor, returning the remainder
+fn divide_small(magnitude: &mut Vec<u64>, divisor: u64) -> u64 {
+    let mut remainder = 0u128;
+    for limb in magnitude.iter_mut().rev() {
+        let t = (remainder << 64) | u128::from(*limb);
+        *limb = (t / u128::from(divisor)) as u64;
+        remainder = t % u128::from(divisor);
+    }
+    trim(magnitude);
+    remainder as u64
+}
+
+/// the quotient and remainder of magnitudes, by knuth's algorithm d, b not zero
+fn div_rem_magnitudes(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if compare_magnitudes(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if let [divisor] = b {
+        let mut quotient = a.to_vec();
+        let remainder = divide_small(&mut quotient, *divisor);
+        return (
+            quotient,
+            if remainder == 0 {
+                Vec::new()
+            } else {
+                vec![remainder]
+            },
+        );
+    }
+    // shifted so the divisor's top limb has its high bit set, which keeps each
+    // estimated quotient limb at most two too large
+    let shift = b[b.len() - 1].leading_zeros() as usize;
+    let divisor = shift_left(b, shift);
+    let mut remainder = shift_left(a, shift);
+    remainder.resize(a.len() + 1, 0);
+    let n = divisor.len();
+    let m = a.len() - n;
+    let top = u128::from(divisor[n - 1]);
+    let next = u128::from(divisor[n - 2]);
+    let mut quotient = vec![0; m + 1];
+    for j in (0..=m).rev() {
+        let numerator = (u128::from(remainder[j + n]) << 64) | u128::from(remainder[j + n - 1]);
+        let mut estimate = numerator / top;
+        let mut rest = numerator % top;
+        while estimate >> 64 != 0
+            || estimate * next > ((rest << 64) | u128::from(remainder[j + n - 2]))
+        {
+            estimate -= 1;
+            rest += top;
+            if rest >> 64 != 0 {
+                break;
+            }
+        }
+        // remainder[j..] -= estimate divisor
+        let mut carry = 0u128;
+        let mut borrow = false;
+        for i in 0..n {
+            let product = estimate * u128::from(divisor[i]) + carry;
+            carry = product >> 64;
+            let (value, first) = remainder[i + j].overflowing_sub(product as u64);
+            let (value, second) = value.overflowing_sub(u64::from(borrow));
+            remainder[i + j] = value;
+            borrow = first || second;
+        }
+        let (value, first) = remainder[j + n].overflowing_sub(carry as u64);
+        let (value, second) = value.overflowing_sub(u64::from(borrow));
+        remainder[j + n] = value;
+        if first || second {
+            // one too many, so add the divisor back
+            estimate -= 1;
+            let mut carry = false;
+            for i in 0..n {
+                let (sum, first) = remainder[i + j].overflowing_add(divisor[i]);
+                let (sum, second) = sum.overflowing_add(u64::from(carry));
+                remainder[i + j] = sum;
+                carry = first || second;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(u64::from(carry));
+        }
+        quotient[j] = estimate as u64;
+    }
+    remainder.truncate(n);
+    trim(&mut quotient);
+    (quotient, shift_right(&remainder, shift))
+}
+
+/// whether any of the lowest bits of the magnitude are set
+fn any_below(magnitude: &[u64], bits: u64) -> bool {
+    let whole = (bits / 64) as usize;
+    let part = bits % 64;
+    magnitude.iter().take(whole).any(|&limb| limb != 0)
+        || (part > 0
+            && magnitude
+                .get(whole)
+                .is_some_and(|limb| limb & ((1 << part) - 1) != 0))
+}
+
+/// magnitude 2^shift
+fn shift_left(magnitude: &[u64], shift: usize) -> Vec<u64> {
+    let (limbs, bits) = (shift / 64, shift % 64);
+    let mut shifted = vec![0; limbs];
+    if bits == 0 {
+        shifted.extend_from_slice(magnitude);
+    } else {
+        let mut carry = 0;
+        for &limb in magnitude {
+            shifted.push((limb << bits) | carry);
+            carry = limb >> (64 - bits);
+        }
+        shifted.push(carry);
+    }
+    trim(&mut shifted);
+    shifted
+}
+
+/// magnitude / 2^shift rounded down
+fn shift_right(magnitude: &[u64], shift: usize) -> Vec<u64> {
+    let (limbs, bits) = (shift / 64, shift % 64);
+    if limbs >= magnitude.len() {
+        return Vec::new();
+    }
+    let rest = &magnitude[limbs..];
+    let mut shifted: Vec<u64> = if bits == 0 {
+        rest.to_vec()
+    } else {
+        (0..rest.len())
+            .map(|i| {
+                let high = rest.get(i + 1).map_or(0, |&next| next << (64 - bits));
+                (rest[i] >> bits) | high
+            })
+            .collect()
+    };
+    trim(&mut shifted);
+    shifted
+}
+
+macro_rules! from_unsigned {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for BigInt {
+                fn from(value: $int) -> Self {
+                    let value = value as u128;
+                    Self::from_parts(false, vec![value as u64, (value >> 64) as u64])
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_signed {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for BigInt {
+                fn from(value: $int) -> Self {
+                    let value = value as i128;
+                    let magnitude = value.unsigned_abs();
+                    Self::from_parts(value < 0, vec![magnitude as u64, (magnitude >> 64) as u64])
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitudes(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match compare_magnitudes(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                rhs.negative,
+                subtract_magnitudes(&rhs.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                subtract_magnitudes(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        // -rhs without cloning its limbs twice
+        let negated = BigInt {
+            negative: !rhs.negative && !rhs.is_zero(),
+            magnitude: rhs.magnitude.clone(),
+        };
+        self + &negated
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// // (2^n - 1)^2 = 2^2n - 2^(n+1) + 1, long enough for karatsuba
+    /// let one = BigInt::from(1);
+    /// let x = (&one << 5000) - &one;
+    /// assert_eq!(&x * &x, (&one << 10000) - (&one << 5001) + &one);
+    /// // and lopsided
+    /// let y = BigInt::from(3).pow(3000);
+    /// assert_eq!(&x * &y / &y, x);
+    /// assert_eq!(BigInt::from(-4) * BigInt::ZERO, BigInt::ZERO);
+    /// ```
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            multiply_magnitudes(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+    /// truncating toward zero, panics when rhs is zero
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+    /// with the sign of self, panics when rhs is zero
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+/// the operators on values and on a value and a reference, through the one on references
+macro_rules! forward {
+    ($($trait:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl $trait for BigInt {
+                type Output = BigInt;
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+                fn $method(self, rhs: &BigInt) -> BigInt {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<BigInt> for &BigInt {
+                type Output = BigInt;
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $assign<&BigInt> for BigInt {
+                fn $assign_method(&mut self, rhs: &BigInt) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+
+            impl $assign for BigInt {
+                fn $assign_method(&mut self, rhs: BigInt) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+    /// self 2^shift
+    fn shl(self, shift: usize) -> BigInt {
+        BigInt::from_parts(self.negative, shift_left(&self.magnitude, shift))
+    }
+}
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+    fn shl(self, shift: usize) -> BigInt {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+    /// self / 2^shift rounded down, toward negative infinity as for the primitive integers
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(BigInt::from(-5) >> 1, BigInt::from(-3));
+    /// assert_eq!(BigInt::from(5) >> 1, BigInt::from(2));
+    /// assert_eq!((BigInt::from(-1) << 300) >> 299, BigInt::from(-2));
+    /// ```
+    fn shr(self, shift: usize) -> BigInt {
+        let shifted = BigInt::from_parts(self.negative, shift_right(&self.magnitude, shift));
+        if self.negative && any_below(&self.magnitude, shift as u64) {
+            shifted - BigInt::from(1)
+        } else {
+            shifted
+        }
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+    fn shr(self, shift: usize) -> BigInt {
+        &self >> shift
+    }
+}
+
+impl fmt::Display for BigInt {
+    /// decimal, with the formatter's sign, width, and zero padding
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let n = BigInt::from(10).pow(25) + BigInt::from(7);
+    /// assert_eq!(n.to_string(), "10000000000000000000000007");
+    /// assert_eq!(format!("{:+}", BigInt::from(42)), "+42");
+    /// assert_eq!(format!("{:08}", BigInt::from(-42)), "-0000042");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 10^19 is the largest power of ten in a limb
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = Vec::new();
+        while !magnitude.is_empty() {
+            chunks.push(divide_small(&mut magnitude, CHUNK));
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl fmt::LowerHex for BigInt {
+    /// the magnitude in hex after a minus sign, not two's complement
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "0x", &self.power_of_two_digits(4, false))
+    }
+}
+
+impl fmt::UpperHex for BigInt {
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(format!("{:X}", BigInt::from(-0xBEEF)), "-BEEF");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "0x", &self.power_of_two_digits(4, true))
+    }
+}
+
+impl fmt::Binary for BigInt {
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// assert_eq!(format!("{:#b}", BigInt::from(5) << 64), format!("0b101{}", "0".repeat(64)));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "0b", &self.power_of_two_digits(1, false))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseError;
+
+    /// decimal digits after an optional sign, as `Display` writes them
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let n: BigInt = "-98765432109876543210987654321".parse().unwrap();
+    /// assert_eq!(n.to_string().parse(), Ok(n));
+    /// assert_eq!("+0".parse(), Ok(BigInt::ZERO));
+    /// assert_eq!("-".parse::<BigInt>().unwrap_err().to_string(), "missing digits at byte 1");
+    /// ```
+    fn from_str(source: &str) -> Result<Self, ParseError> {
+        Self::from_str_radix(source, 10)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigInt {
+    /// as its decimal string, which any size fits in
+    /// ```
+    /// # use vmath::numbers::BigInt;
+    /// let n = BigInt::from(-7).pow(40);
+    /// let json = serde_json::to_string(&n).unwrap();
+    /// assert_eq!(json, format!("\"{n}\""));
+    /// assert_eq!(serde_json::from_str::<BigInt>(&json).unwrap(), n);
+    /// ```
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigInt {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,206 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+use crate::numbers::{Complex, Real, Real32};
+
+/// the numbers a `KahanSum` can hold, whose sums and products split exactly into
+/// the rounded result and the rounding error, by knuth's two-sum and a fused multiply-add.
+/// complex numbers do it part by part, though their products round the small error part
+/// ```
+/// # use vmath::numbers::ErrorFree;
+/// let (sum, error) = 1e16.two_sum(1.0);
+/// assert_eq!((sum, error), (1e16, 1.0));
+/// let (product, error) = (1.0 + f64::EPSILON).two_product(1.0 - f64::EPSILON);
+/// assert_eq!((product, error), (1.0, -f64::EPSILON * f64::EPSILON));
+/// ```
+pub trait ErrorFree: Copy + Add<Output = Self> {
+    const ZERO: Self;
+
+    /// (self + rhs rounded, the error in that), which sum to self + rhs exactly
+    fn two_sum(self, rhs: Self) -> (Self, Self);
+    /// (self rhs rounded, the error in that), which sum to self rhs exactly
+    fn two_product(self, rhs: Self) -> (Self, Self);
+}
+
+macro_rules! error_free {
+    ($($float:ident),*) => {
+        $(
+            impl ErrorFree for $float {
+                const ZERO: Self = 0.0;
+
+                #[inline]
+                fn two_sum(self, rhs: Self) -> (Self, Self) {
+                    // without the branch on magnitudes of fast two-sum
+                    let sum = self + rhs;
+                    let rhs_part = sum - self;
+                    let error = (self - (sum - rhs_part)) + (rhs - rhs_part);
+                    (sum, error)
+                }
+
+                #[inline]
+                fn two_product(self, rhs: Self) -> (Self, Self) {
+                    let product = self * rhs;
+                    (product, self.mul_add(rhs, -product))
+                }
+            }
+
+            impl ErrorFree for Complex<$float> {
+                const ZERO: Self = Complex::new(0.0, 0.0);
+
+                #[inline]
+                fn two_sum(self, rhs: Self) -> (Self, Self) {
+                    let (real, real_error) = self.real.two_sum(rhs.real);
+                    let (imaginary, imaginary_error) = self.imaginary.two_sum(rhs.imaginary);
+                    (Complex::new(real, imaginary), Complex::new(real_error, imaginary_error))
+                }
+
+                #[inline]
+                fn two_product(self, rhs: Self) -> (Self, Self) {
+                    let part = |a: $float, b: $float, c: $float, d: $float| {
+                        // a b + c d from two exact products and an exact sum
+                        let (ab, ab_error) = a.two_product(b);
+                        let (cd, cd_error) = c.two_product(d);
+                        let (sum, sum_error) = ab.two_sum(cd);
+                        (sum, ab_error + cd_error + sum_error)
+                    };
+                    let (real, real_error) = part(self.real, rhs.real, -self.imaginary, rhs.imaginary);
+                    let (imaginary, imaginary_error) =
+                        part(self.real, rhs.imaginary, self.imaginary, rhs.real);
+                    (Complex::new(real, imaginary), Complex::new(real_error, imaginary_error))
+                }
+            }
+        )*
+    };
+}
+
+error_free!(Real, Real32);
+
+/// a running sum that carries the rounding error of every addition alongside it,
+/// neumaier's improvement of kahan's summation, so the total is as accurate as if
+/// it were summed in twice the precision and then rounded, whatever the order
+/// or the cancellation, where a plain sum's error grows with the count
+/// ```
+/// # use vmath::numbers::KahanSum;
+/// // a tenth a million times, which a plain loop gets wrong in the tenth digit
+/// let plain: f64 = std::iter::repeat_n(0.1, 1_000_000).sum();
+/// assert!((plain - 100_000.0).abs() > 1e-6);
+/// let mut sum = KahanSum::new();
+/// for _ in 0..1_000_000 {
+///     sum += 0.1;
+/// }
+/// assert_eq!(sum.total(), 100_000.0);
+/// // the large terms cancel and leave the small ones, which kahan's original loses
+/// let sum: KahanSum = [1.0, 1e100, 1.0, -1e100].into_iter().collect();
+/// assert_eq!(sum.total(), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KahanSum<T = Real> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: ErrorFree> KahanSum<T> {
+    pub const fn new() -> Self {
+        Self {
+            sum: T::ZERO,
+            compensation: T::ZERO,
+        }
+    }
+
+    pub fn push(&mut self, x: T) {
+        let (sum, error) = self.sum.two_sum(x);
+        self.sum = sum;
+        self.compensation = self.compensation + error;
+    }
+
+    /// adds a b, with the rounding error of the product kept as well as of the sum
+    /// ```
+    /// # use vmath::numbers::KahanSum;
+    /// let mut sum = KahanSum::new();
+    /// sum.push_product(1.0 + f64::EPSILON, 1.0 - f64::EPSILON);
+    /// sum.push(-1.0);
+    /// assert_eq!(sum.total(), -f64::EPSILON * f64::EPSILON);
+    /// ```
+    pub fn push_product(&mut self, a: T, b: T) {
+        let (product, product_error) = a.two_product(b);
+        self.push(product);
+        self.compensation = self.compensation + product_error;
+    }
+
+    /// the sum so far, with its carried error added back
+    pub fn total(&self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+impl<T: ErrorFree> Default for KahanSum<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ErrorFree> AddAssign<T> for KahanSum<T> {
+    fn add_assign(&mut self, x: T) {
+        self.push(x);
+    }
+}
+
+impl<T: ErrorFree> Extend<T> for KahanSum<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T: ErrorFree> FromIterator<T> for KahanSum<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+impl<T: ErrorFree> Sum<T> for KahanSum<T> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+/// the sum of the slice by `KahanSum`, for reals or complex numbers
+/// ```
+/// # use vmath::numbers::{sum_compensated, Complex};
+/// let xs: Vec<f64> = (1..=10_000).map(|n| 1.0 / n as f64).collect();
+/// // the exact sum of the rounded terms, where the plain sum is off in the last few bits
+/// assert_eq!(sum_compensated(&xs), 9.787606036044382);
+/// assert_eq!(xs.iter().sum::<f64>(), 9.787606036044348);
+/// let zs = [Complex::new(1e20, 1.0), Complex::new(1.0, 1e-20), Complex::new(-1e20, -1.0)];
+/// assert_eq!(sum_compensated(&zs), Complex::new(1.0, 1e-20));
+/// ```
+pub fn sum_compensated<T: ErrorFree>(xs: &[T]) -> T {
+    xs.iter().copied().collect::<KahanSum<T>>().total()
+}
+
+/// the sum of a_i b_i with every product and sum's rounding error carried,
+/// ogita, rump, and oishi's dot2, as accurate as a dot product in twice the precision.
+/// complex numbers are not conjugated, so conjugate a first for the inner product.
+/// panics when the lengths differ
+/// ```
+/// # use vmath::numbers::{dot_compensated, Complex};
+/// // an ill-conditioned dot product, whose plain evaluation is all rounding error
+/// let a = [1e16, 1.0, -1e16, 1e-16];
+/// let b = [1.0 + f64::EPSILON, 1.0, 1.0, 1.0];
+/// assert_eq!(a.iter().zip(&b).map(|(x, y)| x * y).sum::<f64>(), 4.0);
+/// assert_eq!(dot_compensated(&a, &b), 3.220446049250313);
+/// let z = [Complex::new(3.0, 4.0)];
+/// assert_eq!(dot_compensated(&z, &[z[0].conjugate()]), Complex::new(25.0, 0.0));
+/// ```
+pub fn dot_compensated<T: ErrorFree>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len(), "slice lengths differ");
+    let mut sum = KahanSum::new();
+    for (&x, &y) in a.iter().zip(b) {
+        sum.push_product(x, y);
+    }
+    sum.total()
+}
@@ -1,43 +1,267 @@
+use std::fmt::{self, Write};
+use std::iter::{Product, Sum};
 use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
 
-use super::Real;
+use super::{Float, Real, Real32, Scalar};
+use crate::linalg::Mat2;
+use crate::Error;
+use crate::ParseError;
 
-pub const I: Complex = Complex {
-    real: 0.0,
-    imaginary: 1.0,
-};
+/// the imaginary unit, also `Complex::I`
+pub const I: Complex = Complex::I;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 /// a + bi
-/// where a and b are real numbers
-pub struct Complex {
-    pub real: Real,
-    pub imaginary: Real,
+/// where a and b are real numbers, `Real` unless another `Scalar` is given.
+/// any scalar has the arithmetic operators, any `Float` the norm, angle, polar form,
+/// and exponential, and the rest of the functions are for `Real` parts
+#[repr(C)]
+pub struct Complex<T = Real> {
+    pub real: T,
+    pub imaginary: T,
 }
 
-impl Complex {
+/// single precision, with the arithmetic operators and conversions to and from
+/// `Complex`, which widens exactly and narrows by rounding each part
+/// ```
+/// # use vmath::numbers::{Complex, Complex32};
+/// let z = Complex32::new(1.5, -2.0);
+/// let w = Complex32::new(0.5, 0.5);
+/// assert_eq!(z * w, Complex32::new(1.75, -0.25));
+/// assert_eq!(z * w / w, z);
+/// assert_eq!(2.0 * z - Complex32::from(1.0), Complex32::new(2.0, -4.0));
+///
+/// let wide = Complex::from(z);
+/// assert_eq!(wide, Complex::new(1.5, -2.0));
+/// assert_eq!(wide.to_complex32(), z);
+/// assert_eq!(Complex::new(0.1, 1e300).to_complex32(), Complex32::new(0.1, f32::INFINITY));
+/// ```
+pub type Complex32 = Complex<Real32>;
+
+impl<T> Complex<T> {
     /// ```
     /// # use vmath::numbers::Complex;
     /// let z = Complex::new(1.0, 2.0);
     /// assert_eq!(z.real, 1.0);
     /// assert_eq!(z.imaginary, 2.0);
+    /// let single: Complex<f32> = Complex::new(1.0, 2.0);
+    /// assert_eq!(single.imaginary, 2.0f32);
     /// ```
-    pub fn new(real: Real, imaginary: Real) -> Self {
+    #[inline]
+    pub const fn new(real: T, imaginary: T) -> Self {
         Self { real, imaginary }
     }
+}
 
+/// the functions that need only what any `Float` has, so `Complex32` shares them
+impl<T: Float> Complex<T> {
+    /// the argument, in (-pi, pi], an error for zero which has none
     /// ```
     /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
     /// # use std::f64::consts::PI;
     /// let z = Complex::new(3.0_f64.sqrt() / 2.0, 0.5);
-    /// let angle = z.angle();
+    /// let angle = z.angle().unwrap();
     /// assert!((angle - PI / 6.0).abs() < f64::EPSILON);
+    ///
+    /// // one in each quadrant
+    /// assert_eq!(Complex::new(1.0, 1.0).angle(), Ok(PI / 4.0));
+    /// assert_eq!(Complex::new(-1.0, 1.0).angle(), Ok(3.0 * PI / 4.0));
+    /// assert_eq!(Complex::new(-1.0, -1.0).angle(), Ok(-3.0 * PI / 4.0));
+    /// assert_eq!(Complex::new(1.0, -1.0).angle(), Ok(-PI / 4.0));
+    ///
+    /// // and on each axis, where imaginary / real would be 0 or infinite
+    /// assert_eq!(Complex::new(2.0, 0.0).angle(), Ok(0.0));
+    /// assert_eq!(Complex::new(0.0, 2.0).angle(), Ok(PI / 2.0));
+    /// assert_eq!(Complex::new(-1.0, 0.0).angle(), Ok(PI));
+    /// assert_eq!(Complex::new(-1.0, -0.0).angle(), Ok(PI));
+    /// assert_eq!(Complex::new(0.0, -2.0).angle(), Ok(-PI / 2.0));
+    /// assert_eq!(Complex::new(0.0, 0.0).angle(), Err(Error::DomainError));
+    /// ```
+    pub fn angle(self) -> Result<T, Error> {
+        if self.real == T::ZERO && self.imaginary == T::ZERO {
+            return Err(Error::DomainError);
+        }
+        // atan2 gives -pi below the cut, for a negative zero imaginary part
+        let angle = self.imaginary.atan2(self.real);
+        Ok(if angle == -T::PI { T::PI } else { angle })
+    }
+
+    /// r e^(i theta), the complex number with norm r and angle theta
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let z = Complex::from_polar(2.0, FRAC_PI_2);
+    /// assert!(z.real.abs() < 1e-15);
+    /// assert_eq!(z.imaginary, 2.0);
+    /// ```
+    #[inline]
+    pub fn from_polar(r: T, theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    /// (norm, angle), the inverse of `from_polar`, an error for zero which has no angle
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// # use std::f64::consts::PI;
+    /// assert_eq!(Complex::new(-3.0, 0.0).to_polar(), Ok((3.0, PI)));
+    /// let z = Complex::new(1.0, -2.0);
+    /// let (r, theta) = z.to_polar().unwrap();
+    /// let back = Complex::from_polar(r, theta);
+    /// assert!((back - z).norm() < 1e-15);
+    /// assert_eq!(Complex::new(0.0, 0.0).to_polar(), Err(Error::DomainError));
+    /// ```
+    #[inline]
+    pub fn to_polar(self) -> Result<(T, T), Error> {
+        Ok((self.norm(), self.angle()?))
+    }
+
+    /// the norm, |z|, under the name polar form uses
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(-5.0, 12.0).magnitude(), 13.0);
+    /// ```
+    #[inline]
+    pub fn magnitude(self) -> T {
+        self.norm()
+    }
+
+    /// z / |z|, on the unit circle at z's angle, NaN for zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(0.0, -4.0).unit(), Complex::new(0.0, -1.0));
+    /// assert_eq!(Complex::new(3.0, 4.0).unit(), Complex::new(0.6, 0.8));
+    /// ```
+    #[inline]
+    pub fn unit(self) -> Self {
+        self / self.norm()
+    }
+
+    /// an error rather than NaN for zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// assert_eq!(Complex::new(2.0, 0.0).try_unit(), Ok(Complex::new(1.0, 0.0)));
+    /// assert_eq!(Complex::new(0.0, 0.0).try_unit(), Err(Error::DivisionByZero));
     /// ```
-    pub fn angle(self) -> Real {
-        if self.real == 0.0 {
-            return Real::NAN;
+    #[inline]
+    pub fn try_unit(self) -> Result<Self, Error> {
+        let norm = self.norm();
+        if norm == T::ZERO {
+            return Err(Error::DivisionByZero);
         }
-        (self.imaginary / self.real).atan()
+        Ok(self / norm)
+    }
+
+    /// None for zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(0.0, 0.0).checked_unit(), None);
+    /// ```
+    #[inline]
+    pub fn checked_unit(self) -> Option<Self> {
+        self.try_unit().ok()
+    }
+
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(3.0, 4.0);
+    /// let norm = z.norm();
+    /// assert_eq!(norm, 5.0);
+    /// // by hypot, so neither overflowing nor underflowing in the squares
+    /// let (big, small): (Complex, Complex) = (Complex::new(3e200, 4e200), Complex::new(3e-200, 4e-200));
+    /// assert!((big.norm() / 5e200 - 1.0).abs() < 1e-15);
+    /// assert!((small.norm() / 5e-200 - 1.0).abs() < 1e-15);
+    /// assert_eq!(Complex::new(f64::MAX, 0.0).norm(), f64::MAX);
+    /// assert_eq!(Complex::new(f64::MAX, f64::MAX).norm(), f64::INFINITY);
+    /// assert_eq!(Complex::new(-f64::MIN_POSITIVE, 0.0).norm(), f64::MIN_POSITIVE);
+    /// assert_eq!(Complex::new(3.0f32, -4.0).norm(), 5.0f32);
+    /// // subnormal parts
+    /// assert_eq!(Complex::new(3e-320, 4e-320).norm(), 5e-320);
+    /// // while the square in norm_squared is out of range
+    /// assert_eq!(Complex::new(1e200, 0.0).norm_squared(), f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn norm(self) -> T {
+        self.real.hypot(self.imaginary)
+    }
+
+    /// e^z = e^a (cos b + i sin b) for z = a + bi
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::PI;
+    /// // euler's identity
+    /// let z = Complex::new(0.0, PI).exp();
+    /// assert_eq!(z.real, -1.0);
+    /// assert!(z.imaginary.abs() < 1e-15);
+    /// assert_eq!(Complex::new(1.0, 0.0).exp(), Complex::new(1f64.exp(), 0.0));
+    /// ```
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.real.exp(), self.imaginary)
+    }
+}
+
+impl Complex {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 0.0);
+    /// the imaginary unit
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+    /// assert_eq!(Complex::I, vmath::numbers::complex::I);
+    /// ```
+    pub const I: Self = Self::new(0.0, 1.0);
+    /// both parts NaN, what an undefined operation gives
+    pub const NAN: Self = Self::new(Real::NAN, Real::NAN);
+
+    /// the straight line from self at t = 0 to other at t = 1
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let a = Complex::new(1.0, 0.0);
+    /// let b = Complex::new(-1.0, 4.0);
+    /// assert_eq!(a.lerp(b, 0.5), Complex::new(0.0, 2.0));
+    /// assert_eq!(a.lerp(b, 1.0), b);
+    /// ```
+    #[inline]
+    pub fn lerp(self, other: Self, t: Real) -> Self {
+        self + (other - self) * t
+    }
+
+    /// interpolates the magnitude linearly and turns the angle the short way round,
+    /// so a phasor keeps circling rather than cutting through the origin.
+    /// zero has no angle and takes the other's. halfway between opposites it turns
+    /// counterclockwise
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::PI;
+    /// let a = Complex::new(1.0, 0.0);
+    /// let b = Complex::new(0.0, 3.0);
+    /// let halfway = a.slerp(b, 0.5);
+    /// assert!((halfway.norm() - 2.0).abs() < 1e-15);
+    /// assert!((halfway.angle().unwrap() - PI / 4.0).abs() < 1e-15);
+    ///
+    /// // the short way from 170 to -170 degrees passes through 180
+    /// let a = Complex::from_polar(1.0, PI * 17.0 / 18.0);
+    /// let b = Complex::from_polar(1.0, -PI * 17.0 / 18.0);
+    /// assert!((a.slerp(b, 0.5) - Complex::new(-1.0, 0.0)).norm() < 1e-15);
+    ///
+    /// let zero = Complex::ZERO;
+    /// assert!((zero.slerp(b, 0.5) - b * 0.5).norm() < 1e-15);
+    /// ```
+    pub fn slerp(self, other: Self, t: Real) -> Self {
+        let magnitude = self.norm() + (other.norm() - self.norm()) * t;
+        let start = self.angle().or(other.angle()).unwrap_or(0.0);
+        let turn = (other * self.conjugate()).angle().unwrap_or(0.0);
+        Self::from_polar(magnitude, start + turn * t)
     }
 
     /// ```
@@ -46,7 +270,8 @@ impl Complex {
     /// let conjugate = z.conjugate();
     /// assert_eq!(conjugate, Complex::new(1.0, -2.0));
     /// ```
-    pub fn conjugate(mut self) -> Self {
+    #[inline]
+    pub const fn conjugate(mut self) -> Self {
         self.imaginary *= -1.0;
         self
     }
@@ -57,31 +282,686 @@ impl Complex {
     /// let norm_squared = z.norm_squared();
     /// assert_eq!(norm_squared, 25.0);
     /// ```
-    pub fn norm_squared(self) -> Real {
+    #[inline]
+    pub const fn norm_squared(self) -> Real {
         self.real * self.real + self.imaginary * self.imaginary
     }
 
+    /// 1 / z as conjugate / norm_squared, with z first divided by the larger of
+    /// |re| and |im|, so the square neither overflows past 1e154 nor underflows
+    /// below 1e-154, agreeing with `Complex::from(1.0) / z` to rounding
     /// ```
     /// # use vmath::numbers::Complex;
-    /// let z = Complex::new(3.0, 4.0);
-    /// let norm = z.norm();
-    /// assert_eq!(norm, 5.0);
+    /// let z = Complex::new(3.0, -4.0);
+    /// assert_eq!(z.recip(), Complex::new(0.12, 0.16));
+    /// assert!((z.recip() - Complex::from(1.0) / z).norm() < 1e-17);
+    /// assert_eq!(Complex::new(1e200, 0.0).recip().real, 1e-200);
+    /// assert_eq!(Complex::new(0.0, -1e-200).recip(), Complex::new(0.0, 1e200));
+    /// assert_eq!(Complex::new(5e-324, 0.0).recip().real, f64::INFINITY);
+    /// let w = Complex::new(3e160, 4e160);
+    /// assert!((w.recip() - Complex::new(1.2e-161, -1.6e-161)).norm() < 1e-176);
     /// ```
-    pub fn norm(self) -> Real {
-        self.norm_squared().sqrt()
+    #[inline]
+    pub fn recip(self) -> Self {
+        let scale = self.real.abs().max(self.imaginary.abs());
+        let unit = Self::new(self.real / scale, self.imaginary / scale);
+        unit.conjugate()
+            .scaled((unit.norm_squared() * scale).recip())
+    }
+
+    /// whether either part is NaN
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert!(Complex::new(1.0, f64::NAN).is_nan());
+    /// assert!(Complex::NAN.is_nan());
+    /// assert!(!Complex::new(f64::INFINITY, 0.0).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.real.is_nan() || self.imaginary.is_nan()
+    }
+
+    /// whether either part is infinite and neither is NaN,
+    /// so exactly one of `is_finite`, `is_infinite`, and `is_nan` holds
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert!(Complex::new(f64::NEG_INFINITY, 2.0).is_infinite());
+    /// assert!(!Complex::new(f64::INFINITY, f64::NAN).is_infinite());
+    /// // dividing by a zero real makes the zero part 0 / 0
+    /// assert!((Complex::ONE / 0.0).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.real.is_infinite() || self.imaginary.is_infinite())
+    }
+
+    /// whether both parts are finite, neither infinite nor NaN
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert!(Complex::new(1e308, -1e-320).is_finite());
+    /// assert!(!Complex::new(1e308, 0.0).powi(2).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.real.is_finite() && self.imaginary.is_finite()
+    }
+
+    /// a total order, by real part and then imaginary part, each compared with
+    /// `f64::total_cmp`, so -0 comes before +0 and NaNs sort to the ends by sign.
+    /// `OrdComplex` wraps a complex number with this order
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::cmp::Ordering;
+    /// let mut zs = [Complex::I, Complex::ONE, -Complex::I, Complex::ZERO];
+    /// zs.sort_by(Complex::total_cmp);
+    /// assert_eq!(zs, [-Complex::I, Complex::ZERO, Complex::I, Complex::ONE]);
+    /// assert_eq!(Complex::new(-0.0, 1.0).total_cmp(&Complex::new(0.0, 0.0)), Ordering::Less);
+    /// assert_eq!(Complex::NAN.total_cmp(&Complex::NAN), Ordering::Equal);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.real
+            .total_cmp(&other.real)
+            .then(self.imaginary.total_cmp(&other.imaginary))
+    }
+
+    /// whether both parts are normal, none of zero, subnormal, infinite, or NaN,
+    /// so a real like 1 + 0i is not
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert!(Complex::new(1.0, -2.0).is_normal());
+    /// assert!(!Complex::ONE.is_normal());
+    /// assert!(!Complex::new(1.0, 1e-320).is_normal());
+    /// ```
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        self.real.is_normal() && self.imaginary.is_normal()
+    }
+
+    /// self / rhs, an error rather than infinities and NaN when rhs is zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// let z = Complex::new(2.0, 4.0);
+    /// assert_eq!(z.try_div(Complex::new(0.0, 2.0)), Ok(Complex::new(2.0, -1.0)));
+    /// assert_eq!(z.try_div(Complex::new(0.0, 0.0)), Err(Error::DivisionByZero));
+    /// ```
+    #[inline]
+    pub fn try_div(self, rhs: Self) -> Result<Self, Error> {
+        if rhs.real == 0.0 && rhs.imaginary == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self / rhs)
+    }
+
+    /// self / rhs, None when rhs is zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(2.0, 4.0);
+    /// assert_eq!(z.checked_div(Complex::new(2.0, 0.0)), Some(Complex::new(1.0, 2.0)));
+    /// assert_eq!(z.checked_div(Complex::new(0.0, 0.0)), None);
+    /// ```
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.try_div(rhs).ok()
+    }
+
+    /// 1 / z, an error rather than infinities and NaN when z is zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::Error;
+    /// assert_eq!(Complex::new(0.0, 2.0).try_recip(), Ok(Complex::new(0.0, -0.5)));
+    /// assert_eq!(Complex::new(-0.0, 0.0).try_recip(), Err(Error::DivisionByZero));
+    /// ```
+    #[inline]
+    pub fn try_recip(self) -> Result<Self, Error> {
+        if self.real == 0.0 && self.imaginary == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.recip())
+    }
+
+    /// 1 / z, None when z is zero
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(3.0, -4.0).checked_recip(), Some(Complex::new(0.12, 0.16)));
+    /// assert_eq!(Complex::ZERO.checked_recip(), None);
+    /// ```
+    #[inline]
+    pub fn checked_recip(self) -> Option<Self> {
+        self.try_recip().ok()
+    }
+
+    /// [[a, -b], [b, a]], the matrix multiplying by z is on the plane,
+    /// so sums and products of complex numbers are those of their matrices
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::linalg::{Mat2, Vec2};
+    /// let z = Complex::new(1.0, 2.0);
+    /// let w = Complex::new(3.0, -1.0);
+    /// assert_eq!(z.to_matrix(), Mat2::new([[1.0, -2.0], [2.0, 1.0]]));
+    /// assert_eq!((z * w).to_matrix(), z.to_matrix() * w.to_matrix());
+    /// assert_eq!(z.to_matrix() * Vec2::new(3.0, -1.0), Vec2::new(5.0, 5.0));
+    /// ```
+    pub const fn to_matrix(self) -> Mat2 {
+        let Self {
+            real: a,
+            imaginary: b,
+        } = self;
+        Mat2::new([[a, -b], [b, a]])
+    }
+
+    /// the inverse of `to_matrix`, and for any other matrix the complex number
+    /// whose matrix is nearest it, averaging the pairs that should agree
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use vmath::linalg::Mat2;
+    /// let z = Complex::new(1.0, 2.0);
+    /// assert_eq!(Complex::from_matrix(z.to_matrix()), z);
+    /// // a shear is no complex multiple, its nearest is
+    /// let shear = Mat2::new([[1.0, 1.0], [0.0, 1.0]]);
+    /// assert_eq!(Complex::from_matrix(shear), Complex::new(1.0, -0.5));
+    /// ```
+    pub fn from_matrix(matrix: Mat2) -> Self {
+        let [[a, minus_b], [b, d]] = matrix.rows;
+        Self::new((a + d) / 2.0, (b - minus_b) / 2.0)
+    }
+
+    /// each part rounded to single precision, overflowing to infinity
+    /// and underflowing to zero outside its range
+    #[inline]
+    pub fn to_complex32(self) -> Complex32 {
+        Complex32::new(self.real as Real32, self.imaginary as Real32)
+    }
+
+    /// the principal natural logarithm, ln|z| + i angle(z), with the angle in (-pi, pi]
+    /// so the branch cut runs along the negative real axis. ln 0 is -infinity
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::PI;
+    /// assert_eq!(Complex::new(-1.0, 0.0).ln(), Complex::new(0.0, PI));
+    /// let z = Complex::new(1.0, 2.0);
+    /// assert!((z.ln().exp() - z).norm() < 1e-15);
+    /// assert_eq!(Complex::new(0.0, 0.0).ln().real, f64::NEG_INFINITY);
+    /// ```
+    #[inline]
+    pub fn ln(self) -> Self {
+        Self::new(self.norm().ln(), self.angle().unwrap_or(0.0))
+    }
+
+    /// the logarithm to a real base, ln(z) / ln(base), on the principal branch
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.0, 100.0);
+    /// let log = z.log(10.0);
+    /// assert!((log.real - 2.0).abs() < 1e-15);
+    /// assert!((log.imaginary - std::f64::consts::FRAC_PI_2 / 10f64.ln()).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn log(self, base: Real) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// the logarithm on branch k, ln(z) + 2 pi k i, whose imaginary part is in
+    /// (-pi + 2 pi k, pi + 2 pi k], k = 0 being the principal branch
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::PI;
+    /// let z = Complex::new(0.0, 1.0);
+    /// assert_eq!(z.ln_branch(0), z.ln());
+    /// assert_eq!(z.ln_branch(1), Complex::new(0.0, PI / 2.0 + 2.0 * PI));
+    /// assert_eq!(z.ln_branch(-1).exp().imaginary, 1.0);
+    /// ```
+    #[inline]
+    pub fn ln_branch(self, k: i32) -> Self {
+        let ln = self.ln();
+        Self::new(
+            ln.real,
+            ln.imaginary + 2.0 * std::f64::consts::PI * Real::from(k),
+        )
+    }
+
+    /// z^n by repeated squaring, in about log2 |n| multiplications,
+    /// and exact where the products are, as for gaussian integers
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(1.0, 1.0);
+    /// assert_eq!(z.powi(8), Complex::new(16.0, 0.0));
+    /// assert_eq!(z.powi(-2), Complex::new(0.0, -0.5));
+    /// assert_eq!(z.powi(0), Complex::new(1.0, 0.0));
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        let mut power = n.unsigned_abs();
+        let mut base = self;
+        let mut result = Self::ONE;
+        while power > 0 {
+            if power & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            power >>= 1;
+        }
+        if n < 0 {
+            Self::ONE / result
+        } else {
+            result
+        }
+    }
+
+    /// z^x on the principal branch, |z|^x e^(i x angle(z)) in polar form.
+    /// zero to a power is what it is for reals, so 0^0 is 1
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(-4.0, 0.0);
+    /// let root = z.powf(0.5);
+    /// assert!(root.real.abs() < 1e-15);
+    /// assert!((root.imaginary - 2.0).abs() < 1e-15);
+    /// assert_eq!(Complex::new(0.0, 0.0).powf(2.5), Complex::new(0.0, 0.0));
+    /// ```
+    pub fn powf(self, x: Real) -> Self {
+        match self.to_polar() {
+            Ok((r, theta)) => Self::from_polar(r.powf(x), theta * x),
+            Err(_) => Self::new(0.0_f64.powf(x), 0.0),
+        }
+    }
+
+    /// z^w on the principal branch, e^(w ln z).
+    /// zero to a power with positive real part is zero, to zero is one, and otherwise NaN
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::PI;
+    /// // i^i is real
+    /// let i = Complex::new(0.0, 1.0);
+    /// let z = i.powc(i);
+    /// assert!((z.real - (-PI / 2.0).exp()).abs() < 1e-15);
+    /// assert_eq!(z.imaginary, 0.0);
+    /// assert_eq!(Complex::new(0.0, 0.0).powc(Complex::new(2.0, 1.0)), Complex::new(0.0, 0.0));
+    /// assert!(Complex::new(0.0, 0.0).powc(i).real.is_nan());
+    /// ```
+    pub fn powc(self, w: Self) -> Self {
+        if self.real == 0.0 && self.imaginary == 0.0 {
+            return if w.real > 0.0 {
+                Self::ZERO
+            } else if w.real == 0.0 && w.imaginary == 0.0 {
+                Self::ONE
+            } else {
+                Self::NAN
+            };
+        }
+        (w * self.ln()).exp()
+    }
+
+    /// the principal square root, with a non-negative real part and
+    /// on the negative real axis the positive imaginary one, as angle(z) / 2 says.
+    /// it avoids the cancellation in sqrt((|z| - a) / 2) for the smaller part
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+    /// assert_eq!(Complex::new(0.0, 2.0).sqrt(), Complex::new(1.0, 1.0));
+    /// assert_eq!(Complex::new(3.0, -4.0).sqrt(), Complex::new(2.0, -1.0));
+    /// // a tiny imaginary part survives
+    /// let z = Complex::new(1.0, 1e-20).sqrt();
+    /// assert_eq!(z.imaginary, 5e-21);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if self.real == 0.0 && self.imaginary == 0.0 {
+            return Self::ZERO;
+        }
+        let half = (self.norm() + self.real.abs()) / 2.0;
+        let large = half.sqrt();
+        let small = self.imaginary / (2.0 * large);
+        if self.real >= 0.0 {
+            Self::new(large, small)
+        } else if self.imaginary < 0.0 {
+            Self::new(small.abs(), -large)
+        } else {
+            Self::new(small.abs(), large)
+        }
+    }
+
+    /// the n nth roots, |z|^(1/n) e^(i (angle(z) + 2 pi k) / n) for k = 0 to n - 1,
+    /// evenly spaced in angle counter-clockwise from the principal root
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let roots: Vec<Complex> = Complex::new(8.0, 0.0).nth_roots(3).collect();
+    /// assert_eq!(roots.len(), 3);
+    /// assert_eq!(roots[0], Complex::new(2.0, 0.0));
+    /// for root in roots {
+    ///     assert!((root.powi(3) - Complex::new(8.0, 0.0)).norm() < 1e-13);
+    /// }
+    /// // the fourth roots of unity
+    /// let units: Vec<Complex> = Complex::new(1.0, 0.0).nth_roots(4).collect();
+    /// assert!((units[1] - Complex::new(0.0, 1.0)).norm() < 1e-15);
+    /// ```
+    pub fn nth_roots(self, n: u32) -> impl Iterator<Item = Self> {
+        let (r, theta) = self.to_polar().unwrap_or((0.0, 0.0));
+        let n_real = Real::from(n);
+        let r = r.powf(n_real.recip());
+        (0..n).map(move |k| {
+            let angle = (theta + 2.0 * std::f64::consts::PI * Real::from(k)) / n_real;
+            Self::from_polar(r, angle)
+        })
+    }
+
+    /// the n nth roots of unity, e^(2 pi i k / n) for k = 0 to n - 1, counter-clockwise from 1,
+    /// the twiddle factors of a discrete fourier transform. each is worked out in the
+    /// first quadrant and turned by whole quarters, so those on the axes are exact
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let roots: Vec<Complex> = Complex::roots_of_unity(4).collect();
+    /// assert_eq!(roots, [Complex::ONE, Complex::I, -Complex::ONE, -Complex::I]);
+    /// for root in Complex::roots_of_unity(7) {
+    ///     assert!((root.powi(7) - Complex::ONE).norm() < 1e-14);
+    /// }
+    /// let sum: Complex = Complex::roots_of_unity(12).sum();
+    /// assert!(sum.norm() < 1e-15);
+    /// ```
+    pub fn roots_of_unity(n: u32) -> impl Iterator<Item = Self> {
+        Self::roots_of_unity_scaled(n, 1.0)
+    }
+
+    /// the n nth roots of unity times radius, n points evenly spaced
+    /// round the circle of that radius, starting on the positive real axis
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let points: Vec<Complex> = Complex::roots_of_unity_scaled(2, 3.0).collect();
+    /// assert_eq!(points, [Complex::new(3.0, 0.0), Complex::new(-3.0, 0.0)]);
+    /// ```
+    pub fn roots_of_unity_scaled(n: u32, radius: Real) -> impl Iterator<Item = Self> {
+        (0..n).map(move |k| {
+            // 2 pi k / n is `quarter` whole quarter-turns and rem / n of one more
+            let quarters = 4 * u64::from(k);
+            let (quarter, rem) = (quarters / u64::from(n), quarters % u64::from(n));
+            let angle = std::f64::consts::FRAC_PI_2 * rem as Real / Real::from(n);
+            let (sin, cos) = angle.sin_cos();
+            let (real, imaginary) = match quarter {
+                0 => (cos, sin),
+                1 => (-sin, cos),
+                2 => (-cos, -sin),
+                _ => (sin, -cos),
+            };
+            Self::new(radius * real, radius * imaginary)
+        })
+    }
+
+    /// sin(a + bi) = sin a cosh b + i cos a sinh b
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.5, 0.0);
+    /// assert_eq!(z.sin(), Complex::new(0.5f64.sin(), 0.0));
+    /// // sin(ix) = i sinh(x)
+    /// assert_eq!(Complex::new(0.0, 2.0).sin(), Complex::new(0.0, 2f64.sinh()));
+    /// ```
+    pub fn sin(self) -> Self {
+        let (sin, cos) = self.real.sin_cos();
+        Self::new(sin * self.imaginary.cosh(), cos * self.imaginary.sinh())
+    }
+
+    /// cos(a + bi) = cos a cosh b - i sin a sinh b
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(1.0, -2.0);
+    /// // sin^2 + cos^2 = 1 off the real axis too
+    /// let one = z.sin() * z.sin() + z.cos() * z.cos();
+    /// assert!((one - Complex::new(1.0, 0.0)).norm() < 1e-14);
+    /// ```
+    pub fn cos(self) -> Self {
+        let (sin, cos) = self.real.sin_cos();
+        Self::new(cos * self.imaginary.cosh(), -sin * self.imaginary.sinh())
     }
-}
 
-impl From<Real> for Complex {
-    fn from(real: Real) -> Complex {
-        Complex {
-            real,
-            imaginary: 0.0,
+    /// tan(a + bi) = (sin 2a + i sinh 2b) / (cos 2a + cosh 2b), which unlike sin / cos
+    /// stays finite far from the real axis, where it tends to ±i
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.3, 0.7);
+    /// assert!((z.tan() - z.sin() / z.cos()).norm() < 1e-15);
+    /// assert_eq!(Complex::new(1.0, 800.0).tan(), Complex::new(0.0, 1.0));
+    /// ```
+    pub fn tan(self) -> Self {
+        let (a, b) = (2.0 * self.real, 2.0 * self.imaginary);
+        // past here cosh 2b swamps cos 2a and the quotients would overflow,
+        // leaving sinh 2b / cosh 2b = ±1 and sin 2a / cosh 2b = 2 sin 2a e^-|2b|
+        if b.abs() > 40.0 {
+            return Self::new(2.0 * a.sin() * (-b.abs()).exp(), b.signum());
         }
+        let denominator = a.cos() + b.cosh();
+        Self::new(a.sin() / denominator, b.sinh() / denominator)
+    }
+
+    /// sinh(a + bi) = sinh a cos b + i cosh a sin b
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.4, -1.3);
+    /// let i = Complex::new(0.0, 1.0);
+    /// // sinh(iz) = i sin z
+    /// assert!(((i * z).sinh() - i * z.sin()).norm() < 1e-15);
+    /// assert_eq!(Complex::new(1.0, 0.0).sinh(), Complex::new(1f64.sinh(), 0.0));
+    /// ```
+    pub fn sinh(self) -> Self {
+        let (sin, cos) = self.imaginary.sin_cos();
+        Self::new(self.real.sinh() * cos, self.real.cosh() * sin)
+    }
+
+    /// cosh(a + bi) = cosh a cos b + i sinh a sin b
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.4, -1.3);
+    /// let i = Complex::new(0.0, 1.0);
+    /// // cosh(iz) = cos z
+    /// assert!(((i * z).cosh() - z.cos()).norm() < 1e-15);
+    /// // cosh^2 - sinh^2 = 1
+    /// let one = z.cosh() * z.cosh() - z.sinh() * z.sinh();
+    /// assert!((one - Complex::new(1.0, 0.0)).norm() < 1e-15);
+    /// ```
+    pub fn cosh(self) -> Self {
+        let (sin, cos) = self.imaginary.sin_cos();
+        Self::new(self.real.cosh() * cos, self.real.sinh() * sin)
+    }
+
+    /// tanh z = -i tan(iz), finite far from the imaginary axis, where it tends to ±1
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.4, -1.3);
+    /// let i = Complex::new(0.0, 1.0);
+    /// // tanh(iz) = i tan z
+    /// assert!(((i * z).tanh() - i * z.tan()).norm() < 1e-15);
+    /// assert!((z.tanh() - z.sinh() / z.cosh()).norm() < 1e-15);
+    /// assert_eq!(Complex::new(-900.0, 2.0).tanh(), Complex::new(-1.0, 0.0));
+    /// ```
+    pub fn tanh(self) -> Self {
+        let tan = Self::new(-self.imaginary, self.real).tan();
+        Self::new(tan.imaginary, -tan.real)
+    }
+
+    /// the principal arcsine, with real part in [-pi/2, pi/2] and cuts along the real
+    /// axis beyond ±1. kahan's form of -i ln(iz + sqrt(1 - z^2)), through sqrt(1 - z)
+    /// and sqrt(1 + z), so it neither squares z nor cancels near zero or ±1
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let z = Complex::new(2.0, 3.0);
+    /// assert!((z.asin().sin() - z).norm() < 1e-14);
+    /// assert_eq!(Complex::new(0.5, 0.0).asin(), Complex::new(0.5f64.asin(), 0.0));
+    /// // beyond the real domain, on the cut
+    /// let w = Complex::new(2.0, 0.0).asin();
+    /// assert_eq!(w.real, FRAC_PI_2);
+    /// assert!((w.imaginary.abs() - 2f64.acosh()).abs() < 1e-15);
+    /// ```
+    pub fn asin(self) -> Self {
+        let (s, t) = ((1.0 - self).sqrt(), (1.0 + self).sqrt());
+        Self::new(
+            self.real.atan2(s.real * t.real - s.imaginary * t.imaginary),
+            (s.real * t.imaginary - s.imaginary * t.real).asinh(),
+        )
+    }
+
+    /// the principal arccosine, with real part in [0, pi] and the same cuts as asin.
+    /// kahan's form, accurate near ±1 where pi/2 - asin z would cancel
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(-0.3, 1.7);
+    /// assert!((z.acos().cos() - z).norm() < 1e-14);
+    /// let near_one = Complex::new(1.0 - 1e-12, 0.0).acos();
+    /// assert!((near_one.real / (1.0f64 - 1e-12).acos() - 1.0).abs() < 1e-15);
+    /// assert_eq!(Complex::new(-1.0, 0.0).acos(), Complex::new(std::f64::consts::PI, 0.0));
+    /// ```
+    pub fn acos(self) -> Self {
+        let (s, t) = ((1.0 - self).sqrt(), (1.0 + self).sqrt());
+        Self::new(
+            2.0 * s.real.atan2(t.real),
+            (t.real * s.imaginary - t.imaginary * s.real).asinh(),
+        )
+    }
+
+    /// the principal arctangent, -i atanh(iz), with real part in [-pi/2, pi/2]
+    /// and cuts along the imaginary axis beyond ±i, where it is infinite
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(1.5, -0.5);
+    /// assert!((z.atan().tan() - z).norm() < 1e-15);
+    /// assert_eq!(Complex::new(10.0, 0.0).atan(), Complex::new(10f64.atan(), 0.0));
+    /// ```
+    pub fn atan(self) -> Self {
+        let w = Self::new(-self.imaginary, self.real).atanh();
+        Self::new(w.imaginary, -w.real)
+    }
+
+    /// the principal inverse hyperbolic sine, -i asin(iz), with imaginary part
+    /// in [-pi/2, pi/2] and cuts along the imaginary axis beyond ±i
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(-2.0, 0.5);
+    /// assert!((z.asinh().sinh() - z).norm() < 1e-14);
+    /// // no cancellation near zero
+    /// assert_eq!(Complex::new(1e-10, 0.0).asinh(), Complex::new(1e-10, 0.0));
+    /// ```
+    pub fn asinh(self) -> Self {
+        let w = Self::new(-self.imaginary, self.real).asin();
+        Self::new(w.imaginary, -w.real)
+    }
+
+    /// the principal inverse hyperbolic cosine, with real part at least 0,
+    /// imaginary part in (-pi, pi], and a cut along the real axis below 1
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let z = Complex::new(0.2, -3.0);
+    /// assert!((z.acosh().cosh() - z).norm() < 1e-14);
+    /// assert_eq!(Complex::new(3.0, 0.0).acosh(), Complex::new(3f64.acosh(), 0.0));
+    /// assert_eq!(Complex::new(0.0, 0.0).acosh(), Complex::new(0.0, FRAC_PI_2));
+    /// ```
+    pub fn acosh(self) -> Self {
+        let (s, t) = ((self - 1.0).sqrt(), (self + 1.0).sqrt());
+        Self::new(
+            (s.real * t.real + s.imaginary * t.imaginary).asinh(),
+            2.0 * s.imaginary.atan2(t.real),
+        )
+    }
+
+    /// the principal inverse hyperbolic tangent, (ln(1 + z) - ln(1 - z)) / 2,
+    /// with imaginary part in [-pi/2, pi/2] and cuts along the real axis beyond ±1.
+    /// the real part goes through ln_1p so small arguments keep their precision
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(0.7, 2.0);
+    /// assert!((z.atanh().tanh() - z).norm() < 1e-15);
+    /// assert_eq!(Complex::new(1e-10, 0.0).atanh(), Complex::new(1e-10, 0.0));
+    /// assert_eq!(Complex::new(1.0, 0.0).atanh().real, f64::INFINITY);
+    /// ```
+    pub fn atanh(self) -> Self {
+        let (x, y) = (self.real, self.imaginary);
+        let flipped = (1.0 - x) * (1.0 - x) + y * y;
+        Self::new(
+            (4.0 * x / flipped).ln_1p() / 4.0,
+            (2.0 * y).atan2((1.0 - x) * (1.0 + x) - y * y) / 2.0,
+        )
+    }
+
+    /// self + rhs, which unlike the operator works in constants
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// const Z: Complex = Complex::new(1.0, 2.0).plus(Complex::new(3.0, 4.0));
+    /// assert_eq!(Z, Complex::new(4.0, 6.0));
+    /// ```
+    #[inline]
+    pub const fn plus(self, rhs: Self) -> Self {
+        Self {
+            real: self.real + rhs.real,
+            imaginary: self.imaginary + rhs.imaginary,
+        }
+    }
+
+    /// self - rhs, in constants
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// const Z: Complex = Complex::new(1.0, 2.0);
+    /// // z - conj(z) = 2i im(z)
+    /// const TWICE_IMAGINARY: Complex = Z.minus(Z.conjugate());
+    /// assert_eq!(TWICE_IMAGINARY, Complex::new(0.0, 4.0));
+    /// ```
+    #[inline]
+    pub const fn minus(self, rhs: Self) -> Self {
+        self.plus(rhs.negated())
+    }
+
+    /// -self, in constants
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// // the fourth roots of unity, built at compile time
+    /// const ROOTS: [Complex; 4] = [Complex::ONE, Complex::I, Complex::ONE.negated(), Complex::I.negated()];
+    /// assert_eq!(ROOTS[3].times(ROOTS[1]), Complex::ONE);
+    /// ```
+    #[inline]
+    pub const fn negated(self) -> Self {
+        Self {
+            real: -self.real,
+            imaginary: -self.imaginary,
+        }
+    }
+
+    /// self * rhs, in constants
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// // the eighth roots of unity, for a length 8 fft
+    /// const H: f64 = std::f64::consts::FRAC_1_SQRT_2;
+    /// const W: Complex = Complex::new(H, -H);
+    /// const TWIDDLES: [Complex; 4] = [
+    ///     Complex::new(1.0, 0.0),
+    ///     W,
+    ///     W.times(W),
+    ///     W.times(W).times(W),
+    /// ];
+    /// assert!((TWIDDLES[2].imaginary + 1.0).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub const fn times(self, rhs: Self) -> Self {
+        // (a_0 + b_0 * i) * (a_1 + b_1 * i)
+        // (a_0 * a_1) + (a_0 * b_1 * i) + (b_0 * a_1 * i) + (b_0 * b_1 * i^2)
+        // ((a_0 * a_1) - (b_0 * b_1)) + i * ((a_0 * b_1) + (b_0 * a_1))
+        Self {
+            real: self.real * rhs.real - self.imaginary * rhs.imaginary,
+            imaginary: self.real * rhs.imaginary + self.imaginary * rhs.real,
+        }
+    }
+
+    /// self times a real, in constants
+    #[inline]
+    pub const fn scaled(self, factor: Real) -> Self {
+        Self {
+            real: self.real * factor,
+            imaginary: self.imaginary * factor,
+        }
+    }
+}
+
+impl<T: Scalar> From<T> for Complex<T> {
+    #[inline]
+    fn from(real: T) -> Self {
+        Self::new(real, T::ZERO)
     }
 }
 
-impl Neg for Complex {
+impl<T: Scalar> Neg for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -89,15 +969,13 @@ impl Neg for Complex {
     /// let negative = -z;
     /// assert_eq!(negative, Complex::new(-1.0, -2.0));
     /// ```
+    #[inline]
     fn neg(self) -> Self {
-        Complex {
-            real: -self.real,
-            imaginary: -self.imaginary,
-        }
+        Self::new(-self.real, -self.imaginary)
     }
 }
 
-impl Add for Complex {
+impl<T: Scalar> Add for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -106,15 +984,13 @@ impl Add for Complex {
     /// let sum = z + w;
     /// assert_eq!(sum, Complex::new(4.0, 6.0));
     /// ```
+    #[inline]
     fn add(self, rhs: Self) -> Self {
-        Self {
-            real: self.real + rhs.real,
-            imaginary: self.imaginary + rhs.imaginary,
-        }
+        Self::new(self.real + rhs.real, self.imaginary + rhs.imaginary)
     }
 }
 
-impl Add<Real> for Complex {
+impl<T: Scalar> Add<T> for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -123,15 +999,13 @@ impl Add<Real> for Complex {
     /// let sum = z + x;
     /// assert_eq!(sum, Complex::new(4.0, 2.0));
     /// ```
-    fn add(self, rhs: Real) -> Self {
-        Complex {
-            real: self.real + rhs,
-            imaginary: self.imaginary,
-        }
+    #[inline]
+    fn add(self, rhs: T) -> Self {
+        Self::new(self.real + rhs, self.imaginary)
     }
 }
 
-impl Sub for Complex {
+impl<T: Scalar> Sub for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -140,12 +1014,13 @@ impl Sub for Complex {
     /// let difference = z - w;
     /// assert_eq!(difference, Complex::new(3.0, 2.0));
     /// ```
+    #[inline]
     fn sub(self, rhs: Self) -> Self {
-        self + -rhs
+        Self::new(self.real - rhs.real, self.imaginary - rhs.imaginary)
     }
 }
 
-impl Sub<Real> for Complex {
+impl<T: Scalar> Sub<T> for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -154,12 +1029,13 @@ impl Sub<Real> for Complex {
     /// let difference = z - x;
     /// assert_eq!(difference, Complex::new(-2.0, 2.0));
     /// ```
-    fn sub(self, rhs: Real) -> Self {
-        self + -rhs
+    #[inline]
+    fn sub(self, rhs: T) -> Self {
+        Self::new(self.real - rhs, self.imaginary)
     }
 }
 
-impl Mul for Complex {
+impl<T: Scalar> Mul for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -172,18 +1048,16 @@ impl Mul for Complex {
     /// let product = z * w;
     /// assert_eq!(product, Complex::new(-SQRT_2 / 2.0, SQRT_2 / 2.0));
     /// ```
+    #[inline]
     fn mul(self, rhs: Self) -> Self {
-        // (a_0 + b_0 * i) * (a_1 + b_1 * i)
-        // (a_0 * a_1) + (a_0 * b_1 * i) + (b_0 * a_1 * i) + (b_0 * b_1 * i^2)
-        // ((a_0 * a_1) - (b_0 * b_1)) + i * ((a_0 * b_1) + (b_0 * a_1))
-        Self {
-            real: self.real * rhs.real - self.imaginary * rhs.imaginary,
-            imaginary: self.real * rhs.imaginary + self.imaginary * rhs.real,
-        }
+        Self::new(
+            self.real * rhs.real - self.imaginary * rhs.imaginary,
+            self.real * rhs.imaginary + self.imaginary * rhs.real,
+        )
     }
 }
 
-impl Mul<Real> for Complex {
+impl<T: Scalar> Mul<T> for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -192,18 +1066,19 @@ impl Mul<Real> for Complex {
     /// let product = z * x;
     /// assert_eq!(product, Complex::new(2.0, 4.0));
     /// ```
-    fn mul(self, rhs: Real) -> Self {
-        Self {
-            real: self.real * rhs,
-            imaginary: self.imaginary * rhs,
-        }
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.real * rhs, self.imaginary * rhs)
     }
 }
 
-impl Div for Complex {
+impl<T: Scalar> Div for Complex<T> {
     type Output = Self;
+    /// by `Scalar::complex_div`, which for `Real` and `Real32` is smith's division,
+    /// scaled so it neither overflows nor underflows where the quotient does not,
+    /// and for other scalars the textbook formula
     /// ```
-    /// # use vmath::numbers::Complex;
+    /// # use vmath::numbers::{Complex, Complex32, Dual};
     /// # use std::f64::consts::SQRT_2;
     /// // z with angle 3pi / 4
     /// let z = Complex::new(-SQRT_2 / 2.0, SQRT_2 / 2.0);
@@ -212,27 +1087,115 @@ impl Div for Complex {
     /// // quotient with angle pi / 2
     /// let quotient = z / w;
     /// assert_eq!(quotient, Complex::new(0.0, 1.0));
+    ///
+    /// // c^2 + d^2 would overflow here
+    /// let big = Complex::new(1e300, 1e300);
+    /// assert_eq!(big / big, Complex::new(1.0, 0.0));
+    /// let small = Complex::new(1e-300, -1e-300);
+    /// assert_eq!(small / Complex::new(0.0, 1e-300), Complex::new(-1.0, -1.0));
+    ///
+    /// // the hard cases from baudin and smith, with 2^e built in two steps
+    /// // so the subnormal powers do not underflow
+    /// let two = |e: i32| 2f64.powi(e / 2) * 2f64.powi(e - e / 2);
+    /// let z = |a, b| Complex::new(two(a), two(b));
+    /// let one = Complex::new(1.0, 1.0);
+    /// assert_eq!(one / Complex::new(1.0, two(1023)), z(-1023, -1023).conjugate());
+    /// assert_eq!(one / z(-1023, -1023), Complex::new(two(1023), 0.0));
+    /// assert_eq!(z(1023, -1023) / z(677, -677), Complex::new(two(346), -two(-1008)));
+    /// assert_eq!(z(1023, 1023) / one, Complex::new(two(1023), 0.0));
+    /// assert_eq!(z(1020, -844) / z(656, -780), Complex::new(two(364), -two(-1072)));
+    /// assert_eq!(z(-71, 1021) / z(1001, -323), z(-1072, 20));
+    /// let q = z(-347, -54) / z(-1037, -1058);
+    /// assert!((q.real / 3.898125604559113300e289 - 1.0).abs() < 1e-15);
+    /// assert!((q.imaginary / 8.174961907852353577e295 - 1.0).abs() < 1e-15);
+    /// let q = z(-1074, -1074) / z(-1073, -1074);
+    /// assert!((q - Complex::new(0.6, 0.2)).norm() < 1e-15);
+    /// assert_eq!(z(1015, -989) / z(1023, 1023), Complex::new(two(-9), -two(-9)));
+    /// let q = z(-622, -1071) / z(-343, -798);
+    /// assert!((q.real / 1.02951151789360578e-84 - 1.0).abs() < 1e-15);
+    ///
+    /// // any scalar divides by the textbook formula, dual numbers among them,
+    /// // here differentiating 1 / (t + i) at t = 2
+    /// let q = Complex::from(Dual::ONE) / Complex::new(Dual::variable(2.0), Dual::ONE);
+    /// assert_eq!(q.real.re, 0.4);
+    /// assert!((q.real.eps - -0.12).abs() < 1e-16);
+    /// assert!((q.imaginary.eps - 0.16).abs() < 1e-16);
+    ///
+    /// // single precision, worked in double where the products cannot overflow
+    /// let big = Complex32::new(1e30, 1e30);
+    /// assert_eq!(big / big, Complex32::new(1.0, 0.0));
+    /// assert_eq!(Complex32::new(2.0, 4.0) / Complex32::new(0.0, 2.0), Complex32::new(2.0, -1.0));
     /// ```
+    #[inline]
     fn div(self, rhs: Self) -> Self {
-        // (a_0 + b_0 * i) / (a_1 + b_1 * i)
-        // ((a_0 + b_0 * i) * (a_1 - b_1 * i)) / ((a_1 + b_1 * i) * (a_1 - b_1 * i))
-        // expand the denominator:
-        // (a_1 + b_1 * i) * (a_1 - b_1 * i) = a_1^2 - (b_1 * i)^2
-        // = a_1^2 - (-b_1^2) = a_1^2 + b_1^2
-        // expand the numerator:
-        // (a_0 + b_0 * i) * (a_1 - b_1 * i)
-        // = a_0 * a_1 - a_0 * b_1 * i + b_0 * a_1 * i - b_0 * b_1 * i^2
-        // = (a_0 * a_1 + b_0 * b_1) + (b_0 * a_1 - a_0 * b_1) * i
-        // substitute:
-        // ((a_0 * a_1 + b_0 * b_1) / (a_1^2 + b_1^2)) + ((b_0 * a_1 - a_0 * b_1) / (a_1^2 + b_1^2)) * i
-        Self {
-            real: (self.real * rhs.real + self.imaginary * rhs.imaginary) / rhs.norm_squared(),
-            imaginary: (self.imaginary * rhs.real - self.real * rhs.imaginary) / rhs.norm_squared(),
-        }
+        let (real, imaginary) = T::complex_div(self.real, self.imaginary, rhs.real, rhs.imaginary);
+        Self::new(real, imaginary)
+    }
+}
+
+/// (a + bi) / (c + di) as the parts of the quotient, the way `Real` divides complex numbers
+#[inline]
+pub(crate) fn scaled_div(mut a: Real, mut b: Real, mut c: Real, mut d: Real) -> (Real, Real) {
+    // (a + bi) / (c + di) = ((a + bi)(c - di)) / (c^2 + d^2)
+    // = ((ac + bd) + (bc - ad)i) / (c^2 + d^2)
+    // smith's algorithm divides through by the larger of c and d first,
+    // when |c| >= |d|, with r = d / c:
+    // = ((a + br) + (b - ar)i) / (c + dr)
+    // so c^2 + d^2 is never formed. baudin and smith's refinement
+    // also scales extreme operands toward 1 first, and reorders the
+    // products when r underflows, so the quotient stays accurate
+    // across the whole double range
+    let ab = a.abs().max(b.abs());
+    let cd = c.abs().max(d.abs());
+    let half_max = Real::MAX / 2.0;
+    // in terms of the unit roundoff, half the epsilon
+    let unit = Real::EPSILON / 2.0;
+    let tiny = Real::MIN_POSITIVE * 2.0 / unit;
+    let big = 2.0 / (unit * unit);
+    let mut scale = 1.0;
+    if ab >= half_max {
+        (a, b) = (a / 2.0, b / 2.0);
+        scale *= 2.0;
+    }
+    if cd >= half_max {
+        (c, d) = (c / 2.0, d / 2.0);
+        scale /= 2.0;
+    }
+    if ab <= tiny {
+        (a, b) = (a * big, b * big);
+        scale /= big;
+    }
+    if cd <= tiny {
+        (c, d) = (c * big, d * big);
+        scale *= big;
+    }
+    let (real, imaginary) = if d.abs() <= c.abs() {
+        smith(a, b, c, d)
+    } else {
+        // (a + bi) / (c + di) = (b - ai) / (d - ci), the conjugate of (b + ai) / (d + ci)
+        let (real, imaginary) = smith(b, a, d, c);
+        (real, -imaginary)
+    };
+    (real * scale, imaginary * scale)
+}
+
+/// (a + bi) / (c + di) for |d| <= |c|, as the parts of the quotient
+#[inline]
+fn smith(a: Real, b: Real, c: Real, d: Real) -> (Real, Real) {
+    let r = d / c;
+    let denominator = c + d * r;
+    if r != 0.0 {
+        ((a + b * r) / denominator, (b - a * r) / denominator)
+    } else {
+        // r underflowed, so d (b / c) keeps what b r would have lost
+        (
+            (a + d * (b / c)) / denominator,
+            (b - d * (a / c)) / denominator,
+        )
     }
 }
 
-impl Div<Real> for Complex {
+impl<T: Scalar> Div<T> for Complex<T> {
     type Output = Self;
     /// ```
     /// # use vmath::numbers::Complex;
@@ -242,10 +1205,396 @@ impl Div<Real> for Complex {
     /// let quotient = z / x;
     /// assert_eq!(quotient, Complex::new(1.0, 2.0));
     /// ```
-    fn div(self, rhs: Real) -> Self {
-        Self {
-            real: self.real / rhs,
-            imaginary: self.imaginary / rhs,
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.real / rhs, self.imaginary / rhs)
+    }
+}
+
+/// how the parts of a complex number are written, as `{}`, `{:e}`, or `{:E}` would a real
+#[derive(Clone, Copy)]
+enum Notation {
+    Decimal,
+    LowerExp,
+    UpperExp,
+}
+
+/// whether a complex number is written a + bi or r∠θ, with θ in radians or degrees
+#[derive(Clone, Copy)]
+enum Form {
+    Rectangular,
+    Polar { degrees: bool },
+}
+
+/// a complex number written in polar form with its angle in degrees, r∠θ°,
+/// from `Complex::polar_degrees`
+/// ```
+/// # use vmath::numbers::Complex;
+/// let z = Complex::new(3.0, 4.0);
+/// assert_eq!(format!("{:.2}", z.polar_degrees()), "5.00∠53.13°");
+/// assert_eq!(Complex::new(0.0, -2.0).polar_degrees().to_string(), "2∠-90°");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarDegrees(pub Complex);
+
+impl fmt::Display for PolarDegrees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+            .format(f, Notation::Decimal, Form::Polar { degrees: true })
+    }
+}
+
+impl Complex {
+    /// shown in polar form with the angle in degrees, where the alternate flag,
+    /// `{:#}`, shows it in radians
+    pub fn polar_degrees(self) -> PolarDegrees {
+        PolarDegrees(self)
+    }
+
+    /// the form the alternate flag asks for, polar in radians, or else rectangular
+    fn form(f: &fmt::Formatter<'_>) -> Form {
+        if f.alternate() {
+            Form::Polar { degrees: false }
+        } else {
+            Form::Rectangular
+        }
+    }
+
+    /// a + bi with the formatter's precision applied to both parts, b's sign between them,
+    /// a left out when zero and b when zero, and a coefficient of 1 on i left out
+    /// when no precision is given, or r∠θ in polar form with zero at angle 0,
+    /// padded as a whole to any width
+    fn format(self, f: &mut fmt::Formatter<'_>, notation: Notation, form: Form) -> fmt::Result {
+        let part = |x: Real| match (notation, f.precision()) {
+            (Notation::Decimal, None) => format!("{x}"),
+            (Notation::Decimal, Some(p)) => format!("{x:.p$}"),
+            (Notation::LowerExp, None) => format!("{x:e}"),
+            (Notation::LowerExp, Some(p)) => format!("{x:.p$e}"),
+            (Notation::UpperExp, None) => format!("{x:E}"),
+            (Notation::UpperExp, Some(p)) => format!("{x:.p$E}"),
+        };
+        let coefficient = |x: Real| {
+            if x == 1.0 && f.precision().is_none() {
+                String::new()
+            } else {
+                part(x)
+            }
+        };
+        let (a, b) = (self.real, self.imaginary);
+        let mut text = if let Form::Polar { degrees } = form {
+            let theta = self.angle().unwrap_or(0.0);
+            if degrees {
+                format!("{}∠{}°", part(self.norm()), part(theta.to_degrees()))
+            } else {
+                format!("{}∠{}", part(self.norm()), part(theta))
+            }
+        } else if b == 0.0 {
+            part(a)
+        } else if a == 0.0 {
+            let sign = if b < 0.0 { "-" } else { "" };
+            format!("{sign}{}i", coefficient(b.abs()))
+        } else {
+            let sign = if b < 0.0 { '-' } else { '+' };
+            format!("{}{sign}{}i", part(a), coefficient(b.abs()))
+        };
+        if f.sign_plus() && !text.starts_with('-') {
+            text.insert(0, '+');
+        }
+        let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&text)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Complex {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(Complex::new(3.0, 4.0).to_string(), "3+4i");
+    /// assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+    /// assert_eq!(Complex::new(0.0, -1.0).to_string(), "-i");
+    /// assert_eq!(Complex::new(-2.0, 0.0).to_string(), "-2");
+    /// assert_eq!(Complex::new(0.0, 0.0).to_string(), "0");
+    /// assert_eq!(format!("{:.3}", Complex::new(1.0 / 3.0, 1.0)), "0.333+1.000i");
+    /// assert_eq!(format!("{:>8}", Complex::new(1.0, 2.0)), "    1+2i");
+    /// assert_eq!(format!("{:+}", Complex::new(0.0, 2.0)), "+2i");
+    ///
+    /// // the alternate flag writes polar form, the angle in radians
+    /// assert_eq!(format!("{:#.3}", Complex::new(3.0, 4.0)), "5.000∠0.927");
+    /// assert_eq!(format!("{:#}", Complex::new(-2.0, 0.0)), format!("2∠{}", std::f64::consts::PI));
+    /// assert_eq!(format!("{:#}", Complex::ZERO), "0∠0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(f, Notation::Decimal, Self::form(f))
+    }
+}
+
+impl fmt::LowerExp for Complex {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(1500.0, -0.025);
+    /// assert_eq!(format!("{z:e}"), "1.5e3-2.5e-2i");
+    /// assert_eq!(format!("{z:.2e}"), "1.50e3-2.50e-2i");
+    /// assert_eq!(format!("{:#.1e}", Complex::new(0.0, 1500.0)), "1.5e3∠1.6e0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(f, Notation::LowerExp, Self::form(f))
+    }
+}
+
+impl fmt::UpperExp for Complex {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!(format!("{:E}", Complex::new(0.0, 1e-9)), "1E-9i");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(f, Notation::UpperExp, Self::form(f))
+    }
+}
+
+impl FromStr for Complex {
+    type Err = ParseError;
+
+    /// a + bi, a, or bi, with each part as a real parses and spaces anywhere,
+    /// a coefficient of ±1 on i optional, which reads back what `Display` writes
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// assert_eq!("3+4i".parse(), Ok(Complex::new(3.0, 4.0)));
+    /// assert_eq!("-2.5i".parse(), Ok(Complex::new(0.0, -2.5)));
+    /// assert_eq!("1e-3 - 2i".parse(), Ok(Complex::new(1e-3, -2.0)));
+    /// assert_eq!("7".parse(), Ok(Complex::new(7.0, 0.0)));
+    /// assert_eq!("-i".parse(), Ok(Complex::new(0.0, -1.0)));
+    /// assert_eq!("2.5e+3i".parse(), Ok(Complex::new(0.0, 2500.0)));
+    ///
+    /// let z = Complex::new(-0.125, 1e-7);
+    /// assert_eq!(z.to_string().parse(), Ok(z));
+    /// assert_eq!(format!("{z:e}").parse(), Ok(z));
+    ///
+    /// let error = "3 + 4x".parse::<Complex>().unwrap_err();
+    /// assert_eq!(error.to_string(), "malformed real number at byte 0");
+    /// let error = "3 + 4.0.1i".parse::<Complex>().unwrap_err();
+    /// assert_eq!(error.position, 2);
+    /// ```
+    fn from_str(source: &str) -> Result<Self, ParseError> {
+        // the text without spaces, and for each of its bytes the offset in the source
+        let mut text = String::new();
+        let mut offsets = Vec::new();
+        for (at, c) in source.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+            text.push(c);
+            offsets.resize(text.len(), at);
+        }
+        let offset = |i: usize| offsets.get(i).copied().unwrap_or(source.len());
+        let real = |from: usize, to: usize| {
+            text[from..to].parse::<Real>().map_err(|_| ParseError {
+                position: offset(from),
+                message: "malformed real number",
+            })
+        };
+        if text.is_empty() {
+            return Err(ParseError {
+                position: 0,
+                message: "expected a complex number",
+            });
         }
+        let Some(coefficient_end) = text.strip_suffix('i').map(str::len) else {
+            return Ok(Self::new(real(0, text.len())?, 0.0));
+        };
+        // the sign starting the imaginary part, which is not one leading the text
+        // or one of an exponent
+        let split = text
+            .char_indices()
+            .skip(1)
+            .filter(|&(i, c)| (c == '+' || c == '-') && !text[..i].ends_with(['e', 'E']))
+            .map(|(i, _)| i)
+            .last()
+            .unwrap_or(0);
+        let imaginary = match &text[split..coefficient_end] {
+            "" | "+" => 1.0,
+            "-" => -1.0,
+            _ => real(split, coefficient_end)?,
+        };
+        let real = if split == 0 { 0.0 } else { real(0, split)? };
+        Ok(Self::new(real, imaginary))
+    }
+}
+
+impl<T: Scalar> Sum for Complex<T> {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// // the n-th roots of unity sum to zero
+    /// let sum: Complex = Complex::new(1.0, 0.0).nth_roots(5).sum();
+    /// assert!(sum.norm() < 1e-15);
+    /// assert_eq!(std::iter::empty::<Complex>().sum::<Complex>(), Complex::new(0.0, 0.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ZERO, T::ZERO), Add::add)
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a Complex<T>> for Complex<T> {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let terms = [Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)];
+    /// assert_eq!(terms.iter().sum::<Complex>(), Complex::new(4.0, 1.0));
+    /// ```
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: Scalar> Product for Complex<T> {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let i = Complex::new(0.0, 1.0);
+    /// assert_eq!(std::iter::repeat(i).take(4).product::<Complex>(), Complex::new(1.0, 0.0));
+    /// assert_eq!(std::iter::empty::<Complex>().product::<Complex>(), Complex::new(1.0, 0.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(T::ONE), Mul::mul)
     }
 }
+
+impl<'a, T: Scalar> Product<&'a Complex<T>> for Complex<T> {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let factors = [Complex::new(1.0, 1.0), Complex::new(1.0, -1.0)];
+    /// assert_eq!(factors.iter().product::<Complex>(), Complex::new(2.0, 0.0));
+    /// ```
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl From<Complex32> for Complex {
+    #[inline]
+    fn from(z: Complex32) -> Self {
+        Self::new(z.real.into(), z.imaginary.into())
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex64> for Complex {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::from(num_complex::Complex64::new(1.0, -2.0));
+    /// assert_eq!(z, Complex::new(1.0, -2.0));
+    /// ```
+    fn from(z: num_complex::Complex64) -> Self {
+        Self::new(z.re, z.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<Complex> for num_complex::Complex64 {
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z: num_complex::Complex64 = Complex::new(3.0, 4.0).into();
+    /// assert_eq!(z.norm(), 5.0);
+    /// ```
+    fn from(z: Complex) -> Self {
+        Self::new(z.real, z.imaginary)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex32> for Complex32 {
+    /// ```
+    /// # use vmath::numbers::Complex32;
+    /// let z = Complex32::from(num_complex::Complex32::new(1.5, -2.0));
+    /// assert_eq!(z, Complex32::new(1.5, -2.0));
+    /// ```
+    fn from(z: num_complex::Complex32) -> Self {
+        Self::new(z.re, z.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<Complex32> for num_complex::Complex32 {
+    /// ```
+    /// # use vmath::numbers::Complex32;
+    /// let z: num_complex::Complex32 = Complex32::new(0.0, 2.0).into();
+    /// assert_eq!(z * z, num_complex::Complex32::new(-4.0, 0.0));
+    /// ```
+    fn from(z: Complex32) -> Self {
+        Self::new(z.real, z.imaginary)
+    }
+}
+
+// both are repr(C) pairs of f64, real part first, so references cast between them
+#[cfg(feature = "num-complex")]
+impl Complex {
+    /// the same number seen as a `num_complex::Complex64`, without copying
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let z = Complex::new(3.0, 4.0);
+    /// assert_eq!(z.as_num().norm(), 5.0);
+    /// ```
+    pub fn as_num(&self) -> &num_complex::Complex64 {
+        // SAFETY: the layouts match, and the borrow keeps self alive and unchanged
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    /// the same number as a mutable `num_complex::Complex64`, without copying
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let mut z = Complex::new(1.0, 1.0);
+    /// *z.as_num_mut() *= num_complex::Complex64::i();
+    /// assert_eq!(z, Complex::new(-1.0, 1.0));
+    /// ```
+    pub fn as_num_mut(&mut self) -> &mut num_complex::Complex64 {
+        // SAFETY: the layouts match, and the borrow is unique
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+
+    /// a slice seen as `num_complex::Complex64`s, without copying,
+    /// for handing buffers to crates built on num
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let zs = [Complex::ONE, Complex::I];
+    /// let sum: num_complex::Complex64 = Complex::as_num_slice(&zs).iter().sum();
+    /// assert_eq!(sum, num_complex::Complex64::new(1.0, 1.0));
+    /// ```
+    pub fn as_num_slice(zs: &[Self]) -> &[num_complex::Complex64] {
+        // SAFETY: the layouts match, so the length and alignment carry over
+        unsafe { std::slice::from_raw_parts(zs.as_ptr().cast(), zs.len()) }
+    }
+
+    /// a mutable slice seen as `num_complex::Complex64`s, without copying
+    /// ```
+    /// # use vmath::numbers::Complex;
+    /// let mut zs = [Complex::ONE, Complex::I];
+    /// for z in Complex::as_num_slice_mut(&mut zs) {
+    ///     *z = z.conj();
+    /// }
+    /// assert_eq!(zs, [Complex::ONE, -Complex::I]);
+    /// ```
+    pub fn as_num_slice_mut(zs: &mut [Self]) -> &mut [num_complex::Complex64] {
+        // SAFETY: the layouts match, and the borrow is unique
+        unsafe { std::slice::from_raw_parts_mut(zs.as_mut_ptr().cast(), zs.len()) }
+    }
+}
+
+// SAFETY: repr(C) with only Real fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Complex {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Complex {}
+
+// SAFETY: repr(C) with only Real32 fields, so there is no padding and every bit pattern is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Complex32 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Complex32 {}
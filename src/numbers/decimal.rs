@@ -0,0 +1,734 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::numbers::{Real, Scalar};
+use crate::ParseError;
+
+/// the largest magnitude of a mantissa, 2^96 - 1, about 7.9e28
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+/// how a result between two representable decimals picks one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// toward zero, truncating
+    Down,
+    /// away from zero
+    Up,
+    /// toward negative infinity
+    Floor,
+    /// toward positive infinity
+    Ceiling,
+    /// to the nearer, ties away from zero, as taught in school
+    HalfUp,
+    /// to the nearer, ties toward zero
+    HalfDown,
+    /// to the nearer, ties to the even one, banker's rounding, which does not drift
+    /// on average over many roundings
+    #[default]
+    HalfEven,
+}
+
+/// what is left over below the last kept digit, as a fraction of a unit there
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Rest {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Rounding {
+    /// the kept magnitude, up by one where the rest and the sign call for it
+    fn apply(self, magnitude: u128, rest: Rest, negative: bool) -> u128 {
+        let up = match self {
+            Self::Down => false,
+            Self::Up => rest != Rest::Zero,
+            Self::Floor => negative && rest != Rest::Zero,
+            Self::Ceiling => !negative && rest != Rest::Zero,
+            Self::HalfUp => rest >= Rest::Half,
+            Self::HalfDown => rest > Rest::Half,
+            Self::HalfEven => rest > Rest::Half || (rest == Rest::Half && magnitude % 2 == 1),
+        };
+        magnitude + u128::from(up)
+    }
+}
+
+/// a decimal number, an integer mantissa of up to 96 bits over a power of ten
+/// of up to 28, so the values with up to 28 or 29 significant digits,
+/// 0.1 among them, are held exactly, as they are by .NET's `decimal`.
+/// sums, differences, and products are exact, and panic when the exact result
+/// does not fit, where the `checked_` methods give None.
+/// division rounds, by `div_rounded` to a chosen number of places and mode,
+/// or as the operator does half to even to as many places as fit.
+/// equality and order are by value, so 1.50 == 1.5, while the scale,
+/// the places the number was written with, is kept and printed
+/// ```
+/// # use vmath::numbers::{Decimal, Rounding};
+/// let a: Decimal = "0.1".parse().unwrap();
+/// let b: Decimal = "0.2".parse().unwrap();
+/// assert_eq!(a + b, "0.3".parse().unwrap());
+/// assert_ne!(0.1 + 0.2, 0.3);
+///
+/// // a price, a quantity, and a tax rate, with the tax to the cent
+/// let price: Decimal = "19.99".parse().unwrap();
+/// let subtotal = price * Decimal::from(3);
+/// assert_eq!(subtotal.to_string(), "59.97");
+/// let tax = (subtotal * "0.0825".parse().unwrap()).round(2, Rounding::HalfUp);
+/// assert_eq!(tax.to_string(), "4.95");
+/// let share = subtotal.div_rounded(Decimal::from(7), 2, Rounding::Floor).unwrap();
+/// assert_eq!(share.to_string(), "8.56");
+/// assert_eq!((Decimal::ONE / Decimal::from(3)).to_string(), "0.3333333333333333333333333333");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    // the value is mantissa / 10^scale, with |mantissa| <= MAX_MANTISSA and scale <= MAX_SCALE
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// the most places after the point
+    pub const MAX_SCALE: u32 = 28;
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        scale: 0,
+    };
+    pub const ONE: Self = Self {
+        mantissa: 1,
+        scale: 0,
+    };
+    /// 2^96 - 1, 79228162514264337593543950335
+    pub const MAX: Self = Self {
+        mantissa: MAX_MANTISSA as i128,
+        scale: 0,
+    };
+    pub const MIN: Self = Self {
+        mantissa: -(MAX_MANTISSA as i128),
+        scale: 0,
+    };
+
+    /// mantissa / 10^scale, None when the mantissa is beyond 2^96 - 1 in magnitude
+    /// or the scale beyond 28
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// assert_eq!(Decimal::new(-12345, 2).unwrap().to_string(), "-123.45");
+    /// assert_eq!(Decimal::new(1, 29), None);
+    /// assert_eq!(Decimal::new(1 << 96, 0), None);
+    /// ```
+    pub const fn new(mantissa: i128, scale: u32) -> Option<Self> {
+        if mantissa.unsigned_abs() <= MAX_MANTISSA && scale <= Self::MAX_SCALE {
+            Some(Self { mantissa, scale })
+        } else {
+            None
+        }
+    }
+
+    pub const fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+
+    /// the places after the point
+    pub const fn scale(self) -> u32 {
+        self.scale
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.mantissa < 0
+    }
+
+    pub const fn abs(self) -> Self {
+        Self {
+            mantissa: self.mantissa.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// the same value with no trailing zeros after the point
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let x = Decimal::new(15000, 4).unwrap();
+    /// assert_eq!(x.normalize().to_string(), "1.5");
+    /// assert_eq!(x.normalize(), x);
+    /// ```
+    pub fn normalize(self) -> Self {
+        let (mut mantissa, mut scale) = (self.mantissa, self.scale);
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self { mantissa, scale }
+    }
+
+    /// self + rhs exactly, at the larger of the two scales where it fits,
+    /// None when no scale holds it
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let cents = Decimal::new(1, 2).unwrap();
+    /// assert_eq!(Decimal::from(5).checked_add(cents).unwrap().to_string(), "5.01");
+    /// assert_eq!(Decimal::MAX.checked_add(Decimal::ONE), None);
+    /// assert_eq!(Decimal::from(10_i64.pow(18)).checked_add(Decimal::new(1, 10).unwrap()).unwrap().scale(), 10);
+    /// // 31 significant digits are more than the mantissa holds
+    /// assert_eq!(Decimal::from(10_i64.pow(18)).checked_add(Decimal::new(1, 12).unwrap()), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b, scale) =
+            aligned(self, rhs).or_else(|| aligned(self.normalize(), rhs.normalize()))?;
+        fit(a.checked_add(b)?, scale)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    /// self rhs exactly, at the sum of the scales less any trailing zeros
+    /// that have to go for it to fit, None when it does not
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let half = Decimal::new(5, 1).unwrap();
+    /// assert_eq!(half.checked_mul(half).unwrap().to_string(), "0.25");
+    /// let tiny = Decimal::new(1, 15).unwrap();
+    /// assert_eq!(tiny.checked_mul(tiny), None);
+    /// assert_eq!(Decimal::MAX.checked_mul(Decimal::from(2)), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let negative = self.is_negative() != rhs.is_negative();
+        let mut product = wide_mul(self.mantissa.unsigned_abs(), rhs.mantissa.unsigned_abs());
+        let mut scale = self.scale + rhs.scale;
+        while scale > Self::MAX_SCALE || product[2] != 0 || product[3] != 0 || product[1] >> 32 != 0
+        {
+            let mut shorter = product;
+            if scale == 0 || divide_small(&mut shorter, 10) != 0 {
+                return None;
+            }
+            product = shorter;
+            scale -= 1;
+        }
+        let magnitude = (u128::from(product[1]) << 64 | u128::from(product[0])) as i128;
+        Some(Self {
+            mantissa: if negative { -magnitude } else { magnitude },
+            scale,
+        })
+    }
+
+    /// self / rhs as the operator divides, half to even to as many of the
+    /// 28 places as fit, without trailing zeros, and None when rhs is zero
+    /// or the quotient is beyond `MAX`
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// assert_eq!(Decimal::from(1).checked_div(Decimal::from(8)).unwrap().to_string(), "0.125");
+    /// assert_eq!(Decimal::from(2).checked_div(Decimal::from(3)).unwrap().to_string(), "0.6666666666666666666666666667");
+    /// // fewer places where the whole part is long
+    /// let big = Decimal::from(10_i64.pow(18));
+    /// assert_eq!(big.checked_div(Decimal::from(3)).unwrap().to_string(), "333333333333333333.33333333333");
+    /// assert_eq!(Decimal::ONE.checked_div(Decimal::ZERO), None);
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        (0..=Self::MAX_SCALE)
+            .rev()
+            .find_map(|places| self.div_rounded(rhs, places, Rounding::HalfEven))
+            .map(Self::normalize)
+    }
+
+    /// self / rhs rounded to the given places by the given mode, at exactly that scale,
+    /// None when rhs is zero, the places are beyond 28, or the quotient does not fit
+    /// ```
+    /// # use vmath::numbers::{Decimal, Rounding};
+    /// let ten = Decimal::from(10);
+    /// let three = Decimal::from(3);
+    /// assert_eq!(ten.div_rounded(three, 2, Rounding::HalfEven).unwrap().to_string(), "3.33");
+    /// assert_eq!(ten.div_rounded(three, 2, Rounding::Up).unwrap().to_string(), "3.34");
+    /// assert_eq!((-ten).div_rounded(three, 0, Rounding::Floor).unwrap().to_string(), "-4");
+    /// assert_eq!(ten.div_rounded(Decimal::from(4), 0, Rounding::HalfEven).unwrap().to_string(), "2");
+    /// assert_eq!(ten.div_rounded(Decimal::from(4), 0, Rounding::HalfUp).unwrap().to_string(), "3");
+    /// assert_eq!(ten.div_rounded(Decimal::from(4), 3, Rounding::Down).unwrap().to_string(), "2.500");
+    /// assert_eq!(ten.div_rounded(Decimal::ZERO, 2, Rounding::HalfEven), None);
+    /// ```
+    pub fn div_rounded(self, rhs: Self, places: u32, rounding: Rounding) -> Option<Self> {
+        if rhs.is_zero() || places > Self::MAX_SCALE {
+            return None;
+        }
+        let negative = self.is_negative() != rhs.is_negative();
+        let (a, b) = (self.mantissa.unsigned_abs(), rhs.mantissa.unsigned_abs());
+        // the quotient mantissa is a 10^shift / b, shifted by the difference in scales
+        let shift = places as i32 + rhs.scale as i32 - self.scale as i32;
+        let (mut quotient, mut remainder) = (a / b, a % b);
+        let rest = if shift >= 0 {
+            // digit by digit, the remainder staying below b and so below 2^96
+            for _ in 0..shift {
+                if quotient > MAX_MANTISSA {
+                    return None;
+                }
+                remainder *= 10;
+                quotient = quotient * 10 + remainder / b;
+                remainder %= b;
+            }
+            match (2 * remainder).cmp(&b) {
+                _ if remainder == 0 => Rest::Zero,
+                Ordering::Less => Rest::BelowHalf,
+                Ordering::Equal => Rest::Half,
+                Ordering::Greater => Rest::AboveHalf,
+            }
+        } else {
+            // a / b then 10^-shift, which at most 28 fits, with b's remainder only
+            // deciding between the rests either side of what the power's leaves
+            let power = 10u128.pow(shift.unsigned_abs());
+            let low = quotient % power;
+            quotient /= power;
+            match ((2 * low).cmp(&power), remainder == 0) {
+                (Ordering::Less, true) if low == 0 => Rest::Zero,
+                (Ordering::Less, _) => Rest::BelowHalf,
+                (Ordering::Equal, true) => Rest::Half,
+                _ => Rest::AboveHalf,
+            }
+        };
+        let magnitude = rounding.apply(quotient, rest, negative);
+        if magnitude > MAX_MANTISSA {
+            return None;
+        }
+        Some(Self {
+            mantissa: if negative {
+                -(magnitude as i128)
+            } else {
+                magnitude as i128
+            },
+            scale: places,
+        })
+    }
+
+    /// self rounded to at most the given places by the given mode,
+    /// and self itself when it has no more than that
+    /// ```
+    /// # use vmath::numbers::{Decimal, Rounding};
+    /// let x: Decimal = "-2.345".parse().unwrap();
+    /// assert_eq!(x.round(2, Rounding::HalfEven).to_string(), "-2.34");
+    /// assert_eq!(x.round(2, Rounding::HalfUp).to_string(), "-2.35");
+    /// assert_eq!(x.round(2, Rounding::Ceiling).to_string(), "-2.34");
+    /// assert_eq!(x.round(0, Rounding::Floor).to_string(), "-3");
+    /// assert_eq!(x.round(5, Rounding::Down).to_string(), "-2.345");
+    /// ```
+    pub fn round(self, places: u32, rounding: Rounding) -> Self {
+        if places >= self.scale {
+            return self;
+        }
+        let power = 10u128.pow(self.scale - places);
+        let magnitude = self.mantissa.unsigned_abs();
+        let low = magnitude % power;
+        let rest = match (2 * low).cmp(&power) {
+            _ if low == 0 => Rest::Zero,
+            Ordering::Less => Rest::BelowHalf,
+            Ordering::Equal => Rest::Half,
+            Ordering::Greater => Rest::AboveHalf,
+        };
+        // a place fewer, so rounding up stays within the mantissa
+        let magnitude = rounding.apply(magnitude / power, rest, self.is_negative()) as i128;
+        Self {
+            mantissa: if self.is_negative() {
+                -magnitude
+            } else {
+                magnitude
+            },
+            scale: places,
+        }
+    }
+
+    /// the shortest decimal that reads back as x, as `Display` prints x, rounded
+    /// half to even to as many places as fit, and None when x is infinite, NaN,
+    /// or beyond `MAX`
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// assert_eq!(Decimal::from_real(0.1).unwrap().to_string(), "0.1");
+    /// assert_eq!(Decimal::from_real(-1.0 / 3.0).unwrap().to_string(), "-0.3333333333333333");
+    /// assert_eq!(Decimal::from_real(1.5e-29).unwrap().to_string(), "0.0000000000000000000000000000");
+    /// assert_eq!(Decimal::from_real(1e30), None);
+    /// assert_eq!(Decimal::from_real(f64::NAN), None);
+    /// ```
+    pub fn from_real(x: Real) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let text = format!("{}", x.abs());
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        let digits = format!("{whole}{fraction}");
+        let (decimal, _) = from_digits(
+            x < 0.0,
+            digits.as_bytes(),
+            fraction.len(),
+            Rounding::HalfEven,
+        )?;
+        Some(decimal)
+    }
+
+    /// the nearest real, ties to even
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// assert_eq!(Decimal::new(1, 1).unwrap().to_real(), 0.1);
+    /// assert_eq!(Decimal::MAX.to_real(), 2f64.powi(96));
+    /// ```
+    pub fn to_real(self) -> Real {
+        self.to_string()
+            .parse()
+            .expect("a decimal's digits parse as a real")
+    }
+
+    /// the magnitude's digits with the point, if any, but no sign
+    fn digits(self) -> String {
+        let mut digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        if scale > 0 {
+            if digits.len() <= scale {
+                digits.insert_str(0, &"0".repeat(scale + 1 - digits.len()));
+            }
+            digits.insert(digits.len() - scale, '.');
+        }
+        digits
+    }
+}
+
+/// the mantissas of a and b at the larger scale, and that scale, None when one overflows
+fn aligned(a: Decimal, b: Decimal) -> Option<(i128, i128, u32)> {
+    let scale = a.scale.max(b.scale);
+    let up = |x: Decimal| x.mantissa.checked_mul(10i128.pow(scale - x.scale));
+    Some((up(a)?, up(b)?, scale))
+}
+
+/// mantissa / 10^scale, shedding trailing zeros until it fits, None when it does not
+fn fit(mut mantissa: i128, mut scale: u32) -> Option<Decimal> {
+    while mantissa.unsigned_abs() > MAX_MANTISSA || scale > Decimal::MAX_SCALE {
+        if scale == 0 || mantissa % 10 != 0 {
+            return None;
+        }
+        mantissa /= 10;
+        scale -= 1;
+    }
+    Some(Decimal { mantissa, scale })
+}
+
+/// the decimal digits, the last `places` of them after the point, rounded to as many
+/// places as fit, with what was rounded away; None when even the whole part does not fit
+fn from_digits(
+    negative: bool,
+    digits: &[u8],
+    places: usize,
+    rounding: Rounding,
+) -> Option<(Decimal, Rest)> {
+    let most = places.min(Decimal::MAX_SCALE as usize);
+    (0..=most).rev().find_map(|kept| {
+        let (head, tail) = digits.split_at(digits.len() - (places - kept));
+        let magnitude = head.iter().try_fold(0u128, |value, &digit| {
+            value
+                .checked_mul(10)
+                .map(|value| value + u128::from(digit - b'0'))
+                .filter(|&value| value <= MAX_MANTISSA)
+        })?;
+        let rest = match tail.first() {
+            None => Rest::Zero,
+            Some(&first) => {
+                let beyond = tail[1..].iter().any(|&digit| digit != b'0');
+                match first.cmp(&b'5') {
+                    Ordering::Less if first == b'0' && !beyond => Rest::Zero,
+                    Ordering::Less => Rest::BelowHalf,
+                    Ordering::Equal if !beyond => Rest::Half,
+                    _ => Rest::AboveHalf,
+                }
+            }
+        };
+        let magnitude = rounding.apply(magnitude, rest, negative);
+        Decimal::new(
+            if negative {
+                -(magnitude as i128)
+            } else {
+                magnitude as i128
+            },
+            kept as u32,
+        )
+        .map(|decimal| (decimal, rest))
+    })
+}
+
+/// a b in four 64 bit limbs, least significant first
+fn wide_mul(a: u128, b: u128) -> [u64; 4] {
+    let (a, b) = ([a as u64, (a >> 64) as u64], [b as u64, (b >> 64) as u64]);
+    let mut product = [0u64; 4];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let sum = u128::from(x) * u128::from(y) + u128::from(product[i + j]) + carry;
+            product[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        product[i + 2] = carry as u64;
+    }
+    product
+}
+
+/// divides the limbs in place by a small divisor and gives the remainder
+fn divide_small(limbs: &mut [u64; 4], divisor: u64) -> u64 {
+    let mut remainder = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let value = remainder << 64 | u128::from(*limb);
+        *limb = (value / u128::from(divisor)) as u64;
+        remainder = value % u128::from(divisor);
+    }
+    remainder as u64
+}
+
+macro_rules! from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Decimal {
+                fn from(value: $int) -> Self {
+                    Self {
+                        mantissa: i128::from(value),
+                        scale: 0,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = |x: &Self| x.mantissa.signum();
+        if sign(self) != sign(other) {
+            return sign(self).cmp(&sign(other));
+        }
+        // the magnitudes by whole parts and then by fractions at the larger scale,
+        // neither of which can overflow as the mantissas scaled to it could
+        let scale = self.scale.max(other.scale);
+        let parts = |x: &Self| {
+            let power = 10u128.pow(x.scale);
+            let magnitude = x.mantissa.unsigned_abs();
+            (
+                magnitude / power,
+                magnitude % power * 10u128.pow(scale - x.scale),
+            )
+        };
+        let order = parts(self).cmp(&parts(other));
+        if self.is_negative() {
+            order.reverse()
+        } else {
+            order
+        }
+    }
+}
+
+impl Hash for Decimal {
+    /// of the normalized value, so equal decimals at different scales hash alike
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normal = self.normalize();
+        normal.mantissa.hash(state);
+        normal.scale.hash(state);
+    }
+}
+
+impl Scalar for Decimal {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Neg for Decimal {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            mantissa: -self.mantissa,
+            scale: self.scale,
+        }
+    }
+}
+
+impl Add for Decimal {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("decimal overflow")
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("decimal overflow")
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("decimal overflow")
+    }
+}
+
+impl Div for Decimal {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!("division by zero");
+        }
+        self.checked_div(rhs).expect("decimal overflow")
+    }
+}
+
+impl AddAssign for Decimal {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Decimal {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Decimal {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Decimal {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// the digits at the decimal's own scale, or rounded half to even or padded with zeros
+    /// to the formatter's precision, with its sign, width, and zero padding
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let x = Decimal::new(-1050, 3).unwrap();
+    /// assert_eq!(x.to_string(), "-1.050");
+    /// assert_eq!(format!("{x:.1}"), "-1.0");
+    /// assert_eq!(format!("{x:.5}"), "-1.05000");
+    /// assert_eq!(format!("{:+09.2}", Decimal::new(15, 3).unwrap()), "+00000.02");
+    /// assert_eq!(format!("{:>6}", Decimal::new(5, 3).unwrap()), " 0.005");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = match f.precision() {
+            Some(precision) => self.round(precision as u32, Rounding::HalfEven).digits(),
+            None => self.digits(),
+        };
+        if let Some(precision) = f.precision() {
+            let scale = self.scale.min(precision as u32) as usize;
+            if precision > scale {
+                if scale == 0 {
+                    digits.push('.');
+                }
+                digits.push_str(&"0".repeat(precision - scale));
+            }
+        }
+        f.pad_integral(!self.is_negative(), "", &digits)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseError;
+
+    /// digits with an optional point and sign, as `Display` writes them,
+    /// at the scale they are written with, less any trailing zeros beyond what fits
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let x: Decimal = "-0012.340".parse().unwrap();
+    /// assert_eq!((x.mantissa(), x.scale()), (-12340, 3));
+    /// assert_eq!("+.5".parse(), Ok(Decimal::new(5, 1).unwrap()));
+    /// assert_eq!("1.".parse::<Decimal>().unwrap_err().to_string(), "missing digits at byte 2");
+    /// assert_eq!("1.2.3".parse::<Decimal>().unwrap_err().to_string(), "invalid digit at byte 3");
+    /// assert_eq!("0.00000000000000000000000000001".parse::<Decimal>().unwrap_err().to_string(), "out of range at byte 0");
+    /// ```
+    fn from_str(source: &str) -> Result<Self, ParseError> {
+        let (negative, start) = match source.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let (whole, fraction) = source[start..]
+            .split_once('.')
+            .unwrap_or((&source[start..], ""));
+        let point = start + whole.len();
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseError {
+                position: point,
+                message: "missing digits",
+            });
+        }
+        if fraction.is_empty() && point < source.len() {
+            return Err(ParseError {
+                position: point + 1,
+                message: "missing digits",
+            });
+        }
+        let digits = format!("{whole}{fraction}");
+        if let Some(at) = digits.bytes().position(|byte| !byte.is_ascii_digit()) {
+            return Err(ParseError {
+                position: if at < whole.len() {
+                    start + at
+                } else {
+                    start + at + 1
+                },
+                message: "invalid digit",
+            });
+        }
+        match from_digits(negative, digits.as_bytes(), fraction.len(), Rounding::Down) {
+            Some((decimal, Rest::Zero)) => Ok(decimal),
+            _ => Err(ParseError {
+                position: 0,
+                message: "out of range",
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decimal {
+    /// as the string `Display` writes, which keeps every digit and the scale
+    /// ```
+    /// # use vmath::numbers::Decimal;
+    /// let x: Decimal = "-1234.5600".parse().unwrap();
+    /// let json = serde_json::to_string(&x).unwrap();
+    /// assert_eq!(json, "\"-1234.5600\"");
+    /// assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap().scale(), 4);
+    /// ```
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,786 @@
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::linalg::DMatrix;
+use crate::numbers::{Field, Float, Real, Scalar};
+
+/// re + eps ε with ε^2 = 0, so f(x + ε) = f(x) + f'(x) ε for any f built
+/// from its operators and functions, and a function generic over `Scalar` or `Float`
+/// gives its own derivative, exact to rounding, when called on `Dual::variable(x)`.
+/// it orders as (re, eps), which branches on re alone where the values differ
+/// ```
+/// # use vmath::numbers::{Dual, Float};
+/// fn logistic<T: Float>(x: T) -> T {
+///     T::ONE / (T::ONE + (-x).exp())
+/// }
+/// let y = logistic(Dual::variable(0.5));
+/// let s = logistic(0.5);
+/// assert_eq!(y.re, s);
+/// assert!((y.eps - s * (1.0 - s)).abs() < 1e-16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dual {
+    pub re: Real,
+    pub eps: Real,
+}
+
+impl Dual {
+    pub const ZERO: Self = Self::constant(0.0);
+    pub const ONE: Self = Self::constant(1.0);
+
+    pub const fn new(re: Real, eps: Real) -> Self {
+        Self { re, eps }
+    }
+
+    /// x, which does not change with the variable
+    pub const fn constant(x: Real) -> Self {
+        Self::new(x, 0.0)
+    }
+
+    /// x as the variable, changing at rate 1
+    pub const fn variable(x: Real) -> Self {
+        Self::new(x, 1.0)
+    }
+
+    /// self changing at rate eps times the rate of its value
+    fn chain(self, value: Real, slope: Real) -> Self {
+        Self::new(value, self.eps * slope)
+    }
+
+    pub fn recip(self) -> Self {
+        let r = self.re.recip();
+        self.chain(r, -r * r)
+    }
+
+    /// -self below 0 and self at or above it, so at 0 the slope is the one from the right
+    pub fn abs(self) -> Self {
+        if self.re < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// a constant where eps is 0, rather than the NaN of 0 times the infinite slope at 0
+    /// ```
+    /// # use vmath::numbers::Dual;
+    /// assert_eq!(Dual::variable(4.0).sqrt(), Dual::new(2.0, 0.25));
+    /// assert_eq!(Dual::constant(0.0).sqrt(), Dual::constant(0.0));
+    /// assert_eq!(Dual::variable(0.0).sqrt().eps, f64::INFINITY);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let r = self.re.sqrt();
+        if self.eps == 0.0 {
+            return Self::constant(r);
+        }
+        self.chain(r, 0.5 / r)
+    }
+
+    /// sqrt(self^2 + other^2), scaled like `Real::hypot`, and at (0, 0), its minimum,
+    /// a constant as for `HyperDual`, rather than NaN
+    /// ```
+    /// # use vmath::numbers::Dual;
+    /// let x = Dual::variable(3.0);
+    /// assert_eq!(x.hypot(Dual::constant(4.0)), Dual::new(5.0, 0.6));
+    /// assert_eq!(Dual::variable(0.0).hypot(Dual::constant(0.0)), Dual::constant(0.0));
+    /// ```
+    pub fn hypot(self, other: Self) -> Self {
+        let h = self.re.hypot(other.re);
+        if h == 0.0 {
+            return Self::constant(h);
+        }
+        Self::new(h, (self.re / h) * self.eps + (other.re / h) * other.eps)
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        self.chain(self.re.powi(n), Real::from(n) * self.re.powi(n - 1))
+    }
+
+    /// self to the power of a constant
+    pub fn powf(self, n: Real) -> Self {
+        self.chain(self.re.powf(n), n * self.re.powf(n - 1.0))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    pub fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+
+    pub fn tan(self) -> Self {
+        let t = self.re.tan();
+        self.chain(t, 1.0 + t * t)
+    }
+
+    pub fn atan(self) -> Self {
+        self.chain(self.re.atan(), (1.0 + self.re * self.re).recip())
+    }
+
+    /// the angle of (other, self), whose derivative is
+    /// (other self' - self other') / (self^2 + other^2)
+    pub fn atan2(self, other: Self) -> Self {
+        let h = self.re.hypot(other.re);
+        let (x, y) = (other.re / h, self.re / h);
+        Self::new(self.re.atan2(other.re), (x * self.eps - y * other.eps) / h)
+    }
+
+    pub fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    pub fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    pub fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        self.chain(t, 1.0 - t * t)
+    }
+}
+
+/// f'(x), from one evaluation of f on dual numbers
+/// ```
+/// # use vmath::numbers::Scalar;
+/// # use vmath::numbers::dual::derivative;
+/// fn cube<T: Scalar>(x: T) -> T {
+///     x * x * x
+/// }
+/// assert_eq!(derivative(cube, 2.0), 12.0);
+/// assert_eq!(derivative(|x| x.sin() * x, 0.0), 0.0);
+/// ```
+pub fn derivative(f: impl Fn(Dual) -> Dual, x: Real) -> Real {
+    f(Dual::variable(x)).eps
+}
+
+/// the partial derivatives of f at x, from one evaluation per coordinate
+/// ```
+/// # use vmath::numbers::Dual;
+/// # use vmath::numbers::dual::gradient;
+/// // x y^2 + sin(x)
+/// let f = |v: &[Dual]| v[0] * v[1] * v[1] + v[0].sin();
+/// assert_eq!(gradient(f, &[0.0, 3.0]), vec![10.0, 0.0]);
+/// ```
+pub fn gradient(f: impl Fn(&[Dual]) -> Dual, x: &[Real]) -> Vec<Real> {
+    let mut point: Vec<Dual> = x.iter().map(|&x| Dual::constant(x)).collect();
+    (0..x.len())
+        .map(|i| {
+            point[i].eps = 1.0;
+            let partial = f(&point).eps;
+            point[i].eps = 0.0;
+            partial
+        })
+        .collect()
+}
+
+impl Scalar for Dual {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+/// pivoting on the values, so the derivatives of a solve follow the same
+/// eliminations as the solve
+/// ```
+/// # use vmath::linalg::{DMatrix, DVector};
+/// # use vmath::numbers::Dual;
+/// // d/dt of the solution of [[t, 1], [1, 1]] x = [1, 0] at t = 2, x = [1, -1] / (t - 1)
+/// let t = Dual::variable(2.0);
+/// let m = DMatrix::new(2, 2, vec![t, Dual::ONE, Dual::ONE, Dual::ONE]);
+/// let x = m.solve(&DVector::new(vec![Dual::ONE, Dual::ZERO])).unwrap();
+/// assert!((x[0].eps + 1.0).abs() < 1e-15 && (x[1].eps - 1.0).abs() < 1e-15);
+/// ```
+impl Field for Dual {
+    const ROUNDING: Real = Real::EPSILON;
+
+    #[inline]
+    fn magnitude(self) -> Real {
+        self.re.abs()
+    }
+}
+
+/// the functions of `Complex` written over `Float` then differentiate too
+/// ```
+/// # use vmath::numbers::{Complex, Dual};
+/// // |z| along z = t + 2ti moves at |1 + 2i|
+/// let t = Dual::variable(3.0);
+/// let z = Complex::new(t, Dual::constant(2.0) * t);
+/// assert!((z.norm().eps - 5f64.sqrt()).abs() < 1e-15);
+/// let turning = Complex::from_polar(Dual::ONE, t).angle().unwrap();
+/// assert!((turning.eps - 1.0).abs() < 1e-15);
+/// ```
+impl Float for Dual {
+    const PI: Self = Self::constant(std::f64::consts::PI);
+    const EPSILON: Self = Self::constant(Real::EPSILON);
+
+    #[inline]
+    fn abs(self) -> Self {
+        self.abs()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        self.hypot(other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        self.sin_cos()
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        self.exp()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+
+impl From<Real> for Dual {
+    fn from(x: Real) -> Self {
+        Self::constant(x)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl Add<Real> for Dual {
+    type Output = Self;
+    fn add(self, rhs: Real) -> Self {
+        Self::new(self.re + rhs, self.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl Sub<Real> for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Real) -> Self {
+        Self::new(self.re - rhs, self.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl Mul<Real> for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Real) -> Self {
+        Self::new(self.re * rhs, self.eps * rhs)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    /// (self' rhs - self rhs') / rhs^2, as self' / rhs - (self / rhs) rhs' / rhs
+    /// ```
+    /// # use vmath::numbers::Dual;
+    /// let q = Dual::new(1.0, 2.0) / Dual::new(4.0, 8.0);
+    /// assert_eq!(q, Dual::new(0.25, 0.0));
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        let q = self.re / rhs.re;
+        Self::new(q, (self.eps - q * rhs.eps) / rhs.re)
+    }
+}
+
+impl Div<Real> for Dual {
+    type Output = Self;
+    fn div(self, rhs: Real) -> Self {
+        Self::new(self.re / rhs, self.eps / rhs)
+    }
+}
+
+macro_rules! compound {
+    ($($type:ident),*) => {
+        $(
+            compound!(@assign $type, AddAssign add_assign +);
+            compound!(@assign $type, SubAssign sub_assign -);
+            compound!(@assign $type, MulAssign mul_assign *);
+            compound!(@assign $type, DivAssign div_assign /);
+
+            impl Sum for $type {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ZERO, Add::add)
+                }
+            }
+
+            impl Product for $type {
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ONE, Mul::mul)
+                }
+            }
+        )*
+    };
+    (@assign $type:ident, $trait:ident $method:ident $op:tt) => {
+        impl $trait for $type {
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl $trait<Real> for $type {
+            fn $method(&mut self, rhs: Real) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+compound!(Dual, HyperDual);
+
+/// writes " + xunit" or " - |x|unit", x with the formatter's options
+fn term(f: &mut fmt::Formatter<'_>, x: Real, unit: &str) -> fmt::Result {
+    f.write_str(if x.is_sign_negative() { " - " } else { " + " })?;
+    fmt::Display::fmt(&x.abs(), f)?;
+    f.write_str(unit)
+}
+
+impl fmt::Display for Dual {
+    /// re + epsε, each part with the formatter's options
+    /// ```
+    /// # use vmath::numbers::Dual;
+    /// assert_eq!(Dual::new(1.5, -2.0).to_string(), "1.5 - 2ε");
+    /// assert_eq!(format!("{:.1}", Dual::variable(0.0)), "0.0 + 1.0ε");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.re, f)?;
+        term(f, self.eps, "ε")
+    }
+}
+
+/// re + eps1 ε1 + eps2 ε2 + eps12 ε1ε2 with ε1^2 = ε2^2 = 0 but ε1ε2 not,
+/// so f(x + ε1 + ε2) = f(x) + f'(x) (ε1 + ε2) + f''(x) ε1ε2, and a function
+/// generic over `Scalar` or `Float` gives its second derivative along with its first,
+/// exact to rounding, when called on `HyperDual::variable(x)`.
+/// like `Dual` it orders by its parts in turn
+/// ```
+/// # use vmath::numbers::{Float, HyperDual};
+/// fn gaussian<T: Float>(x: T) -> T {
+///     (-(x * x)).exp()
+/// }
+/// let y = gaussian(HyperDual::variable(1.0));
+/// let e = (-1.0f64).exp();
+/// assert_eq!(y.re, e);
+/// assert_eq!((y.eps1, y.eps2), (-2.0 * e, -2.0 * e));
+/// assert!((y.eps12 - 2.0 * e).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperDual {
+    pub re: Real,
+    pub eps1: Real,
+    pub eps2: Real,
+    pub eps12: Real,
+}
+
+impl HyperDual {
+    pub const ZERO: Self = Self::constant(0.0);
+    pub const ONE: Self = Self::constant(1.0);
+
+    pub const fn new(re: Real, eps1: Real, eps2: Real, eps12: Real) -> Self {
+        Self {
+            re,
+            eps1,
+            eps2,
+            eps12,
+        }
+    }
+
+    /// x, which does not change with either variable
+    pub const fn constant(x: Real) -> Self {
+        Self::new(x, 0.0, 0.0, 0.0)
+    }
+
+    /// x as the variable, changing at rate 1 along both ε1 and ε2,
+    /// so eps12 of a function of it is the second derivative
+    pub const fn variable(x: Real) -> Self {
+        Self::new(x, 1.0, 1.0, 0.0)
+    }
+
+    /// f of self, given f, f' and f'' at re
+    fn chain(self, value: Real, first: Real, second: Real) -> Self {
+        Self::new(
+            value,
+            first * self.eps1,
+            first * self.eps2,
+            first * self.eps12 + second * self.eps1 * self.eps2,
+        )
+    }
+
+    pub fn recip(self) -> Self {
+        let r = self.re.recip();
+        self.chain(r, -r * r, 2.0 * r * r * r)
+    }
+
+    /// -self below 0 and self at or above it, so at 0 the slopes are the ones from the right
+    pub fn abs(self) -> Self {
+        if self.re < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// a constant where the eps parts are 0, as for `Dual::sqrt`
+    pub fn sqrt(self) -> Self {
+        let r = self.re.sqrt();
+        if self.eps1 == 0.0 && self.eps2 == 0.0 && self.eps12 == 0.0 {
+            return Self::constant(r);
+        }
+        self.chain(r, 0.5 / r, -0.25 / (r * r * r))
+    }
+
+    /// sqrt(self^2 + other^2), with the parts scaled by the larger re so the squares
+    /// do not overflow or underflow
+    pub fn hypot(self, other: Self) -> Self {
+        let scale = self.re.abs().max(other.re.abs());
+        if scale == 0.0 || scale.is_infinite() {
+            return Self::constant(self.re.hypot(other.re));
+        }
+        let (x, y) = (self / scale, other / scale);
+        (x * x + y * y).sqrt() * scale
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        let n_real = Real::from(n);
+        self.chain(
+            self.re.powi(n),
+            n_real * self.re.powi(n - 1),
+            n_real * (n_real - 1.0) * self.re.powi(n - 2),
+        )
+    }
+
+    /// self to the power of a constant
+    pub fn powf(self, n: Real) -> Self {
+        self.chain(
+            self.re.powf(n),
+            n * self.re.powf(n - 1.0),
+            n * (n - 1.0) * self.re.powf(n - 2.0),
+        )
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        let r = self.re.recip();
+        self.chain(self.re.ln(), r, -r * r)
+    }
+
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos, -sin), self.chain(cos, -sin, -cos))
+    }
+
+    pub fn tan(self) -> Self {
+        let t = self.re.tan();
+        let slope = 1.0 + t * t;
+        self.chain(t, slope, 2.0 * t * slope)
+    }
+
+    pub fn atan(self) -> Self {
+        let slope = (1.0 + self.re * self.re).recip();
+        self.chain(self.re.atan(), slope, -2.0 * self.re * slope * slope)
+    }
+
+    /// the angle of (other, self), as the angle of re added to the atan
+    /// of what is left after turning (other, self) back by it
+    pub fn atan2(self, other: Self) -> Self {
+        let angle = self.re.atan2(other.re);
+        let (sin, cos) = angle.sin_cos();
+        let along = other * cos + self * sin;
+        let across = self * cos - other * sin;
+        (across / along).atan() + angle
+    }
+
+    pub fn sinh(self) -> Self {
+        let (sinh, cosh) = (self.re.sinh(), self.re.cosh());
+        self.chain(sinh, cosh, sinh)
+    }
+
+    pub fn cosh(self) -> Self {
+        let (sinh, cosh) = (self.re.sinh(), self.re.cosh());
+        self.chain(cosh, sinh, cosh)
+    }
+
+    pub fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        let slope = 1.0 - t * t;
+        self.chain(t, slope, -2.0 * t * slope)
+    }
+}
+
+/// f''(x), from one evaluation of f on hyper-dual numbers
+/// ```
+/// # use vmath::numbers::Scalar;
+/// # use vmath::numbers::dual::second_derivative;
+/// fn quartic<T: Scalar>(x: T) -> T {
+///     x * x * x * x
+/// }
+/// assert_eq!(second_derivative(quartic, 2.0), 48.0);
+/// assert_eq!(second_derivative(|x| x.sin(), 0.0), 0.0);
+/// ```
+pub fn second_derivative(f: impl Fn(HyperDual) -> HyperDual, x: Real) -> Real {
+    f(HyperDual::variable(x)).eps12
+}
+
+/// the matrix of second partial derivatives of f at x, symmetric,
+/// from one evaluation for each entry on or above the diagonal
+/// ```
+/// # use vmath::linalg::{DMatrix, DVector};
+/// # use vmath::numbers::HyperDual;
+/// # use vmath::numbers::dual::{gradient, hessian};
+/// // rosenbrock's valley, (1 - x)^2 + 100 (y - x^2)^2, whose minimum is at (1, 1)
+/// let h = hessian(
+///     |v: &[HyperDual]| {
+///         let (x, y) = (v[0], v[1]);
+///         (HyperDual::ONE - x).powi(2) + (y - x * x).powi(2) * 100.0
+///     },
+///     &[1.0, 1.0],
+/// );
+/// assert_eq!(h, DMatrix::new(2, 2, vec![802.0, -400.0, -400.0, 200.0]));
+/// ```
+pub fn hessian(f: impl Fn(&[HyperDual]) -> HyperDual, x: &[Real]) -> DMatrix {
+    let n = x.len();
+    let mut point: Vec<HyperDual> = x.iter().map(|&x| HyperDual::constant(x)).collect();
+    let mut h = DMatrix::zeros(n, n);
+    for i in 0..n {
+        for j in i..n {
+            point[i].eps1 = 1.0;
+            point[j].eps2 = 1.0;
+            let entry = f(&point).eps12;
+            point[i].eps1 = 0.0;
+            point[j].eps2 = 0.0;
+            h[(i, j)] = entry;
+            h[(j, i)] = entry;
+        }
+    }
+    h
+}
+
+impl Scalar for HyperDual {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Float for HyperDual {
+    const PI: Self = Self::constant(std::f64::consts::PI);
+    const EPSILON: Self = Self::constant(Real::EPSILON);
+
+    #[inline]
+    fn abs(self) -> Self {
+        self.abs()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        self.hypot(other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        self.sin_cos()
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        self.exp()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+
+impl From<Real> for HyperDual {
+    fn from(x: Real) -> Self {
+        Self::constant(x)
+    }
+}
+
+impl From<Dual> for HyperDual {
+    /// the first derivative part along ε1 alone
+    fn from(x: Dual) -> Self {
+        Self::new(x.re, x.eps, 0.0, 0.0)
+    }
+}
+
+impl Neg for HyperDual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps1, -self.eps2, -self.eps12)
+    }
+}
+
+impl Add for HyperDual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.re + rhs.re,
+            self.eps1 + rhs.eps1,
+            self.eps2 + rhs.eps2,
+            self.eps12 + rhs.eps12,
+        )
+    }
+}
+
+impl Add<Real> for HyperDual {
+    type Output = Self;
+    fn add(self, rhs: Real) -> Self {
+        Self {
+            re: self.re + rhs,
+            ..self
+        }
+    }
+}
+
+impl Sub for HyperDual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Sub<Real> for HyperDual {
+    type Output = Self;
+    fn sub(self, rhs: Real) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for HyperDual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re,
+            self.re * rhs.eps1 + self.eps1 * rhs.re,
+            self.re * rhs.eps2 + self.eps2 * rhs.re,
+            self.re * rhs.eps12 + self.eps1 * rhs.eps2 + self.eps2 * rhs.eps1 + self.eps12 * rhs.re,
+        )
+    }
+}
+
+impl Mul<Real> for HyperDual {
+    type Output = Self;
+    fn mul(self, rhs: Real) -> Self {
+        Self::new(
+            self.re * rhs,
+            self.eps1 * rhs,
+            self.eps2 * rhs,
+            self.eps12 * rhs,
+        )
+    }
+}
+
+impl Div for HyperDual {
+    type Output = Self;
+    /// self times the reciprocal of rhs
+    /// ```
+    /// # use vmath::numbers::HyperDual;
+    /// let x = HyperDual::variable(2.0);
+    /// // x / x^3 = x^-2, whose second derivative at 2 is 6 / 2^4
+    /// assert_eq!((x / (x * x * x)).eps12, 0.375);
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+impl Div<Real> for HyperDual {
+    type Output = Self;
+    fn div(self, rhs: Real) -> Self {
+        Self::new(
+            self.re / rhs,
+            self.eps1 / rhs,
+            self.eps2 / rhs,
+            self.eps12 / rhs,
+        )
+    }
+}
+
+impl fmt::Display for HyperDual {
+    /// re + eps1ε1 + eps2ε2 + eps12ε1ε2, each part with the formatter's options
+    /// ```
+    /// # use vmath::numbers::HyperDual;
+    /// let x = HyperDual::variable(3.0);
+    /// assert_eq!((x * x).to_string(), "9 + 6ε1 + 6ε2 + 2ε1ε2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.re, f)?;
+        term(f, self.eps1, "ε1")?;
+        term(f, self.eps2, "ε2")?;
+        term(f, self.eps12, "ε1ε2")
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::linalg::Vec3;
+use crate::numbers::Complex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// the complex plane with a single point at infinity added, the riemann sphere.
+/// z / 0 is infinity for nonzero z and z / infinity is 0, while
+/// infinity + infinity, infinity - infinity, 0 infinity, 0 / 0, and infinity / infinity
+/// have no value and come out as a finite NaN, as they would for reals
+/// ```
+/// # use vmath::numbers::{Complex, ExtendedComplex};
+/// let two = ExtendedComplex::from(Complex::new(2.0, 0.0));
+/// let zero = ExtendedComplex::from(Complex::ZERO);
+/// assert_eq!(two / zero, ExtendedComplex::Infinity);
+/// assert_eq!(two / ExtendedComplex::Infinity, zero);
+/// assert_eq!(ExtendedComplex::Infinity + two, ExtendedComplex::Infinity);
+/// assert!((ExtendedComplex::Infinity * zero).is_nan());
+/// ```
+pub enum ExtendedComplex {
+    Finite(Complex),
+    Infinity,
+}
+
+impl ExtendedComplex {
+    pub const ZERO: Self = Self::Finite(Complex::ZERO);
+    pub const NAN: Self = Self::Finite(Complex::NAN);
+
+    pub fn is_infinity(self) -> bool {
+        self == Self::Infinity
+    }
+
+    pub fn is_nan(self) -> bool {
+        matches!(self, Self::Finite(z) if z.is_nan())
+    }
+
+    /// the finite value, None at infinity
+    pub fn finite(self) -> Option<Complex> {
+        match self {
+            Self::Finite(z) => Some(z),
+            Self::Infinity => None,
+        }
+    }
+
+    /// 1 / z, swapping 0 and infinity
+    /// ```
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// assert_eq!(ExtendedComplex::ZERO.recip(), ExtendedComplex::Infinity);
+    /// assert_eq!(ExtendedComplex::Infinity.recip(), ExtendedComplex::ZERO);
+    /// let i = ExtendedComplex::from(Complex::I);
+    /// assert_eq!(i.recip(), ExtendedComplex::from(-Complex::I));
+    /// ```
+    pub fn recip(self) -> Self {
+        Self::from(Complex::ONE) / self
+    }
+
+    /// the stereographic projection onto the unit sphere from its north pole (0, 0, 1),
+    /// which is where infinity goes, with the unit circle on the equator
+    /// and 0 at the south pole
+    /// ```
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// # use vmath::linalg::{Vec3, Vector};
+    /// assert_eq!(ExtendedComplex::Infinity.to_sphere(), Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(ExtendedComplex::ZERO.to_sphere(), Vec3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(ExtendedComplex::from(Complex::I).to_sphere(), Vec3::new(0.0, 1.0, 0.0));
+    /// // far out, without |z|^2 overflowing
+    /// let far = ExtendedComplex::from(Complex::new(1e300, 0.0)).to_sphere();
+    /// assert!((far - Vec3::new(0.0, 0.0, 1.0)).length() < 1e-15);
+    /// ```
+    pub fn to_sphere(self) -> Vec3 {
+        let z = match self {
+            Self::Finite(z) => z,
+            Self::Infinity => return Vec3::new(0.0, 0.0, 1.0),
+        };
+        if z.norm() <= 1.0 {
+            let n = z.norm_squared();
+            Vec3::new(2.0 * z.real, 2.0 * z.imaginary, n - 1.0) / (n + 1.0)
+        } else {
+            // the same point, worked out from w = 1 / z which is small
+            let w = Complex::ONE / z;
+            let n = w.norm_squared();
+            Vec3::new(2.0 * w.real, -2.0 * w.imaginary, 1.0 - n) / (n + 1.0)
+        }
+    }
+
+    /// the inverse of `to_sphere`, for a point on the unit sphere,
+    /// with the north pole going to infinity
+    /// ```
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// # use vmath::linalg::Vec3;
+    /// let z = ExtendedComplex::from(Complex::new(3.0, -4.0));
+    /// let back = ExtendedComplex::from_sphere(z.to_sphere()).finite().unwrap();
+    /// assert!((back - Complex::new(3.0, -4.0)).norm() < 1e-14);
+    /// let north = Vec3::new(0.0, 0.0, 1.0);
+    /// assert_eq!(ExtendedComplex::from_sphere(north), ExtendedComplex::Infinity);
+    /// ```
+    pub fn from_sphere(point: Vec3) -> Self {
+        if point.z == 1.0 {
+            return Self::Infinity;
+        }
+        Self::Finite(Complex::new(point.x, point.y) / (1.0 - point.z))
+    }
+}
+
+impl From<Complex> for ExtendedComplex {
+    fn from(z: Complex) -> Self {
+        Self::Finite(z)
+    }
+}
+
+impl Add for ExtendedComplex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Finite(a), Self::Finite(b)) => Self::Finite(a + b),
+            (Self::Infinity, Self::Infinity) => Self::NAN,
+            (Self::Finite(z), Self::Infinity) | (Self::Infinity, Self::Finite(z)) if z.is_nan() => {
+                Self::NAN
+            }
+            _ => Self::Infinity,
+        }
+    }
+}
+
+impl Sub for ExtendedComplex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for ExtendedComplex {
+    type Output = Self;
+    /// infinity is its own negative, having no direction
+    fn neg(self) -> Self {
+        match self {
+            Self::Finite(z) => Self::Finite(-z),
+            Self::Infinity => Self::Infinity,
+        }
+    }
+}
+
+impl Mul for ExtendedComplex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Finite(a), Self::Finite(b)) => Self::Finite(a * b),
+            (Self::Infinity, Self::Infinity) => Self::Infinity,
+            (Self::Finite(z), Self::Infinity) | (Self::Infinity, Self::Finite(z)) => {
+                if z == Complex::ZERO || z.is_nan() {
+                    Self::NAN
+                } else {
+                    Self::Infinity
+                }
+            }
+        }
+    }
+}
+
+impl Div for ExtendedComplex {
+    type Output = Self;
+    /// ```
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// let zero = ExtendedComplex::ZERO;
+    /// assert!((zero / zero).is_nan());
+    /// assert!((ExtendedComplex::Infinity / ExtendedComplex::Infinity).is_nan());
+    /// assert_eq!(ExtendedComplex::Infinity / zero, ExtendedComplex::Infinity);
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Finite(a), Self::Finite(b)) if b == Complex::ZERO => {
+                if a == Complex::ZERO || a.is_nan() {
+                    Self::NAN
+                } else {
+                    Self::Infinity
+                }
+            }
+            (Self::Finite(a), Self::Finite(b)) => Self::Finite(a / b),
+            (Self::Finite(a), Self::Infinity) if a.is_nan() => Self::NAN,
+            (Self::Finite(_), Self::Infinity) => Self::ZERO,
+            (Self::Infinity, Self::Finite(b)) if b.is_nan() => Self::NAN,
+            (Self::Infinity, Self::Finite(_)) => Self::Infinity,
+            (Self::Infinity, Self::Infinity) => Self::NAN,
+        }
+    }
+}
+
+impl fmt::Display for ExtendedComplex {
+    /// ```
+    /// # use vmath::numbers::{Complex, ExtendedComplex};
+    /// assert_eq!(ExtendedComplex::Infinity.to_string(), "inf");
+    /// assert_eq!(ExtendedComplex::from(Complex::new(1.0, -1.0)).to_string(), "1-i");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Finite(z) => fmt::Display::fmt(z, f),
+            Self::Infinity => f.pad("inf"),
+        }
+    }
+}
@@ -0,0 +1,617 @@
+//! finite fields whose size is only known at run time: the prime fields GF(p),
+//! the integers modulo a prime, and the binary fields GF(2^k), the polynomials over
+//! GF(2) modulo an irreducible one of degree k, each element's bits its coefficients.
+//! a `PrimeField` or `BinaryField` checks its modulus once and then makes elements,
+//! which carry it along, so they implement `Scalar` and generic code runs over them.
+//! their `ZERO` and `ONE` belong to no field until they meet an element of one,
+//! and arithmetic or comparison between elements of different fields panics
+//!
+//! ```
+//! use vmath::numbers::PrimeField;
+//!
+//! // shamir's secret sharing: the secret is f(0) for a quadratic f, any three of the
+//! // shares f(x) recover it by lagrange interpolation, and fewer say nothing about it
+//! let field = PrimeField::new((1 << 61) - 1).unwrap();
+//! let f = |x: u64| {
+//!     let x = field.element(x);
+//!     field.element(1234) + field.element(166) * x + field.element(94) * x * x
+//! };
+//! let shares: Vec<_> = [2, 5, 9].iter().map(|&x| (field.element(x), f(x))).collect();
+//! let secret: vmath::numbers::Gfp = shares
+//!     .iter()
+//!     .map(|&(xi, yi)| {
+//!         let basis: vmath::numbers::Gfp = shares
+//!             .iter()
+//!             .filter(|&&(xj, _)| xj != xi)
+//!             .map(|&(xj, _)| xj / (xj - xi))
+//!             .product();
+//!         yi * basis
+//!     })
+//!     .sum();
+//! assert_eq!(secret.value(), 1234);
+//! ```
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::numbers::modular::{add, inverse, is_prime, multiply, subtract};
+use crate::numbers::{Field, Real, Scalar};
+
+/// the integers modulo a prime p, checked once, to make `Gfp` elements in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimeField {
+    p: u64,
+}
+
+impl PrimeField {
+    /// None unless p is prime
+    /// ```
+    /// # use vmath::numbers::PrimeField;
+    /// assert!(PrimeField::new(65537).is_some());
+    /// assert!(PrimeField::new(65535).is_none());
+    /// ```
+    pub fn new(p: u64) -> Option<Self> {
+        is_prime(p).then_some(Self { p })
+    }
+
+    pub fn characteristic(self) -> u64 {
+        self.p
+    }
+
+    /// x mod p
+    pub fn element(self, x: u64) -> Gfp {
+        Gfp {
+            value: x % self.p,
+            p: self.p,
+        }
+    }
+
+    /// 0, 1, ..., p - 1
+    pub fn elements(self) -> impl Iterator<Item = Gfp> {
+        (0..self.p).map(move |x| self.element(x))
+    }
+}
+
+/// an element of a `PrimeField`, kept as its least residue, or the fieldless 0 or 1
+/// of `Scalar`. arithmetic on those alone panics where it would leave 0 and 1,
+/// as 1 + 1, whose value depends on the field
+/// ```
+/// # use vmath::numbers::{PrimeField, Scalar, Gfp};
+/// let field = PrimeField::new(7).unwrap();
+/// let three = field.element(3);
+/// assert_eq!(three.inverse(), Some(field.element(5)));
+/// assert_eq!(three.pow(6), Gfp::ONE);
+/// assert_eq!(-three + Gfp::ONE, field.element(5));
+/// assert_eq!(field.elements().filter(|&x| x * x == field.element(2)).count(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Gfp {
+    value: u64,
+    // 0 for the fieldless constants
+    p: u64,
+}
+
+impl Gfp {
+    pub const ZERO: Self = Self { value: 0, p: 0 };
+    pub const ONE: Self = Self { value: 1, p: 0 };
+
+    /// the least residue
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// None for the fieldless 0 and 1
+    pub fn field(self) -> Option<PrimeField> {
+        (self.p != 0).then_some(PrimeField { p: self.p })
+    }
+
+    pub fn pow(self, mut e: u64) -> Self {
+        let (mut power, mut square) = (Self::ONE, self);
+        while e > 0 {
+            if e & 1 == 1 {
+                power *= square;
+            }
+            square = square * square;
+            e >>= 1;
+        }
+        power
+    }
+
+    /// None for 0
+    pub fn inverse(self) -> Option<Self> {
+        match self.p {
+            0 => (self.value == 1).then_some(self),
+            p => inverse(self.value, p).map(|value| Self { value, p }),
+        }
+    }
+
+    /// the modulus of self and rhs, None when both are fieldless
+    fn common(self, rhs: Self) -> Option<u64> {
+        match (self.p, rhs.p) {
+            (0, 0) => None,
+            (0, p) | (p, 0) => Some(p),
+            (p, q) => {
+                assert_eq!(p, q, "elements of different fields");
+                Some(p)
+            }
+        }
+    }
+
+    /// the fieldless value, when it is 0 or 1
+    fn fieldless(value: Option<u64>) -> Self {
+        match value {
+            Some(value @ (0 | 1)) => Self { value, p: 0 },
+            _ => panic!("arithmetic on ZERO and ONE alone left them, with no field to be in"),
+        }
+    }
+}
+
+impl Scalar for Gfp {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Field for Gfp {
+    const ROUNDING: Real = 0.0;
+
+    fn magnitude(self) -> Real {
+        if self.value == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+impl PartialEq for Gfp {
+    /// equal values in the same field, where one may be fieldless.
+    /// panics for elements of different fields, which would otherwise both equal `ONE`
+    /// and not each other
+    /// ```should_panic
+    /// # use vmath::numbers::PrimeField;
+    /// let _ = PrimeField::new(5).unwrap().element(1) == PrimeField::new(7).unwrap().element(1);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.common(*other);
+        self.value == other.value
+    }
+}
+
+impl Eq for Gfp {}
+
+impl Hash for Gfp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Neg for Gfp {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl Add for Gfp {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        match self.common(rhs) {
+            Some(p) => Self {
+                value: add(self.value, rhs.value, p),
+                p,
+            },
+            None => Self::fieldless(Some(self.value + rhs.value)),
+        }
+    }
+}
+
+impl Sub for Gfp {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        match self.common(rhs) {
+            Some(p) => Self {
+                value: subtract(self.value, rhs.value, p),
+                p,
+            },
+            None => Self::fieldless(self.value.checked_sub(rhs.value)),
+        }
+    }
+}
+
+impl Mul for Gfp {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        match self.common(rhs) {
+            Some(p) => Self {
+                value: multiply(self.value, rhs.value, p),
+                p,
+            },
+            None => Self::fieldless(Some(self.value * rhs.value)),
+        }
+    }
+}
+
+impl Div for Gfp {
+    type Output = Self;
+    /// panics when rhs is 0
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("division by zero"))
+    }
+}
+
+impl fmt::Display for Gfp {
+    /// the least residue, with the formatter's options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+/// the polynomials over GF(2) modulo an irreducible one of degree k from 1 to 63,
+/// checked once, to make `Gf2k` elements in. the modulus is given by its bits,
+/// bit i the coefficient of x^i, as are the elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BinaryField {
+    polynomial: u64,
+}
+
+impl BinaryField {
+    /// GF(2^8) modulo x^8 + x^4 + x^3 + x + 1, the field of aes
+    pub const AES: Self = Self { polynomial: 0x11b };
+
+    /// None unless polynomial is irreducible over GF(2), of degree 1 to 63,
+    /// by rabin's test
+    /// ```
+    /// # use vmath::numbers::BinaryField;
+    /// // x^8 + x^4 + x^3 + x + 1
+    /// assert_eq!(BinaryField::new(0x11b), Some(BinaryField::AES));
+    /// // x^2 + 1 = (x + 1)^2
+    /// assert!(BinaryField::new(0b101).is_none());
+    /// // x^4 + x^3 + x^2 + x + 1, irreducible though x is not a generator
+    /// assert!(BinaryField::new(0b11111).is_some());
+    /// // x^6 + x^3 + 1 divides x^9 + 1, and is irreducible
+    /// assert!(BinaryField::new(0b1001001).is_some());
+    /// assert!(BinaryField::new((1 << 63) | 0b11).is_some());
+    /// ```
+    pub fn new(polynomial: u64) -> Option<Self> {
+        if polynomial < 2 {
+            return None;
+        }
+        let field = Self { polynomial };
+        let k = field.degree();
+        let x = field.reduce(0b10);
+        // x^(2^i) mod polynomial, for i up to k
+        let mut powers = vec![x];
+        for i in 0..k as usize {
+            powers.push(field.multiply(powers[i], powers[i]));
+        }
+        let divides_all = powers[k as usize] == x;
+        let no_smaller_factor = (2..=k)
+            .filter(|&q| k.is_multiple_of(q) && is_prime(u64::from(q)))
+            .all(|q| polynomial_gcd(powers[(k / q) as usize] ^ x, polynomial) == 1);
+        (divides_all && no_smaller_factor).then_some(field)
+    }
+
+    pub fn degree(self) -> u32 {
+        degree(self.polynomial)
+    }
+
+    /// 2^k, the number of elements
+    pub fn order(self) -> u64 {
+        1 << self.degree()
+    }
+
+    /// bits reduced modulo the field's polynomial
+    pub fn element(self, bits: u64) -> Gf2k {
+        Gf2k {
+            bits: self.reduce(bits),
+            polynomial: self.polynomial,
+        }
+    }
+
+    /// the elements in order of their bits, from 0 to 2^k - 1
+    pub fn elements(self) -> impl Iterator<Item = Gf2k> {
+        (0..self.order()).map(move |bits| self.element(bits))
+    }
+
+    fn reduce(self, mut bits: u64) -> u64 {
+        let k = self.degree();
+        for i in (k..u64::BITS).rev() {
+            if (bits >> i) & 1 == 1 {
+                bits ^= self.polynomial << (i - k);
+            }
+        }
+        bits
+    }
+
+    /// a b modulo the polynomial, for a and b below x^k, one bit of b at a time
+    fn multiply(self, a: u64, b: u64) -> u64 {
+        let k = self.degree();
+        let mut product = 0;
+        for i in (0..k).rev() {
+            product <<= 1;
+            if (product >> k) & 1 == 1 {
+                product ^= self.polynomial;
+            }
+            if (b >> i) & 1 == 1 {
+                product ^= a;
+            }
+        }
+        product
+    }
+}
+
+/// the index of the top bit, so the degree of a nonzero polynomial over GF(2)
+fn degree(polynomial: u64) -> u32 {
+    u64::BITS - 1 - polynomial.leading_zeros()
+}
+
+/// the sum of polynomials over GF(2), which adds each coefficient mod 2
+fn add_polynomials(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+/// a mod b as polynomials over GF(2), b nonzero
+fn polynomial_remainder(mut a: u64, b: u64) -> u64 {
+    while a != 0 && degree(a) >= degree(b) {
+        a ^= b << (degree(a) - degree(b));
+    }
+    a
+}
+
+fn polynomial_gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, polynomial_remainder(a, b));
+    }
+    a
+}
+
+/// an element of a `BinaryField`, a polynomial of degree below k in its bits,
+/// or the fieldless 0 or 1 of `Scalar`, which like every element of GF(2^k)
+/// are their own negatives, so arithmetic on them alone stays in 0 and 1
+/// ```
+/// # use vmath::numbers::BinaryField;
+/// // the worked examples of fips 197
+/// let field = BinaryField::AES;
+/// let (a, b) = (field.element(0x57), field.element(0x83));
+/// assert_eq!(a + b, field.element(0xd4));
+/// assert_eq!(a * b, field.element(0xc1));
+/// assert_eq!(a * field.element(0x13), field.element(0xfe));
+/// assert_eq!(field.element(0x53).inverse(), Some(field.element(0xca)));
+/// assert_eq!(a.to_string(), "x^6 + x^4 + x^2 + x + 1");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Gf2k {
+    bits: u64,
+    // 0 for the fieldless constants
+    polynomial: u64,
+}
+
+impl Gf2k {
+    pub const ZERO: Self = Self {
+        bits: 0,
+        polynomial: 0,
+    };
+    pub const ONE: Self = Self {
+        bits: 1,
+        polynomial: 0,
+    };
+
+    /// the coefficients, bit i that of x^i
+    pub fn bits(self) -> u64 {
+        self.bits
+    }
+
+    /// None for the fieldless 0 and 1
+    pub fn field(self) -> Option<BinaryField> {
+        (self.polynomial != 0).then_some(BinaryField {
+            polynomial: self.polynomial,
+        })
+    }
+
+    pub fn pow(self, mut e: u64) -> Self {
+        let (mut power, mut square) = (Self::ONE, self);
+        while e > 0 {
+            if e & 1 == 1 {
+                power *= square;
+            }
+            square = square * square;
+            e >>= 1;
+        }
+        power
+    }
+
+    /// None for 0, by euclid's algorithm on the polynomials
+    /// ```
+    /// # use vmath::numbers::BinaryField;
+    /// let field = BinaryField::new((1 << 63) | 0b11).unwrap();
+    /// assert!(field.elements().skip(1).take(1000).all(|a| a * a.inverse().unwrap() == field.element(1)));
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        if self.bits == 0 {
+            return None;
+        }
+        if self.polynomial == 0 {
+            return Some(self);
+        }
+        // r = s self modulo the polynomial, down to r = 1
+        let (mut r, mut next_r) = (self.polynomial, self.bits);
+        let (mut s, mut next_s) = (0, 1);
+        while next_r != 0 {
+            while r != 0 && degree(r) >= degree(next_r) {
+                let shift = degree(r) - degree(next_r);
+                r ^= next_r << shift;
+                s ^= next_s << shift;
+            }
+            (r, next_r) = (next_r, r);
+            (s, next_s) = (next_s, s);
+        }
+        Some(Self {
+            bits: s,
+            polynomial: self.polynomial,
+        })
+    }
+
+    /// the polynomial of self and rhs, None when both are fieldless
+    fn common(self, rhs: Self) -> Option<u64> {
+        match (self.polynomial, rhs.polynomial) {
+            (0, 0) => None,
+            (0, p) | (p, 0) => Some(p),
+            (p, q) => {
+                assert_eq!(p, q, "elements of different fields");
+                Some(p)
+            }
+        }
+    }
+}
+
+impl Scalar for Gf2k {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Field for Gf2k {
+    const ROUNDING: Real = 0.0;
+
+    fn magnitude(self) -> Real {
+        if self.bits == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+impl PartialEq for Gf2k {
+    /// equal bits in the same field, where one may be fieldless.
+    /// panics for elements of different fields, as for `Gfp`
+    fn eq(&self, other: &Self) -> bool {
+        self.common(*other);
+        self.bits == other.bits
+    }
+}
+
+impl Eq for Gf2k {}
+
+impl Hash for Gf2k {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl Neg for Gf2k {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Add for Gf2k {
+    type Output = Self;
+    /// the coefficients' exclusive or
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            bits: add_polynomials(self.bits, rhs.bits),
+            polynomial: self.common(rhs).unwrap_or(0),
+        }
+    }
+}
+
+impl Sub for Gf2k {
+    type Output = Self;
+    /// the same as adding, in characteristic 2
+    fn sub(self, rhs: Self) -> Self {
+        Add::add(self, rhs)
+    }
+}
+
+impl Mul for Gf2k {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        match self.common(rhs) {
+            Some(polynomial) => Self {
+                bits: BinaryField { polynomial }.multiply(self.bits, rhs.bits),
+                polynomial,
+            },
+            None => Self {
+                bits: self.bits * rhs.bits,
+                polynomial: 0,
+            },
+        }
+    }
+}
+
+impl Div for Gf2k {
+    type Output = Self;
+    /// panics when rhs is 0
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("division by zero"))
+    }
+}
+
+impl fmt::Display for Gf2k {
+    /// as a polynomial in x, highest power first
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bits == 0 {
+            return f.write_str("0");
+        }
+        let mut first = true;
+        for i in (0..u64::BITS).rev().filter(|i| (self.bits >> i) & 1 == 1) {
+            if !first {
+                f.write_str(" + ")?;
+            }
+            first = false;
+            match i {
+                0 => f.write_str("1")?,
+                1 => f.write_str("x")?,
+                _ => write!(f, "x^{i}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::LowerHex for Gf2k {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.bits, f)
+    }
+}
+
+impl fmt::Binary for Gf2k {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.bits, f)
+    }
+}
+
+macro_rules! compound {
+    ($($type:ident),*) => {
+        $(
+            compound!(@assign $type, AddAssign add_assign +);
+            compound!(@assign $type, SubAssign sub_assign -);
+            compound!(@assign $type, MulAssign mul_assign *);
+            compound!(@assign $type, DivAssign div_assign /);
+
+            impl Sum for $type {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ZERO, Add::add)
+                }
+            }
+
+            impl Product for $type {
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ONE, Mul::mul)
+                }
+            }
+        )*
+    };
+    (@assign $type:ident, $trait:ident $method:ident $op:tt) => {
+        impl $trait for $type {
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+compound!(Gfp, Gf2k);
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use super::Complex;
+
+/// a + bi where a and b are integers, with exact arithmetic.
+/// like the integers it is built on, it panics on overflow in debug builds,
+/// and dividing by zero panics
+/// ```
+/// # use vmath::numbers::GaussianInt;
+/// let z = GaussianInt::new(3, 4);
+/// assert_eq!(z * z.conjugate(), GaussianInt::new(25, 0));
+/// assert_eq!(z.norm(), 25);
+/// // 5 is not prime over the gaussian integers
+/// assert_eq!(GaussianInt::new(2, 1) * GaussianInt::new(2, -1), GaussianInt::new(5, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaussianInt {
+    pub real: i64,
+    pub imaginary: i64,
+}
+
+impl GaussianInt {
+    pub const ZERO: Self = Self::new(0, 0);
+    pub const ONE: Self = Self::new(1, 0);
+    pub const I: Self = Self::new(0, 1);
+
+    pub const fn new(real: i64, imaginary: i64) -> Self {
+        Self { real, imaginary }
+    }
+
+    pub const fn conjugate(self) -> Self {
+        Self::new(self.real, -self.imaginary)
+    }
+
+    /// a^2 + b^2, widened so it is exact for any parts
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// assert_eq!(GaussianInt::new(i64::MIN, i64::MIN).norm(), 1 << 127);
+    /// ```
+    pub const fn norm(self) -> u128 {
+        let (a, b) = (
+            self.real.unsigned_abs() as u128,
+            self.imaginary.unsigned_abs() as u128,
+        );
+        a * a + b * b
+    }
+
+    /// whether this divides 1, so is one of 1, i, -1, -i
+    pub const fn is_unit(self) -> bool {
+        self.norm() == 1
+    }
+
+    /// the quotient rounded to the nearest gaussian integer and the remainder,
+    /// so self = q rhs + r with r's norm at most half of rhs's.
+    /// panics when rhs is zero
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// let a = GaussianInt::new(27, -23);
+    /// let b = GaussianInt::new(8, 1);
+    /// let (q, r) = a.div_rem(b);
+    /// assert_eq!(q * b + r, a);
+    /// assert!(2 * r.norm() <= b.norm());
+    /// assert_eq!((q, r), (GaussianInt::new(3, -3), GaussianInt::new(0, -2)));
+    /// ```
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        self.checked_div_rem(rhs).expect("division by zero")
+    }
+
+    /// `div_rem`, None when rhs is zero
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// assert_eq!(GaussianInt::ONE.checked_div_rem(GaussianInt::ZERO), None);
+    /// ```
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        // self / rhs = self conj(rhs) / |rhs|^2, rounding each part to the nearest integer
+        let (a, b) = (i128::from(self.real), i128::from(self.imaginary));
+        let (c, d) = (i128::from(rhs.real), i128::from(rhs.imaginary));
+        let norm = c * c + d * d;
+        let nearest = |numerator: i128| {
+            let (quotient, remainder) = (numerator.div_euclid(norm), numerator.rem_euclid(norm));
+            let rounded = if remainder > norm - remainder {
+                quotient + 1
+            } else {
+                quotient
+            };
+            i64::try_from(rounded).expect("quotient overflows i64")
+        };
+        let quotient = Self::new(nearest(a * c + b * d), nearest(b * c - a * d));
+        Some((quotient, self - quotient * rhs))
+    }
+
+    /// the greatest common divisor, by euclid's algorithm, as the associate
+    /// with a positive real part and a nonnegative imaginary one,
+    /// zero only when both are
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// let p = GaussianInt::new(2, 1);
+    /// let a = p * GaussianInt::new(3, 2);
+    /// let b = p * GaussianInt::new(1, -4) * GaussianInt::I;
+    /// assert_eq!(a.gcd(b), GaussianInt::new(2, 1));
+    /// // 3 + 2i and 1 - 4i share no factor but units
+    /// assert!(GaussianInt::new(3, 2).gcd(GaussianInt::new(1, -4)).is_unit());
+    /// assert_eq!(GaussianInt::ZERO.gcd(GaussianInt::new(0, -3)), GaussianInt::new(3, 0));
+    /// ```
+    pub fn gcd(self, rhs: Self) -> Self {
+        let (mut a, mut b) = (self, rhs);
+        while b != Self::ZERO {
+            (a, b) = (b, a % b);
+        }
+        a.normalized()
+    }
+
+    /// the associate, z times a unit, in the quadrant real > 0, imaginary >= 0
+    fn normalized(self) -> Self {
+        let mut z = self;
+        if z == Self::ZERO {
+            return z;
+        }
+        while z.real <= 0 || z.imaginary < 0 {
+            // times -i turns a quarter clockwise
+            z = Self::new(z.imaginary, -z.real);
+        }
+        z
+    }
+}
+
+impl Add for GaussianInt {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.real + rhs.real, self.imaginary + rhs.imaginary)
+    }
+}
+
+impl Sub for GaussianInt {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.real - rhs.real, self.imaginary - rhs.imaginary)
+    }
+}
+
+impl Neg for GaussianInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.imaginary)
+    }
+}
+
+impl Mul for GaussianInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.real * rhs.real - self.imaginary * rhs.imaginary,
+            self.real * rhs.imaginary + self.imaginary * rhs.real,
+        )
+    }
+}
+
+impl Mul<i64> for GaussianInt {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.real * rhs, self.imaginary * rhs)
+    }
+}
+
+impl Div for GaussianInt {
+    type Output = Self;
+    /// the quotient of `div_rem`, rounded to nearest rather than toward zero
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// assert_eq!(GaussianInt::new(7, 0) / GaussianInt::new(2, 0), GaussianInt::new(3, 0));
+    /// assert_eq!(GaussianInt::new(5, 0) / GaussianInt::new(2, 1), GaussianInt::new(2, -1));
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for GaussianInt {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+impl From<i64> for GaussianInt {
+    fn from(real: i64) -> Self {
+        Self::new(real, 0)
+    }
+}
+
+impl From<GaussianInt> for Complex {
+    /// exact while the parts are within 2^53
+    fn from(z: GaussianInt) -> Self {
+        Complex::new(z.real as f64, z.imaginary as f64)
+    }
+}
+
+impl fmt::Display for GaussianInt {
+    /// as `Complex` writes itself
+    /// ```
+    /// # use vmath::numbers::GaussianInt;
+    /// assert_eq!(GaussianInt::new(3, -4).to_string(), "3-4i");
+    /// assert_eq!(GaussianInt::new(0, 1).to_string(), "i");
+    /// assert_eq!(GaussianInt::new(-2, 0).to_string(), "-2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = (self.real, self.imaginary);
+        let coefficient = |b: u64| {
+            if b == 1 {
+                String::new()
+            } else {
+                b.to_string()
+            }
+        };
+        let text = if b == 0 {
+            a.to_string()
+        } else if a == 0 {
+            let sign = if b < 0 { "-" } else { "" };
+            format!("{sign}{}i", coefficient(b.unsigned_abs()))
+        } else {
+            let sign = if b < 0 { '-' } else { '+' };
+            format!("{a}{sign}{}i", coefficient(b.unsigned_abs()))
+        };
+        f.pad(&text)
+    }
+}
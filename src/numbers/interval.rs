@@ -0,0 +1,549 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::numbers::{Complex, Real, Scalar};
+use crate::Error;
+
+/// the reals from lo to hi, with arithmetic rounded outward, so the result of each
+/// operation holds every result of the operation on points of its operands.
+/// carried through a computation in place of reals it bounds the rounding error
+/// rigorously, where a real result gives no hint of how far off it is.
+/// either end may be infinite, lo on the low side and hi on the high,
+/// and dividing by an interval holding zero gives the whole line
+/// ```
+/// # use vmath::numbers::Interval;
+/// // rump's example, which f64 evaluates to about 1.2e21 when it is -0.827...
+/// let (a, b) = (Interval::from(77617.0), Interval::from(33096.0));
+/// let power = |x: Interval, n: u32| (1..n).fold(x, |p, _| p * x);
+/// let f = Interval::from(333.75) * power(b, 6)
+///     + a * a
+///         * (Interval::from(11.0) * a * a * b * b
+///             - power(b, 6)
+///             - Interval::from(121.0) * power(b, 4)
+///             - Interval::from(2.0))
+///     + Interval::from(5.5) * power(b, 8)
+///     + a / (Interval::from(2.0) * b);
+/// // and the interval owns up to knowing nothing
+/// assert!(f.contains(-0.8273960599468213));
+/// assert!(f.width() > 1e21);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[Real; 2]", into = "[Real; 2]"))]
+pub struct Interval {
+    // lo <= hi, neither NaN, lo below infinity and hi above minus infinity
+    lo: Real,
+    hi: Real,
+}
+
+impl Interval {
+    pub const ZERO: Self = Self { lo: 0.0, hi: 0.0 };
+    pub const ONE: Self = Self { lo: 1.0, hi: 1.0 };
+    pub const ENTIRE: Self = Self {
+        lo: Real::NEG_INFINITY,
+        hi: Real::INFINITY,
+    };
+    /// the two reals either side of pi
+    pub const PI: Self = Self {
+        lo: std::f64::consts::PI,
+        hi: std::f64::consts::PI.next_up(),
+    };
+    const HALF_PI: Self = Self {
+        lo: FRAC_PI_2,
+        hi: FRAC_PI_2.next_up(),
+    };
+
+    /// panics unless lo <= hi, lo is below infinity, and hi above minus infinity
+    pub fn new(lo: Real, hi: Real) -> Self {
+        Self::checked_new(lo, hi).expect("invalid interval bounds")
+    }
+
+    /// None unless lo <= hi, lo is below infinity, and hi above minus infinity
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// assert!(Interval::checked_new(1.0, f64::INFINITY).is_some());
+    /// assert!(Interval::checked_new(2.0, 1.0).is_none());
+    /// assert!(Interval::checked_new(f64::NAN, 1.0).is_none());
+    /// ```
+    pub fn checked_new(lo: Real, hi: Real) -> Option<Self> {
+        (lo <= hi && lo < Real::INFINITY && hi > Real::NEG_INFINITY).then_some(Self { lo, hi })
+    }
+
+    pub fn lo(self) -> Real {
+        self.lo
+    }
+
+    pub fn hi(self) -> Real {
+        self.hi
+    }
+
+    /// the midpoint, rounded, or 0 for the whole line and the infinite end
+    /// when only one is
+    pub fn mid(self) -> Real {
+        let mid = self.lo / 2.0 + self.hi / 2.0;
+        if mid.is_nan() {
+            0.0
+        } else {
+            mid.clamp(self.lo, self.hi)
+        }
+    }
+
+    /// hi - lo, rounded up
+    pub fn width(self) -> Real {
+        up(sum(self.hi, -self.lo))
+    }
+
+    pub fn contains(self, x: Real) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// the reals in both, None when they do not meet
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let (a, b) = (Interval::new(0.0, 2.0), Interval::new(1.0, 3.0));
+    /// assert_eq!(a.intersection(b), Some(Interval::new(1.0, 2.0)));
+    /// assert_eq!(a.hull(b), Interval::new(0.0, 3.0));
+    /// assert_eq!(a.intersection(Interval::new(5.0, 6.0)), None);
+    /// ```
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::checked_new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// the smallest interval holding both
+    pub fn hull(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    pub fn abs(self) -> Self {
+        if self.lo >= 0.0 {
+            self
+        } else if self.hi <= 0.0 {
+            -self
+        } else {
+            Self {
+                lo: 0.0,
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+
+    /// the squares of the reals in self, which unlike self * self
+    /// knows both factors are the same
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let x = Interval::new(-1.0, 2.0);
+    /// assert_eq!(x.square(), Interval::new(0.0, 4.0));
+    /// assert_eq!(x * x, Interval::new(-2.0, 4.0));
+    /// ```
+    pub fn square(self) -> Self {
+        let x = self.abs();
+        Self {
+            lo: down(product(x.lo, x.lo)),
+            hi: up(product(x.hi, x.hi)),
+        }
+    }
+
+    /// the square roots of the reals in self at or above 0,
+    /// an error when there are none
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// # use vmath::Error;
+    /// let root = Interval::from(2.0).sqrt().unwrap();
+    /// assert_eq!(root.hi(), root.lo().next_up());
+    /// assert_eq!(Interval::new(-4.0, 9.0).sqrt(), Ok(Interval::new(0.0, 3.0)));
+    /// assert_eq!(Interval::new(-4.0, -1.0).sqrt(), Err(Error::DomainError));
+    /// ```
+    pub fn sqrt(self) -> Result<Self, Error> {
+        if self.hi < 0.0 {
+            return Err(Error::DomainError);
+        }
+        Ok(Self {
+            lo: down(root(self.lo.max(0.0))),
+            hi: up(root(self.hi)),
+        })
+    }
+
+    /// e to the power of self. like ln, sin and cos it moves the ends of the
+    /// platform's results out two steps, as its math library is faithful to within an ulp
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let e = Interval::ONE.exp();
+    /// assert!(e.contains(std::f64::consts::E) && e.width() < 1e-14);
+    /// assert_eq!(Interval::new(f64::NEG_INFINITY, 0.0).exp().lo(), 0.0);
+    /// ```
+    pub fn exp(self) -> Self {
+        Self {
+            lo: self.lo.exp().next_down().next_down().max(0.0),
+            hi: self.hi.exp().next_up().next_up(),
+        }
+    }
+
+    /// the natural logarithms of the reals in self above 0, with minus infinity
+    /// for lo when self reaches 0, an error when there are none
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// # use vmath::Error;
+    /// assert!(Interval::from(10.0).ln().unwrap().contains(std::f64::consts::LN_10));
+    /// assert_eq!(Interval::new(0.0, 1.0).ln().unwrap().lo(), f64::NEG_INFINITY);
+    /// assert_eq!(Interval::new(-1.0, 0.0).ln(), Err(Error::DomainError));
+    /// ```
+    pub fn ln(self) -> Result<Self, Error> {
+        if self.hi <= 0.0 {
+            return Err(Error::DomainError);
+        }
+        let lo = if self.lo <= 0.0 {
+            Real::NEG_INFINITY
+        } else {
+            self.lo.ln().next_down().next_down()
+        };
+        Ok(Self {
+            lo,
+            hi: self.hi.ln().next_up().next_up(),
+        })
+    }
+
+    /// the sines of the reals in self, reaching 1 or -1 where self holds a peak
+    /// or a trough. past 2^20 in magnitude, where the peaks can no longer be placed
+    /// to within a small fraction of an ulp, it is all of [-1, 1]
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let x = Interval::new(1.0, 2.0).sin();
+    /// assert_eq!(x.hi(), 1.0);
+    /// assert!(x.contains(1f64.sin()) && x.lo() < 1f64.sin());
+    /// assert_eq!(Interval::new(-0.5, 0.5).sin().lo(), (-0.5f64).sin().next_down().next_down());
+    /// assert_eq!(Interval::PI.sin().hull(Interval::ZERO), Interval::PI.sin());
+    /// assert_eq!(Interval::new(0.0, 7.0).sin(), Interval::new(-1.0, 1.0));
+    /// ```
+    pub fn sin(self) -> Self {
+        let all = Self { lo: -1.0, hi: 1.0 };
+        if self.lo.abs().max(self.hi.abs()) > 1048576.0 || self.hi - self.lo >= TAU {
+            return all;
+        }
+        let (a, b) = (self.lo.sin(), self.hi.sin());
+        Self {
+            lo: if self.reaches(-FRAC_PI_2) {
+                -1.0
+            } else {
+                a.min(b).next_down().next_down().max(-1.0)
+            },
+            hi: if self.reaches(FRAC_PI_2) {
+                1.0
+            } else {
+                a.max(b).next_up().next_up().min(1.0)
+            },
+        }
+    }
+
+    /// the cosines of the reals in self, as the sines of self + pi / 2
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// assert!(Interval::ZERO.cos().contains(1.0));
+    /// assert_eq!(Interval::new(3.0, 3.5).cos().lo(), -1.0);
+    /// assert!(Interval::PI.cos().contains(-1.0));
+    /// ```
+    pub fn cos(self) -> Self {
+        (self + Self::HALF_PI).sin()
+    }
+
+    /// whether self might hold phase + 2k pi for an integer k, erring towards yes
+    fn reaches(self, phase: Real) -> bool {
+        let margin = 1e-9;
+        let last = ((self.hi - phase) / TAU + margin).floor();
+        last >= (self.lo - phase) / TAU - margin
+    }
+}
+
+/// below this the error of a product or quotient, or the remainder of a quotient,
+/// may be too small for even a subnormal to show which way it went
+const TINY: Real = Real::MIN_POSITIVE * (1u64 << 54) as Real;
+
+/// a + b rounded, and the exact sum minus that, NaN when it overflows
+fn sum(a: Real, b: Real) -> (Real, Real) {
+    let s = a + b;
+    if !s.is_finite() {
+        return (s, Real::NAN);
+    }
+    let b_part = s - a;
+    (s, (a - (s - b_part)) + (b - b_part))
+}
+
+/// a b rounded, and something with the sign of the exact product minus that,
+/// NaN when the sign cannot be known. 0 times infinity is 0, as the bound of a
+/// product of intervals that reaches 0 and infinity
+fn product(a: Real, b: Real) -> (Real, Real) {
+    if a == 0.0 || b == 0.0 {
+        return (0.0, 0.0);
+    }
+    let p = a * b;
+    if a.is_infinite() || b.is_infinite() {
+        (p, 0.0)
+    } else if !p.is_finite() || p.abs() < TINY {
+        (p, Real::NAN)
+    } else {
+        (p, a.mul_add(b, -p))
+    }
+}
+
+/// a / b rounded, and something with the sign of the exact quotient minus that,
+/// NaN when the sign cannot be known. b is not 0, and a quotient of infinities is 0,
+/// which the other ends of the intervals cover for
+fn quotient(a: Real, b: Real) -> (Real, Real) {
+    if a == 0.0 || a.is_infinite() && b.is_infinite() {
+        return (0.0, 0.0);
+    }
+    let q = a / b;
+    if a.is_infinite() || b.is_infinite() {
+        (q, 0.0)
+    } else if !q.is_finite() || q.abs() < TINY || a.abs() < TINY {
+        (q, Real::NAN)
+    } else {
+        let remainder = -q.mul_add(b, -a);
+        (q, if b > 0.0 { remainder } else { -remainder })
+    }
+}
+
+/// the square root of x >= 0 rounded, and the exact root minus that in sign
+fn root(x: Real) -> (Real, Real) {
+    let r = x.sqrt();
+    if x == 0.0 || x.is_infinite() {
+        (r, 0.0)
+    } else if x < TINY {
+        (r, Real::NAN)
+    } else {
+        (r, (-r).mul_add(r, x))
+    }
+}
+
+/// a lower bound on the exact result from its rounding and the sign of the error
+fn down((x, error): (Real, Real)) -> Real {
+    if x == Real::INFINITY {
+        Real::MAX
+    } else if error < 0.0 || error.is_nan() {
+        x.next_down()
+    } else {
+        x
+    }
+}
+
+/// an upper bound on the exact result from its rounding and the sign of the error
+fn up((x, error): (Real, Real)) -> Real {
+    if x == Real::NEG_INFINITY {
+        -Real::MAX
+    } else if error > 0.0 || error.is_nan() {
+        x.next_up()
+    } else {
+        x
+    }
+}
+
+impl Scalar for Interval {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    /// (a + bi) conj(c + di) / |c + di|^2, with the squares as tight as `square` makes them,
+    /// the whole plane when c + di reaches 0
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        let norm_squared = c.square() + d.square();
+        (
+            (a * c + b * d) / norm_squared,
+            (b * c - a * d) / norm_squared,
+        )
+    }
+}
+
+/// the point x, panics when it is infinite or NaN. x is already rounded,
+/// so `Interval::from(0.1)` holds the real nearest to 0.1, not 0.1 itself
+impl From<Real> for Interval {
+    fn from(x: Real) -> Self {
+        assert!(
+            x.is_finite(),
+            "an interval of one point needs a finite real"
+        );
+        Self { lo: x, hi: x }
+    }
+}
+
+impl TryFrom<[Real; 2]> for Interval {
+    type Error = Error;
+    fn try_from([lo, hi]: [Real; 2]) -> Result<Self, Error> {
+        Self::checked_new(lo, hi).ok_or(Error::DomainError)
+    }
+}
+
+impl From<Interval> for [Real; 2] {
+    fn from(x: Interval) -> Self {
+        [x.lo, x.hi]
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    /// the ends summed and rounded away from each other, except where the sums are exact
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// // the reals nearest 0.1 and 0.2 sum to just under 0.30000000000000004
+    /// let sum = Interval::from(0.1) + Interval::from(0.2);
+    /// assert_eq!((sum.lo(), sum.hi()), (0.3, 0.30000000000000004));
+    /// assert_eq!(Interval::ONE + Interval::ONE, Interval::from(2.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            lo: down(sum(self.lo, rhs.lo)),
+            hi: up(sum(self.hi, rhs.hi)),
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let corners = [
+            product(self.lo, rhs.lo),
+            product(self.lo, rhs.hi),
+            product(self.hi, rhs.lo),
+            product(self.hi, rhs.hi),
+        ];
+        Self {
+            lo: corners
+                .map(down)
+                .into_iter()
+                .fold(Real::INFINITY, Real::min),
+            hi: corners
+                .map(up)
+                .into_iter()
+                .fold(Real::NEG_INFINITY, Real::max),
+        }
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+    /// the whole line when rhs holds 0
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let third = Interval::ONE / Interval::from(3.0);
+    /// assert_eq!((third.lo(), third.hi()), (1.0 / 3.0, (1.0f64 / 3.0).next_up()));
+    /// assert_eq!(Interval::new(1.0, 2.0) / Interval::new(4.0, f64::INFINITY), Interval::new(0.0, 0.5));
+    /// assert_eq!(Interval::ONE / Interval::new(-1.0, 1.0), Interval::ENTIRE);
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(0.0) {
+            return Self::ENTIRE;
+        }
+        let corners = [
+            quotient(self.lo, rhs.lo),
+            quotient(self.lo, rhs.hi),
+            quotient(self.hi, rhs.lo),
+            quotient(self.hi, rhs.hi),
+        ];
+        Self {
+            lo: corners
+                .map(down)
+                .into_iter()
+                .fold(Real::INFINITY, Real::min),
+            hi: corners
+                .map(up)
+                .into_iter()
+                .fold(Real::NEG_INFINITY, Real::max),
+        }
+    }
+}
+
+impl Interval {
+    /// [lo, hi], each end written by end with the formatter's options
+    fn format(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        end: fn(&Real, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        f.write_str("[")?;
+        end(&self.lo, f)?;
+        f.write_str(", ")?;
+        end(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+
+impl fmt::Display for Interval {
+    /// [lo, hi], each end formatted as a real with the formatter's options,
+    /// so with a precision the ends are rounded to nearest, not outward
+    /// ```
+    /// # use vmath::numbers::Interval;
+    /// let x = Interval::new(-0.5, 1e6);
+    /// assert_eq!(x.to_string(), "[-0.5, 1000000]");
+    /// assert_eq!(format!("{x:.2e}"), "[-5.00e-1, 1.00e6]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(f, fmt::Display::fmt)
+    }
+}
+
+impl fmt::LowerExp for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(f, fmt::LowerExp::fmt)
+    }
+}
+
+/// a rectangle of the complex plane, a real interval by an imaginary one,
+/// with the operators rounded outward through those of `Interval`
+/// ```
+/// # use vmath::numbers::{Complex, Interval};
+/// let z = Complex::<Interval>::from(Complex::new(11.0, 2.0));
+/// let w = Complex::<Interval>::from(Complex::new(3.0, -4.0));
+/// let q = z / w;
+/// assert!(q.contains(Complex::new(1.0, 2.0)));
+/// assert!(q.real.width() < 1e-15 && q.imaginary.width() < 1e-15);
+/// // the square of the unit circle's rectangle around 1 + i
+/// let near = Complex::new(Interval::new(0.5, 1.5), Interval::new(0.5, 1.5));
+/// assert!((near * near).contains(Complex::new(0.0, 2.0)));
+/// assert!(near.norm().contains(2f64.sqrt()));
+/// ```
+impl Complex<Interval> {
+    pub fn contains(self, z: Complex) -> bool {
+        self.real.contains(z.real) && self.imaginary.contains(z.imaginary)
+    }
+
+    /// the midpoints of the parts
+    pub fn mid(self) -> Complex {
+        Complex::new(self.real.mid(), self.imaginary.mid())
+    }
+
+    pub fn norm_squared(self) -> Interval {
+        self.real.square() + self.imaginary.square()
+    }
+
+    pub fn norm(self) -> Interval {
+        let n = self.norm_squared();
+        Interval {
+            lo: down(root(n.lo)),
+            hi: up(root(n.hi)),
+        }
+    }
+}
+
+impl From<Complex> for Complex<Interval> {
+    fn from(z: Complex) -> Self {
+        Complex::new(z.real.into(), z.imaginary.into())
+    }
+}
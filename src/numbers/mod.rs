@@ -1,5 +1,39 @@
+pub mod angle;
+pub mod approx;
+pub mod bigfloat;
+pub mod bigint;
+pub mod compensated;
 pub mod complex;
+pub mod decimal;
+pub mod dual;
+pub mod extended;
+pub mod finite_field;
+pub mod gaussian;
+pub mod interval;
+pub mod modular;
+pub mod ordered;
+pub mod packed;
+pub mod rational;
 pub mod real;
+pub mod scalar;
+pub mod tape;
 
-pub use crate::numbers::complex::Complex;
-pub use crate::numbers::real::Real;
+pub use crate::numbers::angle::Angle;
+pub use crate::numbers::approx::ApproxEq;
+pub use crate::numbers::bigfloat::BigFloat;
+pub use crate::numbers::bigint::BigInt;
+pub use crate::numbers::compensated::{dot_compensated, sum_compensated, ErrorFree, KahanSum};
+pub use crate::numbers::complex::{Complex, Complex32, PolarDegrees};
+pub use crate::numbers::decimal::{Decimal, Rounding};
+pub use crate::numbers::dual::{Dual, HyperDual};
+pub use crate::numbers::extended::ExtendedComplex;
+pub use crate::numbers::finite_field::{BinaryField, Gf2k, Gfp, PrimeField};
+pub use crate::numbers::gaussian::GaussianInt;
+pub use crate::numbers::interval::Interval;
+pub use crate::numbers::modular::ZMod;
+pub use crate::numbers::ordered::{Finite, NotNan, OrdComplex};
+pub use crate::numbers::packed::{Complex4, Complex8};
+pub use crate::numbers::rational::BigRational;
+pub use crate::numbers::real::{Real, Real32};
+pub use crate::numbers::scalar::{Field, Float, Scalar};
+pub use crate::numbers::tape::{Tape, Var};
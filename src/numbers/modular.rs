@@ -0,0 +1,344 @@
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::numbers::{Field, Real, Scalar};
+
+/// the integers modulo N, kept as their least residue, so each value has one form.
+/// add, sub, neg and pow do not branch on the values, though mul takes a 128 bit
+/// remainder, which some hardware times by its operands, and the inverse is euclid's
+/// algorithm, so it is only constant time-ish. with N prime it is a field, which
+/// `IS_PRIME` tells at compile time, and `/` divides by anything but 0.
+/// `/`, and with it the `Scalar` that generic code like `Complex` divides through,
+/// fail to compile for a composite N, whose residues sharing a factor with it
+/// have no inverse, and `checked_div` and `inverse` are the division there
+/// ```
+/// # use vmath::numbers::ZMod;
+/// // textbook rsa, with n = 61 * 53 and the private exponent from the totient 60 * 52
+/// type Message = ZMod<3233>;
+/// let e = 17;
+/// let d = ZMod::<3120>::new(e).inverse().unwrap().value();
+/// assert_eq!(d, 2753);
+/// let cipher = Message::new(65).pow(e);
+/// assert_eq!(cipher, Message::new(2790));
+/// assert_eq!(cipher.pow(d), Message::new(65));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u64", into = "u64"))]
+pub struct ZMod<const N: u64>(u64);
+
+impl<const N: u64> ZMod<N> {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 % N);
+    /// whether N is prime, so the residues are a field
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// const _: () = assert!(ZMod::<1_000_000_007>::IS_PRIME);
+    /// assert!(!ZMod::<3233>::IS_PRIME);
+    /// // a carmichael number, and a strong pseudoprime to base 2
+    /// assert!(!ZMod::<561>::IS_PRIME && !ZMod::<2047>::IS_PRIME);
+    /// assert!(ZMod::<18446744073709551557>::IS_PRIME);
+    /// ```
+    pub const IS_PRIME: bool = is_prime(N);
+
+    /// x mod N, failing to compile when N is 0
+    pub const fn new(x: u64) -> Self {
+        const { assert!(N > 0, "the modulus is 0") };
+        Self(x % N)
+    }
+
+    /// the least residue, in 0..N
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// self to the power of e, by a ladder that does the same squarings and products
+    /// whatever the bits of e
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// type P = ZMod<1_000_000_007>;
+    /// assert_eq!(P::new(3).pow(1_000_000_000_000_000_000), P::new(246336683));
+    /// // fermat's little theorem
+    /// assert_eq!(P::new(12345).pow(1_000_000_006), P::ONE);
+    /// ```
+    pub fn pow(self, e: u64) -> Self {
+        let (mut low, mut high) = (Self::ONE, self);
+        for i in (0..u64::BITS).rev() {
+            let bit = (e >> i) & 1;
+            swap_if(bit, &mut low, &mut high);
+            high = low * high;
+            low = low * low;
+            swap_if(bit, &mut low, &mut high);
+        }
+        low
+    }
+
+    /// the residue whose product with self is 1, None when self shares
+    /// a factor with N
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// assert_eq!(ZMod::<10>::new(3).inverse(), Some(ZMod::new(7)));
+    /// assert_eq!(ZMod::<10>::new(4).inverse(), None);
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        inverse(self.0, N).map(Self)
+    }
+
+    /// self times the inverse of rhs, None when rhs shares a factor with N,
+    /// for any N where `/` needs a prime one
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// type Z6 = ZMod<6>;
+    /// assert_eq!(Z6::new(4).checked_div(Z6::new(5)), Some(Z6::new(2)));
+    /// assert_eq!(Z6::new(4).checked_div(Z6::new(2)), None);
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        rhs.inverse().map(|inverse| self * inverse)
+    }
+}
+
+/// exchanges a and b when bit is 1 and not when it is 0, by masks rather than a branch
+fn swap_if<const N: u64>(bit: u64, a: &mut ZMod<N>, b: &mut ZMod<N>) {
+    let mask = bit.wrapping_neg() & (a.0 ^ b.0);
+    a.0 ^= mask;
+    b.0 ^= mask;
+}
+
+/// a + b mod n for a and b below n, by masks rather than a branch
+pub(crate) const fn add(a: u64, b: u64, n: u64) -> u64 {
+    let (sum, overflow) = a.overflowing_add(b);
+    let (reduced, borrow) = sum.overflowing_sub(n);
+    // the unreduced sum when it is below n, so neither overflowed nor reached it
+    let keep = ((borrow & !overflow) as u64).wrapping_neg();
+    (sum & keep) | (reduced & !keep)
+}
+
+/// a - b mod n for a and b below n, by masks rather than a branch
+pub(crate) const fn subtract(a: u64, b: u64, n: u64) -> u64 {
+    let (difference, borrow) = a.overflowing_sub(b);
+    difference.wrapping_add(n & (borrow as u64).wrapping_neg())
+}
+
+/// the inverse of a mod n by euclid's algorithm, None when they share a factor
+pub(crate) fn inverse(a: u64, n: u64) -> Option<u64> {
+    let (mut r, mut next_r) = (i128::from(n), i128::from(a));
+    let (mut t, mut next_t) = (0i128, 1i128);
+    while next_r != 0 {
+        let q = r / next_r;
+        (r, next_r) = (next_r, r - q * next_r);
+        (t, next_t) = (next_t, t - q * next_t);
+    }
+    (r == 1).then(|| t.rem_euclid(i128::from(n)) as u64)
+}
+
+/// a b mod n, through 128 bits
+pub(crate) const fn multiply(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+/// miller-rabin with the first twelve primes as witnesses, which settles every u64
+pub(crate) const fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n.is_multiple_of(WITNESSES[i]) {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    // n - 1 = d 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let mut x = 1;
+        let (mut base, mut e) = (WITNESSES[i], d);
+        while e > 0 {
+            if e & 1 == 1 {
+                x = multiply(x, base, n);
+            }
+            base = multiply(base, base, n);
+            e >>= 1;
+        }
+        if x != 1 && x != n - 1 {
+            let mut r = 1;
+            while r < s && x != n - 1 {
+                x = multiply(x, x, n);
+                r += 1;
+            }
+            if x != n - 1 {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+/// a field only for N prime, so generic code using it for a composite N does not compile
+/// ```
+/// # use vmath::numbers::{Complex, ZMod};
+/// // the gaussian integers mod 7, a field of 49 elements as -1 is no square mod 7
+/// type F = ZMod<7>;
+/// let z = Complex::new(F::new(3), F::new(5));
+/// let w = Complex::new(F::new(2), F::new(6));
+/// assert_eq!(z / w * w, z);
+/// ```
+/// ```compile_fail
+/// # use vmath::numbers::{Scalar, ZMod};
+/// fn zero<T: Scalar>() -> T {
+///     T::ZERO
+/// }
+/// let _: ZMod<6> = zero();
+/// ```
+impl<const N: u64> Scalar for ZMod<N> {
+    const ZERO: Self = {
+        assert!(is_prime(N), "ZMod<N> is a Scalar only for N prime");
+        Self::ZERO
+    };
+    const ONE: Self = {
+        assert!(is_prime(N), "ZMod<N> is a Scalar only for N prime");
+        Self::ONE
+    };
+}
+
+impl<const N: u64> Field for ZMod<N> {
+    const ROUNDING: Real = 0.0;
+
+    fn magnitude(self) -> Real {
+        if self.0 == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+impl<const N: u64> From<u64> for ZMod<N> {
+    fn from(x: u64) -> Self {
+        Self::new(x)
+    }
+}
+
+impl<const N: u64> From<i64> for ZMod<N> {
+    /// the residue of x, which for negative x is N less |x| mod N
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// assert_eq!(ZMod::<7>::from(-1i64), ZMod::new(6));
+    /// assert_eq!(ZMod::<7>::from(i64::MIN).value(), (i64::MIN).rem_euclid(7) as u64);
+    /// ```
+    fn from(x: i64) -> Self {
+        const { assert!(N > 0, "the modulus is 0") };
+        Self(i128::from(x).rem_euclid(i128::from(N)) as u64)
+    }
+}
+
+impl<const N: u64> From<ZMod<N>> for u64 {
+    fn from(x: ZMod<N>) -> Self {
+        x.0
+    }
+}
+
+impl<const N: u64> Neg for ZMod<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<const N: u64> Add for ZMod<N> {
+    type Output = Self;
+    /// without overflow for any N, taking N off when the sum reaches it
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// type Big = ZMod<{ u64::MAX - 1 }>;
+    /// assert_eq!(Big::new(u64::MAX - 2) + Big::new(u64::MAX - 3), Big::new(u64::MAX - 4));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        Self(add(self.0, rhs.0, N))
+    }
+}
+
+impl<const N: u64> Sub for ZMod<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(subtract(self.0, rhs.0, N))
+    }
+}
+
+impl<const N: u64> Mul for ZMod<N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(multiply(self.0, rhs.0, N))
+    }
+}
+
+impl<const N: u64> Div for ZMod<N> {
+    type Output = Self;
+    /// self times the inverse of rhs, which for N prime every residue but 0 has,
+    /// panicking at 0. fails to compile for a composite N, for which see `checked_div`
+    /// ```
+    /// # use vmath::numbers::ZMod;
+    /// // 1000 choose 500 mod a prime, from factorials
+    /// type P = ZMod<1_000_000_007>;
+    /// let factorial = |n: u64| (1..=n).map(P::new).product::<P>();
+    /// let choose = factorial(1000) / (factorial(500) * factorial(500));
+    /// assert_eq!(choose.value(), 159835829);
+    /// ```
+    /// ```compile_fail
+    /// # use vmath::numbers::ZMod;
+    /// let _ = ZMod::<6>::new(1) / ZMod::new(2);
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        const {
+            assert!(
+                is_prime(N),
+                "dividing by ZMod<N> needs N prime, see checked_div"
+            )
+        };
+        let inverse = rhs.inverse().expect("division by zero");
+        Mul::mul(self, inverse)
+    }
+}
+
+macro_rules! assign {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(
+            impl<const N: u64> $trait for ZMod<N> {
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+assign!(
+    AddAssign add_assign +,
+    SubAssign sub_assign -,
+    MulAssign mul_assign *,
+    DivAssign div_assign /
+);
+
+impl<const N: u64> Sum for ZMod<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<const N: u64> Product for ZMod<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<const N: u64> fmt::Display for ZMod<N> {
+    /// the least residue, with the formatter's options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Neg;
+
+use crate::numbers::{Complex, Real};
+use crate::Error;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+/// a complex number that is `Eq`, `Ord`, and `Hash`, for keys of maps and sets and
+/// for sorting the same way every time.
+/// it orders by `Complex::total_cmp`, real part first, and is equal only to
+/// the same bits, so -0 and +0 differ and a NaN equals the same NaN
+/// ```
+/// # use vmath::numbers::{Complex, OrdComplex};
+/// # use std::collections::{BTreeSet, HashSet};
+/// let zs = [Complex::new(1.0, 2.0), Complex::new(-1.0, 5.0), Complex::new(1.0, -2.0)];
+/// let set: BTreeSet<OrdComplex> = zs.map(OrdComplex).into();
+/// let sorted: Vec<Complex> = set.into_iter().map(Complex::from).collect();
+/// assert_eq!(sorted, [zs[1], zs[2], zs[0]]);
+///
+/// let seen: HashSet<OrdComplex> = [Complex::NAN, Complex::NAN, Complex::ZERO].map(OrdComplex).into();
+/// assert_eq!(seen.len(), 2);
+/// assert_ne!(OrdComplex(Complex::new(0.0, 0.0)), OrdComplex(Complex::new(-0.0, 0.0)));
+/// ```
+pub struct OrdComplex(pub Complex);
+
+impl From<Complex> for OrdComplex {
+    fn from(z: Complex) -> Self {
+        Self(z)
+    }
+}
+
+impl From<OrdComplex> for Complex {
+    fn from(z: OrdComplex) -> Self {
+        z.0
+    }
+}
+
+impl PartialEq for OrdComplex {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdComplex {}
+
+impl PartialOrd for OrdComplex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdComplex {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrdComplex {
+    /// the bits of each part, which are equal exactly when `total_cmp` says so
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.real.to_bits().hash(state);
+        self.0.imaginary.to_bits().hash(state);
+    }
+}
+
+/// a real that is not NaN, so it is `Eq`, `Ord`, and `Hash`, for sorting results
+/// and keying maps by them. unlike `OrdComplex` it orders as the reals do,
+/// so -0 and +0 are equal and hash alike, and the infinities are at the ends
+/// ```
+/// # use vmath::numbers::NotNan;
+/// # use std::collections::BTreeMap;
+/// let mut xs: Vec<NotNan> = [2.5, f64::NEG_INFINITY, -1.0, 0.0]
+///     .into_iter()
+///     .filter_map(NotNan::new)
+///     .collect();
+/// xs.sort();
+/// assert_eq!(xs.iter().map(|x| x.get()).collect::<Vec<_>>(), [f64::NEG_INFINITY, -1.0, 0.0, 2.5]);
+///
+/// let mut histogram = BTreeMap::new();
+/// for x in [0.5, -0.0, 0.5, 0.0] {
+///     *histogram.entry(NotNan::new(x).unwrap()).or_insert(0) += 1;
+/// }
+/// assert_eq!(histogram.into_values().collect::<Vec<_>>(), [2, 2]);
+/// assert_eq!(NotNan::new(f64::NAN), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Real", into = "Real"))]
+pub struct NotNan(Real);
+
+/// a real that is neither NaN nor infinite, otherwise as `NotNan`,
+/// for values that go on into arithmetic that infinities would poison
+/// ```
+/// # use vmath::numbers::{Finite, NotNan};
+/// # use vmath::Error;
+/// assert!(Finite::new(1e308).is_some());
+/// assert_eq!(Finite::new(f64::INFINITY), None);
+/// assert_eq!(Finite::try_from(f64::NAN), Err(Error::DomainError));
+/// let x = Finite::new(-3.0).unwrap();
+/// assert_eq!(NotNan::from(x), NotNan::new(-3.0).unwrap());
+/// assert_eq!((-x).get(), 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Real", into = "Real"))]
+pub struct Finite(Real);
+
+impl NotNan {
+    pub const ZERO: Self = Self(0.0);
+    pub const INFINITY: Self = Self(Real::INFINITY);
+    pub const NEG_INFINITY: Self = Self(Real::NEG_INFINITY);
+
+    /// None for NaN
+    pub fn new(x: Real) -> Option<Self> {
+        (!x.is_nan()).then_some(Self(x))
+    }
+}
+
+impl Finite {
+    pub const ZERO: Self = Self(0.0);
+    pub const MAX: Self = Self(Real::MAX);
+    pub const MIN: Self = Self(Real::MIN);
+
+    /// None for NaN and the infinities
+    pub fn new(x: Real) -> Option<Self> {
+        x.is_finite().then_some(Self(x))
+    }
+}
+
+impl From<Finite> for NotNan {
+    fn from(x: Finite) -> Self {
+        Self(x.0)
+    }
+}
+
+macro_rules! ordered_real {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                pub const fn get(self) -> Real {
+                    self.0
+                }
+            }
+
+            impl TryFrom<Real> for $name {
+                type Error = Error;
+                fn try_from(x: Real) -> Result<Self, Error> {
+                    Self::new(x).ok_or(Error::DomainError)
+                }
+            }
+
+            impl From<$name> for Real {
+                fn from(x: $name) -> Self {
+                    x.0
+                }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+
+            impl Eq for $name {}
+
+            impl PartialOrd for $name {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for $name {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.partial_cmp(&other.0).expect("neither is NaN")
+                }
+            }
+
+            impl Hash for $name {
+                /// the bits, with -0 taken as +0 since the two are equal
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    (self.0 + 0.0).to_bits().hash(state);
+                }
+            }
+
+            impl Neg for $name {
+                type Output = Self;
+                fn neg(self) -> Self {
+                    Self(-self.0)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+ordered_real!(NotNan, Finite);
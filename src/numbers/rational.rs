@@ -0,0 +1,456 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::numbers::{BigInt, Real};
+use crate::ParseError;
+
+/// an exact fraction of `BigInt`s, kept in lowest terms with a positive denominator,
+/// so equal values have equal parts. dividing by zero panics
+/// ```
+/// # use vmath::numbers::BigRational;
+/// // the hilbert matrix 1 / (i + j + 1) is badly conditioned, but exact arithmetic
+/// // inverts it all the same, and the entries of its inverse sum to n^2
+/// let n = 6;
+/// let mut rows: Vec<Vec<BigRational>> = (0..n)
+///     .map(|i| {
+///         let mut row: Vec<BigRational> =
+///             (0..n).map(|j| BigRational::new((1).into(), (i + j + 1).into())).collect();
+///         row.extend((0..n).map(|j| BigRational::from(i32::from(i == j))));
+///         row
+///     })
+///     .collect();
+/// for column in 0..n {
+///     let pivot = rows[column][column].clone();
+///     rows[column].iter_mut().for_each(|x| *x /= &pivot);
+///     for row in 0..n {
+///         if row != column {
+///             let factor = rows[row][column].clone();
+///             for j in 0..2 * n {
+///                 let step = &factor * &rows[column][j];
+///                 rows[row][j] -= step;
+///             }
+///         }
+///     }
+/// }
+/// let sum = rows.iter().flat_map(|row| &row[n..]).fold(BigRational::from(0), |s, x| s + x);
+/// assert_eq!(sum, BigRational::from(36));
+/// assert_eq!(rows[5][11].to_string(), "698544");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigRational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl BigRational {
+    /// numerator / denominator in lowest terms, panics when the denominator is zero
+    /// ```
+    /// # use vmath::numbers::{BigInt, BigRational};
+    /// let half = BigRational::new(BigInt::from(-3), BigInt::from(-6));
+    /// assert_eq!(half.numerator(), &BigInt::from(1));
+    /// assert_eq!(half.denominator(), &BigInt::from(2));
+    /// ```
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        Self::checked_new(numerator, denominator).expect("zero denominator")
+    }
+
+    /// `new`, None when the denominator is zero
+    pub fn checked_new(numerator: BigInt, denominator: BigInt) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+        let divisor = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator) = (&numerator / &divisor, &denominator / &divisor);
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        Some(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    /// always positive
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInt::from(1)
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// 1 / self, panics for zero
+    pub fn recip(&self) -> Self {
+        self.checked_recip().expect("division by zero")
+    }
+
+    /// `recip`, None for zero
+    pub fn checked_recip(&self) -> Option<Self> {
+        Self::checked_new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    /// self^exponent, a negative exponent taking the reciprocal, so panics for 0^-n
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// let two_thirds = BigRational::new(2.into(), 3.into());
+    /// assert_eq!(two_thirds.pow(-3).to_string(), "27/8");
+    /// ```
+    pub fn pow(&self, exponent: i32) -> Self {
+        let power = Self {
+            numerator: self.numerator.pow(exponent.unsigned_abs()),
+            denominator: self.denominator.pow(exponent.unsigned_abs()),
+        };
+        if exponent < 0 {
+            power.recip()
+        } else {
+            power
+        }
+    }
+
+    /// the largest integer at most self
+    /// ```
+    /// # use vmath::numbers::{BigInt, BigRational};
+    /// assert_eq!(BigRational::new((-7).into(), 2.into()).floor(), BigInt::from(-4));
+    /// assert_eq!(BigRational::new(7.into(), 2.into()).floor(), BigInt::from(3));
+    /// ```
+    pub fn floor(&self) -> BigInt {
+        let (q, r) = self.numerator.div_rem(&self.denominator);
+        if r.is_negative() {
+            q - BigInt::from(1)
+        } else {
+            q
+        }
+    }
+
+    /// the real exactly, None when it is infinite or NaN
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// assert_eq!(BigRational::from_real(0.375).unwrap().to_string(), "3/8");
+    /// let tenth = BigRational::from_real(0.1).unwrap();
+    /// assert_eq!(tenth.denominator().to_string(), "36028797018963968");
+    /// assert_eq!(tenth.to_real(), 0.1);
+    /// assert_eq!(BigRational::from_real(f64::NAN), None);
+    /// ```
+    pub fn from_real(x: Real) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        // x = mantissa 2^(exponent - 1075), with subnormals at the least exponent
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), exponent - 1075)
+        };
+        let mantissa = if x < 0.0 {
+            -BigInt::from(mantissa)
+        } else {
+            BigInt::from(mantissa)
+        };
+        let one = BigInt::from(1);
+        Some(if exponent >= 0 {
+            Self::from(mantissa << exponent as usize)
+        } else {
+            Self::new(mantissa, one << exponent.unsigned_abs() as usize)
+        })
+    }
+
+    /// the nearest real, ties to even, including among the subnormals,
+    /// and infinite past the largest finite one
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// assert_eq!(BigRational::new(1.into(), 3.into()).to_real(), 1.0 / 3.0);
+    /// assert_eq!(BigRational::new((-22).into(), 7.into()).to_real(), -22.0 / 7.0);
+    /// // ties go to even among the subnormals too, 1.5 of the least becoming 2
+    /// let tiny = BigRational::new(3.into(), 1.into()) * BigRational::from_real(f64::from_bits(1)).unwrap();
+    /// assert_eq!((tiny / BigRational::from(2)).to_real(), f64::from_bits(2));
+    /// assert_eq!(BigRational::from(10).pow(400).to_real(), f64::INFINITY);
+    /// ```
+    pub fn to_real(&self) -> Real {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let (n, d) = (self.numerator.abs(), &self.denominator);
+        // 2^e <= n / d < 2^(e + 1)
+        let guess = n.bits() as i64 - d.bits() as i64;
+        let e = if shifted(&n, -guess) >= shifted(d, guess) {
+            guess
+        } else {
+            guess - 1
+        };
+        // 53 significant bits, or fewer where the subnormals have a fixed last place
+        let k = (52 - e).min(1074);
+        let (q, r) = shifted(&n, k).div_rem(&shifted(d, -k));
+        let twice = &r << 1;
+        let round_up = match twice.cmp(&shifted(d, -k)) {
+            Ordering::Greater => true,
+            Ordering::Equal => !(&q % BigInt::from(2)).is_zero(),
+            Ordering::Less => false,
+        };
+        let mantissa = if round_up { q + BigInt::from(1) } else { q };
+        // at most 2^53, so exact, and scaled in two steps so neither power is out of range
+        let half = k / 2;
+        let x = mantissa.to_real()
+            * (2.0 as Real).powi(-half as i32)
+            * (2.0 as Real).powi((half - k) as i32);
+        if self.is_negative() {
+            -x
+        } else {
+            x
+        }
+    }
+}
+
+/// x 2^shift for a nonnegative shift, and x itself otherwise, the other side of a
+/// comparison or division taking the negative shift
+fn shifted(x: &BigInt, shift: i64) -> BigInt {
+    if shift > 0 {
+        x << shift as usize
+    } else {
+        x.clone()
+    }
+}
+
+impl From<BigInt> for BigRational {
+    fn from(numerator: BigInt) -> Self {
+        Self {
+            numerator,
+            denominator: BigInt::from(1),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for BigRational {
+                fn from(value: $int) -> Self {
+                    Self::from(BigInt::from(value))
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigRational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the denominators are positive, so cross multiplying keeps the order
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigRational {
+    type Output = BigRational;
+    fn neg(self) -> BigRational {
+        BigRational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+    fn neg(self) -> BigRational {
+        -self.clone()
+    }
+}
+
+impl Add for &BigRational {
+    type Output = BigRational;
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// let third = BigRational::new(1.into(), 3.into());
+    /// let sixth = BigRational::new(1.into(), 6.into());
+    /// assert_eq!(&third + &sixth, BigRational::new(1.into(), 2.into()));
+    /// assert_eq!(&third - &third, BigRational::from(0));
+    /// ```
+    fn add(self, rhs: &BigRational) -> BigRational {
+        BigRational::new(
+            &self.numerator * &rhs.denominator + &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Sub for &BigRational {
+    type Output = BigRational;
+    fn sub(self, rhs: &BigRational) -> BigRational {
+        BigRational::new(
+            &self.numerator * &rhs.denominator - &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Mul for &BigRational {
+    type Output = BigRational;
+    fn mul(self, rhs: &BigRational) -> BigRational {
+        BigRational::new(
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Div for &BigRational {
+    type Output = BigRational;
+    /// panics when rhs is zero
+    fn div(self, rhs: &BigRational) -> BigRational {
+        if rhs.is_zero() {
+            panic!("division by zero");
+        }
+        BigRational::new(
+            &self.numerator * &rhs.denominator,
+            &self.denominator * &rhs.numerator,
+        )
+    }
+}
+
+/// the operators on values and on a value and a reference, through the one on references
+macro_rules! forward {
+    ($($trait:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl $trait for BigRational {
+                type Output = BigRational;
+                fn $method(self, rhs: BigRational) -> BigRational {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&BigRational> for BigRational {
+                type Output = BigRational;
+                fn $method(self, rhs: &BigRational) -> BigRational {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<BigRational> for &BigRational {
+                type Output = BigRational;
+                fn $method(self, rhs: BigRational) -> BigRational {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $assign<&BigRational> for BigRational {
+                fn $assign_method(&mut self, rhs: &BigRational) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+
+            impl $assign for BigRational {
+                fn $assign_method(&mut self, rhs: BigRational) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign
+);
+
+impl fmt::Display for BigRational {
+    /// n/d, or n alone for an integer, padded as a whole to any width
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// // the convergents of the continued fraction of sqrt(2), [1; 2, 2, 2, ...]
+    /// let mut x = BigRational::from(1);
+    /// for _ in 0..60 {
+    ///     x = BigRational::from(1) + (BigRational::from(1) + &x).recip();
+    /// }
+    /// assert_eq!(x.to_real(), 2f64.sqrt());
+    /// assert!(x.denominator().bits() > 64);
+    /// assert_eq!(BigRational::new((-5).into(), 3.into()).to_string(), "-5/3");
+    /// assert_eq!(format!("{:>6}", BigRational::new(1.into(), 2.into())), "   1/2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            f.pad(&self.numerator.to_string())
+        } else {
+            f.pad(&format!("{}/{}", self.numerator, self.denominator))
+        }
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = ParseError;
+
+    /// n/d or n, as `Display` writes them, put in lowest terms
+    /// ```
+    /// # use vmath::numbers::BigRational;
+    /// assert_eq!("-6/4".parse(), Ok(BigRational::new((-3).into(), 2.into())));
+    /// assert_eq!("12".parse(), Ok(BigRational::from(12)));
+    /// let error = "1/0".parse::<BigRational>().unwrap_err();
+    /// assert_eq!(error.to_string(), "zero denominator at byte 2");
+    /// ```
+    fn from_str(source: &str) -> Result<Self, ParseError> {
+        let Some((numerator, denominator)) = source.split_once('/') else {
+            return Ok(Self::from(source.parse::<BigInt>()?));
+        };
+        let start = numerator.len() + 1;
+        let numerator: BigInt = numerator.parse()?;
+        let denominator: BigInt = denominator
+            .parse()
+            .map_err(|error: ParseError| ParseError {
+                position: start + error.position,
+                ..error
+            })?;
+        Self::checked_new(numerator, denominator).ok_or(ParseError {
+            position: start,
+            message: "zero denominator",
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigRational {
+    /// as the string `Display` writes
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigRational {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -1,9 +1,13 @@
 use std::ops::{Add, Div, Mul, Sub};
 
-use super::Complex;
+use super::{Complex, Complex32};
 
 pub type Real = f64;
 
+/// single precision, for `Complex32` and where memory or bandwidth
+/// matter more than digits
+pub type Real32 = f32;
+
 impl Add<Complex> for Real {
     type Output = Complex;
     /// ```
@@ -59,3 +63,36 @@ impl Div<Complex> for Real {
         Complex::from(self) / rhs
     }
 }
+
+impl Add<Complex32> for Real32 {
+    type Output = Complex32;
+    fn add(self, rhs: Complex32) -> Complex32 {
+        rhs + self
+    }
+}
+
+impl Sub<Complex32> for Real32 {
+    type Output = Complex32;
+    fn sub(self, rhs: Complex32) -> Complex32 {
+        Complex32::from(self) - rhs
+    }
+}
+
+impl Mul<Complex32> for Real32 {
+    type Output = Complex32;
+    fn mul(self, rhs: Complex32) -> Complex32 {
+        rhs * self
+    }
+}
+
+impl Div<Complex32> for Real32 {
+    type Output = Complex32;
+    /// ```
+    /// # use vmath::numbers::Complex32;
+    /// assert_eq!(6.0f32 / Complex32::new(0.0, 3.0), Complex32::new(0.0, -2.0));
+    /// assert_eq!(1.0f32 - Complex32::new(1.0, 2.0), Complex32::new(0.0, -2.0));
+    /// ```
+    fn div(self, rhs: Complex32) -> Complex32 {
+        Complex32::from(self) / rhs
+    }
+}
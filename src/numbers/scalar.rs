@@ -0,0 +1,203 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::complex;
+use super::Real;
+use crate::linalg::simd;
+
+/// what the parts of a `Complex` can be, a copyable number closed under
+/// + - * / and negation, with a zero and a one
+/// ```
+/// # use vmath::numbers::{Complex, Scalar};
+/// fn square<T: Scalar>(z: Complex<T>) -> Complex<T> {
+///     z * z
+/// }
+/// assert_eq!(square(Complex::new(1.0f32, 1.0)), Complex::new(0.0, 2.0));
+/// assert_eq!(square(Complex::new(0.0, 3.0)), Complex::new(-9.0, 0.0));
+/// ```
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// the parts of (a + bi) / (c + di), which is how `Complex<Self>` divides,
+    /// by the textbook ((a + bi)(c - di)) / (c^2 + d^2).
+    /// the floats override it with a scaled division whose squares cannot overflow
+    #[inline]
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        let norm_squared = c * c + d * d;
+        (
+            (a * c + b * d) / norm_squared,
+            (b * c - a * d) / norm_squared,
+        )
+    }
+}
+
+/// a `Scalar` that `DVector` and `DMatrix` hold and eliminate over, with the size
+/// their pivoting compares, how far one operation rounds, and the kernels of
+/// their products, which `Real` overrides with the vectorized ones
+/// ```
+/// # use vmath::linalg::{DMatrix, DVector};
+/// # use vmath::numbers::ZMod;
+/// // exactly, mod 7, where 3x + y = 1 and x + 2y = 0 have x = 6 and y = 4
+/// let m = DMatrix::new(2, 2, [3, 1, 1, 2].map(ZMod::<7>::new).to_vec());
+/// let x = m.solve(&DVector::new(vec![ZMod::new(1), ZMod::new(0)])).unwrap();
+/// assert_eq!(x, DVector::new(vec![ZMod::new(6), ZMod::new(4)]));
+/// assert_eq!(m.determinant(), ZMod::new(5));
+/// ```
+pub trait Field: Scalar {
+    /// the relative error of one operation, 0 where the arithmetic is exact,
+    /// which scales the size below which elimination takes a pivot for 0
+    const ROUNDING: Real;
+
+    /// what partial pivoting picks the largest of, |self| for the reals,
+    /// and for an exact field 0 for 0 and 1 otherwise, any pivot but 0 serving
+    fn magnitude(self) -> Real;
+
+    /// the sum of a_i b_i over slices of one length
+    #[inline]
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        a.iter()
+            .zip(b)
+            .fold(Self::ZERO, |sum, (&a, &b)| sum + a * b)
+    }
+
+    /// c = ab for a row-major m by k matrix a and k by n matrix b into the m by n c
+    fn matmul(a: &[Self], b: &[Self], c: &mut [Self], m: usize, k: usize, n: usize) {
+        for i in 0..m {
+            for j in 0..n {
+                c[i * n + j] = (0..k).fold(Self::ZERO, |sum, p| sum + a[i * k + p] * b[p * n + j]);
+            }
+        }
+    }
+}
+
+/// a `Scalar` with the order, roots, trigonometry, exponentials, and constants
+/// of the reals, which is what the polar functions of `Complex` are written over
+/// ```
+/// # use vmath::numbers::{Complex, Complex32, Float};
+/// fn phase<T: Float>(z: Complex<T>) -> T {
+///     z.angle().unwrap_or(T::ZERO)
+/// }
+/// assert_eq!(phase(Complex32::new(0.0, 1.0)), std::f32::consts::FRAC_PI_2);
+/// assert_eq!(phase(Complex::new(-1.0, 0.0)), std::f64::consts::PI);
+/// assert_eq!(Complex32::from_polar(2.0, 0.0).exp().norm(), 2f32.exp());
+/// ```
+pub trait Float: Scalar + PartialOrd {
+    const PI: Self;
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    /// sqrt(self^2 + other^2) without overflow or underflow in the squares
+    fn hypot(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    /// the angle of (other, self) from the positive x axis, in [-pi, pi]
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+}
+
+macro_rules! float_scalar {
+    ($($float:ident),*) => {
+        $(
+            impl Float for $float {
+                const PI: Self = std::$float::consts::PI;
+                const EPSILON: Self = $float::EPSILON;
+
+                #[inline]
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    self.sqrt()
+                }
+
+                #[inline]
+                fn hypot(self, other: Self) -> Self {
+                    self.hypot(other)
+                }
+
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    self.sin_cos()
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    self.atan2(other)
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    self.exp()
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    self.ln()
+                }
+            }
+        )*
+    };
+}
+
+float_scalar!(f32, f64);
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        complex::scaled_div(a, b, c, d)
+    }
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    /// worked in double precision, where the products cannot overflow,
+    /// then rounded back
+    #[inline]
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        let (real, imaginary) = complex::scaled_div(a.into(), b.into(), c.into(), d.into());
+        (real as f32, imaginary as f32)
+    }
+}
+
+impl Field for f64 {
+    const ROUNDING: Real = Real::EPSILON;
+
+    #[inline]
+    fn magnitude(self) -> Real {
+        self.abs()
+    }
+
+    #[inline]
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        simd::dot(a, b)
+    }
+
+    fn matmul(a: &[Self], b: &[Self], c: &mut [Self], m: usize, k: usize, n: usize) {
+        simd::matmul(a, b, c, m, k, n);
+    }
+}
+
+impl Field for f32 {
+    const ROUNDING: Real = f32::EPSILON as Real;
+
+    #[inline]
+    fn magnitude(self) -> Real {
+        self.abs().into()
+    }
+}
@@ -0,0 +1,526 @@
+//! reverse-mode differentiation: each operation on `Var`s records on a `Tape` how its
+//! result depends on its operands, and one pass back over the record from an output
+//! gives the derivatives of that output with respect to every input at once.
+//! where `Dual` numbers cost an evaluation per input, the tape costs a few,
+//! whatever the number of inputs, so it is the one to use for gradients of a loss
+//! of many parameters, like a sum of squared residuals
+//!
+//! ```
+//! use vmath::numbers::Tape;
+//!
+//! // fit y = a x + b through three points, by the gradient of the squared error
+//! let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+//! let tape = Tape::new();
+//! let (a, b) = (tape.var(1.0), tape.var(0.0));
+//! let loss: vmath::numbers::Var = points
+//!     .iter()
+//!     .map(|&(x, y)| {
+//!         let residual = a * x + b - y;
+//!         residual * residual
+//!     })
+//!     .sum();
+//! let gradient = loss.grad();
+//! // the residuals are -1, -2, -3, so d/da = 2 (0 + -2 + -6) and d/db = 2 (-1 + -2 + -3)
+//! assert_eq!(loss.value(), 14.0);
+//! assert_eq!((gradient.wrt(a), gradient.wrt(b)), (-16.0, -12.0));
+//! ```
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::numbers::{Float, Real, Scalar};
+
+/// how a recorded value depends on earlier ones, by their indices and the partial
+/// derivatives with respect to them
+#[derive(Debug, Clone, Copy)]
+enum Node {
+    Input,
+    Unary(usize, Real),
+    Binary(usize, Real, usize, Real),
+}
+
+/// partial times adjoint, 0 when the partial is, whatever the adjoint
+fn pass_back(partial: Real, adjoint: Real) -> Real {
+    if partial == 0.0 {
+        0.0
+    } else {
+        partial * adjoint
+    }
+}
+
+/// the record of the operations on its `Var`s, which borrow it, so it outlives them.
+/// it only grows, and `clear` empties it for reuse once they are gone
+#[derive(Debug, Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>,
+}
+
+impl Tape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// a new input, x, to differentiate with respect to
+    pub fn var(&self, x: Real) -> Var<'_> {
+        self.record(x, Node::Input)
+    }
+
+    /// how many inputs and results it has recorded
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+    }
+
+    fn record(&self, value: Real, node: Node) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(node);
+        Var {
+            value,
+            tape: Some(self),
+            index: nodes.len() - 1,
+        }
+    }
+}
+
+/// a real that records on its tape the operations done with it, or a constant
+/// with no tape, which is how the `Scalar` constants are made.
+/// it compares by its value, and mixing the vars of two tapes panics
+/// ```
+/// # use vmath::numbers::{Float, Tape, Var};
+/// fn softplus<T: Float>(x: T) -> T {
+///     (T::ONE + x.exp()).ln()
+/// }
+/// let tape = Tape::new();
+/// let x = tape.var(0.0);
+/// let y = softplus(x);
+/// assert_eq!(y.value(), 2f64.ln());
+/// // softplus' is the logistic function, 1 / 2 at 0
+/// assert_eq!(y.grad().wrt(x), 0.5);
+/// assert_eq!(y.grad().wrt(Var::constant(3.0)), 0.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Var<'t> {
+    value: Real,
+    tape: Option<&'t Tape>,
+    index: usize,
+}
+
+impl<'t> Var<'t> {
+    pub const ZERO: Self = Self::constant(0.0);
+    pub const ONE: Self = Self::constant(1.0);
+
+    /// x, on no tape, so nothing is differentiated with respect to it
+    pub const fn constant(x: Real) -> Self {
+        Self {
+            value: x,
+            tape: None,
+            index: 0,
+        }
+    }
+
+    pub fn value(self) -> Real {
+        self.value
+    }
+
+    /// the derivatives of self with respect to everything recorded before it,
+    /// from one pass back over its tape. a partial of 0 passes nothing back,
+    /// so an operand the result does not depend on gets 0, not 0 times the
+    /// infinite slope of sqrt at 0
+    pub fn grad(self) -> Gradient<'t> {
+        let Some(tape) = self.tape else {
+            return Gradient {
+                adjoints: Vec::new(),
+                tape: None,
+            };
+        };
+        let nodes = tape.nodes.borrow();
+        let mut adjoints = vec![0.0; self.index + 1];
+        adjoints[self.index] = 1.0;
+        for i in (0..=self.index).rev() {
+            let adjoint = adjoints[i];
+            if adjoint == 0.0 {
+                continue;
+            }
+            match nodes[i] {
+                Node::Input => {}
+                Node::Unary(a, da) => adjoints[a] += pass_back(da, adjoint),
+                Node::Binary(a, da, b, db) => {
+                    adjoints[a] += pass_back(da, adjoint);
+                    adjoints[b] += pass_back(db, adjoint);
+                }
+            }
+        }
+        Gradient {
+            adjoints,
+            tape: Some(tape),
+        }
+    }
+
+    /// f of self, given f and f' at its value
+    fn unary(self, value: Real, slope: Real) -> Self {
+        match self.tape {
+            Some(tape) => tape.record(value, Node::Unary(self.index, slope)),
+            None => Self::constant(value),
+        }
+    }
+
+    /// f of self and other, given f and its partial derivatives at their values
+    fn binary(self, other: Self, value: Real, da: Real, db: Real) -> Self {
+        match (self.tape, other.tape) {
+            (Some(tape), Some(other_tape)) => {
+                assert!(std::ptr::eq(tape, other_tape), "vars from different tapes");
+                tape.record(value, Node::Binary(self.index, da, other.index, db))
+            }
+            (Some(_), None) => self.unary(value, da),
+            (None, Some(_)) => other.unary(value, db),
+            (None, None) => Self::constant(value),
+        }
+    }
+
+    pub fn recip(self) -> Self {
+        let r = self.value.recip();
+        self.unary(r, -r * r)
+    }
+
+    /// -self below 0 and self at or above it, so at 0 the slope is the one from the right
+    pub fn abs(self) -> Self {
+        if self.value < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// ```
+    /// # use vmath::numbers::Tape;
+    /// let tape = Tape::new();
+    /// let x = tape.var(0.0);
+    /// assert_eq!(x.sqrt().grad().wrt(x), f64::INFINITY);
+    /// // the infinite slope meets the 0 of x * 0 as 0, not NaN
+    /// assert_eq!((x * 0.0).sqrt().grad().wrt(x), 0.0);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let r = self.value.sqrt();
+        self.unary(r, 0.5 / r)
+    }
+
+    /// sqrt(self^2 + other^2), scaled like `Real::hypot`, with partials of 0 at
+    /// (0, 0), its minimum, as for `Dual::hypot`, rather than NaN
+    /// ```
+    /// # use vmath::numbers::{Tape, Var};
+    /// let tape = Tape::new();
+    /// let (x, y) = (tape.var(3.0), tape.var(4.0));
+    /// let gradient = x.hypot(y).grad();
+    /// assert_eq!((gradient.wrt(x), gradient.wrt(y)), (0.6, 0.8));
+    ///
+    /// let x = tape.var(0.0);
+    /// let loss = x.hypot(Var::constant(0.0)) + x * 2.0;
+    /// assert_eq!(loss.grad().wrt(x), 2.0);
+    /// ```
+    pub fn hypot(self, other: Self) -> Self {
+        let h = self.value.hypot(other.value);
+        if h == 0.0 {
+            return self.binary(other, h, 0.0, 0.0);
+        }
+        self.binary(other, h, self.value / h, other.value / h)
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        self.unary(self.value.powi(n), Real::from(n) * self.value.powi(n - 1))
+    }
+
+    /// self to the power of a constant
+    pub fn powf(self, n: Real) -> Self {
+        self.unary(self.value.powf(n), n * self.value.powf(n - 1.0))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.value.exp();
+        self.unary(e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        self.unary(self.value.ln(), self.value.recip())
+    }
+
+    pub fn sin(self) -> Self {
+        self.unary(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.unary(self.value.cos(), -self.value.sin())
+    }
+
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.value.sin_cos();
+        (self.unary(sin, cos), self.unary(cos, -sin))
+    }
+
+    pub fn tan(self) -> Self {
+        let t = self.value.tan();
+        self.unary(t, 1.0 + t * t)
+    }
+
+    pub fn atan(self) -> Self {
+        self.unary(self.value.atan(), (1.0 + self.value * self.value).recip())
+    }
+
+    /// the angle of (other, self)
+    pub fn atan2(self, other: Self) -> Self {
+        let h = self.value.hypot(other.value);
+        let (x, y) = (other.value / h, self.value / h);
+        self.binary(other, self.value.atan2(other.value), x / h, -y / h)
+    }
+
+    pub fn sinh(self) -> Self {
+        self.unary(self.value.sinh(), self.value.cosh())
+    }
+
+    pub fn cosh(self) -> Self {
+        self.unary(self.value.cosh(), self.value.sinh())
+    }
+
+    pub fn tanh(self) -> Self {
+        let t = self.value.tanh();
+        self.unary(t, 1.0 - t * t)
+    }
+}
+
+/// the derivatives of one `Var` with respect to those recorded before it
+#[derive(Debug, Clone)]
+pub struct Gradient<'t> {
+    adjoints: Vec<Real>,
+    tape: Option<&'t Tape>,
+}
+
+impl Gradient<'_> {
+    /// the derivative with respect to x, 0 for a constant or a var recorded after
+    /// the output, and a panic for a var of another tape
+    pub fn wrt(&self, x: Var<'_>) -> Real {
+        match (x.tape, self.tape) {
+            (Some(tape), Some(own)) => {
+                assert!(std::ptr::eq(tape, own), "var from a different tape");
+                self.adjoints.get(x.index).copied().unwrap_or(0.0)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// the partial derivatives of f at x, from one evaluation and one pass back,
+/// however long x is
+/// ```
+/// # use vmath::numbers::{Dual, Var};
+/// # use vmath::numbers::{dual, tape};
+/// // the sum of (x_i - i)^2 over a thousand coordinates, with gradient 2 (x_i - i)
+/// let x: Vec<f64> = (0..1000).map(|i| (i as f64).sqrt()).collect();
+/// let backward = tape::gradient(
+///     |v: &[Var]| v.iter().enumerate().map(|(i, &x)| (x - i as f64).powi(2)).sum(),
+///     &x,
+/// );
+/// assert!(backward.iter().enumerate().all(|(i, &g)| g == 2.0 * (x[i] - i as f64)));
+/// // the same as a thousand evaluations on dual numbers
+/// let forward = dual::gradient(
+///     |v: &[Dual]| v.iter().enumerate().map(|(i, &x)| (x - i as f64).powi(2)).sum(),
+///     &x,
+/// );
+/// assert_eq!(backward, forward);
+/// ```
+pub fn gradient(f: impl for<'t> Fn(&[Var<'t>]) -> Var<'t>, x: &[Real]) -> Vec<Real> {
+    let tape = Tape::new();
+    let inputs: Vec<Var> = x.iter().map(|&x| tape.var(x)).collect();
+    let gradient = f(&inputs).grad();
+    inputs.iter().map(|&x| gradient.wrt(x)).collect()
+}
+
+impl Scalar for Var<'_> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Float for Var<'_> {
+    const PI: Self = Self::constant(std::f64::consts::PI);
+    const EPSILON: Self = Self::constant(Real::EPSILON);
+
+    #[inline]
+    fn abs(self) -> Self {
+        self.abs()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        self.hypot(other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        self.sin_cos()
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        self.exp()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+
+impl From<Real> for Var<'_> {
+    fn from(x: Real) -> Self {
+        Self::constant(x)
+    }
+}
+
+impl PartialEq for Var<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for Var<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Neg for Var<'_> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.unary(-self.value, -1.0)
+    }
+}
+
+impl Add for Var<'_> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl Add<Real> for Var<'_> {
+    type Output = Self;
+    fn add(self, rhs: Real) -> Self {
+        self.unary(self.value + rhs, 1.0)
+    }
+}
+
+impl Sub for Var<'_> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl Sub<Real> for Var<'_> {
+    type Output = Self;
+    fn sub(self, rhs: Real) -> Self {
+        self.unary(self.value - rhs, 1.0)
+    }
+}
+
+impl Mul for Var<'_> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl Mul<Real> for Var<'_> {
+    type Output = Self;
+    fn mul(self, rhs: Real) -> Self {
+        self.unary(self.value * rhs, rhs)
+    }
+}
+
+impl Div for Var<'_> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let q = self.value / rhs.value;
+        self.binary(rhs, q, rhs.value.recip(), -q / rhs.value)
+    }
+}
+
+impl Div<Real> for Var<'_> {
+    type Output = Self;
+    fn div(self, rhs: Real) -> Self {
+        self.unary(self.value / rhs, rhs.recip())
+    }
+}
+
+macro_rules! assign {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(
+            impl $trait for Var<'_> {
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+
+            impl $trait<Real> for Var<'_> {
+                fn $method(&mut self, rhs: Real) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+assign!(
+    AddAssign add_assign +,
+    SubAssign sub_assign -,
+    MulAssign mul_assign *,
+    DivAssign div_assign /
+);
+
+impl Sum for Var<'_> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl Product for Var<'_> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl fmt::Debug for Var<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut var = f.debug_struct("Var");
+        var.field("value", &self.value);
+        if self.tape.is_some() {
+            var.field("index", &self.index);
+        }
+        var.finish()
+    }
+}
+
+impl fmt::Display for Var<'_> {
+    /// the value, with the formatter's options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,508 @@
+//! the `vmath` python extension module, built from this crate with the `python` feature,
+//! for example by `maturin develop`, whose `pyproject.toml` turns it on and builds the cdylib
+//!
+//! the classes wrap the rust types one for one and call straight into them:
+//! `Complex`, `Vec2`, `Vec3`, `Vec4`, and the dense `Vector` and `Matrix`,
+//! which convert to and from numpy arrays of float64, copying once each way,
+//! so `__array__` casts to the dtype asked for and refuses copy=False.
+//! == against an object of another kind is `NotImplemented`, as python expects.
+//! a singular matrix raises `ValueError`, as do mismatched shapes.
+
+use numpy::{AllowTypeChange, PyArray1, PyArray2, PyArrayLike1, PyArrayLike2, PyArrayMethods};
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyBool, PyComplex as PythonComplex};
+
+use crate::linalg::{DMatrix, DVector, Vec2, Vec3, Vec4, Vector};
+use crate::numbers::{Complex, Real};
+use crate::Error;
+
+#[pyclass(name = "Complex", module = "vmath", frozen, from_py_object)]
+#[derive(Clone, Copy)]
+pub struct PyComplex(pub Complex);
+
+/// the right hand side of complex arithmetic, a vmath Complex or anything python
+/// converts to a float
+#[derive(FromPyObject)]
+enum ComplexOperand {
+    Complex(PyComplex),
+    Real(Real),
+}
+
+impl From<ComplexOperand> for Complex {
+    fn from(operand: ComplexOperand) -> Complex {
+        match operand {
+            ComplexOperand::Complex(PyComplex(z)) => z,
+            ComplexOperand::Real(x) => Complex::new(x, 0.0),
+        }
+    }
+}
+
+#[pymethods]
+impl PyComplex {
+    #[new]
+    #[pyo3(signature = (real=0.0, imaginary=0.0))]
+    fn new(real: Real, imaginary: Real) -> Self {
+        Self(Complex::new(real, imaginary))
+    }
+
+    #[getter]
+    fn real(&self) -> Real {
+        self.0.real
+    }
+
+    #[getter]
+    fn imaginary(&self) -> Real {
+        self.0.imaginary
+    }
+
+    fn norm(&self) -> Real {
+        self.0.norm()
+    }
+
+    fn angle(&self) -> PyResult<Real> {
+        Ok(self.0.angle()?)
+    }
+
+    fn conjugate(&self) -> Self {
+        Self(self.0.conjugate())
+    }
+
+    fn __complex__<'py>(&self, py: Python<'py>) -> Bound<'py, PythonComplex> {
+        PythonComplex::from_doubles(py, self.0.real, self.0.imaginary)
+    }
+
+    fn __abs__(&self) -> Real {
+        self.0.norm()
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0)
+    }
+
+    fn __add__(&self, rhs: ComplexOperand) -> Self {
+        Self(self.0 + Complex::from(rhs))
+    }
+
+    fn __radd__(&self, lhs: ComplexOperand) -> Self {
+        Self(Complex::from(lhs) + self.0)
+    }
+
+    fn __sub__(&self, rhs: ComplexOperand) -> Self {
+        Self(self.0 - Complex::from(rhs))
+    }
+
+    fn __rsub__(&self, lhs: ComplexOperand) -> Self {
+        Self(Complex::from(lhs) - self.0)
+    }
+
+    fn __mul__(&self, rhs: ComplexOperand) -> Self {
+        Self(self.0 * Complex::from(rhs))
+    }
+
+    fn __rmul__(&self, lhs: ComplexOperand) -> Self {
+        Self(Complex::from(lhs) * self.0)
+    }
+
+    fn __truediv__(&self, rhs: ComplexOperand) -> Self {
+        Self(self.0 / Complex::from(rhs))
+    }
+
+    fn __rtruediv__(&self, lhs: ComplexOperand) -> Self {
+        Self(Complex::from(lhs) / self.0)
+    }
+
+    fn __eq__(&self, py: Python<'_>, rhs: &Bound<'_, PyAny>) -> Py<PyAny> {
+        match rhs.extract::<ComplexOperand>() {
+            Ok(rhs) => equality(py, self.0 == Complex::from(rhs)),
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Complex({:?}, {:?})", self.0.real, self.0.imaginary)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// a python class for one of the fixed-size vectors, with its components as properties
+macro_rules! vector_class {
+    ($class:ident, $name:literal, $vector:ident, $($component:ident),+) => {
+        #[pyclass(name = $name, module = "vmath", frozen, from_py_object)]
+        #[derive(Clone, Copy)]
+        pub struct $class(pub $vector);
+
+        #[pymethods]
+        impl $class {
+            #[new]
+            fn new($($component: Real),+) -> Self {
+                Self($vector { $($component),+ })
+            }
+
+            $(
+                #[getter]
+                fn $component(&self) -> Real {
+                    self.0.$component
+                }
+            )+
+
+            fn dot(&self, rhs: Self) -> Real {
+                self.0.dot(rhs.0)
+            }
+
+            fn length(&self) -> Real {
+                self.0.length()
+            }
+
+            fn normalize(&self) -> Self {
+                Self(self.0.normalize())
+            }
+
+            fn lerp(&self, other: Self, t: Real) -> Self {
+                Self(self.0.lerp(other.0, t))
+            }
+
+            fn components(&self) -> Vec<Real> {
+                vec![$(self.0.$component),+]
+            }
+
+            fn __len__(&self) -> usize {
+                <$vector as Vector>::DIMENSION
+            }
+
+            fn __getitem__(&self, i: usize) -> PyResult<Real> {
+                if i >= <$vector as Vector>::DIMENSION {
+                    return Err(PyIndexError::new_err("vector index out of range"));
+                }
+                Ok(self.0[i])
+            }
+
+            fn __neg__(&self) -> Self {
+                Self(-self.0)
+            }
+
+            fn __add__(&self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+
+            fn __sub__(&self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+
+            fn __mul__(&self, rhs: Real) -> Self {
+                Self(self.0 * rhs)
+            }
+
+            fn __rmul__(&self, lhs: Real) -> Self {
+                Self(self.0 * lhs)
+            }
+
+            fn __truediv__(&self, rhs: Real) -> Self {
+                Self(self.0 / rhs)
+            }
+
+            fn __eq__(&self, py: Python<'_>, rhs: &Bound<'_, PyAny>) -> Py<PyAny> {
+                match rhs.extract::<Self>() {
+                    Ok(rhs) => equality(py, self.0 == rhs.0),
+                    Err(_) => py.NotImplemented(),
+                }
+            }
+
+            fn __repr__(&self) -> String {
+                let components: Vec<String> =
+                    self.components().iter().map(|c| format!("{c:?}")).collect();
+                format!("{}({})", $name, components.join(", "))
+            }
+        }
+    };
+}
+
+vector_class!(PyVec2, "Vec2", Vec2, x, y);
+vector_class!(PyVec3, "Vec3", Vec3, x, y, z);
+vector_class!(PyVec4, "Vec4", Vec4, x, y, z, w);
+
+#[pyclass(name = "Vector", module = "vmath", from_py_object)]
+#[derive(Clone)]
+pub struct PyVector(pub DVector);
+
+#[pymethods]
+impl PyVector {
+    /// from anything numpy reads as a one dimensional array of floats
+    #[new]
+    fn new(entries: PyArrayLike1<'_, Real, AllowTypeChange>) -> Self {
+        Self(DVector::new(entries.as_array().iter().copied().collect()))
+    }
+
+    fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Real>> {
+        PyArray1::from_slice(py, &self.0.data)
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(
+        &self,
+        py: Python<'py>,
+        dtype: Option<Bound<'py, PyAny>>,
+        copy: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py).into_any(), dtype, copy)
+    }
+
+    fn dot(&self, rhs: &Self) -> PyResult<Real> {
+        check_lengths(&self.0, &rhs.0)?;
+        Ok(self.0.dot(&rhs.0))
+    }
+
+    fn length(&self) -> Real {
+        self.0.length()
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __getitem__(&self, i: usize) -> PyResult<Real> {
+        self.0
+            .data
+            .get(i)
+            .copied()
+            .ok_or_else(|| PyIndexError::new_err("vector index out of range"))
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0.clone())
+    }
+
+    fn __add__(&self, rhs: &Self) -> PyResult<Self> {
+        check_lengths(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() + rhs.0.clone()))
+    }
+
+    fn __sub__(&self, rhs: &Self) -> PyResult<Self> {
+        check_lengths(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() - rhs.0.clone()))
+    }
+
+    fn __mul__(&self, rhs: Real) -> Self {
+        Self(self.0.clone() * rhs)
+    }
+
+    fn __rmul__(&self, lhs: Real) -> Self {
+        Self(self.0.clone() * lhs)
+    }
+
+    fn __eq__(&self, py: Python<'_>, rhs: &Bound<'_, PyAny>) -> Py<PyAny> {
+        match rhs.extract::<PyRef<'_, Self>>() {
+            Ok(rhs) => equality(py, self.0 == rhs.0),
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Vector({:?})", self.0.data)
+    }
+}
+
+#[pyclass(name = "Matrix", module = "vmath", from_py_object)]
+#[derive(Clone)]
+pub struct PyMatrix(pub DMatrix);
+
+/// the right hand side of a matrix product
+#[derive(FromPyObject)]
+enum MatrixOperand {
+    Matrix(PyMatrix),
+    Vector(PyVector),
+}
+
+#[pymethods]
+impl PyMatrix {
+    /// from anything numpy reads as a two dimensional array of floats
+    #[new]
+    fn new(entries: PyArrayLike2<'_, Real, AllowTypeChange>) -> Self {
+        let entries = entries.as_array();
+        let (rows, columns) = entries.dim();
+        Self(DMatrix::new(
+            rows,
+            columns,
+            entries.iter().copied().collect(),
+        ))
+    }
+
+    #[staticmethod]
+    fn identity(n: usize) -> Self {
+        Self(DMatrix::identity(n))
+    }
+
+    #[staticmethod]
+    fn zeros(rows: usize, columns: usize) -> Self {
+        Self(DMatrix::zeros(rows, columns))
+    }
+
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        (self.0.rows, self.0.columns)
+    }
+
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<Real>>> {
+        PyArray1::from_slice(py, &self.0.data).reshape([self.0.rows, self.0.columns])
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(
+        &self,
+        py: Python<'py>,
+        dtype: Option<Bound<'py, PyAny>>,
+        copy: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py)?.into_any(), dtype, copy)
+    }
+
+    fn transpose(&self) -> Self {
+        Self(self.0.transpose())
+    }
+
+    fn determinant(&self) -> PyResult<Real> {
+        check_square(&self.0)?;
+        Ok(self.0.determinant())
+    }
+
+    fn inverse(&self) -> PyResult<Self> {
+        Ok(Self(self.0.inverse()?))
+    }
+
+    /// the x with self x = b
+    fn solve(&self, b: PyArrayLike1<'_, Real, AllowTypeChange>) -> PyResult<PyVector> {
+        let b = DVector::new(b.as_array().iter().copied().collect());
+        Ok(PyVector(self.0.solve(&b)?))
+    }
+
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<Real> {
+        let (i, j) = index;
+        if i >= self.0.rows || j >= self.0.columns {
+            return Err(PyIndexError::new_err("matrix index out of range"));
+        }
+        Ok(self.0[(i, j)])
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0.clone())
+    }
+
+    fn __add__(&self, rhs: &Self) -> PyResult<Self> {
+        check_shapes(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() + rhs.0.clone()))
+    }
+
+    fn __sub__(&self, rhs: &Self) -> PyResult<Self> {
+        check_shapes(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() - rhs.0.clone()))
+    }
+
+    fn __mul__(&self, rhs: Real) -> Self {
+        Self(self.0.clone() * rhs)
+    }
+
+    fn __rmul__(&self, lhs: Real) -> Self {
+        Self(self.0.clone() * lhs)
+    }
+
+    fn __matmul__<'py>(&self, py: Python<'py>, rhs: MatrixOperand) -> PyResult<Bound<'py, PyAny>> {
+        match rhs {
+            MatrixOperand::Matrix(rhs) => {
+                if self.0.columns != rhs.0.rows {
+                    return Err(PyValueError::new_err("matrix shapes do not fit"));
+                }
+                Ok(Bound::new(py, Self(&self.0 * &rhs.0))?.into_any())
+            }
+            MatrixOperand::Vector(rhs) => {
+                if self.0.columns != rhs.0.len() {
+                    return Err(PyValueError::new_err("matrix and vector shapes do not fit"));
+                }
+                Ok(Bound::new(py, PyVector(&self.0 * &rhs.0))?.into_any())
+            }
+        }
+    }
+
+    fn __eq__(&self, py: Python<'_>, rhs: &Bound<'_, PyAny>) -> Py<PyAny> {
+        match rhs.extract::<PyRef<'_, Self>>() {
+            Ok(rhs) => equality(py, self.0 == rhs.0),
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let rows: Vec<String> = (0..self.0.rows)
+            .map(|i| format!("{:?}", self.0.row(i)))
+            .collect();
+        format!("Matrix([{}])", rows.join(", "))
+    }
+}
+
+/// numpy's `__array__`: the fresh float64 array, cast to dtype when one is asked for,
+/// and `ValueError` for copy=False, as the entries live in rust and cannot be shared
+fn array_protocol<'py>(
+    array: Bound<'py, PyAny>,
+    dtype: Option<Bound<'py, PyAny>>,
+    copy: Option<bool>,
+) -> PyResult<Bound<'py, PyAny>> {
+    if copy == Some(false) {
+        return Err(PyValueError::new_err(
+            "the entries cannot be viewed without a copy",
+        ));
+    }
+    match dtype {
+        // the array is already a copy, so the cast need not make another
+        Some(dtype) => {
+            let options = [("copy", false)].into_py_dict(array.py())?;
+            array.call_method("astype", (dtype,), Some(&options))
+        }
+        None => Ok(array),
+    }
+}
+
+/// the answer to == for an operand of the same kind, where one of another kind
+/// gets `NotImplemented`, so python tries its reflection and then identity
+fn equality(py: Python<'_>, equal: bool) -> Py<PyAny> {
+    PyBool::new(py, equal).to_owned().into_any().unbind()
+}
+
+fn check_lengths(a: &DVector, b: &DVector) -> PyResult<()> {
+    if a.len() != b.len() {
+        return Err(PyValueError::new_err("vector lengths differ"));
+    }
+    Ok(())
+}
+
+fn check_shapes(a: &DMatrix, b: &DMatrix) -> PyResult<()> {
+    if (a.rows, a.columns) != (b.rows, b.columns) {
+        return Err(PyValueError::new_err("matrix shapes differ"));
+    }
+    Ok(())
+}
+
+fn check_square(m: &DMatrix) -> PyResult<()> {
+    if !m.is_square() {
+        return Err(PyValueError::new_err("matrix is not square"));
+    }
+    Ok(())
+}
+
+/// every failure is a bad argument as far as python is concerned
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+#[pymodule]
+fn vmath(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyComplex>()?;
+    module.add_class::<PyVec2>()?;
+    module.add_class::<PyVec3>()?;
+    module.add_class::<PyVec4>()?;
+    module.add_class::<PyVector>()?;
+    module.add_class::<PyMatrix>()?;
+    Ok(())
+}
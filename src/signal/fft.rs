@@ -0,0 +1,181 @@
+use std::f64::consts::PI;
+
+use crate::linalg::simd;
+use crate::numbers::{Complex, Real};
+
+/// the discrete fourier transform in place, x_k = sum of x_n e^(-2 pi i k n / len),
+/// for any length, radix-2 when the length is a power of two and bluestein's chirp z
+/// transform through a power of two otherwise, so every length is n log n
+/// ```
+/// # use vmath::numbers::Complex;
+/// # use vmath::signal::fft;
+/// let mut signal = [Complex::new(1.0, 0.0); 4];
+/// fft(&mut signal);
+/// let zero = Complex::new(0.0, 0.0);
+/// assert_eq!(signal, [Complex::new(4.0, 0.0), zero, zero, zero]);
+///
+/// // a length with no power of two, against the transform summed directly
+/// let x: Vec<Complex> = (0..5).map(|n| Complex::new(n as f64, 1.0 - n as f64)).collect();
+/// let mut transformed = x.clone();
+/// fft(&mut transformed);
+/// for (k, &value) in transformed.iter().enumerate() {
+///     let direct = x.iter().enumerate().fold(Complex::new(0.0, 0.0), |sum, (n, &x)| {
+///         let angle = -2.0 * std::f64::consts::PI * (k * n) as f64 / 5.0;
+///         sum + x * Complex::new(angle.cos(), angle.sin())
+///     });
+///     assert!((value - direct).norm() < 1e-13);
+/// }
+/// ```
+pub fn fft(data: &mut [Complex]) {
+    transform(data, -1.0);
+}
+
+/// the inverse of fft, x_n = 1 / len times the sum of x_k e^(2 pi i k n / len)
+/// ```
+/// # use vmath::numbers::Complex;
+/// # use vmath::signal::{fft, inverse_fft};
+/// let x: Vec<Complex> = (0..12).map(|n| Complex::new((n as f64).sin(), 0.5)).collect();
+/// let mut round_trip = x.clone();
+/// fft(&mut round_trip);
+/// inverse_fft(&mut round_trip);
+/// assert!(x.iter().zip(&round_trip).all(|(a, &b)| (*a - b).norm() < 1e-15));
+/// ```
+pub fn inverse_fft(data: &mut [Complex]) {
+    transform(data, 1.0);
+    let scale = 1.0 / data.len() as Real;
+    for value in data {
+        *value = *value * scale;
+    }
+}
+
+/// fft of each consecutive run of length entries, the layout of many equal signals
+/// stored back to back, panics unless length divides the number of entries
+/// ```
+/// # use vmath::numbers::Complex;
+/// # use vmath::signal::{fft, fft_batch};
+/// let signals: Vec<Complex> = (0..12).map(|n| Complex::new(n as f64, 0.0)).collect();
+/// let mut batch = signals.clone();
+/// fft_batch(&mut batch, 3);
+/// let mut last = signals[9..].to_vec();
+/// fft(&mut last);
+/// assert_eq!(&batch[9..], &last[..]);
+/// ```
+pub fn fft_batch(data: &mut [Complex], length: usize) {
+    check_batch(data, length);
+    data.chunks_mut(length).for_each(fft);
+}
+
+/// inverse_fft of each consecutive run of length entries,
+/// panics unless length divides the number of entries
+pub fn inverse_fft_batch(data: &mut [Complex], length: usize) {
+    check_batch(data, length);
+    data.chunks_mut(length).for_each(inverse_fft);
+}
+
+/// fft_batch with the signals shared out across the rayon thread pool
+/// ```
+/// # use vmath::numbers::Complex;
+/// # use vmath::signal::{fft_batch, par_fft_batch};
+/// let signals: Vec<Complex> = (0..600).map(|n| Complex::new((n as f64).cos(), 0.0)).collect();
+/// let (mut serial, mut parallel) = (signals.clone(), signals);
+/// fft_batch(&mut serial, 6);
+/// par_fft_batch(&mut parallel, 6);
+/// assert_eq!(serial, parallel);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_fft_batch(data: &mut [Complex], length: usize) {
+    use rayon::prelude::*;
+    check_batch(data, length);
+    data.par_chunks_mut(length).for_each(fft);
+}
+
+/// inverse_fft_batch with the signals shared out across the rayon thread pool
+#[cfg(feature = "rayon")]
+pub fn par_inverse_fft_batch(data: &mut [Complex], length: usize) {
+    use rayon::prelude::*;
+    check_batch(data, length);
+    data.par_chunks_mut(length).for_each(inverse_fft);
+}
+
+fn check_batch(data: &[Complex], length: usize) {
+    assert!(
+        length > 0 && data.len().is_multiple_of(length),
+        "batch length does not divide the data"
+    );
+}
+
+/// the unscaled transform with e^(sign 2 pi i k n / len)
+fn transform(data: &mut [Complex], sign: Real) {
+    if data.len() <= 1 {
+        return;
+    }
+    if data.len().is_power_of_two() {
+        radix2(data, sign);
+    } else {
+        bluestein(data, sign);
+    }
+}
+
+/// iterative cooley-tukey for a power of two length
+fn radix2(data: &mut [Complex], sign: Real) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < n {
+        let twiddles: Vec<Complex> = (0..half)
+            .map(|j| unit(sign * PI * j as Real / half as Real))
+            .collect();
+        for block in data.chunks_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+            for ((a, b), &twiddle) in low.iter_mut().zip(high).zip(&twiddles) {
+                let t = *b * twiddle;
+                *b = *a - t;
+                *a = *a + t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// bluestein's algorithm, kn = (k^2 + n^2 - (k - n)^2) / 2 turns the transform into a
+/// convolution with the chirp e^(-sign pi i m^2 / len), done by power of two transforms
+fn bluestein(data: &mut [Complex], sign: Real) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    // k^2 reduced mod 2n keeps the angle small, and so accurate, for long signals
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| unit(sign * PI * ((k * k) % (2 * n)) as Real / n as Real))
+        .collect();
+    let zero = Complex::new(0.0, 0.0);
+    let mut a = vec![zero; m];
+    for ((a, &x), &w) in a.iter_mut().zip(data.iter()).zip(&chirp) {
+        *a = x * w;
+    }
+    let mut b = vec![zero; m];
+    b[0] = chirp[0].conjugate();
+    for k in 1..n {
+        b[k] = chirp[k].conjugate();
+        b[m - k] = b[k];
+    }
+    radix2(&mut a, -1.0);
+    radix2(&mut b, -1.0);
+    let mut convolved = vec![zero; m];
+    simd::complex_multiply(&a, &b, &mut convolved);
+    radix2(&mut convolved, 1.0);
+    let scale = 1.0 / m as Real;
+    for ((x, &a), &w) in data.iter_mut().zip(&convolved).zip(&chirp) {
+        *x = a * w * scale;
+    }
+}
+
+/// e^(i angle)
+fn unit(angle: Real) -> Complex {
+    let (sin, cos) = angle.sin_cos();
+    Complex::new(cos, sin)
+}
@@ -0,0 +1,5 @@
+pub mod fft;
+
+pub use crate::signal::fft::{fft, fft_batch, inverse_fft, inverse_fft_batch};
+#[cfg(feature = "rayon")]
+pub use crate::signal::fft::{par_fft_batch, par_inverse_fft_batch};
@@ -0,0 +1,238 @@
+use std::f64::consts::PI;
+
+use crate::consts::EULER_GAMMA;
+use crate::numbers::Real;
+
+/// beyond this j and y use their asymptotic expansions for large arguments
+const ASYMPTOTIC_LIMIT: Real = 25.0;
+
+/// below this j is summed as a power series
+const SERIES_LIMIT: Real = 1.0;
+
+/// below this y keeps only the leading terms of its expansion about zero
+const SMALL_LIMIT: Real = 1e-8;
+
+/// rescaling threshold for the backward recurrence
+const BIG: Real = 1e250;
+
+/// the bessel function of the first kind of integer order,
+/// to an absolute error near 1e-15 for arguments of moderate size
+/// ```
+/// # use vmath::special::bessel_j;
+/// assert_eq!(bessel_j(0, 0.0), 1.0);
+/// assert!((bessel_j(0, 2.404825557695773)).abs() < 1e-15);
+/// assert!((bessel_j(1, 1.0) - 0.44005058574493355).abs() < 1e-15);
+/// assert!((bessel_j(5, 10.0) + 0.23406152818679365).abs() < 1e-15);
+/// assert!((bessel_j(-3, 2.0) + 0.12894324947440205).abs() < 1e-15);
+/// assert!((bessel_j(2, 100.0) + 0.021528757344505366).abs() < 1e-15);
+/// ```
+pub fn bessel_j(n: i32, x: Real) -> Real {
+    // j(-n, x) = (-1)^n j(n, x) = j(n, -x)
+    let odd = n % 2 != 0;
+    let sign = if odd && (n < 0) != (x < 0.0) {
+        -1.0
+    } else {
+        1.0
+    };
+    let n = n.unsigned_abs() as usize;
+    let x = x.abs();
+    sign * if x < SERIES_LIMIT {
+        power_series(n, x, -1.0)
+    } else if x > ASYMPTOTIC_LIMIT && (n as Real) < x {
+        let (j_0, j_1) = hankel(x).0;
+        upward(n, x, j_0, j_1, -1.0)
+    } else {
+        j_sequence(x, n)[n]
+    }
+}
+
+/// the bessel function of the second kind of integer order,
+/// to an absolute error near 1e-15 for arguments of moderate size,
+/// infinite at zero and NaN for negative arguments
+/// ```
+/// # use vmath::special::bessel_y;
+/// assert!((bessel_y(0, 1.0) - 0.08825696421567697).abs() < 1e-15);
+/// assert!((bessel_y(1, 3.0) - 0.32467442479179998).abs() < 1e-15);
+/// assert!((bessel_y(4, 2.5) + 1.4331973429670071).abs() < 1e-14);
+/// assert!((bessel_y(1, 50.0) + 0.05679566856201477).abs() < 1e-15);
+/// assert_eq!(bessel_y(0, 0.0), f64::NEG_INFINITY);
+/// ```
+pub fn bessel_y(n: i32, x: Real) -> Real {
+    if x < 0.0 || x.is_nan() {
+        return Real::NAN;
+    }
+    if x == 0.0 {
+        return if n < 0 && n % 2 != 0 {
+            Real::INFINITY
+        } else {
+            Real::NEG_INFINITY
+        };
+    }
+    let sign = if n < 0 && n % 2 != 0 { -1.0 } else { 1.0 };
+    let n = n.unsigned_abs() as usize;
+    let (y_0, y_1) = if x > ASYMPTOTIC_LIMIT {
+        hankel(x).1
+    } else if x < SMALL_LIMIT {
+        let log = (x / 2.0).ln() + EULER_GAMMA;
+        (2.0 / PI * log, -2.0 / (PI * x))
+    } else {
+        neumann(x)
+    };
+    sign * upward(n, x, y_0, y_1, -1.0)
+}
+
+/// the modified bessel function of the first kind of integer order,
+/// summed as a series of positive terms to a relative error of a few ulps
+/// ```
+/// # use vmath::special::bessel_i;
+/// assert_eq!(bessel_i(0, 0.0), 1.0);
+/// assert!((bessel_i(0, 1.0) - 1.2660658777520082).abs() < 1e-15);
+/// assert!((bessel_i(3, 2.5) / 0.47437040877803559 - 1.0).abs() < 1e-15);
+/// assert!((bessel_i(1, -2.0) + 1.5906368546373291).abs() < 1e-15);
+/// assert!((bessel_i(0, 100.0) / 1.0737517071310738e42 - 1.0).abs() < 1e-13);
+/// ```
+pub fn bessel_i(n: i32, x: Real) -> Real {
+    let sign = if x < 0.0 && n % 2 != 0 { -1.0 } else { 1.0 };
+    let n = n.unsigned_abs() as usize;
+    sign * power_series(n, x.abs(), 1.0)
+}
+
+/// the modified bessel function of the second kind of integer order,
+/// infinite at zero and NaN for negative arguments
+/// ```
+/// # use vmath::special::bessel_k;
+/// assert!((bessel_k(0, 1.0) - 0.42102443824070834).abs() < 1e-15);
+/// assert!((bessel_k(1, 0.01) / 99.973894118296246 - 1.0).abs() < 1e-14);
+/// assert!((bessel_k(4, 3.0) / 0.30585120998610917 - 1.0).abs() < 1e-14);
+/// assert!((bessel_k(0, 50.0) / 3.410167749789496e-23 - 1.0).abs() < 1e-14);
+/// assert_eq!(bessel_k(2, 0.0), f64::INFINITY);
+/// ```
+pub fn bessel_k(n: i32, x: Real) -> Real {
+    if x < 0.0 || x.is_nan() {
+        return Real::NAN;
+    }
+    if x == 0.0 {
+        return Real::INFINITY;
+    }
+    let n = n.unsigned_abs() as usize;
+    upward(n, x, integral_k(0.0, x), integral_k(1.0, x), 1.0)
+}
+
+/// (x/2)^n the sum of (sign x^2/4)^k / (k! (n + k)!),
+/// j when sign is -1 and i when it is 1
+fn power_series(n: usize, x: Real, sign: Real) -> Real {
+    let half = x / 2.0;
+    let mut term = (1..=n).fold(1.0, |term, k| term * half / k as Real);
+    let mut sum = term;
+    let step = sign * half * half;
+    let mut k = 0;
+    while term != 0.0 && term.abs() > Real::EPSILON * sum.abs() {
+        k += 1;
+        term *= step / (k * (n + k)) as Real;
+        sum += term;
+    }
+    sum
+}
+
+/// raises the order from (f(0), f(1)) to f(n) by f(k + 1) = 2k/x f(k) + sign f(k - 1),
+/// which is stable upward for j and y below x with sign -1 and for k with sign 1
+fn upward(n: usize, x: Real, f_0: Real, f_1: Real, sign: Real) -> Real {
+    if n == 0 {
+        return f_0;
+    }
+    let (mut previous, mut current) = (f_0, f_1);
+    for k in 1..n {
+        let next = 2.0 * k as Real / x * current + sign * previous;
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+/// j(0, x) through at least j(last, x) by miller's backward recurrence,
+/// normalized by j(0) + 2 j(2) + 2 j(4) + ... = 1, for x >= SMALL_LIMIT
+fn j_sequence(x: Real, last: usize) -> Vec<Real> {
+    let reach = (last as Real).max(x);
+    let top = 2 * ((reach + 20.0 + (40.0 * reach).sqrt()) as usize / 2 + 1);
+    let mut values = vec![0.0; top + 2];
+    values[top] = 1.0;
+    for k in (1..=top).rev() {
+        values[k - 1] = 2.0 * k as Real / x * values[k] - values[k + 1];
+        if values[k - 1].abs() > BIG {
+            values[k - 1..].iter_mut().for_each(|value| *value /= BIG);
+        }
+    }
+    let norm = values[0] + 2.0 * values[2..=top].iter().step_by(2).sum::<Real>();
+    values.truncate(top + 1);
+    values.iter_mut().for_each(|value| *value /= norm);
+    values
+}
+
+/// y(0, x) and y(1, x) from neumann's expansions in j(k, x),
+/// y0 = 2/pi ((ln(x/2) + gamma) j0 + 2 sum (-1)^(k+1) j(2k) / k)
+/// and y1 = -y0', differentiated term by term
+fn neumann(x: Real) -> (Real, Real) {
+    let j = j_sequence(x, 2);
+    let log = (x / 2.0).ln() + EULER_GAMMA;
+    let mut sum_0 = 0.0;
+    let mut sum_1 = 0.0;
+    let mut sign = 1.0;
+    for k in 1..(j.len() - 1) / 2 {
+        sum_0 += sign * j[2 * k] / k as Real;
+        sum_1 += sign * (j[2 * k - 1] - j[2 * k + 1]) / k as Real;
+        sign = -sign;
+    }
+    let y_0 = 2.0 / PI * (log * j[0] + 2.0 * sum_0);
+    let y_1 = -2.0 / PI * (j[0] / x - log * j[1] + sum_1);
+    (y_0, y_1)
+}
+
+/// ((j0, j1), (y0, y1)) from hankel's asymptotic expansions,
+/// summed until the terms stop shrinking, for large x
+fn hankel(x: Real) -> ((Real, Real), (Real, Real)) {
+    let [(j_0, y_0), (j_1, y_1)] = [0.0, 1.0].map(|order: Real| {
+        let mu = 4.0 * order * order;
+        let mut p = 1.0;
+        let mut q = 0.0;
+        let mut term: Real = 1.0;
+        for k in 1..100 {
+            let odd = (2 * k - 1) as Real;
+            let next = term * (mu - odd * odd) / (k as Real * 8.0 * x);
+            if next.abs() >= term.abs() || next == 0.0 {
+                break;
+            }
+            term = next;
+            // terms alternate between q and p with the sign flipping every two
+            match k % 4 {
+                1 => q += term,
+                2 => p -= term,
+                3 => q -= term,
+                _ => p += term,
+            }
+        }
+        let chi = x - (order / 2.0 + 0.25) * PI;
+        let scale = (2.0 / (PI * x)).sqrt();
+        let (sin, cos) = chi.sin_cos();
+        (scale * (p * cos - q * sin), scale * (p * sin + q * cos))
+    });
+    ((j_0, j_1), (y_0, y_1))
+}
+
+/// k(order, x) = integral from 0 to infinity of e^(-x cosh t) cosh(order t) dt
+/// by the trapezoidal rule, which converges geometrically for this integrand
+fn integral_k(order: Real, x: Real) -> Real {
+    let step = 0.25 / x.max(1.0).sqrt();
+    let mut sum = 0.5;
+    let mut t: Real = 0.0;
+    loop {
+        t += step;
+        // e^(-x (cosh t - 1)) with the e^-x factored out of the sum
+        let half = (t / 2.0).sinh();
+        let term = (-2.0 * x * half * half).exp() * (order * t).cosh();
+        sum += term;
+        if term < Real::EPSILON * sum && x * t.sinh() > order {
+            break;
+        }
+    }
+    step * sum * (-x).exp()
+}
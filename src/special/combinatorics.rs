@@ -0,0 +1,130 @@
+use super::gamma::{gamma, ln_gamma};
+use crate::numbers::Real;
+
+/// the largest n whose factorial is a finite Real
+const MAX_FACTORIAL: u64 = 170;
+
+/// n!, or None when it overflows a u64, which happens from 21! on
+/// ```
+/// # use vmath::special::factorial;
+/// assert_eq!(factorial(0), Some(1));
+/// assert_eq!(factorial(5), Some(120));
+/// assert_eq!(factorial(20), Some(2432902008176640000));
+/// assert_eq!(factorial(21), None);
+/// ```
+pub fn factorial(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1_u64, |product, k| product.checked_mul(k))
+}
+
+/// ln n!, correctly rounded products while n! is finite
+/// and the log gamma function past that
+/// ```
+/// # use vmath::special::ln_factorial;
+/// assert_eq!(ln_factorial(0), 0.0);
+/// assert!((ln_factorial(10) - 3628800.0_f64.ln()).abs() < 1e-15);
+/// assert!((ln_factorial(1000) - 5912.128178488163).abs() < 1e-9);
+/// ```
+pub fn ln_factorial(n: u64) -> Real {
+    if n <= MAX_FACTORIAL {
+        return gamma(n as Real + 1.0).ln();
+    }
+    ln_gamma(n as Real + 1.0)
+}
+
+/// the binomial coefficient n choose k, or None when it overflows a u64
+///
+/// built up by the multiplicative formula, every partial product is itself
+/// a binomial coefficient, so nothing overflows unless the result does
+/// ```
+/// # use vmath::special::binomial;
+/// assert_eq!(binomial(5, 2), Some(10));
+/// assert_eq!(binomial(5, 7), Some(0));
+/// assert_eq!(binomial(67, 33), Some(14226520737620288370));
+/// assert_eq!(binomial(68, 34), None);
+/// // far beyond where n! overflows
+/// assert_eq!(binomial(1_000_000, 2), Some(499999500000));
+/// ```
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    (1..=k).try_fold(1_u64, |product, i| {
+        // product = (n - k + i - 1) choose (i - 1), so the division is exact
+        let next = product as u128 * (n - k + i) as u128 / i as u128;
+        u64::try_from(next).ok()
+    })
+}
+
+/// the rising factorial x (x + 1) ... (x + n - 1), one when n is zero
+/// ```
+/// # use vmath::special::rising_factorial;
+/// assert_eq!(rising_factorial(3.0, 4), 360.0);
+/// assert_eq!(rising_factorial(-2.0, 3), 0.0);
+/// assert_eq!(rising_factorial(0.5, 0), 1.0);
+/// ```
+pub fn rising_factorial(x: Real, n: u32) -> Real {
+    (0..n).fold(1.0, |product, k| product * (x + k as Real))
+}
+
+/// the falling factorial x (x - 1) ... (x - n + 1), one when n is zero
+/// ```
+/// # use vmath::special::falling_factorial;
+/// assert_eq!(falling_factorial(6.0, 3), 120.0);
+/// assert_eq!(falling_factorial(2.0, 3), 0.0);
+/// assert_eq!(falling_factorial(0.5, 2), -0.25);
+/// ```
+pub fn falling_factorial(x: Real, n: u32) -> Real {
+    (0..n).fold(1.0, |product, k| product * (x - k as Real))
+}
+
+/// the unsigned stirling number of the first kind,
+/// the number of permutations of n elements with exactly k cycles,
+/// or None when it overflows a u64
+/// ```
+/// # use vmath::special::stirling_first;
+/// assert_eq!(stirling_first(0, 0), Some(1));
+/// assert_eq!(stirling_first(5, 2), Some(50));
+/// assert_eq!(stirling_first(10, 10), Some(1));
+/// assert_eq!(stirling_first(4, 5), Some(0));
+/// assert_eq!(stirling_first(30, 1), None);
+/// ```
+pub fn stirling_first(n: u64, k: u64) -> Option<u64> {
+    stirling(n, k, |j, _| j - 1)
+}
+
+/// the stirling number of the second kind,
+/// the number of ways to partition n elements into k nonempty sets,
+/// or None when it overflows a u64
+/// ```
+/// # use vmath::special::stirling_second;
+/// assert_eq!(stirling_second(10, 3), Some(9330));
+/// assert_eq!(stirling_second(4, 1), Some(1));
+/// assert_eq!(stirling_second(3, 0), Some(0));
+/// assert_eq!(stirling_second(100, 50), None);
+/// ```
+pub fn stirling_second(n: u64, k: u64) -> Option<u64> {
+    stirling(n, k, |_, i| i)
+}
+
+/// the triangle s(j, i) = weight(j, i) s(j - 1, i) + s(j - 1, i - 1) from s(0, 0) = 1,
+/// filled only in the band that leads to (n, k), where every entry is at most s(n, k),
+/// so an overflow anywhere means the result overflows
+fn stirling(n: u64, k: u64, weight: impl Fn(u64, u64) -> u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let mut row = vec![0_u64; k as usize + 1];
+    row[0] = 1;
+    for j in 1..=n {
+        let low = j.saturating_sub(n - k).max(1);
+        for i in (low..=j.min(k)).rev() {
+            let i = i as usize;
+            row[i] = weight(j, i as u64)
+                .checked_mul(row[i])?
+                .checked_add(row[i - 1])?;
+        }
+        row[0] = 0;
+    }
+    Some(row[k as usize])
+}
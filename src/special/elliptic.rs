@@ -0,0 +1,315 @@
+use std::f64::consts::PI;
+
+use crate::numbers::Real;
+
+/// carlson's duplication stops once the arguments agree this closely,
+/// where the truncated series is accurate to well below an ulp
+const TOLERANCE: Real = 1e-3;
+
+/// the complete elliptic integral of the first kind,
+/// the integral of 1 / sqrt(1 - m sin^2 t) from 0 to pi / 2, in terms of the parameter m = k^2
+///
+/// infinite at m = 1 and NaN above it
+/// ```
+/// # use vmath::special::elliptic_k;
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert_eq!(elliptic_k(0.0), FRAC_PI_2);
+/// assert!((elliptic_k(0.5) - 1.8540746773013719).abs() < 1e-15);
+/// assert!((elliptic_k(-2.0) - 1.1714200841467699).abs() < 1e-15);
+/// assert_eq!(elliptic_k(1.0), f64::INFINITY);
+/// ```
+pub fn elliptic_k(m: Real) -> Real {
+    if m == 1.0 {
+        return Real::INFINITY;
+    }
+    carlson_rf(0.0, 1.0 - m, 1.0)
+}
+
+/// the complete elliptic integral of the second kind,
+/// the integral of sqrt(1 - m sin^2 t) from 0 to pi / 2, a quarter of the
+/// perimeter of an ellipse with unit semi-major axis and eccentricity sqrt(m)
+/// ```
+/// # use vmath::special::elliptic_e;
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert_eq!(elliptic_e(0.0), FRAC_PI_2);
+/// assert!((elliptic_e(0.5) - 1.3506438810476755).abs() < 1e-15);
+/// assert_eq!(elliptic_e(1.0), 1.0);
+/// ```
+pub fn elliptic_e(m: Real) -> Real {
+    if m == 1.0 {
+        return 1.0;
+    }
+    let y = 1.0 - m;
+    carlson_rf(0.0, y, 1.0) - m / 3.0 * carlson_rd(0.0, y, 1.0)
+}
+
+/// the incomplete elliptic integral of the first kind,
+/// the integral of 1 / sqrt(1 - m sin^2 t) from 0 to phi
+///
+/// for m > 1 it is real only while m sin^2 phi <= 1 and NaN beyond
+/// ```
+/// # use vmath::special::{elliptic_f, elliptic_k};
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert_eq!(elliptic_f(0.0, 0.7), 0.0);
+/// assert!((elliptic_f(FRAC_PI_2, 0.7) - elliptic_k(0.7)).abs() < 1e-15);
+/// assert!((elliptic_f(1.0, 0.5) - 1.0832167728451688).abs() < 1e-15);
+/// // it keeps growing past pi / 2, by 2 k(m) every half turn
+/// assert!((elliptic_f(4.0, 0.5) - 4.6195206162571072).abs() < 1e-14);
+/// ```
+pub fn elliptic_f(phi: Real, m: Real) -> Real {
+    let (turns, phi) = reduce(phi);
+    let (sin, cos) = phi.sin_cos();
+    let single = sin * carlson_rf(cos * cos, 1.0 - m * sin * sin, 1.0);
+    if turns == 0.0 {
+        return single;
+    }
+    2.0 * turns * elliptic_k(m) + single
+}
+
+/// the incomplete elliptic integral of the second kind,
+/// the integral of sqrt(1 - m sin^2 t) from 0 to phi, the arc length of an ellipse
+/// ```
+/// # use vmath::special::{elliptic_e, elliptic_e_incomplete};
+/// # use std::f64::consts::{FRAC_PI_2, PI};
+/// assert!((elliptic_e_incomplete(FRAC_PI_2, 0.3) - elliptic_e(0.3)).abs() < 1e-15);
+/// assert!((elliptic_e_incomplete(1.0, 0.5) - 0.92732988362444007).abs() < 1e-15);
+/// assert!((elliptic_e_incomplete(-3.0 * PI, 0.5) + 6.0 * elliptic_e(0.5)).abs() < 1e-14);
+/// ```
+pub fn elliptic_e_incomplete(phi: Real, m: Real) -> Real {
+    let (turns, phi) = reduce(phi);
+    let (sin, cos) = phi.sin_cos();
+    let x = cos * cos;
+    let y = 1.0 - m * sin * sin;
+    let single = sin * carlson_rf(x, y, 1.0) - m / 3.0 * sin.powi(3) * carlson_rd(x, y, 1.0);
+    if turns == 0.0 {
+        return single;
+    }
+    2.0 * turns * elliptic_e(m) + single
+}
+
+/// the complete elliptic integral of the third kind,
+/// the integral of 1 / ((1 - n sin^2 t) sqrt(1 - m sin^2 t)) from 0 to pi / 2,
+/// with characteristic n and parameter m
+///
+/// infinite at n = 1 or m = 1, the cauchy principal value for n > 1 and NaN for m > 1
+/// ```
+/// # use vmath::special::{elliptic_k, elliptic_pi};
+/// assert_eq!(elliptic_pi(0.0, 0.5), elliptic_k(0.5));
+/// assert!((elliptic_pi(0.3, 0.5) - 2.2503768219439467).abs() < 1e-15);
+/// assert!((elliptic_pi(-0.5, 0.8) - 1.7725308316422488).abs() < 1e-15);
+/// // past the pole the principal value is k(m) - pi(m / n, m)
+/// assert!((elliptic_pi(2.0, 0.5) + 0.31354468346518404).abs() < 1e-15);
+/// ```
+pub fn elliptic_pi(n: Real, m: Real) -> Real {
+    if n == 1.0 || m == 1.0 {
+        return Real::INFINITY;
+    }
+    if m > 1.0 {
+        return Real::NAN;
+    }
+    let y = 1.0 - m;
+    carlson_rf(0.0, y, 1.0) + n / 3.0 * carlson_rj(0.0, y, 1.0, 1.0 - n)
+}
+
+/// the incomplete elliptic integral of the third kind,
+/// the integral of 1 / ((1 - n sin^2 t) sqrt(1 - m sin^2 t)) from 0 to phi,
+/// the cauchy principal value once n sin^2 phi passes 1
+/// ```
+/// # use vmath::special::{elliptic_pi, elliptic_pi_incomplete};
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert!((elliptic_pi_incomplete(FRAC_PI_2, 0.3, 0.5) - elliptic_pi(0.3, 0.5)).abs() < 1e-15);
+/// assert!((elliptic_pi_incomplete(1.0, 0.4, 0.5) - 1.2375687366415989).abs() < 1e-15);
+/// assert!((elliptic_pi_incomplete(0.5, 1.5, 0.3) - 0.58408556651431108).abs() < 1e-15);
+/// // it keeps growing past pi / 2, by 2 pi(n, m) every half turn
+/// assert!((elliptic_pi_incomplete(4.0, 0.4, 0.5) - 5.8999132513303372).abs() < 1e-14);
+/// ```
+pub fn elliptic_pi_incomplete(phi: Real, n: Real, m: Real) -> Real {
+    let (turns, phi) = reduce(phi);
+    let (sin, cos) = phi.sin_cos();
+    let x = cos * cos;
+    let y = 1.0 - m * sin * sin;
+    let p = 1.0 - n * sin * sin;
+    let single = sin * carlson_rf(x, y, 1.0) + n / 3.0 * sin.powi(3) * carlson_rj(x, y, 1.0, p);
+    if turns == 0.0 {
+        return single;
+    }
+    2.0 * turns * elliptic_pi(n, m) + single
+}
+
+/// the jacobi elliptic functions (sn, cn, dn) of u for 0 <= m <= 1,
+/// the inverses of elliptic_f in the sense that sn(f(phi, m), m) = sin phi,
+/// NaN outside that range of m
+///
+/// at m = 0 they are (sin, cos, 1) and at m = 1 they are (tanh, sech, sech)
+/// ```
+/// # use vmath::special::{elliptic_f, jacobi_elliptic};
+/// let (sn, cn, dn) = jacobi_elliptic(0.8, 0.6);
+/// assert!((sn - 0.68558977511591676).abs() < 1e-15);
+/// assert!((sn * sn + cn * cn - 1.0).abs() < 1e-15);
+/// assert!((dn * dn + 0.6 * sn * sn - 1.0).abs() < 1e-15);
+/// let (sn, _, _) = jacobi_elliptic(elliptic_f(0.4, 0.9), 0.9);
+/// assert!((sn - 0.4_f64.sin()).abs() < 1e-15);
+/// ```
+pub fn jacobi_elliptic(u: Real, m: Real) -> (Real, Real, Real) {
+    if !(0.0..=1.0).contains(&m) {
+        return (Real::NAN, Real::NAN, Real::NAN);
+    }
+    if m == 1.0 {
+        let sech = 1.0 / u.cosh();
+        return (u.tanh(), sech, sech);
+    }
+    // descending landen transformation through the arithmetic-geometric mean
+    let mut a: Real = 1.0;
+    let mut b = (1.0 - m).sqrt();
+    let mut c = m.sqrt();
+    let mut ratios = Vec::new();
+    while c.abs() > Real::EPSILON {
+        c = (a - b) / 2.0;
+        (a, b) = ((a + b) / 2.0, (a * b).sqrt());
+        ratios.push(c / a);
+    }
+    if ratios.is_empty() {
+        let (sin, cos) = u.sin_cos();
+        return (sin, cos, 1.0);
+    }
+    let mut phi = 2.0_f64.powi(ratios.len() as i32) * a * u;
+    let mut previous = phi;
+    for ratio in ratios.iter().rev() {
+        previous = phi;
+        phi = (phi + (ratio * phi.sin()).asin()) / 2.0;
+    }
+    let (sn, cn) = phi.sin_cos();
+    (sn, cn, cn / (previous - phi).cos())
+}
+
+/// (j, phi - j pi) for the nearest whole j, leaving an angle in [-pi / 2, pi / 2]
+fn reduce(phi: Real) -> (Real, Real) {
+    let turns = (phi / PI).round();
+    (turns, phi - turns * PI)
+}
+
+/// carlson's symmetric integral rf(x, y, z), half the integral from 0 to infinity of
+/// 1 / sqrt((t + x)(t + y)(t + z)), infinite when two arguments are zero
+fn carlson_rf(mut x: Real, mut y: Real, mut z: Real) -> Real {
+    if [x, y, z].iter().filter(|&&value| value == 0.0).count() > 1 {
+        return Real::INFINITY;
+    }
+    loop {
+        let mean = (x + y + z) / 3.0;
+        let (dx, dy, dz) = (1.0 - x / mean, 1.0 - y / mean, 1.0 - z / mean);
+        let deviation = dx.abs().max(dy.abs()).max(dz.abs());
+        if deviation.is_nan() || deviation < TOLERANCE {
+            let e2 = dx * dy - dz * dz;
+            let e3 = dx * dy * dz;
+            let series = 1.0 + (e2 / 24.0 - 0.1 - 3.0 / 44.0 * e3) * e2 + e3 / 14.0;
+            return series / mean.sqrt();
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+    }
+}
+
+/// carlson's symmetric integral rd(x, y, z), three halves the integral from 0 to infinity of
+/// 1 / (sqrt((t + x)(t + y)) (t + z)^(3/2)), for z > 0 and at most one of x and y zero
+fn carlson_rd(mut x: Real, mut y: Real, mut z: Real) -> Real {
+    let mut sum = 0.0;
+    let mut scale = 1.0;
+    loop {
+        let mean = (x + y + 3.0 * z) / 5.0;
+        let (dx, dy, dz) = (1.0 - x / mean, 1.0 - y / mean, 1.0 - z / mean);
+        let deviation = dx.abs().max(dy.abs()).max(dz.abs());
+        if deviation.is_nan() || deviation < TOLERANCE {
+            let ea = dx * dy;
+            let eb = dz * dz;
+            let ec = ea - eb;
+            let ed = ea - 6.0 * eb;
+            let ee = ed + 2.0 * ec;
+            let series = 1.0
+                + ed * (-3.0 / 14.0 + 9.0 / 88.0 * ed - 9.0 / 52.0 * dz * ee)
+                + dz * (ee / 6.0 + dz * (-9.0 / 22.0 * ec + 3.0 / 26.0 * dz * ea));
+            return 3.0 * sum + scale * series / (mean * mean.sqrt());
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        sum += scale / (sz * (z + lambda));
+        scale /= 4.0;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+    }
+}
+
+/// carlson's symmetric integral rj(x, y, z, p), three halves the integral from 0 to infinity
+/// of 1 / (sqrt((t + x)(t + y)(t + z)) (t + p)), for at most one of x, y and z zero,
+/// and its cauchy principal value for p < 0
+fn carlson_rj(x: Real, y: Real, z: Real, p: Real) -> Real {
+    if p > 0.0 {
+        return carlson_rj_positive(x, y, z, p);
+    }
+    // carlson's transformation to a positive p, with x <= y <= z
+    let mut sorted = [x, y, z];
+    sorted.sort_by(Real::total_cmp);
+    let [x, y, z] = sorted;
+    let a = 1.0 / (y - p);
+    let b = a * (z - y) * (y - x);
+    let q = y + b;
+    let rc = carlson_rc(x * z / y, p * q / y);
+    a * (b * carlson_rj_positive(x, y, z, q) + 3.0 * (rc - carlson_rf(x, y, z)))
+}
+
+fn carlson_rj_positive(mut x: Real, mut y: Real, mut z: Real, mut p: Real) -> Real {
+    let mut sum = 0.0;
+    let mut scale = 1.0;
+    loop {
+        let mean = (x + y + z + 2.0 * p) / 5.0;
+        let (dx, dy, dz) = (1.0 - x / mean, 1.0 - y / mean, 1.0 - z / mean);
+        let dp = 1.0 - p / mean;
+        let deviation = dx.abs().max(dy.abs()).max(dz.abs()).max(dp.abs());
+        if deviation.is_nan() || deviation < TOLERANCE {
+            let ea = dx * (dy + dz) + dy * dz;
+            let eb = dx * dy * dz;
+            let ec = dp * dp;
+            let ed = ea - 3.0 * ec;
+            let ee = eb + 2.0 * dp * (ea - ec);
+            let series = 1.0
+                + ed * (-3.0 / 14.0 + 9.0 / 88.0 * ed - 9.0 / 52.0 * ee)
+                + eb * (1.0 / 6.0 + dp * (-3.0 / 11.0 + 3.0 / 26.0 * dp))
+                + dp * ea * (1.0 / 3.0 - 3.0 / 22.0 * dp)
+                - dp * ec / 3.0;
+            return 3.0 * sum + scale * series / (mean * mean.sqrt());
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        let alpha = (p * (sx + sy + sz) + sx * sy * sz).powi(2);
+        let beta = p * (p + lambda).powi(2);
+        sum += scale * carlson_rc(alpha, beta);
+        scale /= 4.0;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+        p = (p + lambda) / 4.0;
+    }
+}
+
+/// carlson's degenerate integral rc(x, y) = rf(x, y, y), elementary but computed
+/// by the same duplication, and its cauchy principal value for y < 0
+fn carlson_rc(x: Real, y: Real) -> Real {
+    if y < 0.0 {
+        return (x / (x - y)).sqrt() * carlson_rc(x - y, -y);
+    }
+    let (mut x, mut y) = (x, y);
+    loop {
+        let mean = (x + 2.0 * y) / 3.0;
+        let s = y / mean - 1.0;
+        if s.is_nan() || s.abs() < TOLERANCE {
+            let series = 1.0 + s * s * (0.3 + s * (1.0 / 7.0 + s * (0.375 + s * 9.0 / 22.0)));
+            return series / mean.sqrt();
+        }
+        let lambda = 2.0 * x.sqrt() * y.sqrt() + y;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+    }
+}
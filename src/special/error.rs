@@ -0,0 +1,339 @@
+use std::f64::consts::{FRAC_2_SQRT_PI, PI, SQRT_2};
+
+use crate::numbers::{Complex, Real};
+
+/// below this erf is summed as a series, above it erfc is a continued fraction
+const SERIES_LIMIT: Real = 2.0;
+/// erfc switches sooner, before 1 - erf loses digits to cancellation
+const COMPLEMENT_LIMIT: Real = 1.0;
+
+/// the error function, 2 / sqrt(pi) times the integral of e^(-t^2) from 0 to x,
+/// with relative error near 1e-15
+/// ```
+/// # use vmath::special::erf;
+/// assert_eq!(erf(0.0), 0.0);
+/// assert!((erf(0.5) - 0.5204998778130465).abs() < 1e-15);
+/// assert!((erf(-1.5) + 0.9661051464753108).abs() < 1e-15);
+/// assert_eq!(erf(f64::INFINITY), 1.0);
+/// ```
+pub fn erf(x: Real) -> Real {
+    if x.abs() < SERIES_LIMIT {
+        return series(x);
+    }
+    (1.0 - continued_fraction(x.abs())).copysign(x)
+}
+
+/// the complementary error function 1 - erf(x),
+/// with relative error near 1e-15 even far in the tail where erf(x) rounds to 1
+/// ```
+/// # use vmath::special::erfc;
+/// assert_eq!(erfc(0.0), 1.0);
+/// assert!((erfc(1.0) - 0.15729920705028513).abs() < 1e-15);
+/// assert!((erfc(10.0) / 2.088487583762545e-45 - 1.0).abs() < 1e-14);
+/// assert!((erfc(-2.5) - 1.999593047982555).abs() < 1e-15);
+/// ```
+pub fn erfc(x: Real) -> Real {
+    if x < 0.0 {
+        return 2.0 - erfc(-x);
+    }
+    if x < COMPLEMENT_LIMIT {
+        return 1.0 - series(x);
+    }
+    continued_fraction(x)
+}
+
+/// the inverse of erf on (-1, 1), infinite at the ends and NaN outside,
+/// refined until erf of the result matches y to within a couple of ulps
+/// ```
+/// # use vmath::special::{erf, erf_inv};
+/// assert_eq!(erf_inv(0.0), 0.0);
+/// assert!((erf(erf_inv(0.3)) - 0.3).abs() < 1e-15);
+/// assert!((erf_inv(-0.999) + 2.326753765513525).abs() < 1e-13);
+/// assert_eq!(erf_inv(1.0), f64::INFINITY);
+/// assert!(erf_inv(1.5).is_nan());
+/// ```
+pub fn erf_inv(y: Real) -> Real {
+    if !(-1.0..=1.0).contains(&y) {
+        return Real::NAN;
+    }
+    if y.abs() == 1.0 {
+        return Real::INFINITY.copysign(y);
+    }
+    // past one half erfc keeps the tail accurate
+    if y.abs() > 0.5 {
+        return erfc_inv(1.0 - y.abs()).copysign(y);
+    }
+    let mut x = initial_guess(y, (1.0 - y) * (1.0 + y));
+    for _ in 0..3 {
+        x = halley(x, erf(x) - y);
+    }
+    x
+}
+
+/// the inverse of erfc on (0, 2), infinite at the ends and NaN outside,
+/// erfc of the result matches y to a relative 1e-14 down to y = 1e-200,
+/// loosening to 1e-12 at the bottom of the double range
+/// ```
+/// # use vmath::special::{erfc, erfc_inv};
+/// assert!((erfc(erfc_inv(1e-100)) / 1e-100 - 1.0).abs() < 1e-13);
+/// assert!((erfc_inv(1.5) + 0.4769362762044699).abs() < 1e-15);
+/// assert_eq!(erfc_inv(0.0), f64::INFINITY);
+/// assert_eq!(erfc_inv(2.0), f64::NEG_INFINITY);
+/// ```
+pub fn erfc_inv(y: Real) -> Real {
+    if !(0.0..=2.0).contains(&y) {
+        return Real::NAN;
+    }
+    if y > 1.0 {
+        return -erfc_inv(2.0 - y);
+    }
+    if y == 0.0 {
+        return Real::INFINITY;
+    }
+    if y > 0.5 {
+        return erf_inv(1.0 - y);
+    }
+    let mut x = initial_guess(1.0 - y, y * (2.0 - y));
+    for _ in 0..3 {
+        x = halley(x, y - erfc(x));
+    }
+    x
+}
+
+/// the inverse of the standard normal cumulative distribution on (0, 1),
+/// the x with probability p of a standard normal falling below it,
+/// -sqrt(2) erfc_inv(2p), which is as accurate as erfc_inv in both tails
+///
+/// infinite at the ends and NaN outside; a uniform sample through it is a normal sample
+/// ```
+/// # use vmath::special::probit;
+/// assert_eq!(probit(0.5), 0.0);
+/// assert!((probit(0.975) - 1.959963984540054).abs() < 1e-15);
+/// assert!((probit(0.3) + 0.5244005127080408).abs() < 1e-15);
+/// assert!((probit(1e-10) + 6.361340902404056).abs() < 1e-14);
+/// assert!((probit(1e-300) + 37.047096299361199).abs() < 1e-13);
+/// assert_eq!(probit(0.0), f64::NEG_INFINITY);
+/// assert!(probit(1.5).is_nan());
+/// ```
+pub fn probit(p: Real) -> Real {
+    -SQRT_2 * erfc_inv(2.0 * p)
+}
+
+/// 2 / sqrt(pi) e^(-x^2) times the sum of 2^n x^(2n + 1) / (1 3 5 ... (2n + 1)),
+/// whose terms are all positive so nothing cancels
+fn series(x: Real) -> Real {
+    let square = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 0.0;
+    while term.abs() > Real::EPSILON * sum.abs() {
+        n += 1.0;
+        term *= 2.0 * square / (2.0 * n + 1.0);
+        sum += term;
+    }
+    2.0 / PI.sqrt() * (-square).exp() * sum
+}
+
+/// erfc(x) = e^(-x^2) / sqrt(pi) / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...)))),
+/// evaluated by the modified lentz method, for x > 0
+fn continued_fraction(x: Real) -> Real {
+    if x == Real::INFINITY {
+        return 0.0;
+    }
+    let tiny = Real::MIN_POSITIVE / Real::EPSILON;
+    let mut f = x;
+    let mut c = x;
+    let mut d = 0.0;
+    for n in 1..500 {
+        let a = n as Real / 2.0;
+        d = x + a * d;
+        d = if d == 0.0 { 1.0 / tiny } else { 1.0 / d };
+        c = x + a / c;
+        if c == 0.0 {
+            c = tiny;
+        }
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() <= Real::EPSILON {
+            break;
+        }
+    }
+    (-x * x).exp() / PI.sqrt() / f
+}
+
+/// winitzki's closed form, good to a few parts in a thousand,
+/// from erf(x) = z and 1 - z^2, which is passed separately to keep it accurate in the tail
+fn initial_guess(z: Real, one_minus_square: Real) -> Real {
+    const A: Real = 0.147;
+    let log = one_minus_square.ln();
+    let b = 2.0 / (PI * A) + log / 2.0;
+    ((b * b - log / A).sqrt() - b).sqrt().copysign(z)
+}
+
+/// one halley step toward a root of erf(x) - y, given that residual,
+/// using d/dx erf = 2 / sqrt(pi) e^(-x^2) and d2/dx2 erf = -2x d/dx erf
+fn halley(x: Real, residual: Real) -> Real {
+    let slope = 2.0 / PI.sqrt() * (-x * x).exp();
+    x - residual / (slope + x * residual)
+}
+
+/// the faddeeva function w(z) = e^(-z^2) erfc(-iz), the scaled complex complementary
+/// error function behind the voigt profile, whose real part on the real axis is e^(-x^2).
+/// by gautschi's algorithm as refined by poppe and wijers, a taylor series near the origin
+/// and a continued fraction, accelerated by a truncated taylor step, beyond,
+/// with relative error near 1e-14 through the upper half plane.
+/// below the real axis it is 2 e^(-z^2) - w(-z), which grows without bound
+/// ```
+/// # use vmath::special::faddeeva;
+/// # use vmath::numbers::Complex;
+/// let w = faddeeva(Complex::new(1.0, 0.0));
+/// assert!((w - Complex::new(0.36787944117144233, 0.6071577058413937)).norm() < 1e-15);
+/// let w = faddeeva(Complex::new(1.0, 1.0));
+/// assert!((w - Complex::new(0.30474420525691259, 0.20821893820283162)).norm() < 1e-15);
+/// // on the imaginary axis it is the scaled erfc, e^(y^2) erfc(y)
+/// let w = faddeeva(Complex::new(0.0, 1.0));
+/// assert!((w - Complex::new(0.42758357615580700, 0.0)).norm() < 1e-15);
+/// // far out it decays as i / (sqrt(pi) z)
+/// let z = Complex::new(30.0, 40.0);
+/// let w = faddeeva(z);
+/// let asymptote = Complex::I / z / std::f64::consts::PI.sqrt();
+/// assert!((w - asymptote).norm() < 1e-3 * w.norm());
+/// // and below the axis from the reflection
+/// let z = Complex::new(0.5, -0.5);
+/// let reflected = (-(z * z)).exp() * 2.0 - faddeeva(-z);
+/// assert!((faddeeva(z) - reflected).norm() < 1e-15);
+/// ```
+pub fn faddeeva(z: Complex) -> Complex {
+    let (x, y) = (z.real.abs(), z.imaginary.abs());
+    let scaled = (x / 6.3).powi(2) + (y / 4.4).powi(2);
+    // -z^2 for z in the first quadrant is -(x_quad + i y_quad)
+    let (x_quad, y_quad) = (x * x - y * y, 2.0 * x * y);
+    let near_origin = scaled < 0.085264;
+    let mut exponential = Complex::ZERO;
+    let mut w = if near_origin {
+        // w = e^(-z^2) (1 - 2 / sqrt(pi) (-i z) sum (-z^2)^n / (n! (2n + 1)))
+        let rho = (1.0 - 0.85 * y / 4.4) * scaled.sqrt();
+        let n = (6.0 + 72.0 * rho).round() as i32;
+        let mut j = 2 * n + 1;
+        let mut sum = Complex::new(1.0 / Real::from(j), 0.0);
+        for i in (1..=n).rev() {
+            j -= 2;
+            sum = sum * Complex::new(x_quad, y_quad) / Real::from(i) + 1.0 / Real::from(j);
+        }
+        let series = Complex::new(
+            1.0 - FRAC_2_SQRT_PI * (sum.real * y + sum.imaginary * x),
+            FRAC_2_SQRT_PI * (sum.real * x - sum.imaginary * y),
+        );
+        exponential = Complex::from_polar((-x_quad).exp(), -y_quad);
+        series * exponential
+    } else {
+        let (h, kappa, nu) = if scaled > 1.0 {
+            let nu = (3.0 + 1442.0 / (26.0 * scaled.sqrt() + 77.0)) as i32;
+            (0.0, 0, nu)
+        } else {
+            let rho = (1.0 - y / 4.4) * (1.0 - scaled).sqrt();
+            let kappa = (7.0 + 34.0 * rho).round() as i32;
+            let nu = (16.0 + 26.0 * rho).round() as i32;
+            (1.88 * rho, kappa, nu)
+        };
+        let mut lambda = if h > 0.0 { (2.0 * h).powi(kappa) } else { 0.0 };
+        let mut r = Complex::ZERO;
+        let mut s = Complex::ZERO;
+        for n in (0..=nu).rev() {
+            let np1 = Real::from(n + 1);
+            let t = Complex::new(y + h + np1 * r.real, x - np1 * r.imaginary);
+            r = t / (2.0 * t.norm_squared());
+            if h > 0.0 && n <= kappa {
+                s = r * (s + lambda);
+                lambda /= 2.0 * h;
+            }
+        }
+        let w = if h > 0.0 { s } else { r } * FRAC_2_SQRT_PI;
+        if y == 0.0 {
+            Complex::new((-x * x).exp(), w.imaginary)
+        } else {
+            w
+        }
+    };
+    if z.imaginary < 0.0 {
+        if !near_origin {
+            exponential = Complex::from_polar((-x_quad).exp(), -y_quad);
+        }
+        // w(z) = 2 e^(-z^2) - w(-z), from the first quadrant value
+        w = exponential * 2.0 - w;
+        if z.real > 0.0 {
+            w = w.conjugate();
+        }
+    } else if z.real < 0.0 {
+        w = w.conjugate();
+    }
+    w
+}
+
+/// erf at a complex argument, summed as a taylor series near the origin
+/// and otherwise as 1 - erfc from the faddeeva function
+/// ```
+/// # use vmath::special::{erf, erf_complex};
+/// # use vmath::numbers::Complex;
+/// let value = erf_complex(Complex::new(1.0, 1.0));
+/// assert!((value - Complex::new(1.3161512816979477, 0.19045346923783471)).norm() < 1e-15);
+/// let value = erf_complex(Complex::new(2.0, 3.0));
+/// assert!((value - Complex::new(-20.829461427614568, 8.6873182714701631)).norm() < 1e-13);
+/// // erf(i y) = i erfi(y), imaginary on the imaginary axis
+/// let value = erf_complex(Complex::new(0.0, 0.5));
+/// assert!((value - Complex::new(0.0, 0.61495209469651098)).norm() < 1e-15);
+/// // and agreeing with erf on the real axis
+/// for x in [-3.0, -0.5, 0.25, 1.5, 4.0] {
+///     assert!((erf_complex(Complex::from(x)).real - erf(x)).abs() < 1e-15);
+/// }
+/// ```
+pub fn erf_complex(z: Complex) -> Complex {
+    if z.real < 0.0 {
+        return -erf_complex(-z);
+    }
+    if z.norm() < COMPLEMENT_LIMIT {
+        return complex_series(z);
+    }
+    Complex::ONE - erfc_complex(z)
+}
+
+/// erfc at a complex argument, e^(-z^2) w(iz) for the right half plane,
+/// where it keeps its relative accuracy as it decays, and 2 - erfc(-z) for the left
+/// ```
+/// # use vmath::special::{erfc, erfc_complex};
+/// # use vmath::numbers::Complex;
+/// let value = erfc_complex(Complex::new(3.0, -1.0));
+/// let expected = Complex::new(5.76138679862376e-5, 7.71795638137801e-7);
+/// assert!((value - expected).norm() < 1e-14 * expected.norm());
+/// assert!((erfc_complex(Complex::from(10.0)).real / erfc(10.0) - 1.0).abs() < 1e-14);
+/// let z = Complex::new(-0.5, 2.0);
+/// assert!((erfc_complex(z) + erfc_complex(-z) - Complex::from(2.0)).norm() < 1e-13);
+/// ```
+pub fn erfc_complex(z: Complex) -> Complex {
+    if z.real < 0.0 {
+        return Complex::from(2.0) - erfc_complex(-z);
+    }
+    if z.norm() < COMPLEMENT_LIMIT {
+        return Complex::ONE - complex_series(z);
+    }
+    (-(z * z)).exp() * faddeeva(Complex::I * z)
+}
+
+/// 2 / sqrt(pi) times the sum of (-1)^n z^(2n + 1) / (n! (2n + 1)),
+/// which for |z| < 1 has terms falling fast enough that little cancels
+fn complex_series(z: Complex) -> Complex {
+    let minus_square = -(z * z);
+    let mut power = z;
+    let mut sum = z;
+    let mut n = 0.0;
+    loop {
+        n += 1.0;
+        power = power * minus_square / n;
+        let term = power / (2.0 * n + 1.0);
+        sum = sum + term;
+        if term.norm() <= Real::EPSILON * sum.norm() {
+            break;
+        }
+    }
+    sum * FRAC_2_SQRT_PI
+}
@@ -0,0 +1,181 @@
+use super::gamma::{digamma, gamma};
+use crate::numbers::Real;
+
+/// past this the gauss series converges too slowly and 2f1 is expanded about z = 1 instead
+const SERIES_LIMIT: Real = 0.75;
+
+/// a cap on the number of series terms, far beyond what any convergent region needs
+const MAX_TERMS: usize = 100_000;
+
+/// the confluent hypergeometric function 1f1(a; b; z), kummer's function m,
+/// the sum of (a)_k / (b)_k z^k / k!
+///
+/// negative z goes through kummer's transformation e^z 1f1(b - a; b; -z) so the
+/// terms do not alternate, a polynomial when a is zero or a negative integer,
+/// and NaN when b is, unless the polynomial stops first
+/// ```
+/// # use vmath::special::hyp1f1;
+/// // with a = b it is e^z
+/// assert!((hyp1f1(2.5, 2.5, 1.5) - 1.5_f64.exp()).abs() < 1e-14);
+/// assert!((hyp1f1(0.5, 1.5, -2.0) - 0.5981440066613041).abs() < 1e-15);
+/// assert!((hyp1f1(1.0, 3.0, 30.0) / 2.3747721292207694e10 - 1.0).abs() < 1e-14);
+/// // a laguerre polynomial, l_2(z) = 1f1(-2; 1; z)
+/// assert_eq!(hyp1f1(-2.0, 1.0, 3.0), 1.0 - 6.0 + 4.5);
+/// assert!(hyp1f1(1.0, -2.0, 0.5).is_nan());
+/// ```
+pub fn hyp1f1(a: Real, b: Real, z: Real) -> Real {
+    if pole(b, &[a]) {
+        return Real::NAN;
+    }
+    if z < 0.0 && !nonpositive_integer(a) {
+        return z.exp() * hyp1f1(b - a, b, -z);
+    }
+    series(|k| (a + k) / ((b + k) * (k + 1.0)) * z)
+}
+
+/// the gauss hypergeometric function 2f1(a, b; c; z),
+/// the sum of (a)_k (b)_k / (c)_k z^k / k! for |z| < 1 continued along the real line below 1
+///
+/// small z sums the series directly, negative z first maps into (0, 1) by pfaff's transformation,
+/// and z near 1 uses the connection formulas about z = 1, with their logarithmic form when
+/// c - a - b is an integer; the parameters should be moderate, as the gamma factors
+/// overflow and c - a - b just short of an integer loses digits
+///
+/// at z = 1 it is gauss's sum when c - a - b > 0 and infinite otherwise, and NaN past 1
+/// except for polynomials, which are valid everywhere
+/// ```
+/// # use vmath::special::hyp2f1;
+/// // -ln(1 - z) / z
+/// let z: f64 = 0.9;
+/// assert!((hyp2f1(1.0, 1.0, 2.0, z) + (1.0 - z).ln() / z).abs() < 1e-15);
+/// // arcsin z / z, through the logarithmic case c - a - b = 0 near 1
+/// let z: f64 = 0.99;
+/// assert!((hyp2f1(0.5, 0.5, 1.5, z * z) - z.asin() / z).abs() < 1e-14);
+/// // (1 - z)^-a, far to the left
+/// assert!((hyp2f1(0.3, 2.0, 2.0, -50.0) - 51.0_f64.powf(-0.3)).abs() < 1e-15);
+/// assert!((hyp2f1(1.5, -0.25, 3.2, 0.8) - 0.88007554321396266).abs() < 1e-15);
+/// assert!((hyp2f1(1.0, 2.0, 4.5, 1.0) - 7.0 / 3.0).abs() < 1e-14);
+/// ```
+pub fn hyp2f1(a: Real, b: Real, c: Real, z: Real) -> Real {
+    if pole(c, &[a, b]) {
+        return Real::NAN;
+    }
+    if nonpositive_integer(a) || nonpositive_integer(b) {
+        return gauss_series(a, b, c, z);
+    }
+    // euler's transformation, (1 - z)^(c - a - b) times a polynomial
+    if nonpositive_integer(c - a) || nonpositive_integer(c - b) {
+        return (1.0 - z).powf(c - a - b) * gauss_series(c - a, c - b, c, z);
+    }
+    if z > 1.0 || z.is_nan() {
+        return Real::NAN;
+    }
+    let d = c - a - b;
+    if z == 1.0 {
+        if d <= 0.0 {
+            return Real::INFINITY;
+        }
+        return gamma(c) * gamma(d) / (gamma(c - a) * gamma(c - b));
+    }
+    if z < 0.0 {
+        // pfaff's transformation, (1 - z)^-a 2f1(a, c - b; c; z / (z - 1))
+        return (1.0 - z).powf(-a) * hyp2f1(a, c - b, c, z / (z - 1.0));
+    }
+    if z <= SERIES_LIMIT {
+        return gauss_series(a, b, c, z);
+    }
+    // c - a - b an integer up to the rounding in forming it
+    if (d - d.round()).abs() <= 4.0 * Real::EPSILON * (a.abs() + b.abs() + c.abs()) {
+        let d = d.round();
+        if d < 0.0 {
+            // euler's transformation turns c - a - b to a positive integer
+            return (1.0 - z).powf(d) * hyp2f1(c - a, c - b, c, z);
+        }
+        return logarithmic(a, b, d as usize, 1.0 - z);
+    }
+    // the connection formula about z = 1 in w = 1 - z
+    let w = 1.0 - z;
+    let first =
+        gamma(c) * gamma(d) / (gamma(c - a) * gamma(c - b)) * gauss_series(a, b, 1.0 - d, w);
+    let second =
+        gamma(c) * gamma(-d) / (gamma(a) * gamma(b)) * gauss_series(c - a, c - b, 1.0 + d, w);
+    first + w.powf(d) * second
+}
+
+/// 2f1(a, b; a + b + m; 1 - w) for a whole m >= 0, where the two series about z = 1
+/// merge into a finite sum and a series with logarithms, abramowitz and stegun 15.3.11
+fn logarithmic(a: Real, b: Real, m: usize, w: Real) -> Real {
+    let c = a + b + m as Real;
+    let mf = m as Real;
+    // the finite part, gamma(m) gamma(c) / (gamma(a + m) gamma(b + m)) times
+    // the sum to m - 1 of (a)_n (b)_n / (n! (1 - m)_n) w^n
+    let mut finite = 0.0;
+    if m > 0 {
+        let mut term = gamma(mf) * gamma(c) / (gamma(a + mf) * gamma(b + mf));
+        for n in 0..m {
+            finite += term;
+            let n = n as Real;
+            term *= (a + n) * (b + n) / ((n + 1.0) * (1.0 - mf + n)) * w;
+        }
+    }
+    // the logarithmic part, (a + m)_n (b + m)_n / (n! (n + m)!) w^n times
+    // ln w - psi(n + 1) - psi(n + m + 1) + psi(a + n + m) + psi(b + n + m),
+    // with each digamma stepped along by psi(x + 1) = psi(x) + 1 / x
+    let mut psi = [
+        digamma(1.0),
+        digamma(mf + 1.0),
+        digamma(a + mf),
+        digamma(b + mf),
+    ];
+    let mut term = (1..=m).fold(1.0, |term, k| term / k as Real);
+    let mut sum = 0.0;
+    let log = w.ln();
+    for n in 0..MAX_TERMS {
+        let contribution = term * (log - psi[0] - psi[1] + psi[2] + psi[3]);
+        sum += contribution;
+        if contribution.abs() <= Real::EPSILON * sum.abs() && n > m {
+            break;
+        }
+        let n = n as Real;
+        term *= (a + mf + n) * (b + mf + n) / ((n + 1.0) * (n + mf + 1.0)) * w;
+        psi[0] += 1.0 / (n + 1.0);
+        psi[1] += 1.0 / (n + mf + 1.0);
+        psi[2] += 1.0 / (a + n + mf);
+        psi[3] += 1.0 / (b + n + mf);
+    }
+    let sign = if m.is_multiple_of(2) { 1.0 } else { -1.0 };
+    finite - sign * w.powi(m as i32) * gamma(c) / (gamma(a) * gamma(b)) * sum
+}
+
+/// the sum of (a)_k (b)_k / (c)_k z^k / k!
+fn gauss_series(a: Real, b: Real, c: Real, z: Real) -> Real {
+    series(|k| (a + k) * (b + k) / ((c + k) * (k + 1.0)) * z)
+}
+
+/// one plus the sum of terms built up by term(k + 1) = ratio(k) term(k),
+/// stopping once the terms are negligible and shrinking, or exactly zero
+fn series(ratio: impl Fn(Real) -> Real) -> Real {
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 0..MAX_TERMS {
+        let step = ratio(k as Real);
+        term *= step;
+        sum += term;
+        if term == 0.0 || (term.abs() <= Real::EPSILON * sum.abs() && step.abs() < 1.0) {
+            break;
+        }
+    }
+    sum
+}
+
+fn nonpositive_integer(x: Real) -> bool {
+    x <= 0.0 && x.fract() == 0.0
+}
+
+/// whether a denominator parameter hits zero before a numerator one ends the series
+fn pole(denominator: Real, numerators: &[Real]) -> bool {
+    nonpositive_integer(denominator)
+        && !numerators
+            .iter()
+            .any(|&a| nonpositive_integer(a) && a > denominator)
+}
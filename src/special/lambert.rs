@@ -0,0 +1,121 @@
+use std::f64::consts::E;
+
+use crate::numbers::Real;
+
+/// the branch point, where the two real branches meet at w = -1
+const BRANCH_POINT: Real = -1.0 / E;
+/// the rounding error in BRANCH_POINT, so that x + 1/e is found to full precision
+const BRANCH_POINT_LOW: Real = 1.242_875_367_278_836_3e-17;
+
+/// closer than this in p to the branch point the series alone is accurate
+const SERIES_LIMIT: Real = 0.01;
+
+/// the principal branch of the lambert w function, the w >= -1 solving w e^w = x,
+/// defined for x >= -1/e and NaN below
+///
+/// the relative error is near 1e-15, rising to a few parts in 1e14
+/// just outside the reach of the series at the branch point
+/// ```
+/// # use vmath::special::lambert_w0;
+/// # use std::f64::consts::E;
+/// assert_eq!(lambert_w0(0.0), 0.0);
+/// assert!((lambert_w0(E) - 1.0).abs() < 1e-15);
+/// assert!((lambert_w0(1.0) - 0.5671432904097838).abs() < 1e-15);
+/// let w = lambert_w0(1e300);
+/// assert!((w.ln() + w - 1e300_f64.ln()).abs() < 1e-12);
+/// assert_eq!(lambert_w0(-1.0 / E), -1.0);
+/// assert!(lambert_w0(-1.0).is_nan());
+/// ```
+pub fn lambert_w0(x: Real) -> Real {
+    if x.is_nan() || x < BRANCH_POINT {
+        return Real::NAN;
+    }
+    if x == 0.0 || x == Real::INFINITY {
+        return x;
+    }
+    if x < -0.25 {
+        return near_branch_point(x, branch_distance(x));
+    }
+    let guess = if x < E { x.ln_1p() } else { asymptotic(x.ln()) };
+    halley(guess, x)
+}
+
+/// the lower branch of the lambert w function, the w <= -1 solving w e^w = x,
+/// defined for -1/e <= x < 0 and NaN elsewhere
+/// ```
+/// # use vmath::special::lambert_wm1;
+/// # use std::f64::consts::E;
+/// assert!((lambert_wm1(-0.1) + 3.577152063957297).abs() < 1e-14);
+/// let w = lambert_wm1(-1e-100);
+/// assert!((w * w.exp() / -1e-100 - 1.0).abs() < 1e-14);
+/// assert_eq!(lambert_wm1(-1.0 / E), -1.0);
+/// assert_eq!(lambert_wm1(0.0), f64::NEG_INFINITY);
+/// assert!(lambert_wm1(0.5).is_nan());
+/// ```
+pub fn lambert_wm1(x: Real) -> Real {
+    if x.is_nan() || !(BRANCH_POINT..=0.0).contains(&x) {
+        return Real::NAN;
+    }
+    if x == 0.0 {
+        return Real::NEG_INFINITY;
+    }
+    if x < -0.25 {
+        return near_branch_point(x, -branch_distance(x));
+    }
+    halley(asymptotic((-x).ln()), x)
+}
+
+/// p = sqrt(2 (e x + 1)), with x + 1/e evaluated without cancellation
+fn branch_distance(x: Real) -> Real {
+    (2.0 * E * ((x - BRANCH_POINT) - BRANCH_POINT_LOW))
+        .max(0.0)
+        .sqrt()
+}
+
+/// the series alone close to the branch point, where halley's residual
+/// w e^w - x cancels badly, and polished by halley further out
+fn near_branch_point(x: Real, p: Real) -> Real {
+    let w = branch_series(p);
+    if p.abs() < SERIES_LIMIT {
+        return w;
+    }
+    halley(w, x)
+}
+
+/// w as a series in p about the branch point, positive p for the principal branch
+/// and negative p for the lower one
+fn branch_series(p: Real) -> Real {
+    const COEFFICIENTS: [Real; 7] = [
+        -1.0,
+        1.0,
+        -1.0 / 3.0,
+        11.0 / 72.0,
+        -43.0 / 540.0,
+        769.0 / 17280.0,
+        -221.0 / 8505.0,
+    ];
+    COEFFICIENTS.iter().rev().fold(0.0, |sum, &c| sum * p + c)
+}
+
+/// l1 - l2 + l2 / l1 with l1 = ln |x| and l2 = ln |l1|, good where |l1| is large
+fn asymptotic(l1: Real) -> Real {
+    let l2 = l1.abs().ln();
+    l1 - l2 + l2 / l1
+}
+
+/// halley's iteration on w e^w - x, cubically convergent from a good guess
+fn halley(mut w: Real, x: Real) -> Real {
+    for _ in 0..20 {
+        if w == -1.0 {
+            return w;
+        }
+        let exp = w.exp();
+        let residual = w * exp - x;
+        let step = residual / (exp * (w + 1.0) - (w + 2.0) * residual / (2.0 * w + 2.0));
+        w -= step;
+        if step.abs() <= 2.0 * Real::EPSILON * w.abs() {
+            break;
+        }
+    }
+    w
+}
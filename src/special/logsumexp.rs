@@ -0,0 +1,146 @@
+use std::iter::Sum;
+use std::ops::AddAssign;
+
+use crate::numbers::Real;
+
+/// ln(e^a + e^b), without the overflow of e^a for large a or the underflow to
+/// ln 0 for very negative a and b, and exact when one term is e^-inf = 0
+/// ```
+/// # use vmath::special::log_add_exp;
+/// assert_eq!(log_add_exp(0.0, 0.0), 2f64.ln());
+/// // e^1000 overflows, the log of the sum does not
+/// assert_eq!(log_add_exp(1000.0, 1000.0), 1000.0 + 2f64.ln());
+/// assert_eq!(log_add_exp(-1000.0, f64::NEG_INFINITY), -1000.0);
+/// assert_eq!(log_add_exp(f64::NEG_INFINITY, f64::NEG_INFINITY), f64::NEG_INFINITY);
+/// assert!(log_add_exp(f64::NAN, 1.0).is_nan());
+/// ```
+pub fn log_add_exp(a: Real, b: Real) -> Real {
+    if a.is_nan() || b.is_nan() {
+        return a + b;
+    }
+    let (high, low) = if a >= b { (a, b) } else { (b, a) };
+    if low == Real::NEG_INFINITY || high == Real::INFINITY {
+        return high;
+    }
+    high + (low - high).exp().ln_1p()
+}
+
+/// a running ln(sum of e^x) over the terms pushed, kept as the largest term and
+/// the sum of the others' e^(x - largest), which is never above the count,
+/// so nothing overflows or underflows to zero however large or small the terms.
+/// the iterator counterpart of `log_sum_exp`, in one pass without storing the terms
+/// ```
+/// # use vmath::special::LogSumExp;
+/// // log likelihoods of a thousand samples, far too small to exponentiate
+/// let lse: LogSumExp = (0..1000).map(|i| -1e4 - i as f64 / 1000.0).collect();
+/// let direct = -1e4 + (0..1000).map(|i| (-i as f64 / 1000.0).exp()).sum::<f64>().ln();
+/// assert!((lse.total() - direct).abs() < 1e-12);
+/// assert_eq!((0..1000).map(|i| (-1e4 - i as f64).exp()).sum::<f64>().ln(), f64::NEG_INFINITY);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogSumExp {
+    largest: Real,
+    rest: Real,
+}
+
+impl LogSumExp {
+    pub const fn new() -> Self {
+        Self {
+            largest: Real::NEG_INFINITY,
+            rest: 0.0,
+        }
+    }
+
+    pub fn push(&mut self, x: Real) {
+        if x == Real::NEG_INFINITY {
+            return;
+        }
+        if x == self.largest {
+            self.rest += 1.0;
+        } else if x < self.largest {
+            self.rest += (x - self.largest).exp();
+        } else {
+            // a new largest, or a NaN, which fails the comparisons and sticks
+            self.rest = (self.rest + 1.0) * (self.largest - x).exp();
+            self.largest = x;
+        }
+    }
+
+    /// ln of the sum of e^x so far, -inf when there are no terms
+    pub fn total(&self) -> Real {
+        self.largest + self.rest.ln_1p()
+    }
+}
+
+impl Default for LogSumExp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AddAssign<Real> for LogSumExp {
+    fn add_assign(&mut self, x: Real) {
+        self.push(x);
+    }
+}
+
+impl Extend<Real> for LogSumExp {
+    fn extend<I: IntoIterator<Item = Real>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<Real> for LogSumExp {
+    fn from_iter<I: IntoIterator<Item = Real>>(iter: I) -> Self {
+        let mut lse = Self::new();
+        lse.extend(iter);
+        lse
+    }
+}
+
+impl Sum<Real> for LogSumExp {
+    fn sum<I: Iterator<Item = Real>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+/// ln(sum of e^x) over the slice by `LogSumExp`, -inf for an empty slice,
+/// +inf when a term is, and NaN when one is
+/// ```
+/// # use vmath::special::log_sum_exp;
+/// assert_eq!(log_sum_exp(&[0.0, 0.0, 0.0, 0.0]), 4f64.ln());
+/// assert!((log_sum_exp(&[1e3, 1e3 + 3f64.ln()]) - (1e3 + 4f64.ln())).abs() < 1e-12);
+/// assert!((log_sum_exp(&[-800.0, -801.0]) - (-800.0 + (-1f64).exp().ln_1p())).abs() < 1e-12);
+/// assert_eq!(log_sum_exp(&[]), f64::NEG_INFINITY);
+/// assert_eq!(log_sum_exp(&[1.0, f64::INFINITY]), f64::INFINITY);
+/// ```
+pub fn log_sum_exp(xs: &[Real]) -> Real {
+    xs.iter().copied().collect::<LogSumExp>().total()
+}
+
+/// e^x / sum of e^x for each x of the slice, the probabilities of which the
+/// slice holds the unnormalized logs, positive and summing to 1 within rounding
+/// however large or small the logs. NaN throughout when every term is -inf
+/// or any is NaN, and in place of a +inf term, the others then being 0
+/// ```
+/// # use vmath::special::softmax;
+/// let p = softmax(&[1.0, 2.0, 3.0]);
+/// assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-15);
+/// assert!((p[2] - 0.6652409557748219).abs() < 1e-15);
+/// // shifting every log leaves the probabilities as they were, even past overflow
+/// let shifted = softmax(&[1001.0, 1002.0, 1003.0]);
+/// assert!(p.iter().zip(&shifted).all(|(a, b)| (a - b).abs() < 1e-15));
+/// assert_eq!(softmax(&[0.0, f64::NEG_INFINITY]), [1.0, 0.0]);
+/// ```
+pub fn softmax(xs: &[Real]) -> Vec<Real> {
+    // against the largest term, whose difference with the nearby ones is exact,
+    // rather than against the log of the total, which rounds
+    let lse: LogSumExp = xs.iter().copied().collect();
+    let scale = 1.0 + lse.rest;
+    xs.iter()
+        .map(|&x| (x - lse.largest).exp() / scale)
+        .collect()
+}
@@ -0,0 +1,29 @@
+pub mod bessel;
+pub mod beta;
+pub mod combinatorics;
+pub mod elliptic;
+pub mod error;
+pub mod gamma;
+pub mod hypergeometric;
+pub mod lambert;
+pub mod logsumexp;
+pub mod zeta;
+
+pub use crate::special::bessel::{bessel_i, bessel_j, bessel_k, bessel_y};
+pub use crate::special::beta::{beta, betainc, ln_beta};
+pub use crate::special::combinatorics::{
+    binomial, factorial, falling_factorial, ln_factorial, rising_factorial, stirling_first,
+    stirling_second,
+};
+pub use crate::special::elliptic::{
+    elliptic_e, elliptic_e_incomplete, elliptic_f, elliptic_k, elliptic_pi, elliptic_pi_incomplete,
+    jacobi_elliptic,
+};
+pub use crate::special::error::{
+    erf, erf_complex, erf_inv, erfc, erfc_complex, erfc_inv, faddeeva, probit,
+};
+pub use crate::special::gamma::{digamma, gamma, gammainc, gammaincc, ln_gamma, polygamma};
+pub use crate::special::hypergeometric::{hyp1f1, hyp2f1};
+pub use crate::special::lambert::{lambert_w0, lambert_wm1};
+pub use crate::special::logsumexp::{log_add_exp, log_sum_exp, softmax, LogSumExp};
+pub use crate::special::zeta::{zeta, zeta_complex};
@@ -0,0 +1,104 @@
+use std::f64::consts::PI;
+
+use super::gamma::{gamma, ln_gamma};
+use crate::numbers::{Complex, Real};
+
+/// the bernoulli numbers b2, b4, ..., b28 for euler-maclaurin and asymptotic tails
+pub(crate) const BERNOULLI: [Real; 14] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+    7.0 / 6.0,
+    -3617.0 / 510.0,
+    43867.0 / 798.0,
+    -174611.0 / 330.0,
+    854513.0 / 138.0,
+    -236364091.0 / 2730.0,
+    8553103.0 / 6.0,
+    -23749461029.0 / 870.0,
+];
+
+/// the riemann zeta function, the sum of 1 / n^s continued to the whole real line,
+/// infinite at the pole s = 1 and zero at the negative even integers
+/// ```
+/// # use vmath::special::zeta;
+/// # use std::f64::consts::PI;
+/// assert!((zeta(2.0) - PI * PI / 6.0).abs() < 1e-15);
+/// assert!((zeta(3.0) - 1.2020569031595943).abs() < 1e-15);
+/// assert_eq!(zeta(0.0), -0.5);
+/// assert!((zeta(-1.0) + 1.0 / 12.0).abs() < 1e-15);
+/// assert_eq!(zeta(-4.0), 0.0);
+/// assert_eq!(zeta(1.0), f64::INFINITY);
+/// ```
+pub fn zeta(s: Real) -> Real {
+    if s == 1.0 {
+        return Real::INFINITY;
+    }
+    if s >= 0.0 {
+        return euler_maclaurin(Complex::from(s)).real;
+    }
+    if s % 2.0 == 0.0 {
+        return 0.0;
+    }
+    // reflection, zeta(s) = 2^s pi^(s - 1) sin(pi s / 2) gamma(1 - s) zeta(1 - s),
+    // with the powers and the gamma combined in logarithms so they cannot overflow early
+    let sine = (PI * s / 2.0).sin();
+    if 1.0 - s < 20.0 {
+        return 2.0_f64.powf(s) * PI.powf(s - 1.0) * sine * gamma(1.0 - s) * zeta(1.0 - s);
+    }
+    let log = s * 2.0_f64.ln() + (s - 1.0) * PI.ln() + ln_gamma(1.0 - s);
+    log.exp() * sine * zeta(1.0 - s)
+}
+
+/// the riemann zeta function at a complex argument
+///
+/// the euler-maclaurin sum grows with |s|, and left of the critical strip
+/// the terms are large and cancel, so accuracy falls as the real part goes negative
+/// ```
+/// # use vmath::special::zeta_complex;
+/// # use vmath::numbers::Complex;
+/// // the first nontrivial zero on the critical line
+/// let zero = zeta_complex(Complex::new(0.5, 14.134725141734693));
+/// assert!(zero.norm() < 1e-14);
+/// let value = zeta_complex(Complex::new(2.0, 1.0));
+/// assert!((value - Complex::new(1.1503557032549027, -0.43753086591960788)).norm() < 1e-15);
+/// ```
+pub fn zeta_complex(s: Complex) -> Complex {
+    if s == Complex::from(1.0) {
+        return Complex::new(Real::INFINITY, 0.0);
+    }
+    euler_maclaurin(s)
+}
+
+/// n^-s
+fn power(n: Real, s: Complex) -> Complex {
+    let log = n.ln();
+    let (sin, cos) = (s.imaginary * log).sin_cos();
+    Complex::new(cos, -sin) * (-s.real * log).exp()
+}
+
+/// zeta(s) = sum to N - 1 of n^-s + N^(1 - s) / (s - 1) + N^-s / 2 +
+/// sum over k of b(2k) / (2k)! s (s + 1) ... (s + 2k - 2) N^(-s - 2k + 1),
+/// with N past |s| so the tail shrinks by at least (2 pi)^2 each term
+fn euler_maclaurin(s: Complex) -> Complex {
+    let n = (20.0 + s.norm()).ceil();
+    let mut sum = (1..n as usize).fold(Complex::from(0.0), |sum, k| sum + power(k as Real, s));
+    let last = power(n, s);
+    sum = sum + last * n / (s - 1.0) + last * 0.5;
+    // s (s + 1) ... (s + 2k - 2) N^(-s - 2k + 1) / (2k)!
+    let mut factor = s * last / (2.0 * n);
+    for (k, &bernoulli) in BERNOULLI.iter().enumerate() {
+        let term = factor * bernoulli;
+        sum = sum + term;
+        if term.norm() <= Real::EPSILON * sum.norm() {
+            break;
+        }
+        let k = k as Real + 1.0;
+        factor = factor * (s + (2.0 * k - 1.0)) * (s + 2.0 * k)
+            / (n * n * (2.0 * k + 1.0) * (2.0 * k + 2.0));
+    }
+    sum
+}
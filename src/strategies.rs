@@ -0,0 +1,150 @@
+//! proptest strategies for the crate's types, with the feature `proptest`
+//!
+//! each entry is drawn from a finite range, so the values shrink toward zero
+//! and identities can be checked to a tolerance without NaN or overflow getting in
+//! ```
+//! use proptest::prelude::*;
+//! use vmath::linalg::Vector;
+//! use vmath::strategies;
+//!
+//! proptest!(|(q in strategies::orthogonal::<3>(), v in strategies::vec3(10.0))| {
+//!     let moved = q * v;
+//!     prop_assert!((moved.length() - v.length()).abs() < 1e-12);
+//! });
+//! ```
+
+use std::f64::consts::PI;
+
+use proptest::prelude::*;
+
+use crate::linalg::{DMatrix, DVector, Mat, Vec2, Vec3, Vec4, Vector};
+use crate::numbers::{Complex, Real};
+
+/// a real in [-bound, bound]
+pub fn real(bound: Real) -> impl Strategy<Value = Real> {
+    -bound..=bound
+}
+
+/// real and imaginary parts each in [-bound, bound]
+/// ```
+/// use proptest::prelude::*;
+/// use vmath::strategies;
+///
+/// proptest!(|(z in strategies::complex(1e3), w in strategies::complex(1e3))| {
+///     prop_assert!(((z * w).norm() - z.norm() * w.norm()).abs() <= 1e-9 * z.norm() * w.norm());
+/// });
+/// ```
+pub fn complex(bound: Real) -> impl Strategy<Value = Complex> {
+    (real(bound), real(bound)).prop_map(|(real, imaginary)| Complex::new(real, imaginary))
+}
+
+/// a complex number of norm one, by its angle
+pub fn unit_complex() -> impl Strategy<Value = Complex> {
+    (-PI..=PI).prop_map(|angle: Real| Complex::new(angle.cos(), angle.sin()))
+}
+
+/// components each in [-bound, bound]
+pub fn vec2(bound: Real) -> impl Strategy<Value = Vec2> {
+    [real(bound), real(bound)].prop_map(Vec2::from)
+}
+
+/// components each in [-bound, bound]
+pub fn vec3(bound: Real) -> impl Strategy<Value = Vec3> {
+    [real(bound), real(bound), real(bound)].prop_map(Vec3::from)
+}
+
+/// components each in [-bound, bound]
+pub fn vec4(bound: Real) -> impl Strategy<Value = Vec4> {
+    [real(bound), real(bound), real(bound), real(bound)].prop_map(Vec4::from)
+}
+
+/// a vector of length one, normalized from a cube around the origin
+/// with its center cut out so the direction is well defined
+pub fn unit_vec3() -> impl Strategy<Value = Vec3> {
+    vec3(1.0)
+        .prop_filter("too close to zero to normalize", |v| v.length() > 0.1)
+        .prop_map(Vec3::normalize)
+}
+
+/// entries each in [-bound, bound]
+pub fn mat<const N: usize>(bound: Real) -> impl Strategy<Value = Mat<N>> {
+    proptest::array::uniform(proptest::array::uniform(real(bound))).prop_map(Mat::new)
+}
+
+/// entries in [-1, 1] off the diagonal and n more than that on it, so every row is
+/// strictly diagonally dominant by at least one: invertible, with an infinity-norm
+/// condition number at most 2n
+/// ```
+/// use proptest::prelude::*;
+/// use vmath::linalg::Mat4;
+/// use vmath::strategies;
+///
+/// proptest!(|(m in strategies::well_conditioned::<4>())| {
+///     let error = m * m.inverse().unwrap() - Mat4::IDENTITY;
+///     prop_assert!(error.rows.iter().flatten().all(|e| e.abs() < 1e-14));
+/// });
+/// ```
+pub fn well_conditioned<const N: usize>() -> impl Strategy<Value = Mat<N>> {
+    mat::<N>(1.0).prop_map(|mut m| {
+        for i in 0..N {
+            m.rows[i][i] += N as Real * m.rows[i][i].signum();
+        }
+        m
+    })
+}
+
+/// an orthogonal matrix, the real unitary matrices, as a product of n householder
+/// reflections, which reaches every one with determinant (-1)^n, and then a coin flip
+/// on the sign of the first row, which reaches the rest
+/// ```
+/// use proptest::prelude::*;
+/// use vmath::linalg::Mat3;
+/// use vmath::strategies;
+///
+/// proptest!(|(q in strategies::orthogonal::<3>())| {
+///     let error = q * q.transpose() - Mat3::IDENTITY;
+///     prop_assert!(error.rows.iter().flatten().all(|e| e.abs() < 1e-14));
+///     prop_assert!((q.determinant().abs() - 1.0).abs() < 1e-14);
+/// });
+/// ```
+pub fn orthogonal<const N: usize>() -> impl Strategy<Value = Mat<N>> {
+    let normal = proptest::array::uniform(real(1.0))
+        .prop_filter("too close to zero to reflect in", |v: &[Real; N]| {
+            v.iter().map(|x| x * x).sum::<Real>() > 0.01
+        });
+    (proptest::array::uniform::<_, N>(normal), any::<bool>()).prop_map(|(normals, flip)| {
+        let mut q = normals
+            .iter()
+            .fold(Mat::IDENTITY, |product, v| product * householder(v));
+        if flip && N > 0 {
+            q.rows[0] = q.rows[0].map(|entry| -entry);
+        }
+        q
+    })
+}
+
+/// i - 2 v v^t / v^t v, the reflection in the hyperplane normal to v
+fn householder<const N: usize>(v: &[Real; N]) -> Mat<N> {
+    let scale = 2.0 / v.iter().map(|x| x * x).sum::<Real>();
+    let mut m = Mat::IDENTITY;
+    for i in 0..N {
+        for j in 0..N {
+            m.rows[i][j] -= scale * v[i] * v[j];
+        }
+    }
+    m
+}
+
+/// a length in the range and entries each in [-bound, bound]
+pub fn dvector(
+    len: impl Into<proptest::collection::SizeRange>,
+    bound: Real,
+) -> impl Strategy<Value = DVector> {
+    proptest::collection::vec(real(bound), len).prop_map(DVector::new)
+}
+
+/// the given shape with entries each in [-bound, bound]
+pub fn dmatrix(rows: usize, columns: usize, bound: Real) -> impl Strategy<Value = DMatrix> {
+    proptest::collection::vec(real(bound), rows * columns)
+        .prop_map(move |data| DMatrix::new(rows, columns, data))
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+use std::ops::{Div, Mul};
+
+/// the powers of the si base dimensions a quantity carries,
+/// so a velocity is length^1 time^-1. each power is an i8, and products, quotients
+/// and powers panic rather than wrap when one would leave -128..=127
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dimension {
+    pub length: i8,
+    pub mass: i8,
+    pub time: i8,
+    pub current: i8,
+    pub temperature: i8,
+    pub amount: i8,
+    pub luminosity: i8,
+}
+
+impl Dimension {
+    pub const DIMENSIONLESS: Self = Self::new(0, 0, 0, 0, 0, 0, 0);
+    pub const LENGTH: Self = Self::new(1, 0, 0, 0, 0, 0, 0);
+    pub const MASS: Self = Self::new(0, 1, 0, 0, 0, 0, 0);
+    pub const TIME: Self = Self::new(0, 0, 1, 0, 0, 0, 0);
+    pub const CURRENT: Self = Self::new(0, 0, 0, 1, 0, 0, 0);
+    pub const TEMPERATURE: Self = Self::new(0, 0, 0, 0, 1, 0, 0);
+    pub const AMOUNT: Self = Self::new(0, 0, 0, 0, 0, 1, 0);
+    pub const LUMINOSITY: Self = Self::new(0, 0, 0, 0, 0, 0, 1);
+
+    pub const fn new(
+        length: i8,
+        mass: i8,
+        time: i8,
+        current: i8,
+        temperature: i8,
+        amount: i8,
+        luminosity: i8,
+    ) -> Self {
+        Self {
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            amount,
+            luminosity,
+        }
+    }
+
+    /// the dimension of a product, adding the powers
+    /// ```
+    /// # use vmath::units::Dimension;
+    /// let area = Dimension::LENGTH.times(Dimension::LENGTH);
+    /// assert_eq!(area.length, 2);
+    /// ```
+    pub const fn times(self, rhs: Self) -> Self {
+        self.combine(rhs, 1)
+    }
+
+    /// the dimension of a quotient, subtracting the powers
+    pub const fn per(self, rhs: Self) -> Self {
+        self.combine(rhs, -1)
+    }
+
+    /// every power multiplied by n, panicking when one leaves the range of i8
+    /// ```
+    /// # use vmath::units::Dimension;
+    /// let frequency = Dimension::TIME.powi(-1);
+    /// assert_eq!(frequency.time, -1);
+    /// ```
+    /// ```should_panic
+    /// # use vmath::units::Dimension;
+    /// let _ = Dimension::LENGTH.powi(16).powi(8);
+    /// ```
+    pub const fn powi(self, n: i8) -> Self {
+        Self::DIMENSIONLESS.combine(self, n)
+    }
+
+    /// every power divided by n, or none unless they all divide evenly
+    /// ```
+    /// # use vmath::units::Dimension;
+    /// let area = Dimension::LENGTH.powi(2);
+    /// assert_eq!(area.root(2), Some(Dimension::LENGTH));
+    /// assert_eq!(Dimension::LENGTH.root(2), None);
+    /// ```
+    pub fn root(self, n: i8) -> Option<Self> {
+        let powers = self.powers();
+        // checked, as -128 / -1 would overflow
+        if powers.iter().any(|power| power.checked_rem(n) != Some(0)) {
+            return None;
+        }
+        let [length, mass, time, current, temperature, amount, luminosity] =
+            powers.map(|power| power / n);
+        Some(Self::new(
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            amount,
+            luminosity,
+        ))
+    }
+
+    pub fn is_dimensionless(self) -> bool {
+        self == Self::DIMENSIONLESS
+    }
+
+    fn powers(self) -> [i8; 7] {
+        [
+            self.length,
+            self.mass,
+            self.time,
+            self.current,
+            self.temperature,
+            self.amount,
+            self.luminosity,
+        ]
+    }
+
+    /// self + n rhs, power by power
+    const fn combine(self, rhs: Self, n: i8) -> Self {
+        Self::new(
+            combine(self.length, rhs.length, n),
+            combine(self.mass, rhs.mass, n),
+            combine(self.time, rhs.time, n),
+            combine(self.current, rhs.current, n),
+            combine(self.temperature, rhs.temperature, n),
+            combine(self.amount, rhs.amount, n),
+            combine(self.luminosity, rhs.luminosity, n),
+        )
+    }
+}
+
+/// a + n b, panicking where an i8 would wrap
+const fn combine(a: i8, b: i8, n: i8) -> i8 {
+    if let Some(nb) = b.checked_mul(n) {
+        if let Some(power) = a.checked_add(nb) {
+            return power;
+        }
+    }
+    panic!("the power of a dimension left the range of i8, -128 to 127");
+}
+
+impl Mul for Dimension {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.times(rhs)
+    }
+}
+
+impl Div for Dimension {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.per(rhs)
+    }
+}
+
+impl fmt::Display for Dimension {
+    /// the si base units with their powers, empty when dimensionless
+    /// ```
+    /// # use vmath::units::Dimension;
+    /// let force = Dimension::MASS * Dimension::LENGTH / Dimension::TIME.powi(2);
+    /// assert_eq!(force.to_string(), "m kg s^-2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbols = ["m", "kg", "s", "A", "K", "mol", "cd"];
+        let mut first = true;
+        for (symbol, power) in symbols.into_iter().zip(self.powers()) {
+            if power == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            f.write_str(symbol)?;
+            if power != 1 {
+                write!(f, "^{power}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! dimensional analysis, checked at run time, for reals and the fixed-size vectors.
+//! a `Quantity` stores its value in si base units alongside the powers of the
+//! base dimensions, so mixing up meters and seconds is an error rather than a number
+//! ```
+//! use vmath::units::Unit;
+//!
+//! let distance = 100.0 * Unit::METER;
+//! let time = 9.58 * Unit::SECOND;
+//! let speed = distance / time;
+//! let kph = speed.value_in(Unit::KILOMETER / Unit::HOUR).unwrap();
+//! assert!((kph - 37.578).abs() < 1e-3);
+//! assert!(speed.value_in(Unit::METER).is_err());
+//! ```
+
+pub mod dimension;
+pub mod quantity;
+pub mod unit;
+
+pub use crate::units::dimension::Dimension;
+pub use crate::units::quantity::Quantity;
+pub use crate::units::unit::Unit;
@@ -0,0 +1,300 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::linalg::{Vec3, Vector};
+use crate::numbers::Real;
+use crate::units::{Dimension, Unit};
+use crate::Error;
+
+/// a value in si base units, real or vector, tagged with its dimension,
+/// which arithmetic carries along and checks at run time.
+/// adding or subtracting quantities of different dimensions panics,
+/// `try_add` and `try_sub` return an error instead
+/// ```
+/// # use vmath::linalg::Vec3;
+/// # use vmath::units::Unit;
+/// let mass = 2.0 * Unit::KILOGRAM;
+/// let acceleration = Vec3::new(0.0, 0.0, -9.81) * (Unit::METER / Unit::SECOND.powi(2));
+/// let force = acceleration * mass;
+/// assert_eq!(force.dimension, Unit::NEWTON.dimension);
+/// assert_eq!(force.value_in(Unit::NEWTON), Ok(Vec3::new(0.0, 0.0, -19.62)));
+///
+/// let time = 3.0 * Unit::SECOND;
+/// assert!(force.try_add(acceleration * time * mass / time).is_ok());
+/// assert!(force.try_add(acceleration).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantity<T = Real> {
+    pub value: T,
+    pub dimension: Dimension,
+}
+
+impl<T> Quantity<T> {
+    /// value measured in unit
+    pub fn new(value: T, unit: Unit) -> Self
+    where
+        T: Mul<Real, Output = T>,
+    {
+        Self {
+            value: value * unit.scale,
+            dimension: unit.dimension,
+        }
+    }
+
+    pub fn dimensionless(value: T) -> Self {
+        Self {
+            value,
+            dimension: Dimension::DIMENSIONLESS,
+        }
+    }
+
+    /// the value measured in unit, an error if the unit's dimension differs
+    /// ```
+    /// # use vmath::units::Unit;
+    /// let distance = 2.0 * Unit::KILOMETER;
+    /// assert_eq!(distance.value_in(Unit::METER), Ok(2000.0));
+    /// assert!(distance.value_in(Unit::SECOND).is_err());
+    /// ```
+    pub fn value_in(self, unit: Unit) -> Result<T, Error>
+    where
+        T: Div<Real, Output = T>,
+    {
+        if self.dimension == unit.dimension {
+            Ok(self.value / unit.scale)
+        } else {
+            Err(Error::DimensionMismatch)
+        }
+    }
+
+    pub fn try_add(self, rhs: Self) -> Result<Self, Error>
+    where
+        T: Add<Output = T>,
+    {
+        self.same_dimension(rhs.dimension)?;
+        Ok(Self {
+            value: self.value + rhs.value,
+            dimension: self.dimension,
+        })
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, Error>
+    where
+        T: Sub<Output = T>,
+    {
+        self.same_dimension(rhs.dimension)?;
+        Ok(Self {
+            value: self.value - rhs.value,
+            dimension: self.dimension,
+        })
+    }
+
+    fn same_dimension(&self, dimension: Dimension) -> Result<(), Error> {
+        if self.dimension == dimension {
+            Ok(())
+        } else {
+            Err(Error::DimensionMismatch)
+        }
+    }
+}
+
+impl<V: Vector> Quantity<V> {
+    /// ```
+    /// # use vmath::linalg::Vec2;
+    /// # use vmath::units::Unit;
+    /// let velocity = Vec2::new(3.0, 4.0) * (Unit::METER / Unit::SECOND);
+    /// assert_eq!(velocity.length().value_in(Unit::METER / Unit::SECOND), Ok(5.0));
+    /// ```
+    pub fn length(self) -> Quantity {
+        Quantity {
+            value: self.value.length(),
+            dimension: self.dimension,
+        }
+    }
+
+    pub fn dot(self, rhs: Self) -> Quantity {
+        Quantity {
+            value: self.value.dot(rhs.value),
+            dimension: self.dimension * rhs.dimension,
+        }
+    }
+
+    /// the i-th component, which has the vector's dimension
+    pub fn component(self, i: usize) -> Quantity {
+        Quantity {
+            value: self.value[i],
+            dimension: self.dimension,
+        }
+    }
+}
+
+impl Quantity<Vec3> {
+    /// ```
+    /// # use vmath::linalg::Vec3;
+    /// # use vmath::units::Unit;
+    /// let r = Vec3::new(2.0, 0.0, 0.0) * Unit::METER;
+    /// let f = Vec3::new(0.0, 5.0, 0.0) * Unit::NEWTON;
+    /// let torque = r.cross(f);
+    /// assert_eq!(torque.value_in(Unit::JOULE), Ok(Vec3::new(0.0, 0.0, 10.0)));
+    /// ```
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.cross(rhs.value),
+            dimension: self.dimension * rhs.dimension,
+        }
+    }
+}
+
+impl Quantity {
+    pub fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+            ..self
+        }
+    }
+
+    /// panics when a power of the dimension leaves the range of i8
+    pub fn powi(self, n: i8) -> Self {
+        Self {
+            value: self.value.powi(n.into()),
+            dimension: self.dimension.powi(n),
+        }
+    }
+
+    /// a `DomainError` unless every power of the dimension is even
+    /// ```
+    /// # use vmath::units::Unit;
+    /// let area = 9.0 * Unit::METER.powi(2);
+    /// assert_eq!(area.sqrt().unwrap().value_in(Unit::METER), Ok(3.0));
+    /// assert!((9.0 * Unit::METER).sqrt().is_err());
+    /// ```
+    pub fn sqrt(self) -> Result<Self, Error> {
+        Ok(Self {
+            value: self.value.sqrt(),
+            dimension: self.dimension.root(2).ok_or(Error::DomainError)?,
+        })
+    }
+}
+
+impl<T: Add<Output = T>> Add for Quantity<T> {
+    type Output = Self;
+    /// panics if the dimensions differ
+    fn add(self, rhs: Self) -> Self {
+        self.try_add(rhs).expect("dimensions differ")
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Quantity<T> {
+    type Output = Self;
+    /// panics if the dimensions differ
+    fn sub(self, rhs: Self) -> Self {
+        self.try_sub(rhs).expect("dimensions differ")
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Quantity<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            dimension: self.dimension,
+        }
+    }
+}
+
+impl<T: Mul<U>, U> Mul<Quantity<U>> for Quantity<T> {
+    type Output = Quantity<T::Output>;
+    fn mul(self, rhs: Quantity<U>) -> Self::Output {
+        Quantity {
+            value: self.value * rhs.value,
+            dimension: self.dimension * rhs.dimension,
+        }
+    }
+}
+
+impl<T: Div<U>, U> Div<Quantity<U>> for Quantity<T> {
+    type Output = Quantity<T::Output>;
+    fn div(self, rhs: Quantity<U>) -> Self::Output {
+        Quantity {
+            value: self.value / rhs.value,
+            dimension: self.dimension / rhs.dimension,
+        }
+    }
+}
+
+impl<T: Mul<Real, Output = T>> Mul<Real> for Quantity<T> {
+    type Output = Self;
+    fn mul(self, rhs: Real) -> Self {
+        Self {
+            value: self.value * rhs,
+            dimension: self.dimension,
+        }
+    }
+}
+
+impl<T: Div<Real, Output = T>> Div<Real> for Quantity<T> {
+    type Output = Self;
+    fn div(self, rhs: Real) -> Self {
+        Self {
+            value: self.value / rhs,
+            dimension: self.dimension,
+        }
+    }
+}
+
+impl<T: Mul<Real, Output = T>> Mul<Unit> for Quantity<T> {
+    type Output = Self;
+    /// ```
+    /// # use vmath::units::Unit;
+    /// let energy = 2.0 * Unit::NEWTON * Unit::METER;
+    /// assert_eq!(energy.value_in(Unit::JOULE), Ok(2.0));
+    /// ```
+    fn mul(self, unit: Unit) -> Self {
+        Self {
+            value: self.value * unit.scale,
+            dimension: self.dimension * unit.dimension,
+        }
+    }
+}
+
+impl<T: Div<Real, Output = T>> Div<Unit> for Quantity<T> {
+    type Output = Self;
+    fn div(self, unit: Unit) -> Self {
+        Self {
+            value: self.value / unit.scale,
+            dimension: self.dimension / unit.dimension,
+        }
+    }
+}
+
+impl PartialOrd for Quantity {
+    /// none when the dimensions differ, as well as when a value is nan
+    /// ```
+    /// # use vmath::units::Unit;
+    /// assert!(1.0 * Unit::MILE > 1.0 * Unit::KILOMETER);
+    /// assert_eq!((1.0 * Unit::MILE).partial_cmp(&(1.0 * Unit::HOUR)), None);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.dimension == other.dimension {
+            self.value.partial_cmp(&other.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Quantity<T> {
+    /// the value in si base units followed by them
+    /// ```
+    /// # use vmath::units::Unit;
+    /// assert_eq!((1.5 * Unit::KILOMETER / Unit::SECOND).to_string(), "1500 m s^-1");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.dimension.is_dimensionless() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.dimension)
+        }
+    }
+}
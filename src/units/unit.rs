@@ -0,0 +1,115 @@
+use std::ops::{Div, Mul};
+
+use crate::linalg::{Vec2, Vec3, Vec4};
+use crate::numbers::{Complex, Real};
+use crate::units::{Dimension, Quantity};
+
+/// a unit of measure, as the number of si base units it is worth and its dimension.
+/// units are pure scales, so temperatures are kelvin or differences, never celsius readings
+/// ```
+/// # use vmath::units::Unit;
+/// let speed = 90.0 * (Unit::KILOMETER / Unit::HOUR);
+/// assert_eq!(speed.value_in(Unit::METER / Unit::SECOND), Ok(25.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unit {
+    pub scale: Real,
+    pub dimension: Dimension,
+}
+
+impl Unit {
+    pub const ONE: Self = Self::new(1.0, Dimension::DIMENSIONLESS);
+    pub const RADIAN: Self = Self::ONE;
+    pub const DEGREE: Self = Self::new(std::f64::consts::PI / 180.0, Dimension::DIMENSIONLESS);
+
+    pub const METER: Self = Self::new(1.0, Dimension::LENGTH);
+    pub const KILOMETER: Self = Self::METER.scaled(1e3);
+    pub const CENTIMETER: Self = Self::METER.scaled(1e-2);
+    pub const MILLIMETER: Self = Self::METER.scaled(1e-3);
+    pub const INCH: Self = Self::METER.scaled(0.0254);
+    pub const FOOT: Self = Self::INCH.scaled(12.0);
+    pub const MILE: Self = Self::FOOT.scaled(5280.0);
+
+    pub const KILOGRAM: Self = Self::new(1.0, Dimension::MASS);
+    pub const GRAM: Self = Self::KILOGRAM.scaled(1e-3);
+    pub const POUND: Self = Self::KILOGRAM.scaled(0.45359237);
+
+    pub const SECOND: Self = Self::new(1.0, Dimension::TIME);
+    pub const MILLISECOND: Self = Self::SECOND.scaled(1e-3);
+    pub const MINUTE: Self = Self::SECOND.scaled(60.0);
+    pub const HOUR: Self = Self::MINUTE.scaled(60.0);
+
+    pub const AMPERE: Self = Self::new(1.0, Dimension::CURRENT);
+    pub const KELVIN: Self = Self::new(1.0, Dimension::TEMPERATURE);
+    pub const MOLE: Self = Self::new(1.0, Dimension::AMOUNT);
+    pub const CANDELA: Self = Self::new(1.0, Dimension::LUMINOSITY);
+
+    pub const HERTZ: Self = Self::ONE.per(Self::SECOND);
+    pub const NEWTON: Self = Self::KILOGRAM
+        .times(Self::METER)
+        .per(Self::SECOND.times(Self::SECOND));
+    pub const PASCAL: Self = Self::NEWTON.per(Self::METER.times(Self::METER));
+    pub const JOULE: Self = Self::NEWTON.times(Self::METER);
+    pub const WATT: Self = Self::JOULE.per(Self::SECOND);
+    pub const COULOMB: Self = Self::AMPERE.times(Self::SECOND);
+    pub const VOLT: Self = Self::WATT.per(Self::AMPERE);
+
+    pub const fn new(scale: Real, dimension: Dimension) -> Self {
+        Self { scale, dimension }
+    }
+
+    /// the unit worth factor of this one, as a kilometer is a meter scaled by 1000
+    pub const fn scaled(self, factor: Real) -> Self {
+        Self::new(self.scale * factor, self.dimension)
+    }
+
+    pub const fn times(self, rhs: Self) -> Self {
+        Self::new(self.scale * rhs.scale, self.dimension.times(rhs.dimension))
+    }
+
+    pub const fn per(self, rhs: Self) -> Self {
+        Self::new(self.scale / rhs.scale, self.dimension.per(rhs.dimension))
+    }
+
+    /// panics when a power of the dimension leaves the range of i8
+    /// ```
+    /// # use vmath::units::Unit;
+    /// let liter = Unit::CENTIMETER.scaled(10.0).powi(3);
+    /// assert!((liter.scale - 1e-3).abs() < 1e-18);
+    /// assert_eq!(liter.dimension.length, 3);
+    /// ```
+    pub fn powi(self, n: i8) -> Self {
+        Self::new(self.scale.powi(n.into()), self.dimension.powi(n))
+    }
+}
+
+impl Mul for Unit {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.times(rhs)
+    }
+}
+
+impl Div for Unit {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.per(rhs)
+    }
+}
+
+/// a value times a unit is a quantity of it
+macro_rules! measured {
+    ($($value:ty),*) => {
+        $(
+            impl Mul<Unit> for $value {
+                type Output = Quantity<$value>;
+                fn mul(self, unit: Unit) -> Quantity<$value> {
+                    Quantity::new(self, unit)
+                }
+            }
+        )*
+    };
+}
+
+measured!(Real, Complex, Vec2, Vec3, Vec4);
@@ -0,0 +1,127 @@
+//! small scalar helpers for interpolating, easing, and wrapping reals,
+//! with the argument order of their glsl namesakes where they have one.
+//! clamping is `f64::clamp` from the standard library
+//!
+//! ```
+//! use vmath::util::{lerp, remap, smoothstep};
+//!
+//! // a fade between two colours as a cursor crosses a 200 pixel band
+//! let t = smoothstep(100.0, 300.0, 250.0);
+//! let brightness = lerp(0.2, 0.8, t);
+//! assert!((brightness - 0.70625).abs() < 1e-15);
+//! // celsius to fahrenheit
+//! assert_eq!(remap(100.0, (0.0, 100.0), (32.0, 212.0)), 212.0);
+//! ```
+
+use crate::numbers::Real;
+
+/// the straight line from a at t = 0 to b at t = 1, extrapolating beyond them,
+/// exact at both ends and monotonic in t
+/// ```
+/// # use vmath::util::lerp;
+/// assert_eq!(lerp(2.0, 6.0, 0.25), 3.0);
+/// assert_eq!(lerp(0.1, 0.7, 1.0), 0.7);
+/// assert_eq!(lerp(2.0, 6.0, 1.5), 8.0);
+/// assert_eq!(lerp(5.0, 5.0, 0.3), 5.0);
+/// ```
+pub fn lerp(a: Real, b: Real, t: Real) -> Real {
+    // measured from the nearer end, so that end is hit exactly
+    if t < 0.5 {
+        a + (b - a) * t
+    } else {
+        b - (b - a) * (1.0 - t)
+    }
+}
+
+/// the t at which lerp(a, b, t) is x, so 0 at a and 1 at b,
+/// infinite or NaN when a = b
+/// ```
+/// # use vmath::util::inverse_lerp;
+/// assert_eq!(inverse_lerp(2.0, 6.0, 3.0), 0.25);
+/// assert_eq!(inverse_lerp(2.0, 6.0, 10.0), 2.0);
+/// assert_eq!(inverse_lerp(6.0, 2.0, 6.0), 0.0);
+/// assert!(inverse_lerp(1.0, 1.0, 1.0).is_nan());
+/// ```
+pub fn inverse_lerp(a: Real, b: Real, x: Real) -> Real {
+    (x - a) / (b - a)
+}
+
+/// x carried linearly from the range from.0..from.1 to the range to.0..to.1,
+/// extrapolating outside it; either range may run backwards
+/// ```
+/// # use vmath::util::remap;
+/// assert_eq!(remap(5.0, (0.0, 10.0), (100.0, 200.0)), 150.0);
+/// assert_eq!(remap(0.25, (0.0, 1.0), (1.0, -1.0)), 0.5);
+/// assert_eq!(remap(-1.0, (0.0, 1.0), (0.0, 10.0)), -10.0);
+/// ```
+pub fn remap(x: Real, from: (Real, Real), to: (Real, Real)) -> Real {
+    lerp(to.0, to.1, inverse_lerp(from.0, from.1, x))
+}
+
+/// 0 below the edge and 1 from it on
+/// ```
+/// # use vmath::util::step;
+/// assert_eq!(step(1.0, 0.5), 0.0);
+/// assert_eq!(step(1.0, 1.0), 1.0);
+/// assert_eq!(step(1.0, 2.0), 1.0);
+/// ```
+pub fn step(edge: Real, x: Real) -> Real {
+    if x < edge {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+/// 0 up to edge0 and 1 from edge1, between them the cubic 3t^2 - 2t^3
+/// in t = inverse_lerp(edge0, edge1, x), which is flat at both edges
+/// ```
+/// # use vmath::util::smoothstep;
+/// assert_eq!(smoothstep(0.0, 2.0, -1.0), 0.0);
+/// assert_eq!(smoothstep(0.0, 2.0, 0.0), 0.0);
+/// assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+/// assert_eq!(smoothstep(0.0, 2.0, 0.5), 0.15625);
+/// assert_eq!(smoothstep(0.0, 2.0, 2.0), 1.0);
+/// // reversed edges fall instead of rising
+/// assert_eq!(smoothstep(2.0, 0.0, 0.5), 0.84375);
+/// ```
+pub fn smoothstep(edge0: Real, edge1: Real, x: Real) -> Real {
+    let t = inverse_lerp(edge0, edge1, x).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// ken perlin's quintic 6t^5 - 15t^4 + 10t^3 in place of smoothstep's cubic,
+/// whose second derivative also vanishes at the edges
+/// ```
+/// # use vmath::util::smootherstep;
+/// assert_eq!(smootherstep(0.0, 1.0, -0.5), 0.0);
+/// assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+/// assert_eq!(smootherstep(0.0, 1.0, 1.0), 1.0);
+/// ```
+pub fn smootherstep(edge0: Real, edge1: Real, x: Real) -> Real {
+    let t = inverse_lerp(edge0, edge1, x).clamp(0.0, 1.0);
+    t * t * t * (t * (6.0 * t - 15.0) + 10.0)
+}
+
+/// x shifted by a whole number of periods hi - lo into [lo, hi),
+/// for angles, texture coordinates, and periodic boundaries
+/// ```
+/// # use vmath::util::wrap;
+/// # use std::f64::consts::PI;
+/// assert_eq!(wrap(370.0, 0.0, 360.0), 10.0);
+/// assert_eq!(wrap(-30.0, 0.0, 360.0), 330.0);
+/// assert_eq!(wrap(360.0, 0.0, 360.0), 0.0);
+/// assert!((wrap(3.0 * PI, -PI, PI) + PI).abs() < 1e-15);
+/// // just below lo rounds up to hi, which is not in the range, so it is lo
+/// assert_eq!(wrap(-1e-20, 0.0, 1.0), 0.0);
+/// ```
+pub fn wrap(x: Real, lo: Real, hi: Real) -> Real {
+    let width = hi - lo;
+    let offset = (x - lo).rem_euclid(width);
+    if offset == width {
+        lo
+    } else {
+        lo + offset
+    }
+}
@@ -0,0 +1,274 @@
+//! javascript bindings through wasm-bindgen, built with the `wasm-bindgen` feature for
+//! `wasm32-unknown-unknown` as a cdylib, which the manifest leaves to the build, for example
+//! `cargo rustc --lib --crate-type cdylib --release --target wasm32-unknown-unknown
+//! --features wasm-bindgen` and then `wasm-bindgen` on the module
+//!
+//! the classes wrap the rust types one for one: `Complex`, `Vec2`, `Vec3`, `Vec4`, and a
+//! dense `Matrix`, whose entries cross the boundary as a row-major `Float64Array`.
+//! mismatched shapes throw, and a singular matrix gives `undefined` from `inverse` and `solve`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::linalg::{DMatrix, DVector, Vec2, Vec3, Vec4, Vector};
+use crate::numbers::{Complex, Real};
+
+#[wasm_bindgen(js_name = Complex)]
+#[derive(Clone, Copy)]
+pub struct JsComplex(Complex);
+
+#[wasm_bindgen(js_class = Complex)]
+impl JsComplex {
+    #[wasm_bindgen(constructor)]
+    pub fn new(real: Real, imaginary: Real) -> Self {
+        Self(Complex::new(real, imaginary))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn real(&self) -> Real {
+        self.0.real
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn imaginary(&self) -> Real {
+        self.0.imaginary
+    }
+
+    pub fn add(&self, rhs: &JsComplex) -> Self {
+        Self(self.0 + rhs.0)
+    }
+
+    pub fn sub(&self, rhs: &JsComplex) -> Self {
+        Self(self.0 - rhs.0)
+    }
+
+    pub fn mul(&self, rhs: &JsComplex) -> Self {
+        Self(self.0 * rhs.0)
+    }
+
+    pub fn div(&self, rhs: &JsComplex) -> Self {
+        Self(self.0 / rhs.0)
+    }
+
+    pub fn scale(&self, factor: Real) -> Self {
+        Self(self.0 * factor)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self(-self.0)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self(self.0.conjugate())
+    }
+
+    pub fn norm(&self) -> Real {
+        self.0.norm()
+    }
+
+    /// throws for zero, which has no angle
+    pub fn angle(&self) -> Result<Real, JsError> {
+        Ok(self.0.angle()?)
+    }
+
+    pub fn equals(&self, rhs: &JsComplex) -> bool {
+        self.0 == rhs.0
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        format!("{} + {}i", self.0.real, self.0.imaginary)
+    }
+}
+
+/// a javascript class for one of the fixed-size vectors, with its components as properties
+macro_rules! vector_class {
+    ($class:ident, $name:ident, $vector:ident, $($component:ident),+) => {
+        #[wasm_bindgen(js_name = $name)]
+        #[derive(Clone, Copy)]
+        pub struct $class($vector);
+
+        #[wasm_bindgen(js_class = $name)]
+        impl $class {
+            #[wasm_bindgen(constructor)]
+            pub fn new($($component: Real),+) -> Self {
+                Self($vector { $($component),+ })
+            }
+
+            $(
+                #[wasm_bindgen(getter)]
+                pub fn $component(&self) -> Real {
+                    self.0.$component
+                }
+            )+
+
+            pub fn add(&self, rhs: &$class) -> Self {
+                Self(self.0 + rhs.0)
+            }
+
+            pub fn sub(&self, rhs: &$class) -> Self {
+                Self(self.0 - rhs.0)
+            }
+
+            pub fn scale(&self, factor: Real) -> Self {
+                Self(self.0 * factor)
+            }
+
+            pub fn neg(&self) -> Self {
+                Self(-self.0)
+            }
+
+            pub fn dot(&self, rhs: &$class) -> Real {
+                self.0.dot(rhs.0)
+            }
+
+            pub fn length(&self) -> Real {
+                self.0.length()
+            }
+
+            pub fn normalize(&self) -> Self {
+                Self(self.0.normalize())
+            }
+
+            pub fn distance(&self, other: &$class) -> Real {
+                self.0.distance(other.0)
+            }
+
+            pub fn lerp(&self, other: &$class, t: Real) -> Self {
+                Self(self.0.lerp(other.0, t))
+            }
+
+            pub fn equals(&self, rhs: &$class) -> bool {
+                self.0 == rhs.0
+            }
+
+            #[wasm_bindgen(js_name = toArray)]
+            pub fn to_array(&self) -> Vec<Real> {
+                vec![$(self.0.$component),+]
+            }
+        }
+    };
+}
+
+vector_class!(JsVec2, Vec2, Vec2, x, y);
+vector_class!(JsVec3, Vec3, Vec3, x, y, z);
+vector_class!(JsVec4, Vec4, Vec4, x, y, z, w);
+
+#[wasm_bindgen(js_class = Vec3)]
+impl JsVec3 {
+    pub fn cross(&self, rhs: &JsVec3) -> Self {
+        Self(self.0.cross(rhs.0))
+    }
+}
+
+#[wasm_bindgen(js_name = Matrix)]
+#[derive(Clone)]
+pub struct JsMatrix(DMatrix);
+
+#[wasm_bindgen(js_class = Matrix)]
+impl JsMatrix {
+    /// from rows * columns entries in row-major order
+    #[wasm_bindgen(constructor)]
+    pub fn new(rows: usize, columns: usize, data: Vec<Real>) -> Result<JsMatrix, JsError> {
+        if data.len() != rows * columns {
+            return Err(JsError::new("entry count does not match the shape"));
+        }
+        Ok(Self(DMatrix::new(rows, columns, data)))
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self(DMatrix::identity(n))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.0.rows
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn columns(&self) -> usize {
+        self.0.columns
+    }
+
+    /// a copy of the entries in row-major order
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Vec<Real> {
+        self.0.data.clone()
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<Real, JsError> {
+        if i >= self.0.rows || j >= self.0.columns {
+            return Err(JsError::new("matrix index out of range"));
+        }
+        Ok(self.0[(i, j)])
+    }
+
+    pub fn add(&self, rhs: &JsMatrix) -> Result<JsMatrix, JsError> {
+        check_shapes(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() + rhs.0.clone()))
+    }
+
+    pub fn sub(&self, rhs: &JsMatrix) -> Result<JsMatrix, JsError> {
+        check_shapes(&self.0, &rhs.0)?;
+        Ok(Self(self.0.clone() - rhs.0.clone()))
+    }
+
+    pub fn scale(&self, factor: Real) -> Self {
+        Self(self.0.clone() * factor)
+    }
+
+    pub fn mul(&self, rhs: &JsMatrix) -> Result<JsMatrix, JsError> {
+        if self.0.columns != rhs.0.rows {
+            return Err(JsError::new("matrix shapes do not fit"));
+        }
+        Ok(Self(&self.0 * &rhs.0))
+    }
+
+    #[wasm_bindgen(js_name = mulVector)]
+    pub fn mul_vector(&self, v: Vec<Real>) -> Result<Vec<Real>, JsError> {
+        if self.0.columns != v.len() {
+            return Err(JsError::new("matrix and vector shapes do not fit"));
+        }
+        Ok((&self.0 * &DVector::new(v)).data)
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self(self.0.transpose())
+    }
+
+    pub fn determinant(&self) -> Result<Real, JsError> {
+        check_square(&self.0)?;
+        Ok(self.0.determinant())
+    }
+
+    pub fn inverse(&self) -> Result<Option<JsMatrix>, JsError> {
+        check_square(&self.0)?;
+        Ok(self.0.inverse().ok().map(Self))
+    }
+
+    /// the x with self x = b
+    pub fn solve(&self, b: Vec<Real>) -> Result<Option<Vec<Real>>, JsError> {
+        check_square(&self.0)?;
+        if self.0.rows != b.len() {
+            return Err(JsError::new("matrix and vector shapes do not fit"));
+        }
+        Ok(self.0.solve(&DVector::new(b)).ok().map(|x| x.data))
+    }
+
+    pub fn equals(&self, rhs: &JsMatrix) -> bool {
+        self.0 == rhs.0
+    }
+}
+
+fn check_shapes(a: &DMatrix, b: &DMatrix) -> Result<(), JsError> {
+    if (a.rows, a.columns) != (b.rows, b.columns) {
+        return Err(JsError::new("matrix shapes differ"));
+    }
+    Ok(())
+}
+
+fn check_square(m: &DMatrix) -> Result<(), JsError> {
+    if !m.is_square() {
+        return Err(JsError::new("matrix is not square"));
+    }
+    Ok(())
+}